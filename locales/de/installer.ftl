@@ -0,0 +1,445 @@
+## DesktopMate Installer — Deutsch
+
+app-title = DesktopMate-Installer
+press-any-key = Beliebige Taste zum Beenden drücken...
+config-ignored = installer.toml wird ignoriert: { $error }
+proxy-invalid = Der Proxy wird ignoriert: { $error }
+ca-cert-invalid = Das CA-Zertifikat wird ignoriert: { $error }
+theme-color-unknown = Unbekannte Designfarbe "{ $color }" in installer.toml; die Farbe der Vorlage wird beibehalten.
+language-unsupported = Sprache „{ $language }“ ist nicht verfügbar; die Systemsprache wird verwendet. Verfügbar: { $available }
+
+## Installer self-update
+
+update-check-failed = Suche nach Installer-Updates fehlgeschlagen: { $error }
+update-available = Ein neuerer Installer ist verfügbar: Installierte Version: { $installed }, Neueste Version: { $latest }
+update-prompt = Installer jetzt aktualisieren? (Y/N):{" "}
+update-downloading = Installer-Update wird heruntergeladen...
+update-rejected = FEHLER: Installer-Update wird nicht ausgeführt: { $reason }
+update-reason-no-key = eine minisign-Signatur wurde veröffentlicht, aber dieser Build enthält keinen Prüfschlüssel
+update-reason-bad-key = ungültiger eingebauter öffentlicher Schlüssel: { $error }
+update-reason-bad-signature-file = Signatur nicht lesbar: { $error }
+update-reason-mismatch = minisign-Signatur stimmt nicht überein: { $error }
+update-reason-unsigned = der Download hat keine gültige Signatur
+update-reason-wrong-publisher = sie ist von { $publisher } signiert, nicht von { $expected }
+update-applied = Installer auf { $version } aktualisiert. Neustart...
+
+## Classic prompts
+
+prompt-yes = Ja
+prompt-no = Nein
+prompt-timed-out = Keine Antwort innerhalb der Zeit; es wird mit dem Standardwert ({ $answer }) fortgefahren.
+err-unattended-question = diese Frage hat keine Standardantwort und kann in einem unbeaufsichtigten Lauf nicht beantwortet werden: { $question }
+first-run-ownership-prompt = Besitzen Sie DesktopMate auf Steam? (Y/N):{" "}
+first-run-ownership-declined = Dieses Installationsprogramm ist nur für Besitzer von DesktopMate. Bitte kaufen Sie das Spiel zuerst auf Steam.
+unattended-ownership-hint = Bestätigen Sie in unbeaufsichtigten Läufen den Besitz des Spiels mit --confirm-ownership.
+prompt-install-path = Installationspfad eingeben (Standard: { $path }):{" "}
+install-dir-set = Installationsverzeichnis: { $path }
+download-size = etwa { $mb } MB
+download-size-unknown = (zzgl. { $items }, Größe unbekannt)
+download-estimate = Geschätzter Download: { $size }
+download-estimate-continue = Mit der Installation fortfahren? (Y/N):{" "}
+credentials-failed = Die Anmeldedatei kann nicht verwendet werden: { $error }
+credentials-no-password = FEHLER: --steam-user benötigt in einem unbeaufsichtigten Lauf das Passwort in der Umgebungsvariable { $var }.
+err-credentials-read = { $path } konnte nicht gelesen werden: { $error }
+err-credentials-permissions = { $path } ist für { $group } lesbar. Beschränken Sie die Datei zuerst auf Ihr Konto, z. B. icacls "{ $path }" /inheritance:r /grant:r "%USERNAME%:R"
+err-credentials-incomplete = { $path } enthält kein "login" und "password" für "machine steam" oder einen default-Eintrag.
+prompt-steam-user = Steam-Benutzernamen eingeben:{" "}
+steam-user-required = Ein Steam-Benutzername ist erforderlich.
+prompt-steam-password = Steam-Passwort eingeben:{" "}
+install-failed = Installation fehlgeschlagen: { $error }
+rollback-prompt = Änderungen dieses Durchlaufs am Spielordner und an den Verknüpfungen rückgängig machen? (Y/N):{" "}
+rollback-declined = Änderungen wurden beibehalten. Starten Sie den Installer erneut, um es noch einmal zu versuchen.
+rollback-done = { $count } Änderung(en) rückgängig gemacht; der Spielordner ist wieder im Zustand vor diesem Durchlauf.
+rollback-incomplete = Einige Änderungen konnten nicht rückgängig gemacht werden: { $error }
+injection-prompt = DesktopMate für bis zu { $seconds } Sekunden starten, um zu prüfen, ob MelonLoader geladen wird?
+injection-running = DesktopMate wird gestartet, MelonLoader wird erwartet...
+injection-ok = MelonLoader { $version } wurde in das Spiel geladen.
+injection-game-exited = DesktopMate wurde beendet (Exit-Code { $code }), bevor MelonLoader geladen wurde. Prüfen Sie Ihr Antivirenprogramm und ob die Visual C++-Laufzeit installiert ist.
+injection-no-log = MelonLoader ist nicht innerhalb von { $seconds } Sekunden gestartet; Mods werden nicht geladen. Prüfen Sie Ihr Antivirenprogramm oder führen Sie den Befehl remove-melonloader aus und installieren Sie erneut.
+injection-failed = DesktopMate konnte für die Prüfung nicht gestartet werden: { $error }
+launch-prompt = DesktopMate jetzt starten?
+launch-console = Ja, mit MelonLoader-Konsole
+launch-no-console = Ja, ohne Konsole
+launch-no = Nein
+launch-choose = Wählen Sie 1-3:{" "}
+launch-started = DesktopMate wird gestartet.
+launch-failed = DesktopMate konnte nicht gestartet werden: { $error }
+install-complete-exit = Installation abgeschlossen. Beliebige Taste zum Beenden drücken.
+summary-title = Zusammenfassung der Installation
+summary-install-dir = Installationsverzeichnis: { $path }
+summary-game-build = Spiel-Manifest: { $manifest }
+summary-melonloader = MelonLoader: { $version }
+summary-mod = { $mod }: { $version }
+summary-unknown = unbekannt
+
+## DepotDownloader and the game depot
+
+dd-missing = DepotDownloader.exe nicht gefunden! Wird jetzt heruntergeladen...
+dd-download-failed = FEHLER: DepotDownloader konnte nicht heruntergeladen werden! { $error }
+dd-extracting = DepotDownloader wird entpackt...
+dd-extract-failed = FEHLER: DepotDownloader konnte nicht entpackt werden! { $error }
+dd-still-missing = FEHLER: DepotDownloader.exe auch nach dem Entpacken nicht gefunden!
+dd-ready = DepotDownloader erfolgreich heruntergeladen und entpackt.
+dd-version-unknown = eine unbekannte Version
+dd-upgrade-prompt = DepotDownloader { $installed } ist installiert; { $latest } ist verfügbar. Möchten Sie aktualisieren? (Y/N):{" "}
+dd-switching = DepotDownloader { $installed } wird durch die festgelegte Version { $version } ersetzt...
+dotnet-missing = DepotDownloader benötigt die .NET-Laufzeit { $runtime }, die nicht installiert ist.
+dotnet-install-prompt = Möchten Sie das Installationsprogramm von Microsoft jetzt herunterladen und ausführen? (Y/N):{" "}
+dotnet-downloading = Das Installationsprogramm der .NET-Laufzeit wird heruntergeladen...
+dotnet-installing = Die .NET-Laufzeit wird installiert...
+depot-downloading = DesktopMate-Depot wird heruntergeladen (über DepotDownloader)...
+depot-failed = FEHLER: DepotDownloader ist auf einen Fehler gestoßen. Exit-Code = { $code }
+depot-not-owned = Laut Steam besitzt dieses Konto DesktopMate nicht. Melden Sie sich mit dem Steam-Konto an, mit dem das Spiel gekauft wurde; andere oder über die Familienbibliothek geteilte Konten können es nicht herunterladen.
+depot-login-rejected = Steam hat die Anmeldung abgelehnt. Prüfen Sie Benutzername und Passwort und geben Sie bei Aufforderung den aktuellen Steam-Guard-Code ein.
+depot-complete = Depot-Download abgeschlossen.
+game-update-available = DesktopMate-Build { $installed } ist installiert; Build { $latest } ist verfügbar.
+game-update-prompt = Das Spiel jetzt aktualisieren? Goldberg wird danach erneut angewendet und die Mods werden geprüft. (Y/N){" "}
+game-updating = DesktopMate wird auf Build { $build } aktualisiert...
+game-validating = Alle Spieldateien werden geprüft und beschädigte erneut heruntergeladen...
+check-updates-current = { $component }: { $installed } (aktuell)
+check-updates-outdated = { $component }: { $installed } installiert, { $latest } verfügbar
+check-updates-unknown = { $component }: installierte Version unbekannt
+check-updates-none = Alles ist auf dem neuesten Stand.
+update-failed = FEHLER: Nach Updates konnte nicht gesucht werden: { $error }
+check-updates-install = Die Updates jetzt installieren? (Y/N){" "}
+watch-started = { $path } wird auf Updates überwacht. Jetzt wird geprüft, danach einmal täglich; schließen Sie dieses Fenster oder drücken Sie Strg+C zum Beenden.
+watch-toast-title = DesktopMate-Updates verfügbar — führen Sie das Installationsprogramm aus
+watch-check-failed = Update-Prüfung fehlgeschlagen: { $error }
+package-fetching = { $file } wird gepackt...
+package-done = Offline-Paket in { $path } erstellt. Geben Sie den ganzen Ordner weiter; das Spiel selbst wird weiterhin von Steam heruntergeladen.
+package-failed = Das Offline-Paket konnte nicht erstellt werden: { $error }
+package-needs-folder = --package benötigt den Ordner für das Paket, z. B. --package D:\DesktopMateKit
+pin-hashing = Hash von { $artifact } wird berechnet...
+pin-recorded = Festgelegte Hashes in { $path } geschrieben.
+pin-record-failed = Festgelegte Hashes konnten nicht gespeichert werden: { $error }
+pin-needs-file = --pin-hashes benötigt die zu aktualisierende Datenbankdatei, z. B. --pin-hashes manifest\hashes.json
+hash-check-failed = FEHLER: { $error }
+pin-overridden = WARNUNG: { $artifact } hat die Integritätsprüfung nicht bestanden und wird wegen --allow-hash-mismatch trotzdem installiert.
+pin-mod-unpinned = WARNUNG: { $artifact } ist neuer als die Datenbank der festgelegten Hashes; der Download konnte nicht geprüft werden.
+logs-missing = Unter { $path } gibt es noch kein Protokoll.
+logs-empty = Keine passenden Protokolleinträge.
+logs-unreadable = Das Protokoll konnte nicht gelesen werden: { $error }
+logs-bad-level = Unbekannte Stufe "{ $level }"; verwenden Sie info, warning oder error. Es wird alles angezeigt.
+logs-run-started = ── Lauf gestartet { $time } (Installer { $version }) ──
+logs-step = { $time }  ▶ { $step }
+logs-run-succeeded = { $time }  Installation erfolgreich abgeschlossen.
+logs-run-failed = { $time }  Installation fehlgeschlagen: { $error }
+game-manifest-invalid = Die Spieldefinition in installer.toml (game_manifest) kann nicht verwendet werden: { $error }
+depot-skipped = DesktopMate-Dateien sind bereits vorhanden. Depot-Download wird übersprungen.
+steam-copy-prompt = DesktopMate ist bereits von Steam in { $path } installiert (Build { $build }). Möchten Sie diese Dateien kopieren, statt sie herunterzuladen? (Y/N):{" "}
+steam-copy-copying = Die Spieldateien werden aus der Steam-Bibliothek kopiert...
+steam-copy-done = { $count } Spieldateien aus Steam kopiert.
+steam-copy-failed = Das Spiel konnte nicht aus Steam kopiert werden ({ $error }); es wird stattdessen heruntergeladen.
+verify-fetching-manifest = Depot-Manifest wird von Steam abgerufen...
+verify-checking = { $count } Spieldateien werden geprüft...
+verify-component = { $component } { $version }, heruntergeladen von { $source }
+verify-progress = Prüfen: { $files }/{ $total_files } Dateien ({ $mb } / { $total_mb } MB)
+verify-missing = [FEHLT] { $file }
+verify-modified = [GEÄNDERT] { $file }
+verify-patched = [GEPATCHT] { $file } (erwartet: vom Installer ersetzt)
+verify-ok = Alle { $count } Spieldateien stimmen mit dem Depot-Manifest überein.
+verify-damaged = { $count } Spieldatei(en) fehlen oder wurden geändert. Zum Reparieren löschen Sie den Ordner DesktopMate_Data und starten den Installer erneut.
+verify-failed = FEHLER: Prüfung fehlgeschlagen: { $error }
+repair-starting = Die Installation in { $path } wird repariert...
+integrity-checking = Die installierten Komponenten werden mit ihren gespeicherten Hashes verglichen...
+integrity-damaged = { $component }: { $missing } Datei(en) fehlen, { $modified } Datei(en) wurden geändert.
+integrity-ok = Die Dateien aller installierten Komponenten stimmen mit ihren gespeicherten Hashes überein.
+integrity-unhashed = Mit einem älteren Installer installiert, daher können ihre Dateien nicht geprüft werden: { $components }
+repair-reinstalling = Wird neu installiert: { $components }
+repair-components-intact = Keine Komponente ist beschädigt; nur die Spieldateien werden geprüft.
+uninstall-prompt = { $path } mit allem Inhalt, einschließlich Mods und Avataren, löschen? (Y/N):{" "}
+uninstall-nothing = Nichts zu deinstallieren: { $path } existiert nicht.
+uninstall-removing = { $path } wird gelöscht...
+uninstall-done = Deinstalliert. Eventuelle Sicherungen liegen im Ordner Backups neben dem Installer.
+uninstall-failed = FEHLER: Deinstallation fehlgeschlagen: { $error }
+steam-mode-restoring = Die ursprüngliche steam_api64.dll wird wiederhergestellt und Goldberg entfernt...
+steam-mode-done = Steam-Modus wiederhergestellt. Das Spiel läuft jetzt über den Steam-Client; Installieren oder Aktualisieren mit diesem Installer wendet den Patch erneut an.
+steam-mode-failed = FEHLER: Wechsel in den Steam-Modus fehlgeschlagen: { $error }
+err-no-game = Keine DesktopMate-Installation in { $path } gefunden
+err-mod-url = „{ $url }“ ist weder ein GitHub-Repository (https://github.com/owner/repo) noch ein Thunderstore-Paket (Namespace-Name) oder GameBanana-Mod (https://gamebanana.com/mods/<id>)
+err-mod-not-installed = { $mod } wurde nicht installiert; siehe die Meldungen oben
+err-mod-unknown = Kein installierter Mod und keine DLL in Mods heißt „{ $mod }“
+err-profile-unknown = Kein Profil heißt „{ $profile }“
+err-mod-rollback-disabled = { $mod } ist deaktiviert; aktivieren Sie es vor dem Zurücksetzen
+err-mod-nothing-kept = Von { $mod } ist noch keine frühere Version aufbewahrt; Versionen werden ab dem nächsten Update aufbewahrt
+err-mod-no-kept-version = Keine aufbewahrte Version von { $mod } zum Zurücksetzen (aufbewahrt: { $versions })
+err-steam-mode-no-backup = Für diese Installation ist keine Sicherung der ursprünglichen steam_api64.dll verzeichnet. Stellen Sie sie mit „Dateien auf Fehler überprüfen“ in Steam wieder her
+err-steam-mode-backup-changed = Die Sicherung { $path } fehlt oder wurde verändert und wurde daher nicht wiederhergestellt. Verwenden Sie stattdessen „Dateien auf Fehler überprüfen“ in Steam
+err-verify-no-manifest = DepotDownloader hat keine Manifest-Liste erzeugt
+err-dd-download = Download von DepotDownloader fehlgeschlagen
+err-dd-extract = Entpacken von DepotDownloader fehlgeschlagen
+err-dd-missing = DepotDownloader.exe fehlt nach dem Entpacken
+err-dotnet-missing = DepotDownloader benötigt die .NET-Laufzeit { $runtime }. Installieren Sie sie von { $url } und starten Sie den Installer erneut
+err-dotnet-unsigned = Das heruntergeladene Installationsprogramm der .NET-Laufzeit ist nicht signiert und wurde nicht ausgeführt
+err-dotnet-wrong-publisher = Das heruntergeladene Installationsprogramm der .NET-Laufzeit ist von { $publisher } signiert, nicht von Microsoft, und wurde nicht ausgeführt
+signature-invalid = WARNUNG: Die Signatur von { $file } lässt sich nicht bestätigen (angeblicher Herausgeber: { $publisher }). Die Datei wurde möglicherweise manipuliert.
+signature-wrong-publisher = WARNUNG: { $file } ist von { $publisher } signiert, nicht von { $expected }. Es ist möglicherweise nicht die offizielle Version.
+signature-unsigned = WARNUNG: { $file } ist nicht signiert, obwohl { $expected } seine Versionen signiert. Es ist möglicherweise nicht die offizielle Version.
+err-depot-exit = DepotDownloader wurde mit Code { $code } beendet
+
+## Goldberg patch
+
+goldberg-downloading = Goldberg-Patch wird heruntergeladen...
+goldberg-provider-fallback = Der Goldberg-Patch konnte nicht geladen werden ({ $error }). Stattdessen wird { $provider } versucht...
+goldberg-applied = Goldberg-Patch erfolgreich angewendet.
+goldberg-rechecking = Es wird geprüft, ob der Patch bestehen bleibt...
+av-patch-removed = FEHLER: steam_api64.dll ist Sekunden nach dem Schreiben verschwunden oder wurde verändert. Vermutlich hat Ihr Virenschutz den Goldberg-Patch in Quarantäne verschoben.
+av-patch-lost = Der zuletzt angewendete Goldberg-Patch fehlt oder wurde verändert. Vermutlich hat Ihr Virenschutz ihn in Quarantäne verschoben; er wird jetzt erneut angewendet.
+av-exclusion-hint = Schließen Sie den Spielordner von der Prüfung aus (für Microsoft Defender den Installer mit --defender-exclusion starten, sonst in den Einstellungen Ihres Virenschutzes) und starten Sie den Installer dann erneut.
+goldberg-no-target = FEHLER: Zielverzeichnis für die Goldberg-Patch-DLL kann nicht ermittelt werden.
+goldberg-dll-missing = FEHLER: steam_api64.dll im Patch-Archiv nicht gefunden!
+err-goldberg-target = Zielverzeichnis für den Goldberg-Patch nicht verfügbar
+err-goldberg-dll = steam_api64.dll fehlt im Goldberg-Archiv
+err-steam-api-backup = Die ursprüngliche steam_api64.dll konnte nicht gesichert werden und wurde daher nicht ersetzt: { $error }
+err-av-quarantined = der Goldberg-Patch wurde direkt nach dem Anwenden entfernt, vermutlich vom Virenschutz
+err-defender-exclusion = die Defender-Ausnahme konnte nicht hinzugefügt werden oder wurde abgebrochen (Exit-Code { $code })
+
+## MelonLoader
+
+melon-up-to-date = MelonLoader ist aktuell (Version { $version }).
+adopt-found = Manuell installierter { $component } gefunden (Version: { $version }); er wird ab jetzt mit aktualisiert.
+melon-installing = MelonLoader { $version } wird installiert...
+melon-downgrading = Installierter MelonLoader { $installed } ist neuer als die unterstützte Version { $version } und wird ersetzt.
+melon-extracting = MelonLoader wird in das Spielverzeichnis entpackt...
+melon-remove-prompt = MelonLoader aus { $path } entfernen? Mods und ihre Einstellungen bleiben erhalten.
+melon-removing = MelonLoader wird entfernt...
+melon-remove-done = MelonLoader wurde entfernt. Das Spiel startet jetzt ohne Mods.
+melon-remove-nothing = MelonLoader ist dort nicht installiert.
+melon-remove-failed = FEHLER: MelonLoader konnte nicht entfernt werden: { $error }
+melon-version-corrected = install.json verzeichnete MelonLoader { $recorded }, installiert ist aber { $detected }; der Eintrag wurde korrigiert.
+melon-versions = MelonLoader-Versionen:
+melon-choose = Zu installierende MelonLoader-Version (Nummer):{" "}
+melon-version-recommended = { $version } (empfohlen)
+melon-choose-failed = FEHLER: Es konnte keine MelonLoader-Version gewählt werden: { $error }
+catalog-heading = Mods, die Sie neben Custom Avatar Loader installieren können:
+catalog-choose = Nummern der zu installierenden Mods, durch Kommas getrennt (leer für keine):{" "}
+catalog-empty = Der Mod-Katalog enthält noch keine weiteren Mods.
+catalog-mod-unknown = { $component } ist nicht mehr im Mod-Katalog und wurde übersprungen.
+catalog-choose-failed = FEHLER: Mods konnten nicht ausgewählt werden: { $error }
+melon-version-unavailable = MelonLoader { $version } ist nicht verfügbar ({ $error }); stattdessen wird die empfohlene Version installiert.
+melon-installed = MelonLoader erfolgreich installiert.
+melon-incomplete = FEHLER: Das MelonLoader-Archiv ist unvollständig; die vorhandene Installation wurde nicht verändert.
+err-melon-incomplete = Im MelonLoader-Archiv fehlen version.dll oder der Ordner MelonLoader
+err-melon-version-unknown = Keine MelonLoader-Version „{ $version }“ in diesem Kanal
+
+## Custom Avatar Loader
+
+mod-checking = Suche nach Updates für { $mod }...
+mod-up-to-date = { $mod } ist aktuell (Version { $version }).
+mod-newer-installed = Installierte Version von { $mod } { $installed } ist neuer als das neueste Release { $latest } und bleibt unverändert.
+mod-not-installed = { $mod } ist nicht installiert. Wird jetzt installiert...
+mod-update-available = Update für { $mod } verfügbar: Installierte Version: { $installed }, Neueste Version: { $latest }
+mod-update-prompt = { $mod } aktualisieren? (Y/N):{" "}
+mod-update-skipped = Update von { $mod } wird übersprungen.
+mod-downloading = { $mod } wird heruntergeladen...
+mod-download-failed = FEHLER: { $mod } konnte nicht heruntergeladen werden: { $error }
+mod-no-matching-asset = FEHLER: Keine Datei im neuesten Release von { $mod } passt zu "{ $pattern }".
+mod-no-dirs = FEHLER: Weder 'Mods' noch 'UserLibs' im entpackten Archiv gefunden!
+mod-installed = { $mod } erfolgreich installiert/aktualisiert.
+mod-reinstalling = { $mod } { $version } wird neu installiert...
+mod-no-release-info = Release-Informationen von { $mod } nicht abrufbar ({ $error }). Update-Prüfung wird übersprungen.
+mod-registered = { $mod } ist installiert und wird künftig mit dem Rest der Installation aktualisiert.
+mod-install-failed = FEHLER: Die Mod konnte nicht installiert werden: { $error }
+thunderstore-result = { $package } { $version }
+thunderstore-no-results = Kein Thunderstore-Paket passt zu „{ $query }“.
+thunderstore-install-hint = Installieren mit: mod install <Paket>
+thunderstore-needs = benötigt { $packages }
+thunderstore-dependency-missing = Die Abhängigkeit { $package } ist nicht auf Thunderstore und wurde nicht installiert.
+thunderstore-failed = FEHLER: Thunderstore-Abfrage fehlgeschlagen: { $error }
+mod-disabled = { $mod } ist deaktiviert; die Dateien liegen in Mods.disabled:
+mod-enabled = { $mod } ist wieder aktiviert; die Dateien liegen wieder in Mods:
+mod-already-disabled = { $mod } ist bereits deaktiviert.
+mod-already-enabled = { $mod } ist nicht deaktiviert.
+mod-disabled-skipped = { $mod } ist deaktiviert und wird nicht aktualisiert. Aktivieren Sie es mit: mod enable "{ $mod }"
+mod-toggle-failed = FEHLER: { $mod } konnte nicht aktiviert oder deaktiviert werden: { $error }
+mod-updates-checking = Installierte Mods werden auf Updates geprüft...
+mod-updates-no-mods = Keine installierten Mods zu prüfen.
+mod-updates-column-mod = Mod
+mod-updates-column-installed = Installiert
+mod-updates-column-latest = Neueste
+mod-updates-lookup-failed = (Abfrage fehlgeschlagen)
+mod-updates-none = Alle Mods sind aktuell.
+mod-updates-notes = Neu in { $mod } { $version }:
+mod-updates-no-notes = (keine Versionshinweise)
+mod-updates-done = { $count } Mods aktualisiert.
+mod-updates-failed = FEHLER: Aktualisieren der Mods fehlgeschlagen: { $error }
+mod-rolled-back = { $mod } ist wieder auf Version { $version } (war { $from }). Das nächste Update bietet die neuere Version wieder an; lehnen Sie es ab, um diese zu behalten.
+mod-rollback-failed = FEHLER: { $mod } konnte nicht zurückgesetzt werden: { $error }
+profile-saved = Profil { $profile } mit { $files } aktivierten Dateien gespeichert.
+profile-switched = Zu Profil { $profile } gewechselt.
+profile-file-missing = { $file } aus dem Profil ist nicht mehr installiert.
+profile-entry = { $profile } ({ $files } Dateien)
+profile-none = Keine Profile gespeichert. Speichern Sie die aktuellen Mods mit: profile save <Name>
+profile-deleted = Profil { $profile } gelöscht.
+profile-failed = FEHLER: { $error }
+err-mod-no-dirs = Das Archiv von { $mod } enthält weder Mods noch UserLibs
+err-no-matching-asset = Keine Release-Datei passt zu "{ $pattern }". Setzen Sie avatar_loader_asset in installer.toml auf den neuen Dateinamen.
+
+## Shortcuts
+
+shortcuts-creating = Desktop-Verknüpfungen werden erstellt...
+shortcuts-no-desktop = FEHLER: Desktop-Verzeichnis kann nicht ermittelt werden.
+shortcuts-created = Desktop-Verknüpfungen erfolgreich erstellt.
+shortcuts-prompt = Welche Desktopverknüpfungen sollen erstellt werden?
+shortcuts-choose = Wählen Sie 1-4:{" "}
+shortcuts-name-prompt = Name der Verknüpfung (Standard: { $name }):{" "}
+pin-prompt = DesktopMate an das Startmenü anheften? (Y/N){" "}
+safe-mode-prompt = Zusätzlich eine Verknüpfung „DesktopMate (Safe Mode)“ erstellen, die das Spiel ohne Mods startet, um zu prüfen, ob ein Absturz an einer Mod liegt? (Y/N){" "}
+pin-done = „{ $name }“ wurde zum Startmenü hinzugefügt. Falls es noch nicht angeheftet ist, klicken Sie es unter „Alle Apps“ mit der rechten Maustaste an und wählen Sie „An Start anheften“.
+pin-failed = DesktopMate konnte nicht zum Startmenü hinzugefügt werden: { $error }
+shortcuts-variant-both = Beide (mit und ohne Konsole)
+shortcuts-variant-no-console = Nur ohne Konsole
+shortcuts-variant-console = Nur mit MelonLoader-Konsole
+shortcuts-variant-none = Keine
+shortcuts-skipped = Desktopverknüpfungen werden wie gewünscht übersprungen.
+fix-shortcuts-created = Verknüpfung erstellt: { $path }
+fix-shortcuts-removed = Alte Verknüpfung entfernt: { $path }
+fix-shortcuts-failed = Die Verknüpfungen konnten nicht neu erstellt werden: { $error }
+defender-adding = { $path } wird von Microsoft-Defender-Prüfungen ausgeschlossen. Windows fragt nach Administratorrechten...
+defender-added = Ausnahme hinzugefügt. Starten Sie den Installer erneut, um den Patch wieder anzuwenden.
+defender-failed = Die Ausnahme konnte nicht hinzugefügt werden: { $error }
+err-fix-shortcuts-no-game = In { $path } wurde keine DesktopMate-Installation gefunden. Geben Sie den Installationsordner an: --fix-shortcuts "D:\Games\DesktopMate"
+health-running = Installation wird geprüft...
+health-game-exe = DesktopMate.exe ist vorhanden
+health-goldberg = steam_api64.dll entspricht dem Goldberg-Patch
+health-melonloader = version.dll von MelonLoader ist vorhanden
+health-mod = { $mod }: { $dll } ist vorhanden
+health-shortcut = Verknüpfung { $name } zeigt auf eine vorhandene Datei
+health-missing = fehlt: { $path }
+health-hash-mismatch = Hash stimmt nicht überein
+health-shortcut-unreadable = Ziel der Verknüpfung konnte nicht gelesen werden
+health-pass = [OK] { $check }
+health-fail = [FEHLER] { $check }
+health-all-passed = Alle Prüfungen bestanden.
+step-failed-continuing = WARNUNG: { $step } ist fehlgeschlagen und wurde übersprungen: { $error }. Die Installation wird fortgesetzt.
+resume-continuing = Eine frühere Installation in diesen Ordner wurde nicht abgeschlossen; sie wird an der unterbrochenen Stelle fortgesetzt.
+resume-step-done = { $step } wurde bereits beim früheren Durchlauf abgeschlossen und wird übersprungen.
+steps-skipped = Mit übersprungenen Schritten abgeschlossen: { $steps }. Starten Sie den Installer erneut, um sie zu wiederholen.
+recovery-heading = { $step } ist fehlgeschlagen: { $error }
+recovery-retry = Schritt wiederholen
+recovery-skip = Überspringen und fortfahren
+recovery-abort = Abbrechen und die Änderungen dieses Laufs rückgängig machen
+recovery-choose = Wählen Sie 1-3:{" "}
+health-some-failed = { $count } Prüfung(en) fehlgeschlagen; das Spiel startet möglicherweise nicht richtig. Details stehen im Protokoll.
+err-no-desktop = Desktop-Verzeichnis nicht verfügbar
+err-shortcut = Verknüpfung konnte nicht erstellt werden
+err-no-start-menu = Der Programmordner des Startmenüs wurde nicht gefunden
+err-toast = Die Benachrichtigung konnte nicht angezeigt werden (PowerShell-Exitcode { $code })
+err-kit-checksum = { $file } im Offline-Paket stimmt nicht mit der gespeicherten Prüfsumme überein; das Paket ist beschädigt oder wurde verändert
+err-kit-missing = { $file } fehlt im Offline-Paket: { $error }
+err-pin-mismatch = { $artifact } stimmt nicht mit dem festgelegten SHA-256 überein (erwartet { $expected }, erhalten { $actual }); der Download ist möglicherweise beschädigt oder manipuliert. Mit --allow-hash-mismatch wird trotzdem installiert
+err-pin-unknown-version = { $artifact } ist noch nicht in der Hash-Datenbank enthalten. Mit --allow-hash-mismatch wird trotzdem installiert
+err-pin-unknown-hash = { $artifact } (SHA-256 { $actual }) passt zu keinem festgelegten Hash. Mit --allow-hash-mismatch wird trotzdem installiert
+
+## Network
+
+err-http = HTTP-Fehler: { $status }
+err-download-incomplete = Der Download endete nach { $received } von { $expected } Bytes
+network-retrying = { $what } ist fehlgeschlagen: { $error }. Neuer Versuch in { $seconds } s (Versuch { $attempt } von { $attempts })...
+download-trying-mirror = Download fehlgeschlagen ({ $error }); der Mirror { $url } wird versucht...
+cache-pruned = { $files } zwischengespeicherte Dateien gelöscht ({ $mb } MB).
+cache-prune-failed = Der Download-Cache konnte nicht bereinigt werden: { $error }
+github-rate-limited = GitHub-API-Limit erreicht; es wird um { $time } zurückgesetzt
+github-unreachable = GitHub nicht erreichbar: { $error }
+github-no-release = GitHub hat kein verwendbares Release geliefert
+github-using-cache = { $reason }. Verwende die am { $time } gespeicherten Release-Informationen.
+err-archive-unsafe-path = Archiveintrag „{ $entry }“ würde außerhalb des Zielordners geschrieben
+err-archive-unsupported = { $file } ist kein unterstütztes Archiv (erwartet: zip, 7z, tar.gz oder tar.zst)
+extract-progress = Entpacken: { $files }/{ $total_files } Dateien ({ $mb } / { $total_mb } MB)
+copy-progress = Kopieren: { $files }/{ $total_files } Dateien ({ $mb } / { $total_mb } MB)
+backup-prompt = Ihre Daten ({ $items }) sichern, bevor diese Installation geändert wird? (Y/N):{" "}
+backup-creating = Benutzerdaten werden gesichert...
+backup-progress = Sichern: { $files }/{ $total_files } Dateien ({ $mb } / { $total_mb } MB)
+backup-created = Benutzerdaten gesichert in { $path }
+backup-pruned = { $count } alte Sicherung(en) gelöscht.
+backup-failed = FEHLER: Benutzerdaten konnten nicht gesichert werden: { $error }
+restore-available = Verfügbare Sicherungen:
+restore-choose = Wiederherzustellende Sicherung (Nummer):{" "}
+restore-contents = Die Sicherung vom { $created } enthält:
+restore-choose-items = Wiederherzustellende Teile (z. B. 1,3; Enter für alle):{" "}
+restore-target-prompt = In welche Installation wiederherstellen? [{ $path }]:{" "}
+restore-none = Keine Sicherungen in { $path } gefunden.
+restore-done = { $count } Datei(en) nach { $path } wiederhergestellt
+restore-failed = FEHLER: Wiederherstellung fehlgeschlagen: { $error }
+err-restore-not-found = Keine Sicherung namens "{ $name }" im Ordner Backups
+err-invalid-choice = Ungültige Auswahl: { $choice }
+err-restore-target-missing = Wiederherstellungsziel existiert nicht: { $path }
+copy-kept = Ihre geänderte Datei wurde beibehalten: { $path }
+copy-backed-up = Ihre geänderte Datei wurde nach { $path } gesichert
+
+## Wizard (full-screen UI)
+
+step-depot-downloader = DepotDownloader
+step-game-files = Spieldateien
+step-goldberg = Goldberg-Patch
+step-melonloader = MelonLoader
+step-shortcuts = Verknüpfungen
+step-health-check = Prüfung
+
+page-welcome = Willkommen
+page-location = Installationsort
+page-steam = Steam-Konto
+page-options = Optionen
+page-review = Überprüfen
+
+tui-sidebar-title = Schritte
+tui-sidebar-setup = Einrichtung
+tui-sidebar-install = Installation
+
+wizard-welcome-intro = Dieses Installationsprogramm richtet DesktopMate mit Mod-Unterstützung ein. Installiert werden:
+wizard-welcome-game = die DesktopMate-Spieldateien, mit Ihrem Konto von Steam heruntergeladen
+wizard-welcome-goldberg = der Goldberg-Steam-Emulator, damit das Spiel ohne Steam-Client startet
+wizard-welcome-melonloader = MelonLoader, der Mod-Loader
+wizard-welcome-avatar-loader = Custom Avatar Loader, zum Laden eigener VRM-Avatare
+wizard-welcome-shortcuts = Verknüpfungen auf dem Desktop und im Startmenü
+wizard-ownership = Ich besitze DesktopMate auf Steam
+wizard-ownership-required = Bitte bestätigen Sie, dass Sie DesktopMate besitzen, um fortzufahren.
+wizard-path-required = Ein Installationspfad ist erforderlich.
+wizard-location-question = Wohin soll DesktopMate installiert werden?
+wizard-location-label = Installationspfad
+wizard-location-existing = Hier sind bereits DesktopMate-Dateien vorhanden; der Depot-Download wird übersprungen.
+wizard-steam-intro = DepotDownloader benötigt das Steam-Konto, dem DesktopMate gehört.
+wizard-steam-guard = Eine eventuelle Steam-Guard-Abfrage erscheint, sobald der Download beginnt.
+wizard-steam-user = Steam-Benutzername
+wizard-steam-password = Steam-Passwort
+wizard-options-intro = Optionales Verhalten
+wizard-option-update-mod = Custom Avatar Loader aktualisieren, wenn eine neuere Version verfügbar ist
+wizard-option-backup = Benutzerdaten (Mod-Einstellungen, Avatare, Spielstände) sichern, bevor eine bestehende Installation geändert wird
+wizard-option-shortcuts = Desktopverknüpfungen
+wizard-option-pin = DesktopMate an das Startmenü anheften
+wizard-option-safe-mode = Zusätzlich eine Verknüpfung „DesktopMate (Safe Mode)“ erstellen (startet ohne Mods)
+wizard-review-intro = Bereit zur Installation. Bitte Auswahl prüfen:
+wizard-review-path = Installationspfad
+wizard-review-depot = Spiel-Depot
+wizard-review-depot-download = als { $user } herunterladen
+wizard-review-depot-update = Aktualisierung auf Build { $build } als { $user }
+wizard-review-depot-skip = überspringen (Dateien bereits vorhanden)
+wizard-review-update = Mod aktualisieren
+wizard-review-backup = Benutzerdaten sichern
+wizard-review-shortcuts = Verknüpfungen
+wizard-review-pin = An Start anheften
+wizard-review-safe-mode = Verknüpfung für den abgesicherten Modus
+wizard-review-download = Downloadgröße
+wizard-yes = ja
+wizard-no = nein
+wizard-review-start = Enter drücken, um die Installation zu starten.
+
+hint-welcome = Leertaste: Besitz bestätigen   Enter: weiter   Esc: beenden
+hint-location-back = Enter: weiter   Esc: zurück
+hint-location = Enter: weiter   Esc: beenden
+hint-steam = Enter: weiter   Tab: Feld wechseln   Esc: zurück
+hint-options = ↑/↓: auswählen   Leertaste: umschalten   Enter: weiter   Esc: zurück
+hint-review = Enter: installieren   Esc: zurück   Strg+C: beenden
+hint-running = ↑/↓ Bild↑/Bild↓ Pos1/Ende: Protokoll scrollen   Strg+C: abbrechen
+hint-finished = Beliebige Taste zum Beenden   ↑/↓ Bild↑/Bild↓: Protokoll scrollen
+
+progress-title = Fortschritt
+progress-steps = { $completed }/{ $total } Schritte
+status-title = Status
+status-complete = Installation abgeschlossen.
+status-failed = Installation fehlgeschlagen: { $error }
+status-running = { $step }...
+status-starting = Wird gestartet...
+log-title = Protokoll
+log-title-scrolled = Protokoll ({ $lines } Zeilen zurückgescrollt)
+tui-stopped-unexpectedly = Der Installer wurde unerwartet beendet.
@@ -0,0 +1,446 @@
+## DesktopMate Installer — English (reference locale)
+## Every other locale falls back to these messages for anything it doesn't translate.
+
+app-title = DesktopMate Installer
+press-any-key = Press any key to exit...
+config-ignored = Ignoring installer.toml: { $error }
+proxy-invalid = Ignoring the proxy: { $error }
+ca-cert-invalid = Ignoring the CA certificate: { $error }
+theme-color-unknown = Unknown theme color "{ $color }" in installer.toml; keeping the preset's color.
+language-unsupported = Language "{ $language }" is not available; using the system language. Available: { $available }
+
+## Installer self-update
+
+update-check-failed = Installer update check failed: { $error }
+update-available = A newer installer is available: Installed version: { $installed }, Latest version: { $latest }
+update-prompt = Do you want to update the installer now? (Y/N):{" "}
+update-downloading = Downloading installer update...
+update-rejected = ERROR: Refusing to run installer update: { $reason }
+update-reason-no-key = a minisign signature was published but this build has no verification key
+update-reason-bad-key = invalid built-in public key: { $error }
+update-reason-bad-signature-file = unreadable signature: { $error }
+update-reason-mismatch = minisign signature does not match: { $error }
+update-reason-unsigned = the download carries no valid signature
+update-reason-wrong-publisher = it is signed by { $publisher }, not { $expected }
+update-applied = Installer updated to { $version }. Restarting...
+
+## Classic prompts
+
+prompt-yes = Yes
+prompt-no = No
+prompt-timed-out = No answer in time; continuing with the default ({ $answer }).
+err-unattended-question = this question has no default answer, so an unattended run can't answer it: { $question }
+first-run-ownership-prompt = Do you own DesktopMate on Steam? (Y/N):{" "}
+first-run-ownership-declined = This installer is only for owners of DesktopMate. Please buy the game on Steam first.
+unattended-ownership-hint = Unattended runs confirm owning the game with --confirm-ownership.
+prompt-install-path = Enter installation path (default: { $path }):{" "}
+install-dir-set = Installation directory: { $path }
+download-size = about { $mb } MB
+download-size-unknown = (plus { $items }, size unknown)
+download-estimate = Estimated download: { $size }
+download-estimate-continue = Continue with the installation? (Y/N):{" "}
+credentials-failed = Could not use the credentials file: { $error }
+credentials-no-password = ERROR: --steam-user needs the password in the { $var } environment variable in an unattended run.
+err-credentials-read = Could not read { $path }: { $error }
+err-credentials-permissions = { $path } can be read by { $group }. Restrict it to your account first, e.g. icacls "{ $path }" /inheritance:r /grant:r "%USERNAME%:R"
+err-credentials-incomplete = { $path } has no "login" and "password" for "machine steam" or a default entry.
+prompt-steam-user = Enter your Steam username:{" "}
+steam-user-required = Steam username is required.
+prompt-steam-password = Enter your Steam password:{" "}
+install-failed = Installation failed: { $error }
+rollback-prompt = Undo the changes this run made to the game folder and shortcuts? (Y/N):{" "}
+rollback-declined = Changes kept. Run the installer again to retry.
+rollback-done = Rolled back { $count } change(s); the game folder is back to how it was before this run.
+rollback-incomplete = Some changes could not be rolled back: { $error }
+injection-prompt = Start DesktopMate for up to { $seconds } seconds to check that MelonLoader loads?
+injection-running = Starting DesktopMate and waiting for MelonLoader...
+injection-ok = MelonLoader { $version } loaded into the game.
+injection-game-exited = DesktopMate closed (exit code { $code }) before MelonLoader loaded. Check your antivirus and that the Visual C++ runtime is installed.
+injection-no-log = MelonLoader did not start within { $seconds } seconds; mods will not load. Check your antivirus, or try the remove-melonloader command and install again.
+injection-failed = Could not start DesktopMate for the check: { $error }
+launch-prompt = Launch DesktopMate now?
+launch-console = Yes, with the MelonLoader console
+launch-no-console = Yes, without the console
+launch-no = No
+launch-choose = Choose 1-3:{" "}
+launch-started = DesktopMate is starting.
+launch-failed = Could not start DesktopMate: { $error }
+install-complete-exit = Installation complete. Press any key to exit.
+summary-title = Installation summary
+summary-install-dir = Install directory: { $path }
+summary-game-build = Game manifest: { $manifest }
+summary-melonloader = MelonLoader: { $version }
+summary-mod = { $mod }: { $version }
+summary-unknown = unknown
+
+## DepotDownloader and the game depot
+
+dd-missing = DepotDownloader.exe not found! Downloading now...
+dd-download-failed = ERROR: Failed to download DepotDownloader! { $error }
+dd-extracting = Extracting DepotDownloader...
+dd-extract-failed = ERROR: Failed to extract DepotDownloader! { $error }
+dd-still-missing = ERROR: DepotDownloader.exe still not found after extraction!
+dd-ready = DepotDownloader downloaded and extracted successfully.
+dd-version-unknown = an unknown version
+dd-upgrade-prompt = DepotDownloader { $installed } is installed; { $latest } is available. Upgrade it? (Y/N):{" "}
+dd-switching = Replacing DepotDownloader { $installed } with the pinned version { $version }...
+dotnet-missing = DepotDownloader needs the .NET runtime { $runtime }, which isn't installed.
+dotnet-install-prompt = Download and run Microsoft's installer for it now? (Y/N):{" "}
+dotnet-downloading = Downloading the .NET runtime installer...
+dotnet-installing = Installing the .NET runtime...
+depot-downloading = Downloading DesktopMate depot (via DepotDownloader)...
+depot-failed = ERROR: DepotDownloader encountered an error. Exit code = { $code }
+depot-not-owned = Steam says this account does not own DesktopMate. Sign in with the Steam account that bought the game; a different or family-shared account cannot download it.
+depot-login-rejected = Steam rejected the sign-in. Check the username and password, and enter the current Steam Guard code if asked.
+depot-complete = Depot download complete.
+game-update-available = DesktopMate build { $installed } is installed; build { $latest } is available.
+game-update-prompt = Update the game now? Goldberg is re-applied and the mods are checked afterwards. (Y/N){" "}
+game-updating = Updating DesktopMate to build { $build }...
+game-validating = Checking every game file and re-downloading damaged ones...
+check-updates-current = { $component }: { $installed } (up to date)
+check-updates-outdated = { $component }: { $installed } installed, { $latest } available
+check-updates-unknown = { $component }: installed version unknown
+check-updates-none = Everything is up to date.
+update-failed = ERROR: Could not check for updates: { $error }
+check-updates-install = Install the updates now? (Y/N){" "}
+watch-started = Watching { $path } for updates. Checking now and then once a day; close this window or press Ctrl+C to stop.
+watch-toast-title = DesktopMate updates available — run the installer to update
+watch-check-failed = Update check failed: { $error }
+package-fetching = Packaging { $file }...
+package-done = Offline kit ready in { $path }. Share the whole folder; the game itself still downloads from Steam.
+package-failed = Building the offline kit failed: { $error }
+package-needs-folder = --package needs the folder to build the kit in, e.g. --package D:\DesktopMateKit
+pin-hashing = Hashing { $artifact }...
+pin-recorded = Pinned hashes written to { $path }.
+pin-record-failed = Recording pinned hashes failed: { $error }
+pin-needs-file = --pin-hashes needs the database file to update, e.g. --pin-hashes manifest\hashes.json
+hash-check-failed = ERROR: { $error }
+pin-overridden = WARNING: { $artifact } failed its integrity check; installing it anyway because of --allow-hash-mismatch.
+pin-mod-unpinned = WARNING: { $artifact } is newer than the pinned hash database, so its download could not be verified.
+logs-missing = No log yet at { $path }.
+logs-empty = No log entries match.
+logs-unreadable = Could not read the log: { $error }
+logs-bad-level = Unknown level "{ $level }"; use info, warning, or error. Showing everything.
+logs-run-started = ── Run started { $time } (installer { $version }) ──
+logs-step = { $time }  ▶ { $step }
+logs-run-succeeded = { $time }  Installation finished successfully.
+logs-run-failed = { $time }  Installation failed: { $error }
+game-manifest-invalid = The game definition in installer.toml (game_manifest) can't be used: { $error }
+depot-skipped = DesktopMate files already exist. Skipping depot download.
+steam-copy-prompt = DesktopMate is already installed by Steam in { $path } (build { $build }). Copy those files instead of downloading them? (Y/N):{" "}
+steam-copy-copying = Copying the game files from the Steam library...
+steam-copy-done = Copied { $count } game files from Steam.
+steam-copy-failed = Could not copy the game from Steam ({ $error }); downloading it instead.
+verify-fetching-manifest = Fetching the depot manifest from Steam...
+verify-checking = Checking { $count } game files...
+verify-component = { $component } { $version }, downloaded from { $source }
+verify-progress = Verifying: { $files }/{ $total_files } files ({ $mb } / { $total_mb } MB)
+verify-missing = [MISSING] { $file }
+verify-modified = [MODIFIED] { $file }
+verify-patched = [PATCHED] { $file } (expected: replaced by the installer)
+verify-ok = All { $count } game files match the depot manifest.
+verify-damaged = { $count } game file(s) are missing or modified. To repair, delete the DesktopMate_Data folder and run the installer again.
+verify-failed = ERROR: Verification failed: { $error }
+repair-starting = Repairing the install in { $path }...
+integrity-checking = Checking the installed components against their recorded hashes...
+integrity-damaged = { $component }: { $missing } file(s) missing, { $modified } file(s) changed.
+integrity-ok = Every installed component's files match their recorded hashes.
+integrity-unhashed = Installed by an older installer, so their files can't be checked: { $components }
+repair-reinstalling = Reinstalling: { $components }
+repair-components-intact = No component is damaged; only the game files will be checked.
+uninstall-prompt = Delete { $path } and everything in it, including mods and avatars? (Y/N):{" "}
+uninstall-nothing = Nothing to uninstall: { $path } doesn't exist.
+uninstall-removing = Deleting { $path }...
+uninstall-done = Uninstalled. Backups, if any, are in the Backups folder next to the installer.
+uninstall-failed = ERROR: Uninstall failed: { $error }
+steam-mode-restoring = Restoring the original steam_api64.dll and removing Goldberg...
+steam-mode-done = Steam mode restored. The game now runs through the Steam client; installing or updating with this installer applies the patch again.
+steam-mode-failed = ERROR: Switching to Steam mode failed: { $error }
+err-no-game = No DesktopMate install found in { $path }
+err-mod-url = "{ $url }" is not a GitHub repository (https://github.com/owner/repo), Thunderstore package (Namespace-Name) or GameBanana mod (https://gamebanana.com/mods/<id>)
+err-mod-not-installed = { $mod } was not installed; see the messages above
+err-mod-unknown = No installed mod or DLL in Mods is called "{ $mod }"
+err-profile-unknown = No profile is called "{ $profile }"
+err-mod-rollback-disabled = { $mod } is disabled; enable it before rolling it back
+err-mod-nothing-kept = No earlier version of { $mod } is kept yet; versions are kept from its next update on
+err-mod-no-kept-version = No kept version of { $mod } to roll back to (kept: { $versions })
+err-steam-mode-no-backup = No backup of the original steam_api64.dll was recorded for this install. Use "Verify integrity of game files" in Steam to get it back
+err-steam-mode-backup-changed = The backup { $path } is missing or changed, so it was not restored. Use "Verify integrity of game files" in Steam instead
+err-verify-no-manifest = DepotDownloader did not produce a manifest listing
+err-dd-download = DepotDownloader download failed
+err-dd-extract = DepotDownloader extraction failed
+err-dd-missing = DepotDownloader.exe missing after extraction
+err-dotnet-missing = The .NET runtime { $runtime } is required by DepotDownloader. Install it from { $url } and run the installer again
+err-dotnet-unsigned = The downloaded .NET runtime installer isn't signed; it was not run
+err-dotnet-wrong-publisher = The downloaded .NET runtime installer is signed by { $publisher }, not Microsoft; it was not run
+signature-invalid = WARNING: { $file } has a signature that doesn't verify (claimed publisher: { $publisher }). It may have been tampered with.
+signature-wrong-publisher = WARNING: { $file } is signed by { $publisher }, not { $expected }. It may not be the official release.
+signature-unsigned = WARNING: { $file } isn't signed, though { $expected } signs its releases. It may not be the official release.
+err-depot-exit = DepotDownloader exited with code { $code }
+
+## Goldberg patch
+
+goldberg-downloading = Downloading Goldberg patch...
+goldberg-provider-fallback = Could not get the Goldberg patch ({ $error }). Trying { $provider } instead...
+goldberg-applied = Goldberg patch applied successfully.
+goldberg-rechecking = Making sure the patch stays in place...
+av-patch-removed = ERROR: steam_api64.dll disappeared or changed seconds after it was written. Your antivirus most likely quarantined the Goldberg patch.
+av-patch-lost = The Goldberg patch applied last time is missing or changed. Your antivirus most likely quarantined it; it will be applied again now.
+av-exclusion-hint = Exclude the game folder from scanning (run the installer with --defender-exclusion for Microsoft Defender, or use your antivirus's settings), then run the installer again.
+goldberg-no-target = ERROR: Unable to determine target directory for Goldberg patch DLL.
+goldberg-dll-missing = ERROR: steam_api64.dll not found in the patch archive!
+err-goldberg-target = Goldberg patch target directory unavailable
+err-goldberg-dll = steam_api64.dll missing from the Goldberg archive
+err-steam-api-backup = Could not back up the original steam_api64.dll, so it was left in place: { $error }
+err-av-quarantined = the Goldberg patch was removed right after it was applied, most likely by antivirus
+err-defender-exclusion = adding the Defender exclusion failed or was cancelled (exit code { $code })
+
+## MelonLoader
+
+melon-up-to-date = MelonLoader is up-to-date (version { $version }).
+adopt-found = Found { $component } installed manually (version: { $version }); it will be updated from now on.
+melon-installing = Installing MelonLoader { $version }...
+melon-downgrading = Installed MelonLoader { $installed } is newer than the supported { $version }; replacing it.
+melon-extracting = Extracting MelonLoader contents to game directory...
+melon-remove-prompt = Remove MelonLoader from { $path }? Mods and their settings are kept.
+melon-removing = Removing MelonLoader...
+melon-remove-done = MelonLoader removed. The game now starts without mods.
+melon-remove-nothing = MelonLoader is not installed there.
+melon-remove-failed = ERROR: Removing MelonLoader failed: { $error }
+melon-version-corrected = install.json recorded MelonLoader { $recorded }, but { $detected } is installed; the record was corrected.
+melon-versions = MelonLoader releases:
+melon-choose = MelonLoader release to install (number):{" "}
+melon-version-recommended = { $version } (recommended)
+melon-choose-failed = ERROR: Could not choose a MelonLoader release: { $error }
+catalog-heading = Mods you can install besides Custom Avatar Loader:
+catalog-choose = Numbers of the mods to install, separated by commas (empty for none):{" "}
+catalog-empty = The mod catalog lists no other mods yet.
+catalog-mod-unknown = { $component } is no longer in the mod catalog and was skipped.
+catalog-choose-failed = ERROR: Could not choose mods: { $error }
+melon-version-unavailable = MelonLoader { $version } is unavailable ({ $error }); installing the recommended release instead.
+melon-installed = MelonLoader installed successfully.
+melon-incomplete = ERROR: The MelonLoader archive is incomplete; the existing installation was left untouched.
+err-melon-incomplete = MelonLoader archive is missing version.dll or the MelonLoader folder
+err-melon-version-unknown = No MelonLoader release "{ $version }" on this channel
+
+## Custom Avatar Loader
+
+mod-checking = Checking for { $mod } updates...
+mod-up-to-date = { $mod } is up-to-date (version { $version }).
+mod-newer-installed = Installed { $mod } { $installed } is newer than the latest release { $latest }. Leaving it in place.
+mod-not-installed = { $mod } not installed. Installing now...
+mod-update-available = { $mod } update available: Installed version: { $installed }, Latest version: { $latest }
+mod-update-prompt = Do you want to update { $mod }? (Y/N):{" "}
+mod-update-skipped = Skipping { $mod } update.
+mod-downloading = Downloading { $mod }...
+mod-download-failed = ERROR: Failed to download { $mod }: { $error }
+mod-no-matching-asset = ERROR: No file in the latest { $mod } release matches "{ $pattern }".
+mod-no-dirs = ERROR: Neither 'Mods' nor 'UserLibs' directory found in the extracted archive!
+mod-installed = { $mod } installed/updated successfully.
+mod-reinstalling = Reinstalling { $mod } { $version }...
+mod-no-release-info = Could not retrieve latest { $mod } release info ({ $error }). Skipping update check.
+mod-registered = { $mod } is installed and will be updated with the rest of the install.
+mod-install-failed = ERROR: Installing the mod failed: { $error }
+thunderstore-result = { $package } { $version }
+thunderstore-no-results = No Thunderstore package matches "{ $query }".
+thunderstore-install-hint = Install one with: mod install <package>
+thunderstore-needs = needs { $packages }
+thunderstore-dependency-missing = Dependency { $package } is not on Thunderstore and was not installed.
+thunderstore-failed = ERROR: Thunderstore lookup failed: { $error }
+mod-disabled = { $mod } is disabled; its files are in Mods.disabled:
+mod-enabled = { $mod } is enabled again; its files are back in Mods:
+mod-already-disabled = { $mod } is already disabled.
+mod-already-enabled = { $mod } is not disabled.
+mod-disabled-skipped = { $mod } is disabled; not updating it. Enable it with: mod enable "{ $mod }"
+mod-toggle-failed = ERROR: Could not enable or disable { $mod }: { $error }
+mod-updates-checking = Checking installed mods for updates...
+mod-updates-no-mods = No installed mods to check.
+mod-updates-column-mod = Mod
+mod-updates-column-installed = Installed
+mod-updates-column-latest = Latest
+mod-updates-lookup-failed = (lookup failed)
+mod-updates-none = Every mod is up to date.
+mod-updates-notes = What's new in { $mod } { $version }:
+mod-updates-no-notes = (no release notes)
+mod-updates-done = Updated { $count } mods.
+mod-updates-failed = ERROR: Updating mods failed: { $error }
+mod-rolled-back = { $mod } is back at version { $version } (was { $from }). The next update offers the newer release again; decline it to keep this one.
+mod-rollback-failed = ERROR: Could not roll back { $mod }: { $error }
+profile-saved = Saved profile { $profile } with { $files } enabled files.
+profile-switched = Switched to profile { $profile }.
+profile-file-missing = { $file } from the profile is no longer installed.
+profile-entry = { $profile } ({ $files } files)
+profile-none = No profiles saved. Save the current mods with: profile save <name>
+profile-deleted = Deleted profile { $profile }.
+profile-failed = ERROR: { $error }
+err-mod-no-dirs = { $mod } archive has no Mods or UserLibs directory
+err-no-matching-asset = No release asset matches "{ $pattern }". Set avatar_loader_asset in installer.toml to the new file name.
+
+## Shortcuts
+
+shortcuts-creating = Creating desktop shortcuts...
+shortcuts-no-desktop = ERROR: Cannot determine Desktop directory.
+shortcuts-created = Desktop shortcuts created successfully.
+shortcuts-prompt = Which desktop shortcuts should be created?
+shortcuts-choose = Choose 1-4:{" "}
+shortcuts-name-prompt = Shortcut name (default: { $name }):{" "}
+pin-prompt = Pin DesktopMate to the Start menu? (Y/N){" "}
+safe-mode-prompt = Also create a "DesktopMate (Safe Mode)" shortcut that starts the game without mods, for checking whether a crash is mod-related? (Y/N){" "}
+pin-done = Added "{ $name }" to the Start menu. If it isn't pinned yet, right-click it under All apps and choose "Pin to Start".
+pin-failed = Could not add DesktopMate to the Start menu: { $error }
+shortcuts-variant-both = Both (with and without console)
+shortcuts-variant-no-console = Without console only
+shortcuts-variant-console = With MelonLoader console only
+shortcuts-variant-none = None
+shortcuts-skipped = Skipping desktop shortcuts as requested.
+fix-shortcuts-created = Shortcut created: { $path }
+fix-shortcuts-removed = Removed old shortcut: { $path }
+fix-shortcuts-failed = Could not recreate the shortcuts: { $error }
+defender-adding = Excluding { $path } from Microsoft Defender scans. Windows will ask for administrator permission...
+defender-added = Exclusion added. Run the installer again to re-apply the patch.
+defender-failed = Could not add the exclusion: { $error }
+err-fix-shortcuts-no-game = No DesktopMate install found in { $path }. Pass the install folder: --fix-shortcuts "D:\Games\DesktopMate"
+health-running = Checking the installation...
+health-game-exe = DesktopMate.exe is present
+health-goldberg = steam_api64.dll matches the Goldberg patch
+health-melonloader = MelonLoader's version.dll is present
+health-mod = { $mod }: { $dll } is present
+health-shortcut = Shortcut { $name } points to an existing file
+health-missing = missing: { $path }
+health-hash-mismatch = hash does not match
+health-shortcut-unreadable = could not read the shortcut target
+health-pass = [PASS] { $check }
+health-fail = [FAIL] { $check }
+health-all-passed = All health checks passed.
+step-failed-continuing = WARNING: { $step } failed and was skipped: { $error }. The rest of the install continues.
+resume-continuing = An earlier install into this folder didn't finish; continuing where it stopped.
+resume-step-done = { $step } was completed by the earlier run; skipping.
+steps-skipped = Finished with skipped steps: { $steps }. Run the installer again to retry them.
+recovery-heading = { $step } failed: { $error }
+recovery-retry = Retry the step
+recovery-skip = Skip it and continue
+recovery-abort = Abort and undo this run's changes
+recovery-choose = Choose 1-3:{" "}
+health-some-failed = { $count } health check(s) failed; the game may not start correctly. See the log for details.
+err-no-desktop = Desktop directory unavailable
+err-shortcut = Failed to create shortcut
+err-no-start-menu = Could not find the Start menu programs folder
+err-toast = Showing the notification failed (PowerShell exit code { $code })
+err-kit-checksum = { $file } in the offline kit doesn't match its recorded checksum; the kit is damaged or was modified
+err-kit-missing = { $file } is missing from the offline kit: { $error }
+err-pin-mismatch = { $artifact } doesn't match its pinned SHA-256 (expected { $expected }, got { $actual }); the download may be corrupted or tampered with. Pass --allow-hash-mismatch to install it anyway
+err-pin-unknown-version = { $artifact } isn't in the pinned hash database yet. Pass --allow-hash-mismatch to install it anyway
+err-pin-unknown-hash = { $artifact } (SHA-256 { $actual }) matches none of its pinned hashes. Pass --allow-hash-mismatch to install it anyway
+
+## Network
+
+err-http = HTTP error: { $status }
+err-download-incomplete = The download ended after { $received } of { $expected } bytes
+network-retrying = { $what } failed: { $error }. Retrying in { $seconds } s (attempt { $attempt } of { $attempts })...
+download-trying-mirror = Download failed ({ $error }); trying the mirror { $url }...
+cache-pruned = Deleted { $files } cached files ({ $mb } MB).
+cache-prune-failed = Could not prune the download cache: { $error }
+github-rate-limited = GitHub API rate limit reached; it resets at { $time }
+github-unreachable = Could not reach GitHub: { $error }
+github-no-release = GitHub returned no usable release
+github-using-cache = { $reason }. Using release information saved on { $time }.
+err-archive-unsafe-path = Archive entry "{ $entry }" would be written outside the destination folder
+err-archive-unsupported = { $file } is not a supported archive (expected zip, 7z, tar.gz, or tar.zst)
+extract-progress = Extracting: { $files }/{ $total_files } files ({ $mb } / { $total_mb } MB)
+copy-progress = Copying: { $files }/{ $total_files } files ({ $mb } / { $total_mb } MB)
+backup-prompt = Back up your data ({ $items }) before changing this install? (Y/N):{" "}
+backup-creating = Backing up user data...
+backup-progress = Backing up: { $files }/{ $total_files } files ({ $mb } / { $total_mb } MB)
+backup-created = User data backed up to { $path }
+backup-pruned = Deleted { $count } old backup(s).
+backup-failed = ERROR: Could not back up user data: { $error }
+restore-available = Available backups:
+restore-choose = Backup to restore (number):{" "}
+restore-contents = Backup made { $created } contains:
+restore-choose-items = Parts to restore (e.g. 1,3; Enter for all):{" "}
+restore-target-prompt = Restore into which install? [{ $path }]:{" "}
+restore-none = No backups found in { $path }.
+restore-done = Restored { $count } file(s) to { $path }
+restore-failed = ERROR: Restore failed: { $error }
+err-restore-not-found = No backup named "{ $name }" in the Backups folder
+err-invalid-choice = Not a valid choice: { $choice }
+err-restore-target-missing = Restore target does not exist: { $path }
+copy-kept = Kept your modified file: { $path }
+copy-backed-up = Your modified file was backed up to { $path }
+
+## Wizard (full-screen UI)
+
+step-depot-downloader = DepotDownloader
+step-game-files = Game files
+step-goldberg = Goldberg patch
+step-melonloader = MelonLoader
+step-shortcuts = Shortcuts
+step-health-check = Health check
+
+page-welcome = Welcome
+page-location = Install location
+page-steam = Steam account
+page-options = Options
+page-review = Review
+
+tui-sidebar-title = Steps
+tui-sidebar-setup = Setup
+tui-sidebar-install = Install
+
+wizard-welcome-intro = This installer sets up DesktopMate with mod support. It will install:
+wizard-welcome-game = the DesktopMate game files, downloaded from Steam with your account
+wizard-welcome-goldberg = the Goldberg Steam emulator, so the game runs without the Steam client
+wizard-welcome-melonloader = MelonLoader, the mod loader
+wizard-welcome-avatar-loader = Custom Avatar Loader, for loading your own VRM avatars
+wizard-welcome-shortcuts = desktop and Start menu shortcuts
+wizard-ownership = I own DesktopMate on Steam
+wizard-ownership-required = Please confirm that you own DesktopMate to continue.
+wizard-path-required = Installation path is required.
+wizard-location-question = Where should DesktopMate be installed?
+wizard-location-label = Installation path
+wizard-location-existing = DesktopMate files already exist here; the depot download will be skipped.
+wizard-steam-intro = DepotDownloader needs the Steam account that owns DesktopMate.
+wizard-steam-guard = A Steam Guard prompt, if any, appears once the download starts.
+wizard-steam-user = Steam username
+wizard-steam-password = Steam password
+wizard-options-intro = Optional behaviour
+wizard-option-update-mod = Update Custom Avatar Loader when a newer release is available
+wizard-option-backup = Back up user data (mod settings, avatars, saves) before changing an existing install
+wizard-option-shortcuts = Desktop shortcuts
+wizard-option-pin = Pin DesktopMate to the Start menu
+wizard-option-safe-mode = Also create a "DesktopMate (Safe Mode)" shortcut (starts without mods)
+wizard-review-intro = Ready to install. Review your choices:
+wizard-review-path = Install path
+wizard-review-depot = Game depot
+wizard-review-depot-download = download as { $user }
+wizard-review-depot-update = update to build { $build } as { $user }
+wizard-review-depot-skip = skip (files already present)
+wizard-review-update = Update mod
+wizard-review-backup = Back up user data
+wizard-review-shortcuts = Shortcuts
+wizard-review-pin = Pin to Start
+wizard-review-safe-mode = Safe mode shortcut
+wizard-review-download = Download size
+wizard-yes = yes
+wizard-no = no
+wizard-review-start = Press Enter to start the installation.
+
+hint-welcome = Space: confirm ownership   Enter: next   Esc: quit
+hint-location-back = Enter: next   Esc: back
+hint-location = Enter: next   Esc: quit
+hint-steam = Enter: next   Tab: switch field   Esc: back
+hint-options = ↑/↓: select   Space: toggle   Enter: next   Esc: back
+hint-review = Enter: install   Esc: back   Ctrl+C: quit
+hint-running = ↑/↓ PgUp/PgDn Home/End: scroll log   Ctrl+C: abort
+hint-finished = Press any key to exit   ↑/↓ PgUp/PgDn: scroll log
+
+progress-title = Progress
+progress-steps = { $completed }/{ $total } steps
+status-title = Status
+status-complete = Installation complete.
+status-failed = Installation failed: { $error }
+status-running = { $step }...
+status-starting = Starting...
+log-title = Log
+log-title-scrolled = Log (scrolled back { $lines } lines)
+tui-stopped-unexpectedly = Installer stopped unexpectedly.
@@ -0,0 +1,445 @@
+## DesktopMate Installer — Español
+
+app-title = Instalador de DesktopMate
+press-any-key = Pulsa cualquier tecla para salir...
+config-ignored = Se ignora installer.toml: { $error }
+proxy-invalid = Se ignora el proxy: { $error }
+ca-cert-invalid = Se ignora el certificado de CA: { $error }
+theme-color-unknown = Color de tema desconocido "{ $color }" en installer.toml; se mantiene el color del preajuste.
+language-unsupported = El idioma "{ $language }" no está disponible; se usará el idioma del sistema. Disponibles: { $available }
+
+## Installer self-update
+
+update-check-failed = No se pudo comprobar si hay actualizaciones del instalador: { $error }
+update-available = Hay un instalador más reciente: Versión instalada: { $installed }, Última versión: { $latest }
+update-prompt = ¿Quieres actualizar el instalador ahora? (Y/N):{" "}
+update-downloading = Descargando la actualización del instalador...
+update-rejected = ERROR: Se rechaza ejecutar la actualización del instalador: { $reason }
+update-reason-no-key = se publicó una firma minisign pero esta compilación no tiene clave de verificación
+update-reason-bad-key = clave pública integrada no válida: { $error }
+update-reason-bad-signature-file = no se puede leer la firma: { $error }
+update-reason-mismatch = la firma minisign no coincide: { $error }
+update-reason-unsigned = la descarga no tiene una firma válida
+update-reason-wrong-publisher = está firmada por { $publisher }, no por { $expected }
+update-applied = Instalador actualizado a { $version }. Reiniciando...
+
+## Classic prompts
+
+prompt-yes = Sí
+prompt-no = No
+prompt-timed-out = No hubo respuesta a tiempo; se continúa con el valor predeterminado ({ $answer }).
+err-unattended-question = esta pregunta no tiene respuesta predeterminada, así que una ejecución desatendida no puede responderla: { $question }
+first-run-ownership-prompt = ¿Tienes DesktopMate en Steam? (Y/N):{" "}
+first-run-ownership-declined = Este instalador es solo para quienes tienen DesktopMate. Compra primero el juego en Steam.
+unattended-ownership-hint = En ejecuciones desatendidas, confirma que tienes el juego con --confirm-ownership.
+prompt-install-path = Introduce la ruta de instalación (predeterminada: { $path }):{" "}
+install-dir-set = Directorio de instalación: { $path }
+download-size = unos { $mb } MB
+download-size-unknown = (más { $items }, tamaño desconocido)
+download-estimate = Descarga estimada: { $size }
+download-estimate-continue = ¿Continuar con la instalación? (Y/N):{" "}
+credentials-failed = No se puede usar el archivo de credenciales: { $error }
+credentials-no-password = ERROR: en una ejecución desatendida, --steam-user necesita la contraseña en la variable de entorno { $var }.
+err-credentials-read = No se pudo leer { $path }: { $error }
+err-credentials-permissions = { $group } puede leer { $path }. Restríngelo primero a tu cuenta, p. ej. icacls "{ $path }" /inheritance:r /grant:r "%USERNAME%:R"
+err-credentials-incomplete = { $path } no tiene "login" y "password" para "machine steam" ni una entrada default.
+prompt-steam-user = Introduce tu nombre de usuario de Steam:{" "}
+steam-user-required = El nombre de usuario de Steam es obligatorio.
+prompt-steam-password = Introduce tu contraseña de Steam:{" "}
+install-failed = La instalación falló: { $error }
+rollback-prompt = ¿Deshacer los cambios que esta ejecución hizo en la carpeta del juego y los accesos directos? (Y/N):{" "}
+rollback-declined = Se conservaron los cambios. Vuelva a ejecutar el instalador para reintentarlo.
+rollback-done = Se deshicieron { $count } cambio(s); la carpeta del juego volvió a su estado anterior a esta ejecución.
+rollback-incomplete = No se pudieron deshacer algunos cambios: { $error }
+injection-prompt = ¿Iniciar DesktopMate durante un máximo de { $seconds } segundos para comprobar que MelonLoader se carga?
+injection-running = Iniciando DesktopMate y esperando a MelonLoader...
+injection-ok = MelonLoader { $version } se cargó en el juego.
+injection-game-exited = DesktopMate se cerró (código de salida { $code }) antes de que MelonLoader se cargara. Revisa tu antivirus y que el runtime de Visual C++ esté instalado.
+injection-no-log = MelonLoader no se inició en { $seconds } segundos; los mods no se cargarán. Revisa tu antivirus, o ejecuta el comando remove-melonloader y vuelve a instalar.
+injection-failed = No se pudo iniciar DesktopMate para la comprobación: { $error }
+launch-prompt = ¿Iniciar DesktopMate ahora?
+launch-console = Sí, con la consola de MelonLoader
+launch-no-console = Sí, sin la consola
+launch-no = No
+launch-choose = Elige 1-3:{" "}
+launch-started = DesktopMate se está iniciando.
+launch-failed = No se pudo iniciar DesktopMate: { $error }
+install-complete-exit = Instalación completada. Pulsa cualquier tecla para salir.
+summary-title = Resumen de la instalación
+summary-install-dir = Carpeta de instalación: { $path }
+summary-game-build = Manifiesto del juego: { $manifest }
+summary-melonloader = MelonLoader: { $version }
+summary-mod = { $mod }: { $version }
+summary-unknown = desconocida
+
+## DepotDownloader and the game depot
+
+dd-missing = ¡No se encontró DepotDownloader.exe! Descargándolo...
+dd-download-failed = ERROR: ¡No se pudo descargar DepotDownloader! { $error }
+dd-extracting = Extrayendo DepotDownloader...
+dd-extract-failed = ERROR: ¡No se pudo extraer DepotDownloader! { $error }
+dd-still-missing = ERROR: ¡DepotDownloader.exe sigue sin aparecer tras la extracción!
+dd-ready = DepotDownloader descargado y extraído correctamente.
+dd-version-unknown = una versión desconocida
+dd-upgrade-prompt = DepotDownloader { $installed } está instalado; { $latest } está disponible. ¿Actualizarlo? (Y/N):{" "}
+dd-switching = Reemplazando DepotDownloader { $installed } por la versión fijada { $version }...
+dotnet-missing = DepotDownloader necesita el entorno de ejecución .NET { $runtime }, que no está instalado.
+dotnet-install-prompt = ¿Descargar y ejecutar ahora el instalador de Microsoft? (Y/N):{" "}
+dotnet-downloading = Descargando el instalador del entorno de ejecución .NET...
+dotnet-installing = Instalando el entorno de ejecución .NET...
+depot-downloading = Descargando el depot de DesktopMate (mediante DepotDownloader)...
+depot-failed = ERROR: DepotDownloader encontró un error. Código de salida = { $code }
+depot-not-owned = Steam indica que esta cuenta no tiene DesktopMate. Inicia sesión con la cuenta de Steam que compró el juego; otra cuenta o una compartida en familia no puede descargarlo.
+depot-login-rejected = Steam rechazó el inicio de sesión. Comprueba el usuario y la contraseña, e introduce el código de Steam Guard actual si se solicita.
+depot-complete = Descarga del depot completada.
+game-update-available = Está instalada la compilación { $installed } de DesktopMate; la compilación { $latest } está disponible.
+game-update-prompt = ¿Actualizar el juego ahora? Después se vuelve a aplicar Goldberg y se comprueban los mods. (Y/N){" "}
+game-updating = Actualizando DesktopMate a la compilación { $build }...
+game-validating = Comprobando todos los archivos del juego y volviendo a descargar los dañados...
+check-updates-current = { $component }: { $installed } (actualizado)
+check-updates-outdated = { $component }: { $installed } instalado, { $latest } disponible
+check-updates-unknown = { $component }: versión instalada desconocida
+check-updates-none = Todo está actualizado.
+update-failed = ERROR: No se pudo buscar actualizaciones: { $error }
+check-updates-install = ¿Instalar las actualizaciones ahora? (Y/N){" "}
+watch-started = Vigilando { $path } en busca de actualizaciones. Se comprueba ahora y luego una vez al día; cierra esta ventana o pulsa Ctrl+C para detenerlo.
+watch-toast-title = Hay actualizaciones de DesktopMate — ejecuta el instalador para actualizar
+watch-check-failed = Error al buscar actualizaciones: { $error }
+package-fetching = Empaquetando { $file }...
+package-done = Paquete sin conexión listo en { $path }. Comparte la carpeta completa; el juego en sí se sigue descargando de Steam.
+package-failed = No se pudo crear el paquete sin conexión: { $error }
+package-needs-folder = --package necesita la carpeta donde crear el paquete, p. ej. --package D:\DesktopMateKit
+pin-hashing = Calculando el hash de { $artifact }...
+pin-recorded = Hashes fijados escritos en { $path }.
+pin-record-failed = No se pudieron registrar los hashes fijados: { $error }
+pin-needs-file = --pin-hashes necesita el archivo de base de datos que actualizar, p. ej. --pin-hashes manifest\hashes.json
+hash-check-failed = ERROR: { $error }
+pin-overridden = ADVERTENCIA: { $artifact } no superó la comprobación de integridad; se instala igualmente por --allow-hash-mismatch.
+pin-mod-unpinned = ADVERTENCIA: { $artifact } es más reciente que la base de datos de hashes fijados, así que no se pudo verificar la descarga.
+logs-missing = Todavía no hay registro en { $path }.
+logs-empty = Ninguna entrada del registro coincide.
+logs-unreadable = No se pudo leer el registro: { $error }
+logs-bad-level = Nivel desconocido "{ $level }"; usa info, warning o error. Se muestra todo.
+logs-run-started = ── Ejecución iniciada { $time } (instalador { $version }) ──
+logs-step = { $time }  ▶ { $step }
+logs-run-succeeded = { $time }  Instalación completada correctamente.
+logs-run-failed = { $time }  La instalación falló: { $error }
+game-manifest-invalid = No se puede usar la definición de juego de installer.toml (game_manifest): { $error }
+depot-skipped = Los archivos de DesktopMate ya existen. Se omite la descarga del depot.
+steam-copy-prompt = Steam ya tiene DesktopMate instalado en { $path } (compilación { $build }). ¿Copiar esos archivos en lugar de descargarlos? (Y/N):{" "}
+steam-copy-copying = Copiando los archivos del juego desde la biblioteca de Steam...
+steam-copy-done = Se copiaron { $count } archivos del juego desde Steam.
+steam-copy-failed = No se pudo copiar el juego desde Steam ({ $error }); se descargará en su lugar.
+verify-fetching-manifest = Obteniendo el manifiesto del depósito desde Steam...
+verify-checking = Comprobando { $count } archivos del juego...
+verify-component = { $component } { $version }, descargado de { $source }
+verify-progress = Verificando: { $files }/{ $total_files } archivos ({ $mb } / { $total_mb } MB)
+verify-missing = [FALTA] { $file }
+verify-modified = [MODIFICADO] { $file }
+verify-patched = [PARCHEADO] { $file } (esperado: reemplazado por el instalador)
+verify-ok = Los { $count } archivos del juego coinciden con el manifiesto del depósito.
+verify-damaged = { $count } archivo(s) del juego faltan o están modificados. Para repararlo, elimine la carpeta DesktopMate_Data y vuelva a ejecutar el instalador.
+verify-failed = ERROR: La verificación falló: { $error }
+repair-starting = Reparando la instalación en { $path }...
+integrity-checking = Comparando los componentes instalados con sus hashes registrados...
+integrity-damaged = { $component }: faltan { $missing } archivo(s) y { $modified } archivo(s) han cambiado.
+integrity-ok = Los archivos de todos los componentes instalados coinciden con sus hashes registrados.
+integrity-unhashed = Instalados con un instalador anterior, así que sus archivos no se pueden comprobar: { $components }
+repair-reinstalling = Reinstalando: { $components }
+repair-components-intact = Ningún componente está dañado; solo se comprobarán los archivos del juego.
+uninstall-prompt = ¿Eliminar { $path } y todo su contenido, incluidos mods y avatares? (Y/N):{" "}
+uninstall-nothing = Nada que desinstalar: { $path } no existe.
+uninstall-removing = Eliminando { $path }...
+uninstall-done = Desinstalado. Las copias de seguridad, si las hay, están en la carpeta Backups junto al instalador.
+uninstall-failed = ERROR: La desinstalación falló: { $error }
+steam-mode-restoring = Restaurando el steam_api64.dll original y quitando Goldberg...
+steam-mode-done = Modo Steam restaurado. El juego ahora se ejecuta a través del cliente de Steam; instalar o actualizar con este instalador vuelve a aplicar el parche.
+steam-mode-failed = ERROR: No se pudo cambiar al modo Steam: { $error }
+err-no-game = No se encontró una instalación de DesktopMate en { $path }
+err-mod-url = «{ $url }» no es un repositorio de GitHub (https://github.com/owner/repo), un paquete de Thunderstore (Namespace-Name) ni un mod de GameBanana (https://gamebanana.com/mods/<id>)
+err-mod-not-installed = { $mod } no se instaló; consulta los mensajes anteriores
+err-mod-unknown = Ningún mod instalado ni DLL en Mods se llama «{ $mod }»
+err-profile-unknown = Ningún perfil se llama «{ $profile }»
+err-mod-rollback-disabled = { $mod } está desactivado; actívalo antes de revertirlo
+err-mod-nothing-kept = Aún no hay ninguna versión anterior de { $mod } guardada; se guardan a partir de su próxima actualización
+err-mod-no-kept-version = No hay ninguna versión guardada de { $mod } a la que volver (guardadas: { $versions })
+err-steam-mode-no-backup = No hay ninguna copia del steam_api64.dll original registrada para esta instalación. Usa «Verificar la integridad de los archivos del juego» en Steam para recuperarlo
+err-steam-mode-backup-changed = La copia { $path } falta o ha cambiado, así que no se restauró. Usa «Verificar la integridad de los archivos del juego» en Steam
+err-verify-no-manifest = DepotDownloader no generó una lista del manifiesto
+err-dd-download = Falló la descarga de DepotDownloader
+err-dd-extract = Falló la extracción de DepotDownloader
+err-dd-missing = Falta DepotDownloader.exe tras la extracción
+err-dotnet-missing = DepotDownloader necesita el entorno de ejecución .NET { $runtime }. Instálalo desde { $url } y vuelve a ejecutar el instalador
+err-dotnet-unsigned = El instalador descargado del entorno de ejecución .NET no está firmado; no se ejecutó
+err-dotnet-wrong-publisher = El instalador descargado del entorno de ejecución .NET está firmado por { $publisher }, no por Microsoft; no se ejecutó
+signature-invalid = ADVERTENCIA: la firma de { $file } no se puede verificar (editor indicado: { $publisher }). Puede haber sido manipulado.
+signature-wrong-publisher = ADVERTENCIA: { $file } está firmado por { $publisher }, no por { $expected }. Puede que no sea la versión oficial.
+signature-unsigned = ADVERTENCIA: { $file } no está firmado, aunque { $expected } firma sus versiones. Puede que no sea la versión oficial.
+err-depot-exit = DepotDownloader terminó con el código { $code }
+
+## Goldberg patch
+
+goldberg-downloading = Descargando el parche de Goldberg...
+goldberg-provider-fallback = No se pudo obtener el parche de Goldberg ({ $error }). Probando { $provider } en su lugar...
+goldberg-applied = Parche de Goldberg aplicado correctamente.
+goldberg-rechecking = Comprobando que el parche sigue en su sitio...
+av-patch-removed = ERROR: steam_api64.dll desapareció o cambió segundos después de escribirse. Lo más probable es que tu antivirus haya puesto en cuarentena el parche de Goldberg.
+av-patch-lost = El parche de Goldberg aplicado la última vez falta o ha cambiado. Lo más probable es que tu antivirus lo haya puesto en cuarentena; se aplicará de nuevo ahora.
+av-exclusion-hint = Excluye la carpeta del juego del análisis (ejecuta el instalador con --defender-exclusion para Microsoft Defender, o usa la configuración de tu antivirus) y vuelve a ejecutar el instalador.
+goldberg-no-target = ERROR: No se pudo determinar el directorio de destino de la DLL del parche de Goldberg.
+goldberg-dll-missing = ERROR: ¡No se encontró steam_api64.dll en el archivo del parche!
+err-goldberg-target = Directorio de destino del parche de Goldberg no disponible
+err-goldberg-dll = Falta steam_api64.dll en el archivo de Goldberg
+err-steam-api-backup = No se pudo hacer una copia del steam_api64.dll original, así que no se reemplazó: { $error }
+err-av-quarantined = el parche de Goldberg se eliminó justo después de aplicarse, probablemente por el antivirus
+err-defender-exclusion = no se pudo añadir la exclusión de Defender o se canceló (código de salida { $code })
+
+## MelonLoader
+
+melon-up-to-date = MelonLoader está actualizado (versión { $version }).
+adopt-found = Se encontró { $component } instalado manualmente (versión: { $version }); a partir de ahora se actualizará.
+melon-installing = Instalando MelonLoader { $version }...
+melon-downgrading = El MelonLoader instalado { $installed } es más reciente que la versión compatible { $version }; se reemplazará.
+melon-extracting = Extrayendo MelonLoader en el directorio del juego...
+melon-remove-prompt = ¿Quitar MelonLoader de { $path }? Los mods y sus ajustes se conservan.
+melon-removing = Quitando MelonLoader...
+melon-remove-done = MelonLoader quitado. El juego ahora se inicia sin mods.
+melon-remove-nothing = MelonLoader no está instalado ahí.
+melon-remove-failed = ERROR: No se pudo quitar MelonLoader: { $error }
+melon-version-corrected = install.json registraba MelonLoader { $recorded }, pero está instalado { $detected }; se ha corregido el registro.
+melon-versions = Versiones de MelonLoader:
+melon-choose = Versión de MelonLoader que instalar (número):{" "}
+melon-version-recommended = { $version } (recomendada)
+melon-choose-failed = ERROR: No se pudo elegir una versión de MelonLoader: { $error }
+catalog-heading = Mods que puedes instalar además de Custom Avatar Loader:
+catalog-choose = Números de los mods que instalar, separados por comas (vacío para ninguno):{" "}
+catalog-empty = El catálogo de mods aún no incluye otros mods.
+catalog-mod-unknown = { $component } ya no está en el catálogo de mods y se ha omitido.
+catalog-choose-failed = ERROR: No se pudieron elegir los mods: { $error }
+melon-version-unavailable = MelonLoader { $version } no está disponible ({ $error }); se instalará la versión recomendada.
+melon-installed = MelonLoader instalado correctamente.
+melon-incomplete = ERROR: El archivo de MelonLoader está incompleto; la instalación existente no se modificó.
+err-melon-incomplete = Al archivo de MelonLoader le falta version.dll o la carpeta MelonLoader
+err-melon-version-unknown = No hay ninguna versión de MelonLoader «{ $version }» en este canal
+
+## Custom Avatar Loader
+
+mod-checking = Buscando actualizaciones de { $mod }...
+mod-up-to-date = { $mod } está actualizado (versión { $version }).
+mod-newer-installed = La versión instalada de { $mod } { $installed } es más reciente que la última versión { $latest }. Se deja como está.
+mod-not-installed = { $mod } no está instalado. Instalándolo...
+mod-update-available = Hay una actualización de { $mod }: Versión instalada: { $installed }, Última versión: { $latest }
+mod-update-prompt = ¿Quieres actualizar { $mod }? (Y/N):{" "}
+mod-update-skipped = Se omite la actualización de { $mod }.
+mod-downloading = Descargando { $mod }...
+mod-download-failed = ERROR: No se pudo descargar { $mod }: { $error }
+mod-no-matching-asset = ERROR: Ningún archivo de la última versión de { $mod } coincide con "{ $pattern }".
+mod-no-dirs = ERROR: ¡El archivo extraído no contiene ni 'Mods' ni 'UserLibs'!
+mod-installed = { $mod } instalado/actualizado correctamente.
+mod-reinstalling = Reinstalando { $mod } { $version }...
+mod-no-release-info = No se pudo obtener la información de la última versión de { $mod } ({ $error }). Se omite la comprobación.
+mod-registered = { $mod } está instalado y se actualizará junto con el resto de la instalación.
+mod-install-failed = ERROR: No se pudo instalar el mod: { $error }
+thunderstore-result = { $package } { $version }
+thunderstore-no-results = Ningún paquete de Thunderstore coincide con «{ $query }».
+thunderstore-install-hint = Instala uno con: mod install <paquete>
+thunderstore-needs = necesita { $packages }
+thunderstore-dependency-missing = La dependencia { $package } no está en Thunderstore y no se instaló.
+thunderstore-failed = ERROR: Falló la consulta a Thunderstore: { $error }
+mod-disabled = { $mod } está desactivado; sus archivos están en Mods.disabled:
+mod-enabled = { $mod } vuelve a estar activado; sus archivos están de nuevo en Mods:
+mod-already-disabled = { $mod } ya está desactivado.
+mod-already-enabled = { $mod } no está desactivado.
+mod-disabled-skipped = { $mod } está desactivado; no se actualiza. Actívalo con: mod enable "{ $mod }"
+mod-toggle-failed = ERROR: No se pudo activar o desactivar { $mod }: { $error }
+mod-updates-checking = Buscando actualizaciones de los mods instalados...
+mod-updates-no-mods = No hay mods instalados que comprobar.
+mod-updates-column-mod = Mod
+mod-updates-column-installed = Instalada
+mod-updates-column-latest = Última
+mod-updates-lookup-failed = (falló la consulta)
+mod-updates-none = Todos los mods están actualizados.
+mod-updates-notes = Novedades de { $mod } { $version }:
+mod-updates-no-notes = (sin notas de la versión)
+mod-updates-done = { $count } mods actualizados.
+mod-updates-failed = ERROR: Falló la actualización de los mods: { $error }
+mod-rolled-back = { $mod } vuelve a la versión { $version } (era { $from }). La próxima actualización volverá a ofrecer la versión nueva; recházala para conservar esta.
+mod-rollback-failed = ERROR: No se pudo revertir { $mod }: { $error }
+profile-saved = Perfil { $profile } guardado con { $files } archivos activados.
+profile-switched = Cambiado al perfil { $profile }.
+profile-file-missing = { $file } del perfil ya no está instalado.
+profile-entry = { $profile } ({ $files } archivos)
+profile-none = No hay perfiles guardados. Guarda los mods actuales con: profile save <nombre>
+profile-deleted = Perfil { $profile } eliminado.
+profile-failed = ERROR: { $error }
+err-mod-no-dirs = El archivo de { $mod } no contiene Mods ni UserLibs
+err-no-matching-asset = Ningún archivo de la versión coincide con "{ $pattern }". Establece avatar_loader_asset en installer.toml con el nuevo nombre de archivo.
+
+## Shortcuts
+
+shortcuts-creating = Creando accesos directos en el escritorio...
+shortcuts-no-desktop = ERROR: No se pudo determinar el directorio del escritorio.
+shortcuts-created = Accesos directos creados correctamente.
+shortcuts-prompt = ¿Qué accesos directos del escritorio se deben crear?
+shortcuts-choose = Elige 1-4:{" "}
+shortcuts-name-prompt = Nombre del acceso directo (predeterminado: { $name }):{" "}
+pin-prompt = ¿Anclar DesktopMate al menú Inicio? (Y/N){" "}
+safe-mode-prompt = ¿Crear también un acceso directo "DesktopMate (Safe Mode)" que inicia el juego sin mods, para comprobar si un fallo se debe a un mod? (Y/N){" "}
+pin-done = Se añadió "{ $name }" al menú Inicio. Si aún no está anclado, haz clic derecho sobre él en Todas las aplicaciones y elige "Anclar a Inicio".
+pin-failed = No se pudo añadir DesktopMate al menú Inicio: { $error }
+shortcuts-variant-both = Ambos (con y sin consola)
+shortcuts-variant-no-console = Solo sin consola
+shortcuts-variant-console = Solo con la consola de MelonLoader
+shortcuts-variant-none = Ninguno
+shortcuts-skipped = Se omiten los accesos directos del escritorio, como se pidió.
+fix-shortcuts-created = Acceso directo creado: { $path }
+fix-shortcuts-removed = Acceso directo antiguo eliminado: { $path }
+fix-shortcuts-failed = No se pudieron recrear los accesos directos: { $error }
+defender-adding = Excluyendo { $path } de los análisis de Microsoft Defender. Windows pedirá permisos de administrador...
+defender-added = Exclusión añadida. Vuelve a ejecutar el instalador para aplicar de nuevo el parche.
+defender-failed = No se pudo añadir la exclusión: { $error }
+err-fix-shortcuts-no-game = No se encontró DesktopMate en { $path }. Indica la carpeta de instalación: --fix-shortcuts "D:\Games\DesktopMate"
+health-running = Comprobando la instalación...
+health-game-exe = DesktopMate.exe está presente
+health-goldberg = steam_api64.dll coincide con el parche de Goldberg
+health-melonloader = version.dll de MelonLoader está presente
+health-mod = { $mod }: { $dll } está presente
+health-shortcut = El acceso directo { $name } apunta a un archivo existente
+health-missing = falta: { $path }
+health-hash-mismatch = el hash no coincide
+health-shortcut-unreadable = no se pudo leer el destino del acceso directo
+health-pass = [OK] { $check }
+health-fail = [ERROR] { $check }
+health-all-passed = Todas las comprobaciones se superaron.
+step-failed-continuing = ADVERTENCIA: { $step } falló y se omitió: { $error }. El resto de la instalación continúa.
+resume-continuing = Una instalación anterior en esta carpeta no terminó; se continúa donde se detuvo.
+resume-step-done = { $step } ya se completó en la ejecución anterior; se omite.
+steps-skipped = Terminado con pasos omitidos: { $steps }. Vuelve a ejecutar el instalador para reintentarlos.
+recovery-heading = { $step } falló: { $error }
+recovery-retry = Reintentar el paso
+recovery-skip = Omitirlo y continuar
+recovery-abort = Cancelar y deshacer los cambios de esta ejecución
+recovery-choose = Elige 1-3:{" "}
+health-some-failed = { $count } comprobación(es) fallaron; es posible que el juego no se inicie correctamente. Consulte el registro.
+err-no-desktop = Directorio del escritorio no disponible
+err-shortcut = No se pudo crear el acceso directo
+err-no-start-menu = No se encontró la carpeta de programas del menú Inicio
+err-toast = No se pudo mostrar la notificación (código de salida de PowerShell { $code })
+err-kit-checksum = { $file } del paquete sin conexión no coincide con su suma de comprobación; el paquete está dañado o fue modificado
+err-kit-missing = Falta { $file } en el paquete sin conexión: { $error }
+err-pin-mismatch = { $artifact } no coincide con su SHA-256 fijado (se esperaba { $expected }, se obtuvo { $actual }); la descarga puede estar dañada o manipulada. Usa --allow-hash-mismatch para instalarlo igualmente
+err-pin-unknown-version = { $artifact } aún no está en la base de datos de hashes fijados. Usa --allow-hash-mismatch para instalarlo igualmente
+err-pin-unknown-hash = { $artifact } (SHA-256 { $actual }) no coincide con ninguno de sus hashes fijados. Usa --allow-hash-mismatch para instalarlo igualmente
+
+## Network
+
+err-http = Error HTTP: { $status }
+err-download-incomplete = La descarga terminó tras { $received } de { $expected } bytes
+network-retrying = { $what } falló: { $error }. Reintentando en { $seconds } s (intento { $attempt } de { $attempts })...
+download-trying-mirror = La descarga falló ({ $error }); probando el espejo { $url }...
+cache-pruned = Se eliminaron { $files } archivos en caché ({ $mb } MB).
+cache-prune-failed = No se pudo limpiar la caché de descargas: { $error }
+github-rate-limited = Se alcanzó el límite de la API de GitHub; se restablece a las { $time }
+github-unreachable = No se pudo contactar con GitHub: { $error }
+github-no-release = GitHub no devolvió ninguna versión utilizable
+github-using-cache = { $reason }. Se usa la información de versión guardada el { $time }.
+err-archive-unsafe-path = La entrada "{ $entry }" del archivo se escribiría fuera de la carpeta de destino
+err-archive-unsupported = { $file } no es un archivo compatible (se esperaba zip, 7z, tar.gz o tar.zst)
+extract-progress = Extrayendo: { $files }/{ $total_files } archivos ({ $mb } / { $total_mb } MB)
+copy-progress = Copiando: { $files }/{ $total_files } archivos ({ $mb } / { $total_mb } MB)
+backup-prompt = ¿Hacer copia de tus datos ({ $items }) antes de modificar esta instalación? (Y/N):{" "}
+backup-creating = Haciendo copia de los datos de usuario...
+backup-progress = Copiando datos: { $files }/{ $total_files } archivos ({ $mb } / { $total_mb } MB)
+backup-created = Datos de usuario guardados en { $path }
+backup-pruned = Se eliminaron { $count } copia(s) antigua(s).
+backup-failed = ERROR: No se pudo hacer copia de los datos de usuario: { $error }
+restore-available = Copias de seguridad disponibles:
+restore-choose = Copia que restaurar (número):{" "}
+restore-contents = La copia creada el { $created } contiene:
+restore-choose-items = Partes que restaurar (p. ej. 1,3; Enter para todas):{" "}
+restore-target-prompt = ¿En qué instalación restaurar? [{ $path }]:{" "}
+restore-none = No se encontraron copias en { $path }.
+restore-done = Restaurados { $count } archivo(s) en { $path }
+restore-failed = ERROR: La restauración falló: { $error }
+err-restore-not-found = No hay ninguna copia llamada "{ $name }" en la carpeta Backups
+err-invalid-choice = Opción no válida: { $choice }
+err-restore-target-missing = El destino de la restauración no existe: { $path }
+copy-kept = Se conservó su archivo modificado: { $path }
+copy-backed-up = Su archivo modificado se guardó como copia en { $path }
+
+## Wizard (full-screen UI)
+
+step-depot-downloader = DepotDownloader
+step-game-files = Archivos del juego
+step-goldberg = Parche de Goldberg
+step-melonloader = MelonLoader
+step-shortcuts = Accesos directos
+step-health-check = Comprobación
+
+page-welcome = Bienvenida
+page-location = Ubicación
+page-steam = Cuenta de Steam
+page-options = Opciones
+page-review = Resumen
+
+tui-sidebar-title = Pasos
+tui-sidebar-setup = Configuración
+tui-sidebar-install = Instalación
+
+wizard-welcome-intro = Este instalador configura DesktopMate con soporte para mods. Se instalará:
+wizard-welcome-game = los archivos del juego DesktopMate, descargados de Steam con tu cuenta
+wizard-welcome-goldberg = el emulador de Steam Goldberg, para que el juego funcione sin el cliente de Steam
+wizard-welcome-melonloader = MelonLoader, el cargador de mods
+wizard-welcome-avatar-loader = Custom Avatar Loader, para cargar tus propios avatares VRM
+wizard-welcome-shortcuts = accesos directos en el escritorio y el menú Inicio
+wizard-ownership = Tengo DesktopMate en Steam
+wizard-ownership-required = Confirma que tienes DesktopMate para continuar.
+wizard-path-required = La ruta de instalación es obligatoria.
+wizard-location-question = ¿Dónde quieres instalar DesktopMate?
+wizard-location-label = Ruta de instalación
+wizard-location-existing = Ya hay archivos de DesktopMate aquí; se omitirá la descarga del depot.
+wizard-steam-intro = DepotDownloader necesita la cuenta de Steam que posee DesktopMate.
+wizard-steam-guard = Si hace falta un código de Steam Guard, se pedirá al empezar la descarga.
+wizard-steam-user = Usuario de Steam
+wizard-steam-password = Contraseña de Steam
+wizard-options-intro = Comportamiento opcional
+wizard-option-update-mod = Actualizar Custom Avatar Loader cuando haya una versión más reciente
+wizard-option-backup = Hacer copia de los datos de usuario (ajustes de mods, avatares, partidas) antes de modificar una instalación existente
+wizard-option-shortcuts = Accesos directos del escritorio
+wizard-option-pin = Anclar DesktopMate al menú Inicio
+wizard-option-safe-mode = Crear también un acceso directo "DesktopMate (Safe Mode)" (inicia sin mods)
+wizard-review-intro = Listo para instalar. Revisa tus opciones:
+wizard-review-path = Ruta de instalación
+wizard-review-depot = Depot del juego
+wizard-review-depot-download = descargar como { $user }
+wizard-review-depot-update = actualizar a la compilación { $build } como { $user }
+wizard-review-depot-skip = omitir (los archivos ya existen)
+wizard-review-update = Actualizar mod
+wizard-review-backup = Copia de datos de usuario
+wizard-review-shortcuts = Accesos directos
+wizard-review-pin = Anclar a Inicio
+wizard-review-safe-mode = Acceso directo en modo seguro
+wizard-review-download = Tamaño de descarga
+wizard-yes = sí
+wizard-no = no
+wizard-review-start = Pulsa Enter para empezar la instalación.
+
+hint-welcome = Espacio: confirmar propiedad   Enter: siguiente   Esc: salir
+hint-location-back = Enter: siguiente   Esc: atrás
+hint-location = Enter: siguiente   Esc: salir
+hint-steam = Enter: siguiente   Tab: cambiar campo   Esc: atrás
+hint-options = ↑/↓: elegir   Espacio: alternar   Enter: siguiente   Esc: atrás
+hint-review = Enter: instalar   Esc: atrás   Ctrl+C: salir
+hint-running = ↑/↓ RePág/AvPág Inicio/Fin: desplazar registro   Ctrl+C: cancelar
+hint-finished = Pulsa cualquier tecla para salir   ↑/↓ RePág/AvPág: desplazar registro
+
+progress-title = Progreso
+progress-steps = { $completed }/{ $total } pasos
+status-title = Estado
+status-complete = Instalación completada.
+status-failed = La instalación falló: { $error }
+status-running = { $step }...
+status-starting = Iniciando...
+log-title = Registro
+log-title-scrolled = Registro (desplazado { $lines } líneas atrás)
+tui-stopped-unexpectedly = El instalador se detuvo inesperadamente.
@@ -0,0 +1,445 @@
+## DesktopMate Installer — 日本語
+
+app-title = DesktopMate インストーラー
+press-any-key = 何かキーを押すと終了します...
+config-ignored = installer.toml を無視します: { $error }
+proxy-invalid = プロキシ設定を無視します: { $error }
+ca-cert-invalid = CA 証明書を無視します: { $error }
+theme-color-unknown = installer.toml のテーマ色 "{ $color }" は不明です。プリセットの色を使います。
+language-unsupported = 言語「{ $language }」は利用できません。システムの言語を使用します。利用可能: { $available }
+
+## Installer self-update
+
+update-check-failed = インストーラーの更新確認に失敗しました: { $error }
+update-available = 新しいインストーラーがあります: 現在のバージョン: { $installed }、最新バージョン: { $latest }
+update-prompt = 今すぐインストーラーを更新しますか? (Y/N):{" "}
+update-downloading = インストーラーの更新をダウンロードしています...
+update-rejected = エラー: インストーラーの更新を実行できません: { $reason }
+update-reason-no-key = minisign 署名が公開されていますが、このビルドには検証用の鍵がありません
+update-reason-bad-key = 組み込みの公開鍵が無効です: { $error }
+update-reason-bad-signature-file = 署名を読み込めません: { $error }
+update-reason-mismatch = minisign 署名が一致しません: { $error }
+update-reason-unsigned = ダウンロードしたファイルに有効な署名がありません
+update-reason-wrong-publisher = { $expected } ではなく { $publisher } によって署名されています
+update-applied = インストーラーを { $version } に更新しました。再起動します...
+
+## Classic prompts
+
+prompt-yes = はい
+prompt-no = いいえ
+prompt-timed-out = 時間内に応答がなかったため、既定値 ({ $answer }) で続行します。
+err-unattended-question = この質問には既定の回答がないため、無人実行では回答できません: { $question }
+first-run-ownership-prompt = Steam で DesktopMate を所有していますか？ (Y/N):{" "}
+first-run-ownership-declined = このインストーラーは DesktopMate の所有者専用です。先に Steam でゲームを購入してください。
+unattended-ownership-hint = 無人実行では --confirm-ownership でゲームの所有を確認します。
+prompt-install-path = インストール先を入力してください (既定: { $path }):{" "}
+install-dir-set = インストール先: { $path }
+download-size = 約 { $mb } MB
+download-size-unknown = (これに加えて { $items }、サイズ不明)
+download-estimate = ダウンロード量の目安: { $size }
+download-estimate-continue = インストールを続行しますか？ (Y/N):{" "}
+credentials-failed = 資格情報ファイルを使用できません: { $error }
+credentials-no-password = エラー: 無人実行で --steam-user を使うには、パスワードを環境変数 { $var } に設定してください。
+err-credentials-read = { $path } を読み取れません: { $error }
+err-credentials-permissions = { $path } は { $group } から読み取り可能です。先に自分のアカウントのみに制限してください。例: icacls "{ $path }" /inheritance:r /grant:r "%USERNAME%:R"
+err-credentials-incomplete = { $path } に "machine steam" または default エントリの "login" と "password" がありません。
+prompt-steam-user = Steam のユーザー名を入力してください:{" "}
+steam-user-required = Steam のユーザー名は必須です。
+prompt-steam-password = Steam のパスワードを入力してください:{" "}
+install-failed = インストールに失敗しました: { $error }
+rollback-prompt = 今回の実行でゲームフォルダーとショートカットに加えた変更を元に戻しますか? (Y/N):{" "}
+rollback-declined = 変更はそのまま残しました。再試行するにはインストーラーをもう一度実行してください。
+rollback-done = { $count } 件の変更を元に戻しました。ゲームフォルダーは今回の実行前の状態です。
+rollback-incomplete = 一部の変更を元に戻せませんでした: { $error }
+injection-prompt = MelonLoader が読み込まれるか確認するため、DesktopMate を最大 { $seconds } 秒間起動しますか？
+injection-running = DesktopMate を起動し、MelonLoader を待っています...
+injection-ok = MelonLoader { $version } がゲームに読み込まれました。
+injection-game-exited = MelonLoader が読み込まれる前に DesktopMate が終了しました (終了コード { $code })。ウイルス対策ソフトと Visual C++ ランタイムのインストールを確認してください。
+injection-no-log = MelonLoader が { $seconds } 秒以内に起動しませんでした。Mod は読み込まれません。ウイルス対策ソフトを確認するか、remove-melonloader コマンドを実行してから再インストールしてください。
+injection-failed = 確認のために DesktopMate を起動できませんでした: { $error }
+launch-prompt = 今すぐ DesktopMate を起動しますか？
+launch-console = はい (MelonLoader コンソールあり)
+launch-no-console = はい (コンソールなし)
+launch-no = いいえ
+launch-choose = 1-3 を選択してください:{" "}
+launch-started = DesktopMate を起動しています。
+launch-failed = DesktopMate を起動できません: { $error }
+install-complete-exit = インストールが完了しました。何かキーを押すと終了します。
+summary-title = インストールの概要
+summary-install-dir = インストール先: { $path }
+summary-game-build = ゲームのマニフェスト: { $manifest }
+summary-melonloader = MelonLoader: { $version }
+summary-mod = { $mod }: { $version }
+summary-unknown = 不明
+
+## DepotDownloader and the game depot
+
+dd-missing = DepotDownloader.exe が見つかりません。ダウンロードしています...
+dd-download-failed = エラー: DepotDownloader のダウンロードに失敗しました。{ $error }
+dd-extracting = DepotDownloader を展開しています...
+dd-extract-failed = エラー: DepotDownloader の展開に失敗しました。{ $error }
+dd-still-missing = エラー: 展開後も DepotDownloader.exe が見つかりません。
+dd-ready = DepotDownloader のダウンロードと展開が完了しました。
+dd-version-unknown = 不明なバージョン
+dd-upgrade-prompt = DepotDownloader { $installed } がインストールされています。{ $latest } が利用可能です。アップグレードしますか? (Y/N):{" "}
+dd-switching = DepotDownloader { $installed } を固定バージョン { $version } に置き換えています...
+dotnet-missing = DepotDownloader には .NET ランタイム { $runtime } が必要ですが、インストールされていません。
+dotnet-install-prompt = Microsoft のインストーラーを今すぐダウンロードして実行しますか? (Y/N):{" "}
+dotnet-downloading = .NET ランタイムのインストーラーをダウンロードしています...
+dotnet-installing = .NET ランタイムをインストールしています...
+depot-downloading = DesktopMate のデポをダウンロードしています (DepotDownloader 使用)...
+depot-failed = エラー: DepotDownloader でエラーが発生しました。終了コード = { $code }
+depot-not-owned = Steam によると、このアカウントは DesktopMate を所有していません。ゲームを購入した Steam アカウントでサインインしてください。別のアカウントやファミリー共有のアカウントではダウンロードできません。
+depot-login-rejected = Steam がサインインを拒否しました。ユーザー名とパスワードを確認し、求められた場合は最新の Steam Guard コードを入力してください。
+depot-complete = デポのダウンロードが完了しました。
+game-update-available = DesktopMate のビルド { $installed } がインストールされています。ビルド { $latest } が利用可能です。
+game-update-prompt = 今すぐゲームを更新しますか? 更新後に Goldberg を再適用し、MOD を確認します。 (Y/N){" "}
+game-updating = DesktopMate をビルド { $build } に更新しています...
+game-validating = すべてのゲームファイルを確認し、破損したファイルを再ダウンロードしています...
+check-updates-current = { $component }: { $installed } (最新)
+check-updates-outdated = { $component }: { $installed } がインストール済み、{ $latest } が利用可能
+check-updates-unknown = { $component }: インストール済みのバージョンは不明です
+check-updates-none = すべて最新です。
+update-failed = エラー: アップデートを確認できませんでした: { $error }
+check-updates-install = 今すぐ更新をインストールしますか? (Y/N){" "}
+watch-started = { $path } の更新を監視しています。今すぐ確認し、その後は 1 日 1 回確認します。停止するにはこのウィンドウを閉じるか Ctrl+C を押してください。
+watch-toast-title = DesktopMate の更新があります — インストーラーを実行して更新してください
+watch-check-failed = 更新の確認に失敗しました: { $error }
+package-fetching = { $file } をパッケージ化しています...
+package-done = オフライン キットを { $path } に作成しました。フォルダーごと共有してください。ゲーム本体は引き続き Steam からダウンロードされます。
+package-failed = オフライン キットの作成に失敗しました: { $error }
+package-needs-folder = --package にはキットを作成するフォルダーを指定してください (例: --package D:\DesktopMateKit)
+pin-hashing = { $artifact } のハッシュを計算しています...
+pin-recorded = 固定ハッシュを { $path } に書き込みました。
+pin-record-failed = 固定ハッシュを記録できませんでした: { $error }
+pin-needs-file = --pin-hashes には更新するデータベースファイルが必要です (例: --pin-hashes manifest\hashes.json)
+hash-check-failed = エラー: { $error }
+pin-overridden = 警告: { $artifact } は整合性チェックに失敗しましたが、--allow-hash-mismatch が指定されているためインストールします。
+pin-mod-unpinned = 警告: { $artifact } は固定ハッシュのデータベースより新しいため、ダウンロードを検証できませんでした。
+logs-missing = { $path } にログはまだありません。
+logs-empty = 条件に一致するログはありません。
+logs-unreadable = ログを読み込めませんでした: { $error }
+logs-bad-level = 不明なレベル "{ $level }" です。info、warning、error のいずれかを指定してください。すべて表示します。
+logs-run-started = ── 実行開始 { $time } (インストーラー { $version }) ──
+logs-step = { $time }  ▶ { $step }
+logs-run-succeeded = { $time }  インストールが正常に完了しました。
+logs-run-failed = { $time }  インストールに失敗しました: { $error }
+game-manifest-invalid = installer.toml のゲーム定義 (game_manifest) を使用できません: { $error }
+depot-skipped = DesktopMate のファイルは既に存在します。デポのダウンロードをスキップします。
+steam-copy-prompt = DesktopMate は Steam によって { $path } に既にインストールされています (ビルド { $build })。ダウンロードする代わりにそのファイルをコピーしますか? (Y/N):{" "}
+steam-copy-copying = Steam ライブラリからゲームファイルをコピーしています...
+steam-copy-done = Steam からゲームファイルを { $count } 個コピーしました。
+steam-copy-failed = Steam からゲームをコピーできませんでした ({ $error })。代わりにダウンロードします。
+verify-fetching-manifest = Steam からデポのマニフェストを取得しています...
+verify-checking = { $count } 個のゲームファイルを確認しています...
+verify-component = { $component } { $version } (ダウンロード元: { $source })
+verify-progress = 検証中: { $files }/{ $total_files } ファイル ({ $mb } / { $total_mb } MB)
+verify-missing = [欠落] { $file }
+verify-modified = [変更] { $file }
+verify-patched = [パッチ済み] { $file } (インストーラーによる置き換えで正常)
+verify-ok = { $count } 個のゲームファイルすべてがデポのマニフェストと一致しました。
+verify-damaged = { $count } 個のゲームファイルが欠落または変更されています。修復するには DesktopMate_Data フォルダーを削除してインストーラーを再実行してください。
+verify-failed = エラー: 検証に失敗しました: { $error }
+repair-starting = { $path } のインストールを修復しています...
+integrity-checking = インストール済みのコンポーネントを記録済みのハッシュと照合しています...
+integrity-damaged = { $component }: { $missing } 個のファイルが欠落、{ $modified } 個のファイルが変更されています。
+integrity-ok = インストール済みのすべてのコンポーネントのファイルが記録済みのハッシュと一致しました。
+integrity-unhashed = 以前のインストーラーでインストールされたため、ファイルを確認できません: { $components }
+repair-reinstalling = 再インストールします: { $components }
+repair-components-intact = 破損したコンポーネントはありません。ゲームファイルのみを確認します。
+uninstall-prompt = { $path } と、その中の MOD やアバターを含むすべてを削除しますか? (Y/N):{" "}
+uninstall-nothing = アンインストールするものはありません: { $path } は存在しません。
+uninstall-removing = { $path } を削除しています...
+uninstall-done = アンインストールしました。バックアップがある場合は、インストーラーの隣の Backups フォルダーにあります。
+uninstall-failed = エラー: アンインストールに失敗しました: { $error }
+steam-mode-restoring = 元の steam_api64.dll を復元し、Goldberg を削除しています...
+steam-mode-done = Steam モードに戻しました。ゲームは Steam クライアント経由で動作します。このインストーラーでインストールまたは更新すると、パッチが再び適用されます。
+steam-mode-failed = エラー: Steam モードへの切り替えに失敗しました: { $error }
+err-no-game = { $path } に DesktopMate のインストールが見つかりません
+err-mod-url = 「{ $url }」は GitHub リポジトリ (https://github.com/owner/repo)、Thunderstore パッケージ (Namespace-Name)、GameBanana の Mod (https://gamebanana.com/mods/<id>) のいずれでもありません
+err-mod-not-installed = { $mod } はインストールされませんでした。上のメッセージを確認してください
+err-mod-unknown = 「{ $mod }」という名前のインストール済み Mod や Mods 内の DLL はありません
+err-profile-unknown = 「{ $profile }」という名前のプロファイルはありません
+err-mod-rollback-disabled = { $mod } は無効です。戻す前に有効にしてください
+err-mod-nothing-kept = { $mod } の以前のバージョンはまだ保存されていません。次回の更新から保存されます
+err-mod-no-kept-version = { $mod } の戻せる保存済みバージョンがありません (保存済み: { $versions })
+err-steam-mode-no-backup = このインストールには元の steam_api64.dll のバックアップが記録されていません。Steam の「ゲームファイルの整合性を確認」で元に戻してください
+err-steam-mode-backup-changed = バックアップ { $path } が見つからないか変更されているため、復元しませんでした。代わりに Steam の「ゲームファイルの整合性を確認」を使ってください
+err-verify-no-manifest = DepotDownloader がマニフェストの一覧を出力しませんでした
+err-dd-download = DepotDownloader のダウンロードに失敗しました
+err-dd-extract = DepotDownloader の展開に失敗しました
+err-dd-missing = 展開後に DepotDownloader.exe がありません
+err-dotnet-missing = DepotDownloader には .NET ランタイム { $runtime } が必要です。{ $url } からインストールして、インストーラーを再度実行してください
+err-dotnet-unsigned = ダウンロードした .NET ランタイムのインストーラーに署名がないため、実行しませんでした
+err-dotnet-wrong-publisher = ダウンロードした .NET ランタイムのインストーラーは Microsoft ではなく { $publisher } によって署名されているため、実行しませんでした
+signature-invalid = 警告: { $file } の署名を検証できません (発行元: { $publisher })。改ざんされている可能性があります。
+signature-wrong-publisher = 警告: { $file } は { $expected } ではなく { $publisher } によって署名されています。公式リリースではない可能性があります。
+signature-unsigned = 警告: { $expected } はリリースに署名していますが、{ $file } は署名されていません。公式リリースではない可能性があります。
+err-depot-exit = DepotDownloader が終了コード { $code } で終了しました
+
+## Goldberg patch
+
+goldberg-downloading = Goldberg パッチをダウンロードしています...
+goldberg-provider-fallback = Goldberg パッチを取得できませんでした ({ $error })。代わりに { $provider } を試します...
+goldberg-applied = Goldberg パッチを適用しました。
+goldberg-rechecking = パッチが残っているか確認しています...
+av-patch-removed = エラー: steam_api64.dll が書き込みから数秒後に消えたか変更されました。ウイルス対策ソフトが Goldberg パッチを隔離した可能性が高いです。
+av-patch-lost = 前回適用した Goldberg パッチが見つからないか変更されています。ウイルス対策ソフトが隔離した可能性が高いため、もう一度適用します。
+av-exclusion-hint = ゲームフォルダーをスキャン対象から除外してから (Microsoft Defender ならインストーラーを --defender-exclusion 付きで実行、その他はウイルス対策ソフトの設定で)、インストーラーをもう一度実行してください。
+goldberg-no-target = エラー: Goldberg パッチ DLL の配置先フォルダーを特定できません。
+goldberg-dll-missing = エラー: パッチのアーカイブに steam_api64.dll がありません。
+err-goldberg-target = Goldberg パッチの配置先フォルダーを特定できません
+err-goldberg-dll = Goldberg のアーカイブに steam_api64.dll がありません
+err-steam-api-backup = 元の steam_api64.dll をバックアップできなかったため、置き換えていません: { $error }
+err-av-quarantined = 適用直後に Goldberg パッチが削除されました。ウイルス対策ソフトによるものと思われます
+err-defender-exclusion = Defender の除外設定の追加に失敗したか、キャンセルされました (終了コード { $code })
+
+## MelonLoader
+
+melon-up-to-date = MelonLoader は最新です (バージョン { $version })。
+adopt-found = 手動でインストールされた { $component } が見つかりました (バージョン: { $version })。今後はインストーラーが更新します。
+melon-installing = MelonLoader { $version } をインストールしています...
+melon-downgrading = インストール済みの MelonLoader { $installed } はサポート対象の { $version } より新しいため、置き換えます。
+melon-extracting = MelonLoader をゲームフォルダーに展開しています...
+melon-remove-prompt = { $path } から MelonLoader を削除しますか？ Mod とその設定は残ります。
+melon-removing = MelonLoader を削除しています...
+melon-remove-done = MelonLoader を削除しました。ゲームは Mod なしで起動します。
+melon-remove-nothing = MelonLoader はインストールされていません。
+melon-remove-failed = エラー: MelonLoader を削除できませんでした: { $error }
+melon-version-corrected = install.json には MelonLoader { $recorded } と記録されていましたが、実際には { $detected } がインストールされています。記録を修正しました。
+melon-versions = MelonLoader のリリース:
+melon-choose = インストールする MelonLoader のリリース (番号):{" "}
+melon-version-recommended = { $version } (推奨)
+melon-choose-failed = エラー: MelonLoader のリリースを選択できませんでした: { $error }
+catalog-heading = Custom Avatar Loader 以外にインストールできる Mod:
+catalog-choose = インストールする Mod の番号 (カンマ区切り、なしは空欄):{" "}
+catalog-empty = Mod カタログにはまだ他の Mod がありません。
+catalog-mod-unknown = { $component } は Mod カタログにないため、スキップしました。
+catalog-choose-failed = エラー: Mod を選択できませんでした: { $error }
+melon-version-unavailable = MelonLoader { $version } は利用できません ({ $error })。代わりに推奨リリースをインストールします。
+melon-installed = MelonLoader をインストールしました。
+melon-incomplete = エラー: MelonLoader のアーカイブが不完全です。既存のインストールは変更していません。
+err-melon-incomplete = MelonLoader のアーカイブに version.dll または MelonLoader フォルダーがありません
+err-melon-version-unknown = このチャンネルに MelonLoader のリリース「{ $version }」はありません
+
+## Custom Avatar Loader
+
+mod-checking = { $mod } の更新を確認しています...
+mod-up-to-date = { $mod } は最新です (バージョン { $version })。
+mod-newer-installed = インストール済みの { $mod } { $installed } は最新リリース { $latest } より新しいため、そのままにします。
+mod-not-installed = { $mod } がインストールされていません。インストールします...
+mod-update-available = { $mod } の更新があります: 現在のバージョン: { $installed }、最新バージョン: { $latest }
+mod-update-prompt = { $mod } を更新しますか? (Y/N):{" "}
+mod-update-skipped = { $mod } の更新をスキップします。
+mod-downloading = { $mod } をダウンロードしています...
+mod-download-failed = エラー: { $mod } のダウンロードに失敗しました: { $error }
+mod-no-matching-asset = エラー: { $mod } の最新リリースに "{ $pattern }" に一致するファイルがありません。
+mod-no-dirs = エラー: 展開したアーカイブに 'Mods' も 'UserLibs' もありません。
+mod-installed = { $mod } をインストール/更新しました。
+mod-reinstalling = { $mod } { $version } を再インストールしています...
+mod-no-release-info = { $mod } の最新リリース情報を取得できませんでした ({ $error })。更新確認をスキップします。
+mod-registered = { $mod } をインストールしました。今後はほかのコンポーネントと一緒に更新されます。
+mod-install-failed = エラー: Mod のインストールに失敗しました: { $error }
+thunderstore-result = { $package } { $version }
+thunderstore-no-results = 「{ $query }」に一致する Thunderstore パッケージはありません。
+thunderstore-install-hint = インストールするには: mod install <パッケージ>
+thunderstore-needs = 必要: { $packages }
+thunderstore-dependency-missing = 依存パッケージ { $package } は Thunderstore にないため、インストールされませんでした。
+thunderstore-failed = エラー: Thunderstore の照会に失敗しました: { $error }
+mod-disabled = { $mod } を無効にしました。ファイルは Mods.disabled にあります:
+mod-enabled = { $mod } を再び有効にしました。ファイルは Mods に戻りました:
+mod-already-disabled = { $mod } はすでに無効です。
+mod-already-enabled = { $mod } は無効になっていません。
+mod-disabled-skipped = { $mod } は無効のため更新しません。有効にするには: mod enable "{ $mod }"
+mod-toggle-failed = エラー: { $mod } を有効化または無効化できませんでした: { $error }
+mod-updates-checking = インストール済み Mod の更新を確認しています...
+mod-updates-no-mods = 確認するインストール済み Mod はありません。
+mod-updates-column-mod = Mod
+mod-updates-column-installed = インストール済み
+mod-updates-column-latest = 最新
+mod-updates-lookup-failed = (照会失敗)
+mod-updates-none = すべての Mod は最新です。
+mod-updates-notes = { $mod } { $version } の変更点:
+mod-updates-no-notes = (リリースノートなし)
+mod-updates-done = { $count } 個の Mod を更新しました。
+mod-updates-failed = エラー: Mod の更新に失敗しました: { $error }
+mod-rolled-back = { $mod } をバージョン { $version } に戻しました ({ $from } から)。次回の更新では新しいリリースが再び提示されます。このバージョンを使い続けるには更新を断ってください。
+mod-rollback-failed = エラー: { $mod } を戻せませんでした: { $error }
+profile-saved = 有効なファイル { $files } 個をプロファイル { $profile } に保存しました。
+profile-switched = プロファイル { $profile } に切り替えました。
+profile-file-missing = プロファイルの { $file } はもうインストールされていません。
+profile-entry = { $profile } (ファイル { $files } 個)
+profile-none = 保存されたプロファイルはありません。現在の Mod を保存するには: profile save <名前>
+profile-deleted = プロファイル { $profile } を削除しました。
+profile-failed = エラー: { $error }
+err-mod-no-dirs = { $mod } のアーカイブに Mods または UserLibs フォルダーがありません
+err-no-matching-asset = "{ $pattern }" に一致するリリースファイルがありません。installer.toml の avatar_loader_asset に新しいファイル名を設定してください。
+
+## Shortcuts
+
+shortcuts-creating = デスクトップにショートカットを作成しています...
+shortcuts-no-desktop = エラー: デスクトップのフォルダーを特定できません。
+shortcuts-created = デスクトップのショートカットを作成しました。
+shortcuts-prompt = 作成するデスクトップショートカットを選んでください
+shortcuts-choose = 1-4 を選択してください:{" "}
+shortcuts-name-prompt = ショートカット名 (既定: { $name }):{" "}
+pin-prompt = DesktopMate をスタート メニューにピン留めしますか? (Y/N){" "}
+safe-mode-prompt = クラッシュの原因が MOD かどうかを確かめられるよう、MOD なしでゲームを起動する「DesktopMate (Safe Mode)」ショートカットも作成しますか? (Y/N){" "}
+pin-done = 「{ $name }」をスタート メニューに追加しました。ピン留めされていない場合は、[すべてのアプリ] で右クリックして [スタートにピン留めする] を選んでください。
+pin-failed = DesktopMate をスタート メニューに追加できませんでした: { $error }
+shortcuts-variant-both = 両方 (コンソールあり・なし)
+shortcuts-variant-no-console = コンソールなしのみ
+shortcuts-variant-console = MelonLoader コンソールありのみ
+shortcuts-variant-none = 作成しない
+shortcuts-skipped = 指定に従い、デスクトップショートカットは作成しません。
+fix-shortcuts-created = ショートカットを作成しました: { $path }
+fix-shortcuts-removed = 古いショートカットを削除しました: { $path }
+fix-shortcuts-failed = ショートカットを再作成できません: { $error }
+defender-adding = { $path } を Microsoft Defender のスキャン対象から除外します。Windows が管理者権限を求めます...
+defender-added = 除外を追加しました。パッチを再適用するにはインストーラーをもう一度実行してください。
+defender-failed = 除外を追加できませんでした: { $error }
+err-fix-shortcuts-no-game = { $path } に DesktopMate が見つかりません。インストール先を指定してください: --fix-shortcuts "D:\Games\DesktopMate"
+health-running = インストールを確認しています...
+health-game-exe = DesktopMate.exe が存在する
+health-goldberg = steam_api64.dll が Goldberg パッチと一致する
+health-melonloader = MelonLoader の version.dll が存在する
+health-mod = { $mod }: { $dll } が存在する
+health-shortcut = ショートカット { $name } のリンク先が存在する
+health-missing = 見つかりません: { $path }
+health-hash-mismatch = ハッシュが一致しません
+health-shortcut-unreadable = ショートカットのリンク先を読み取れませんでした
+health-pass = [OK] { $check }
+health-fail = [NG] { $check }
+health-all-passed = すべてのチェックに合格しました。
+step-failed-continuing = 警告: { $step } に失敗したためスキップしました: { $error }。インストールは続行します。
+resume-continuing = このフォルダーへの前回のインストールが完了していません。中断したところから再開します。
+resume-step-done = { $step } は前回の実行で完了しているため、スキップします。
+steps-skipped = 一部の手順をスキップして完了しました: { $steps }。再試行するにはインストーラーをもう一度実行してください。
+recovery-heading = { $step } に失敗しました: { $error }
+recovery-retry = この手順を再試行する
+recovery-skip = スキップして続行する
+recovery-abort = 中止して今回の変更を元に戻す
+recovery-choose = 1-3 を選択してください:{" "}
+health-some-failed = { $count } 件のチェックに失敗しました。ゲームが正しく起動しない可能性があります。詳細はログを参照してください。
+err-no-desktop = デスクトップのフォルダーを特定できません
+err-shortcut = ショートカットの作成に失敗しました
+err-no-start-menu = スタート メニューのプログラム フォルダーが見つかりません
+err-toast = 通知を表示できませんでした (PowerShell の終了コード { $code })
+err-kit-checksum = オフライン キットの { $file } が記録されたチェックサムと一致しません。キットが破損しているか変更されています
+err-kit-missing = オフライン キットに { $file } がありません: { $error }
+err-pin-mismatch = { $artifact } が固定された SHA-256 と一致しません (期待値 { $expected }、実際 { $actual })。ダウンロードが破損しているか改ざんされている可能性があります。それでもインストールするには --allow-hash-mismatch を指定してください
+err-pin-unknown-version = { $artifact } はまだ固定ハッシュのデータベースにありません。それでもインストールするには --allow-hash-mismatch を指定してください
+err-pin-unknown-hash = { $artifact } (SHA-256 { $actual }) はどの固定ハッシュとも一致しません。それでもインストールするには --allow-hash-mismatch を指定してください
+
+## Network
+
+err-http = HTTP エラー: { $status }
+err-download-incomplete = ダウンロードが { $expected } バイト中 { $received } バイトで終了しました
+network-retrying = { $what } に失敗しました: { $error }。{ $seconds } 秒後に再試行します ({ $attempts } 回中 { $attempt } 回目)...
+download-trying-mirror = ダウンロードに失敗しました ({ $error })。ミラー { $url } を試します...
+cache-pruned = キャッシュ済みファイルを { $files } 個削除しました ({ $mb } MB)。
+cache-prune-failed = ダウンロードキャッシュを整理できませんでした: { $error }
+github-rate-limited = GitHub API のレート制限に達しました。{ $time } に解除されます
+github-unreachable = GitHub に接続できませんでした: { $error }
+github-no-release = GitHub から利用可能なリリースが返されませんでした
+github-using-cache = { $reason }。{ $time } に保存したリリース情報を使用します。
+err-archive-unsafe-path = アーカイブ内の「{ $entry }」が展開先フォルダーの外に書き込まれるため拒否しました
+err-archive-unsupported = { $file } は対応していないアーカイブ形式です (zip、7z、tar.gz、tar.zst のいずれかが必要です)
+extract-progress = 展開中: { $files }/{ $total_files } ファイル ({ $mb } / { $total_mb } MB)
+copy-progress = コピー中: { $files }/{ $total_files } ファイル ({ $mb } / { $total_mb } MB)
+backup-prompt = このインストールを変更する前にデータ ({ $items }) をバックアップしますか? (Y/N):{" "}
+backup-creating = ユーザーデータをバックアップしています...
+backup-progress = バックアップ中: { $files }/{ $total_files } ファイル ({ $mb } / { $total_mb } MB)
+backup-created = ユーザーデータを { $path } にバックアップしました
+backup-pruned = 古いバックアップを { $count } 個削除しました。
+backup-failed = エラー: ユーザーデータをバックアップできませんでした: { $error }
+restore-available = 利用可能なバックアップ:
+restore-choose = 復元するバックアップ (番号):{" "}
+restore-contents = { $created } に作成したバックアップの内容:
+restore-choose-items = 復元する項目 (例: 1,3、Enter ですべて):{" "}
+restore-target-prompt = どのインストールに復元しますか? [{ $path }]:{" "}
+restore-none = { $path } にバックアップが見つかりません。
+restore-done = { $count } 個のファイルを { $path } に復元しました
+restore-failed = エラー: 復元に失敗しました: { $error }
+err-restore-not-found = Backups フォルダーに "{ $name }" というバックアップはありません
+err-invalid-choice = 無効な選択です: { $choice }
+err-restore-target-missing = 復元先が存在しません: { $path }
+copy-kept = 変更済みのファイルを保持しました: { $path }
+copy-backed-up = 変更済みのファイルを { $path } にバックアップしました
+
+## Wizard (full-screen UI)
+
+step-depot-downloader = DepotDownloader
+step-game-files = ゲームファイル
+step-goldberg = Goldberg パッチ
+step-melonloader = MelonLoader
+step-shortcuts = ショートカット
+step-health-check = 動作チェック
+
+page-welcome = ようこそ
+page-location = インストール先
+page-steam = Steam アカウント
+page-options = オプション
+page-review = 確認
+
+tui-sidebar-title = ステップ
+tui-sidebar-setup = 設定
+tui-sidebar-install = インストール
+
+wizard-welcome-intro = このインストーラーは DesktopMate を MOD 対応の状態でセットアップします。インストールされるもの:
+wizard-welcome-game = DesktopMate のゲームファイル (お使いのアカウントで Steam からダウンロード)
+wizard-welcome-goldberg = Goldberg Steam エミュレーター (Steam クライアントなしでゲームを起動するため)
+wizard-welcome-melonloader = MOD ローダーの MelonLoader
+wizard-welcome-avatar-loader = 独自の VRM アバターを読み込む Custom Avatar Loader
+wizard-welcome-shortcuts = デスクトップとスタートメニューのショートカット
+wizard-ownership = Steam で DesktopMate を所有しています
+wizard-ownership-required = 続行するには DesktopMate を所有していることを確認してください。
+wizard-path-required = インストール先は必須です。
+wizard-location-question = DesktopMate をどこにインストールしますか?
+wizard-location-label = インストール先
+wizard-location-existing = ここには DesktopMate のファイルが既にあります。デポのダウンロードはスキップされます。
+wizard-steam-intro = DepotDownloader には DesktopMate を所有している Steam アカウントが必要です。
+wizard-steam-guard = Steam Guard の入力が必要な場合は、ダウンロード開始時に表示されます。
+wizard-steam-user = Steam ユーザー名
+wizard-steam-password = Steam パスワード
+wizard-options-intro = オプション設定
+wizard-option-update-mod = 新しいリリースがあれば Custom Avatar Loader を更新する
+wizard-option-backup = 既存のインストールを変更する前にユーザーデータ (Mod 設定、アバター、セーブ) をバックアップする
+wizard-option-shortcuts = デスクトップショートカット
+wizard-option-pin = DesktopMate をスタート メニューにピン留めする
+wizard-option-safe-mode = 「DesktopMate (Safe Mode)」ショートカットも作成する (MOD なしで起動)
+wizard-review-intro = インストールの準備ができました。内容を確認してください:
+wizard-review-path = インストール先
+wizard-review-depot = ゲームデポ
+wizard-review-depot-download = { $user } としてダウンロード
+wizard-review-depot-update = { $user } としてビルド { $build } に更新
+wizard-review-depot-skip = スキップ (ファイルは既に存在)
+wizard-review-update = Mod の更新
+wizard-review-backup = ユーザーデータのバックアップ
+wizard-review-shortcuts = ショートカット
+wizard-review-pin = スタートにピン留め
+wizard-review-safe-mode = セーフ モードのショートカット
+wizard-review-download = ダウンロード量
+wizard-yes = はい
+wizard-no = いいえ
+wizard-review-start = Enter キーでインストールを開始します。
+
+hint-welcome = Space: 所有を確認   Enter: 次へ   Esc: 終了
+hint-location-back = Enter: 次へ   Esc: 戻る
+hint-location = Enter: 次へ   Esc: 終了
+hint-steam = Enter: 次へ   Tab: 項目の切り替え   Esc: 戻る
+hint-options = ↑/↓: 選択   Space: 切り替え   Enter: 次へ   Esc: 戻る
+hint-review = Enter: インストール   Esc: 戻る   Ctrl+C: 終了
+hint-running = ↑/↓ PgUp/PgDn Home/End: ログのスクロール   Ctrl+C: 中止
+hint-finished = 何かキーを押すと終了   ↑/↓ PgUp/PgDn: ログのスクロール
+
+progress-title = 進行状況
+progress-steps = { $completed }/{ $total } ステップ
+status-title = 状態
+status-complete = インストールが完了しました。
+status-failed = インストールに失敗しました: { $error }
+status-running = { $step }...
+status-starting = 開始しています...
+log-title = ログ
+log-title-scrolled = ログ ({ $lines } 行さかのぼって表示中)
+tui-stopped-unexpectedly = インストーラーが予期せず停止しました。
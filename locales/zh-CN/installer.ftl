@@ -0,0 +1,445 @@
+## DesktopMate Installer — 简体中文
+
+app-title = DesktopMate 安装程序
+press-any-key = 按任意键退出...
+config-ignored = 已忽略 installer.toml：{ $error }
+proxy-invalid = 已忽略代理设置：{ $error }
+ca-cert-invalid = 已忽略 CA 证书：{ $error }
+theme-color-unknown = installer.toml 中的主题颜色 "{ $color }" 无法识别；将使用预设颜色。
+language-unsupported = 不支持语言“{ $language }”，将使用系统语言。可用语言：{ $available }
+
+## Installer self-update
+
+update-check-failed = 检查安装程序更新失败：{ $error }
+update-available = 有新版安装程序可用：当前版本：{ $installed }，最新版本：{ $latest }
+update-prompt = 是否立即更新安装程序？(Y/N)：{" "}
+update-downloading = 正在下载安装程序更新...
+update-rejected = 错误：拒绝运行安装程序更新：{ $reason }
+update-reason-no-key = 发布了 minisign 签名，但此版本没有用于验证的公钥
+update-reason-bad-key = 内置公钥无效：{ $error }
+update-reason-bad-signature-file = 无法读取签名：{ $error }
+update-reason-mismatch = minisign 签名不匹配：{ $error }
+update-reason-unsigned = 下载的文件没有有效签名
+update-reason-wrong-publisher = 它由 { $publisher } 签名，而不是 { $expected }
+update-applied = 安装程序已更新到 { $version }。正在重新启动...
+
+## Classic prompts
+
+prompt-yes = 是
+prompt-no = 否
+prompt-timed-out = 未在规定时间内回答，使用默认值（{ $answer }）继续。
+err-unattended-question = 此问题没有默认答案，无人值守运行无法回答：{ $question }
+first-run-ownership-prompt = 您是否在 Steam 上拥有 DesktopMate？(Y/N)：{" "}
+first-run-ownership-declined = 本安装程序仅供 DesktopMate 的拥有者使用。请先在 Steam 上购买游戏。
+unattended-ownership-hint = 无人值守运行请使用 --confirm-ownership 确认拥有该游戏。
+prompt-install-path = 请输入安装路径（默认：{ $path }）：{" "}
+install-dir-set = 安装目录：{ $path }
+download-size = 约 { $mb } MB
+download-size-unknown = （另加 { $items }，大小未知）
+download-estimate = 预计下载量：{ $size }
+download-estimate-continue = 是否继续安装？(Y/N)：{" "}
+credentials-failed = 无法使用凭据文件：{ $error }
+credentials-no-password = 错误：无人值守运行中使用 --steam-user 时，需要在环境变量 { $var } 中提供密码。
+err-credentials-read = 无法读取 { $path }：{ $error }
+err-credentials-permissions = { $group } 可以读取 { $path }。请先将其限制为仅您的账户可访问，例如：icacls "{ $path }" /inheritance:r /grant:r "%USERNAME%:R"
+err-credentials-incomplete = { $path } 中没有 "machine steam" 或 default 条目的 "login" 和 "password"。
+prompt-steam-user = 请输入 Steam 用户名：{" "}
+steam-user-required = 必须填写 Steam 用户名。
+prompt-steam-password = 请输入 Steam 密码：{" "}
+install-failed = 安装失败：{ $error }
+rollback-prompt = 是否撤销本次运行对游戏文件夹和快捷方式所做的更改？(Y/N)：{" "}
+rollback-declined = 已保留更改。请重新运行安装程序以重试。
+rollback-done = 已撤销 { $count } 项更改，游戏文件夹已恢复到本次运行之前的状态。
+rollback-incomplete = 部分更改无法撤销：{ $error }
+injection-prompt = 启动 DesktopMate 最多 { $seconds } 秒，以检查 MelonLoader 是否加载？
+injection-running = 正在启动 DesktopMate 并等待 MelonLoader...
+injection-ok = MelonLoader { $version } 已加载到游戏中。
+injection-game-exited = DesktopMate 在 MelonLoader 加载前已关闭（退出代码 { $code }）。请检查杀毒软件以及是否安装了 Visual C++ 运行库。
+injection-no-log = MelonLoader 未在 { $seconds } 秒内启动；模组将不会加载。请检查杀毒软件，或运行 remove-melonloader 命令后重新安装。
+injection-failed = 无法启动 DesktopMate 进行检查：{ $error }
+launch-prompt = 现在启动 DesktopMate 吗？
+launch-console = 是，显示 MelonLoader 控制台
+launch-no-console = 是，不显示控制台
+launch-no = 否
+launch-choose = 请选择 1-3：{" "}
+launch-started = DesktopMate 正在启动。
+launch-failed = 无法启动 DesktopMate：{ $error }
+install-complete-exit = 安装完成。按任意键退出。
+summary-title = 安装摘要
+summary-install-dir = 安装目录：{ $path }
+summary-game-build = 游戏清单：{ $manifest }
+summary-melonloader = MelonLoader：{ $version }
+summary-mod = { $mod }：{ $version }
+summary-unknown = 未知
+
+## DepotDownloader and the game depot
+
+dd-missing = 未找到 DepotDownloader.exe！正在下载...
+dd-download-failed = 错误：下载 DepotDownloader 失败！{ $error }
+dd-extracting = 正在解压 DepotDownloader...
+dd-extract-failed = 错误：解压 DepotDownloader 失败！{ $error }
+dd-still-missing = 错误：解压后仍未找到 DepotDownloader.exe！
+dd-ready = DepotDownloader 已下载并解压完成。
+dd-version-unknown = 未知版本
+dd-upgrade-prompt = 已安装 DepotDownloader { $installed }；可用版本为 { $latest }。是否升级？(Y/N)：{" "}
+dd-switching = 正在将 DepotDownloader { $installed } 替换为固定版本 { $version }...
+dotnet-missing = DepotDownloader 需要 .NET 运行时 { $runtime }，但尚未安装。
+dotnet-install-prompt = 是否立即下载并运行 Microsoft 的安装程序？(Y/N)：{" "}
+dotnet-downloading = 正在下载 .NET 运行时安装程序...
+dotnet-installing = 正在安装 .NET 运行时...
+depot-downloading = 正在下载 DesktopMate 仓库文件（通过 DepotDownloader）...
+depot-failed = 错误：DepotDownloader 出错。退出代码 = { $code }
+depot-not-owned = Steam 表示此账户未拥有 DesktopMate。请使用购买了该游戏的 Steam 账户登录；其他账户或家庭共享账户无法下载。
+depot-login-rejected = Steam 拒绝了登录。请检查用户名和密码，并在提示时输入当前的 Steam 令牌验证码。
+depot-complete = 仓库文件下载完成。
+game-update-available = 已安装 DesktopMate 版本 { $installed }；可用版本为 { $latest }。
+game-update-prompt = 现在更新游戏吗？更新后将重新应用 Goldberg 并检查模组。(Y/N){" "}
+game-updating = 正在将 DesktopMate 更新到版本 { $build }...
+game-validating = 正在检查所有游戏文件并重新下载损坏的文件...
+check-updates-current = { $component }：{ $installed }（已是最新）
+check-updates-outdated = { $component }：已安装 { $installed }，可用 { $latest }
+check-updates-unknown = { $component }：已安装的版本未知
+check-updates-none = 所有内容均已是最新。
+update-failed = 错误：无法检查更新：{ $error }
+check-updates-install = 现在安装更新吗？(Y/N){" "}
+watch-started = 正在监视 { $path } 的更新。现在检查一次，之后每天检查一次；关闭此窗口或按 Ctrl+C 停止。
+watch-toast-title = DesktopMate 有可用更新 — 运行安装程序进行更新
+watch-check-failed = 检查更新失败：{ $error }
+package-fetching = 正在打包 { $file }...
+package-done = 离线包已在 { $path } 中准备就绪。请分享整个文件夹；游戏本体仍从 Steam 下载。
+package-failed = 构建离线包失败：{ $error }
+package-needs-folder = --package 需要指定用于构建离线包的文件夹，例如 --package D:\DesktopMateKit
+pin-hashing = 正在计算 { $artifact } 的哈希...
+pin-recorded = 固定哈希已写入 { $path }。
+pin-record-failed = 记录固定哈希失败：{ $error }
+pin-needs-file = --pin-hashes 需要指定要更新的数据库文件，例如 --pin-hashes manifest\hashes.json
+hash-check-failed = 错误：{ $error }
+pin-overridden = 警告：{ $artifact } 未通过完整性检查；由于指定了 --allow-hash-mismatch，仍将安装。
+pin-mod-unpinned = 警告：{ $artifact } 比固定哈希数据库更新，因此无法校验其下载。
+logs-missing = { $path } 尚无日志。
+logs-empty = 没有匹配的日志条目。
+logs-unreadable = 无法读取日志：{ $error }
+logs-bad-level = 未知级别 "{ $level }"；请使用 info、warning 或 error。将显示全部。
+logs-run-started = ── 运行开始于 { $time }（安装程序 { $version }）──
+logs-step = { $time }  ▶ { $step }
+logs-run-succeeded = { $time }  安装成功完成。
+logs-run-failed = { $time }  安装失败：{ $error }
+game-manifest-invalid = 无法使用 installer.toml 中的游戏定义（game_manifest）：{ $error }
+depot-skipped = DesktopMate 文件已存在，跳过仓库下载。
+steam-copy-prompt = Steam 已将 DesktopMate 安装在 { $path }（版本 { $build }）。是否复制这些文件而不是重新下载？(Y/N)：{" "}
+steam-copy-copying = 正在从 Steam 库复制游戏文件...
+steam-copy-done = 已从 Steam 复制 { $count } 个游戏文件。
+steam-copy-failed = 无法从 Steam 复制游戏（{ $error }）；将改为下载。
+verify-fetching-manifest = 正在从 Steam 获取仓库清单...
+verify-checking = 正在检查 { $count } 个游戏文件...
+verify-component = { $component } { $version }，下载自 { $source }
+verify-progress = 正在校验：{ $files }/{ $total_files } 个文件（{ $mb } / { $total_mb } MB）
+verify-missing = [缺失] { $file }
+verify-modified = [已修改] { $file }
+verify-patched = [已打补丁] { $file }（正常：由安装程序替换）
+verify-ok = 全部 { $count } 个游戏文件均与仓库清单一致。
+verify-damaged = 有 { $count } 个游戏文件缺失或被修改。如需修复，请删除 DesktopMate_Data 文件夹并重新运行安装程序。
+verify-failed = 错误：校验失败：{ $error }
+repair-starting = 正在修复 { $path } 中的安装...
+integrity-checking = 正在将已安装的组件与记录的哈希值进行比对...
+integrity-damaged = { $component }：{ $missing } 个文件缺失，{ $modified } 个文件被修改。
+integrity-ok = 所有已安装组件的文件均与记录的哈希值一致。
+integrity-unhashed = 以下组件由旧版安装程序安装，无法检查其文件：{ $components }
+repair-reinstalling = 正在重新安装：{ $components }
+repair-components-intact = 没有组件损坏；只会检查游戏文件。
+uninstall-prompt = 删除 { $path } 及其中的所有内容（包括模组和头像）吗？(Y/N)：{" "}
+uninstall-nothing = 没有可卸载的内容：{ $path } 不存在。
+uninstall-removing = 正在删除 { $path }...
+uninstall-done = 已卸载。如有备份，位于安装程序旁边的 Backups 文件夹中。
+uninstall-failed = 错误：卸载失败：{ $error }
+steam-mode-restoring = 正在恢复原始 steam_api64.dll 并移除 Goldberg...
+steam-mode-done = 已恢复 Steam 模式。游戏现在通过 Steam 客户端运行；使用本安装程序安装或更新会再次应用补丁。
+steam-mode-failed = 错误：切换到 Steam 模式失败：{ $error }
+err-no-game = 在 { $path } 中未找到 DesktopMate 安装
+err-mod-url = “{ $url }”不是 GitHub 仓库（https://github.com/owner/repo）、Thunderstore 包（Namespace-Name）或 GameBanana 模组（https://gamebanana.com/mods/<id>）
+err-mod-not-installed = 未安装 { $mod }；请查看上面的消息
+err-mod-unknown = 没有名为“{ $mod }”的已安装模组或 Mods 中的 DLL
+err-profile-unknown = 没有名为“{ $profile }”的配置
+err-mod-rollback-disabled = { $mod } 已禁用；请先启用再回滚
+err-mod-nothing-kept = 尚未保留 { $mod } 的旧版本；从下次更新起才会保留
+err-mod-no-kept-version = 没有可回滚的 { $mod } 保留版本（已保留：{ $versions }）
+err-steam-mode-no-backup = 此安装没有记录原始 steam_api64.dll 的备份。请在 Steam 中使用“验证游戏文件的完整性”来恢复它
+err-steam-mode-backup-changed = 备份 { $path } 缺失或已更改，因此未恢复。请改用 Steam 中的“验证游戏文件的完整性”
+err-verify-no-manifest = DepotDownloader 未生成清单列表
+err-dd-download = DepotDownloader 下载失败
+err-dd-extract = DepotDownloader 解压失败
+err-dd-missing = 解压后缺少 DepotDownloader.exe
+err-dotnet-missing = DepotDownloader 需要 .NET 运行时 { $runtime }。请从 { $url } 安装后重新运行安装程序
+err-dotnet-unsigned = 下载的 .NET 运行时安装程序没有签名，因此未运行
+err-dotnet-wrong-publisher = 下载的 .NET 运行时安装程序由 { $publisher } 签名，而不是 Microsoft，因此未运行
+signature-invalid = 警告：{ $file } 的签名无法验证（声称的发布者：{ $publisher }）。它可能已被篡改。
+signature-wrong-publisher = 警告：{ $file } 由 { $publisher } 签名，而不是 { $expected }。它可能不是官方版本。
+signature-unsigned = 警告：{ $file } 未签名，但 { $expected } 会为其版本签名。它可能不是官方版本。
+err-depot-exit = DepotDownloader 以代码 { $code } 退出
+
+## Goldberg patch
+
+goldberg-downloading = 正在下载 Goldberg 补丁...
+goldberg-provider-fallback = 无法获取 Goldberg 补丁（{ $error }）。改为尝试 { $provider }...
+goldberg-applied = Goldberg 补丁已成功应用。
+goldberg-rechecking = 正在确认补丁仍然存在...
+av-patch-removed = 错误：steam_api64.dll 写入几秒后就消失或被更改。很可能是杀毒软件隔离了 Goldberg 补丁。
+av-patch-lost = 上次应用的 Goldberg 补丁已丢失或被更改，很可能被杀毒软件隔离；现在将重新应用。
+av-exclusion-hint = 请将游戏文件夹排除在扫描之外（Microsoft Defender 可使用 --defender-exclusion 运行安装程序，其他杀毒软件请在其设置中添加），然后重新运行安装程序。
+goldberg-no-target = 错误：无法确定 Goldberg 补丁 DLL 的目标目录。
+goldberg-dll-missing = 错误：补丁压缩包中未找到 steam_api64.dll！
+err-goldberg-target = 无法确定 Goldberg 补丁的目标目录
+err-goldberg-dll = Goldberg 压缩包中缺少 steam_api64.dll
+err-steam-api-backup = 无法备份原始 steam_api64.dll，因此未替换它：{ $error }
+err-av-quarantined = Goldberg 补丁在应用后立即被删除，很可能是杀毒软件所为
+err-defender-exclusion = 添加 Defender 排除项失败或已取消（退出代码 { $code }）
+
+## MelonLoader
+
+melon-up-to-date = MelonLoader 已是最新版本（版本 { $version }）。
+adopt-found = 发现手动安装的 { $component }（版本：{ $version }），今后将由安装程序更新。
+melon-installing = 正在安装 MelonLoader { $version }...
+melon-downgrading = 已安装的 MelonLoader { $installed } 比受支持的 { $version } 更新，将进行替换。
+melon-extracting = 正在将 MelonLoader 解压到游戏目录...
+melon-remove-prompt = 要从 { $path } 移除 MelonLoader 吗？模组及其设置会保留。
+melon-removing = 正在移除 MelonLoader...
+melon-remove-done = 已移除 MelonLoader。游戏现在将不加载模组启动。
+melon-remove-nothing = 该位置没有安装 MelonLoader。
+melon-remove-failed = 错误：移除 MelonLoader 失败：{ $error }
+melon-version-corrected = install.json 记录的是 MelonLoader { $recorded }，但实际安装的是 { $detected }；已更正记录。
+melon-versions = MelonLoader 版本：
+melon-choose = 要安装的 MelonLoader 版本（编号）：{" "}
+melon-version-recommended = { $version }（推荐）
+melon-choose-failed = 错误：无法选择 MelonLoader 版本：{ $error }
+catalog-heading = 除 Custom Avatar Loader 外可安装的模组：
+catalog-choose = 要安装的模组编号，以逗号分隔（留空表示不安装）：{" "}
+catalog-empty = 模组目录中暂无其他模组。
+catalog-mod-unknown = { $component } 已不在模组目录中，已跳过。
+catalog-choose-failed = 错误：无法选择模组：{ $error }
+melon-version-unavailable = MelonLoader { $version } 不可用（{ $error }）；改为安装推荐版本。
+melon-installed = MelonLoader 安装成功。
+melon-incomplete = 错误：MelonLoader 压缩包不完整，现有安装未被改动。
+err-melon-incomplete = MelonLoader 压缩包缺少 version.dll 或 MelonLoader 文件夹
+err-melon-version-unknown = 此渠道没有 MelonLoader 版本“{ $version }”
+
+## Custom Avatar Loader
+
+mod-checking = 正在检查 { $mod } 更新...
+mod-up-to-date = { $mod } 已是最新版本（版本 { $version }）。
+mod-newer-installed = 已安装的 { $mod } { $installed } 比最新发布版本 { $latest } 更新，保持不变。
+mod-not-installed = 未安装 { $mod }，正在安装...
+mod-update-available = { $mod } 有可用更新：当前版本：{ $installed }，最新版本：{ $latest }
+mod-update-prompt = 是否更新 { $mod }？(Y/N)：{" "}
+mod-update-skipped = 跳过 { $mod } 更新。
+mod-downloading = 正在下载 { $mod } ...
+mod-download-failed = 错误：下载 { $mod } 失败：{ $error }
+mod-no-matching-asset = 错误：{ $mod } 最新版本中没有与 "{ $pattern }" 匹配的文件。
+mod-no-dirs = 错误：解压的压缩包中既没有 'Mods' 也没有 'UserLibs' 目录！
+mod-installed = { $mod } 已安装/更新成功。
+mod-reinstalling = 正在重新安装 { $mod } { $version }...
+mod-no-release-info = 无法获取 { $mod } 的最新发布信息（{ $error }），跳过更新检查。
+mod-registered = 已安装 { $mod }，今后会随其他组件一起更新。
+mod-install-failed = 错误：安装模组失败：{ $error }
+thunderstore-result = { $package } { $version }
+thunderstore-no-results = 没有与“{ $query }”匹配的 Thunderstore 包。
+thunderstore-install-hint = 安装方法：mod install <包名>
+thunderstore-needs = 需要 { $packages }
+thunderstore-dependency-missing = 依赖 { $package } 不在 Thunderstore 上，未安装。
+thunderstore-failed = 错误：Thunderstore 查询失败：{ $error }
+mod-disabled = 已禁用 { $mod }；其文件位于 Mods.disabled：
+mod-enabled = 已重新启用 { $mod }；其文件已移回 Mods：
+mod-already-disabled = { $mod } 已处于禁用状态。
+mod-already-enabled = { $mod } 未被禁用。
+mod-disabled-skipped = { $mod } 已禁用，不会更新。启用方法：mod enable "{ $mod }"
+mod-toggle-failed = 错误：无法启用或禁用 { $mod }：{ $error }
+mod-updates-checking = 正在检查已安装模组的更新...
+mod-updates-no-mods = 没有需要检查的已安装模组。
+mod-updates-column-mod = 模组
+mod-updates-column-installed = 已安装
+mod-updates-column-latest = 最新
+mod-updates-lookup-failed = （查询失败）
+mod-updates-none = 所有模组均为最新。
+mod-updates-notes = { $mod } { $version } 的更新内容：
+mod-updates-no-notes = （无发行说明）
+mod-updates-done = 已更新 { $count } 个模组。
+mod-updates-failed = 错误：更新模组失败：{ $error }
+mod-rolled-back = 已将 { $mod } 恢复到版本 { $version }（原为 { $from }）。下次更新时会再次提供较新的版本；拒绝即可保留此版本。
+mod-rollback-failed = 错误：无法回滚 { $mod }：{ $error }
+profile-saved = 已将 { $files } 个已启用文件保存为配置 { $profile }。
+profile-switched = 已切换到配置 { $profile }。
+profile-file-missing = 配置中的 { $file } 已不再安装。
+profile-entry = { $profile }（{ $files } 个文件）
+profile-none = 尚未保存任何配置。保存当前模组：profile save <名称>
+profile-deleted = 已删除配置 { $profile }。
+profile-failed = 错误：{ $error }
+err-mod-no-dirs = { $mod } 压缩包中没有 Mods 或 UserLibs 目录
+err-no-matching-asset = 没有与 "{ $pattern }" 匹配的发布文件。请在 installer.toml 中将 avatar_loader_asset 设置为新的文件名。
+
+## Shortcuts
+
+shortcuts-creating = 正在创建桌面快捷方式...
+shortcuts-no-desktop = 错误：无法确定桌面目录。
+shortcuts-created = 桌面快捷方式创建成功。
+shortcuts-prompt = 要创建哪些桌面快捷方式？
+shortcuts-choose = 请选择 1-4：{" "}
+shortcuts-name-prompt = 快捷方式名称（默认：{ $name }）：{" "}
+pin-prompt = 将 DesktopMate 固定到"开始"菜单？(Y/N){" "}
+safe-mode-prompt = 是否同时创建"DesktopMate (Safe Mode)"快捷方式，以不加载模组的方式启动游戏，用于判断崩溃是否由模组引起？(Y/N){" "}
+pin-done = 已将"{ $name }"添加到"开始"菜单。如果尚未固定，请在"所有应用"中右键单击它并选择"固定到"开始"屏幕"。
+pin-failed = 无法将 DesktopMate 添加到"开始"菜单：{ $error }
+shortcuts-variant-both = 两者（带控制台和不带控制台）
+shortcuts-variant-no-console = 仅不带控制台
+shortcuts-variant-console = 仅带 MelonLoader 控制台
+shortcuts-variant-none = 不创建
+shortcuts-skipped = 按要求跳过桌面快捷方式。
+fix-shortcuts-created = 已创建快捷方式：{ $path }
+fix-shortcuts-removed = 已删除旧的快捷方式：{ $path }
+fix-shortcuts-failed = 无法重新创建快捷方式：{ $error }
+defender-adding = 正在将 { $path } 排除在 Microsoft Defender 扫描之外。Windows 将请求管理员权限...
+defender-added = 已添加排除项。请重新运行安装程序以重新应用补丁。
+defender-failed = 无法添加排除项：{ $error }
+err-fix-shortcuts-no-game = 在 { $path } 中未找到 DesktopMate。请指定安装文件夹：--fix-shortcuts "D:\Games\DesktopMate"
+health-running = 正在检查安装...
+health-game-exe = DesktopMate.exe 存在
+health-goldberg = steam_api64.dll 与 Goldberg 补丁一致
+health-melonloader = MelonLoader 的 version.dll 存在
+health-mod = { $mod }：{ $dll } 存在
+health-shortcut = 快捷方式 { $name } 指向的文件存在
+health-missing = 缺失：{ $path }
+health-hash-mismatch = 哈希值不匹配
+health-shortcut-unreadable = 无法读取快捷方式的目标
+health-pass = [通过] { $check }
+health-fail = [失败] { $check }
+health-all-passed = 所有检查均已通过。
+step-failed-continuing = 警告：{ $step } 失败，已跳过：{ $error }。安装将继续进行。
+resume-continuing = 之前对此文件夹的安装未完成；将从中断处继续。
+resume-step-done = { $step } 已在之前的运行中完成；跳过。
+steps-skipped = 已完成，但跳过了以下步骤：{ $steps }。请重新运行安装程序以重试。
+recovery-heading = { $step } 失败：{ $error }
+recovery-retry = 重试此步骤
+recovery-skip = 跳过并继续
+recovery-abort = 中止并撤销本次运行的更改
+recovery-choose = 请选择 1-3：{" "}
+health-some-failed = { $count } 项检查失败，游戏可能无法正常启动。详情请查看日志。
+err-no-desktop = 无法确定桌面目录
+err-shortcut = 创建快捷方式失败
+err-no-start-menu = 找不到"开始"菜单程序文件夹
+err-toast = 显示通知失败（PowerShell 退出代码 { $code }）
+err-kit-checksum = 离线包中的 { $file } 与记录的校验和不符；离线包已损坏或被修改
+err-kit-missing = 离线包中缺少 { $file }：{ $error }
+err-pin-mismatch = { $artifact } 与固定的 SHA-256 不一致（应为 { $expected }，实际为 { $actual }）；下载可能已损坏或被篡改。如仍要安装，请使用 --allow-hash-mismatch
+err-pin-unknown-version = { $artifact } 尚未收录在固定哈希数据库中。如仍要安装，请使用 --allow-hash-mismatch
+err-pin-unknown-hash = { $artifact }（SHA-256 { $actual }）与任何固定哈希都不一致。如仍要安装，请使用 --allow-hash-mismatch
+
+## Network
+
+err-http = HTTP 错误：{ $status }
+err-download-incomplete = 下载在 { $expected } 字节中的 { $received } 字节处结束
+network-retrying = { $what } 失败：{ $error }。将在 { $seconds } 秒后重试（第 { $attempt } 次，共 { $attempts } 次）...
+download-trying-mirror = 下载失败（{ $error }）；正在尝试镜像 { $url }...
+cache-pruned = 已删除 { $files } 个缓存文件（{ $mb } MB）。
+cache-prune-failed = 无法清理下载缓存：{ $error }
+github-rate-limited = 已达到 GitHub API 速率限制，将于 { $time } 重置
+github-unreachable = 无法连接 GitHub：{ $error }
+github-no-release = GitHub 未返回可用的发布版本
+github-using-cache = { $reason }。将使用 { $time } 保存的发布信息。
+err-archive-unsafe-path = 压缩包条目“{ $entry }”会写入目标文件夹之外，已拒绝
+err-archive-unsupported = { $file } 不是受支持的压缩包格式（需要 zip、7z、tar.gz 或 tar.zst）
+extract-progress = 正在解压：{ $files }/{ $total_files } 个文件（{ $mb } / { $total_mb } MB）
+copy-progress = 正在复制：{ $files }/{ $total_files } 个文件（{ $mb } / { $total_mb } MB）
+backup-prompt = 在修改此安装前备份您的数据（{ $items }）？(Y/N)：{" "}
+backup-creating = 正在备份用户数据...
+backup-progress = 正在备份：{ $files }/{ $total_files } 个文件（{ $mb } / { $total_mb } MB）
+backup-created = 用户数据已备份到 { $path }
+backup-pruned = 已删除 { $count } 个旧备份。
+backup-failed = 错误：无法备份用户数据：{ $error }
+restore-available = 可用的备份：
+restore-choose = 要恢复的备份（编号）：{" "}
+restore-contents = 创建于 { $created } 的备份包含：
+restore-choose-items = 要恢复的部分（例如 1,3；按 Enter 全部恢复）：{" "}
+restore-target-prompt = 恢复到哪个安装？[{ $path }]：{" "}
+restore-none = 在 { $path } 中未找到备份。
+restore-done = 已将 { $count } 个文件恢复到 { $path }
+restore-failed = 错误：恢复失败：{ $error }
+err-restore-not-found = Backups 文件夹中没有名为 "{ $name }" 的备份
+err-invalid-choice = 无效的选择：{ $choice }
+err-restore-target-missing = 恢复目标不存在：{ $path }
+copy-kept = 已保留您修改过的文件：{ $path }
+copy-backed-up = 您修改过的文件已备份到 { $path }
+
+## Wizard (full-screen UI)
+
+step-depot-downloader = DepotDownloader
+step-game-files = 游戏文件
+step-goldberg = Goldberg 补丁
+step-melonloader = MelonLoader
+step-shortcuts = 快捷方式
+step-health-check = 健康检查
+
+page-welcome = 欢迎
+page-location = 安装位置
+page-steam = Steam 账户
+page-options = 选项
+page-review = 确认
+
+tui-sidebar-title = 步骤
+tui-sidebar-setup = 设置
+tui-sidebar-install = 安装
+
+wizard-welcome-intro = 本安装程序将安装支持模组的 DesktopMate。将安装以下内容：
+wizard-welcome-game = DesktopMate 游戏文件（使用您的账户从 Steam 下载）
+wizard-welcome-goldberg = Goldberg Steam 模拟器，使游戏无需 Steam 客户端即可运行
+wizard-welcome-melonloader = 模组加载器 MelonLoader
+wizard-welcome-avatar-loader = Custom Avatar Loader，用于加载您自己的 VRM 形象
+wizard-welcome-shortcuts = 桌面和开始菜单快捷方式
+wizard-ownership = 我在 Steam 上拥有 DesktopMate
+wizard-ownership-required = 请确认您拥有 DesktopMate 以继续。
+wizard-path-required = 必须填写安装路径。
+wizard-location-question = 要将 DesktopMate 安装到哪里？
+wizard-location-label = 安装路径
+wizard-location-existing = 此处已存在 DesktopMate 文件，将跳过仓库下载。
+wizard-steam-intro = DepotDownloader 需要拥有 DesktopMate 的 Steam 账户。
+wizard-steam-guard = 如需 Steam 令牌验证，将在下载开始时提示。
+wizard-steam-user = Steam 用户名
+wizard-steam-password = Steam 密码
+wizard-options-intro = 可选项
+wizard-option-update-mod = 有新版本时更新 Custom Avatar Loader
+wizard-option-backup = 修改现有安装前备份用户数据（模组设置、头像、存档）
+wizard-option-shortcuts = 桌面快捷方式
+wizard-option-pin = 将 DesktopMate 固定到"开始"菜单
+wizard-option-safe-mode = 同时创建"DesktopMate (Safe Mode)"快捷方式（不加载模组启动）
+wizard-review-intro = 准备安装。请确认您的选择：
+wizard-review-path = 安装路径
+wizard-review-depot = 游戏仓库
+wizard-review-depot-download = 以 { $user } 身份下载
+wizard-review-depot-update = 以 { $user } 身份更新到版本 { $build }
+wizard-review-depot-skip = 跳过（文件已存在）
+wizard-review-update = 更新模组
+wizard-review-backup = 备份用户数据
+wizard-review-shortcuts = 快捷方式
+wizard-review-pin = 固定到"开始"
+wizard-review-safe-mode = 安全模式快捷方式
+wizard-review-download = 下载量
+wizard-yes = 是
+wizard-no = 否
+wizard-review-start = 按 Enter 开始安装。
+
+hint-welcome = Space：确认拥有   Enter：下一步   Esc：退出
+hint-location-back = Enter：下一步   Esc：返回
+hint-location = Enter：下一步   Esc：退出
+hint-steam = Enter：下一步   Tab：切换输入框   Esc：返回
+hint-options = ↑/↓：选择   Space：切换   Enter：下一步   Esc：返回
+hint-review = Enter：安装   Esc：返回   Ctrl+C：退出
+hint-running = ↑/↓ PgUp/PgDn Home/End：滚动日志   Ctrl+C：中止
+hint-finished = 按任意键退出   ↑/↓ PgUp/PgDn：滚动日志
+
+progress-title = 进度
+progress-steps = { $completed }/{ $total } 步
+status-title = 状态
+status-complete = 安装完成。
+status-failed = 安装失败：{ $error }
+status-running = { $step }...
+status-starting = 正在开始...
+log-title = 日志
+log-title-scrolled = 日志（已向上滚动 { $lines } 行）
+tui-stopped-unexpectedly = 安装程序意外停止。
@@ -0,0 +1,44 @@
+// about.rs
+//! `--version`: the installer's version and build, and exactly which components a run would
+//! install, for pasting into bug reports.
+//!
+//! Like the receipt, the text is English whatever the UI language, since it is read by whoever
+//! triages the report.
+
+use crate::config::Channel;
+use crate::manifest::ComponentManifest;
+use crate::pins;
+
+/// The `--version` report for a run on `channel` using `manifest`.
+pub fn report(manifest: &ComponentManifest, channel: Channel) -> Vec<String> {
+    let mut lines = Vec::new();
+    lines.push(format!("DesktopMate Installer {}", env!("CARGO_PKG_VERSION")));
+    lines.push(format!("Commit:            {}", env!("DMI_GIT_COMMIT")));
+    lines.push(format!("Built:             {}", env!("DMI_BUILD_DATE")));
+    lines.push(format!("Channel:           {}", channel));
+    lines.push(format!("Manifest revision: {}", manifest.revision));
+    lines.push(format!("Hash database:     {}", pins::revision()));
+    lines.push(String::new());
+
+    let game = &manifest.game;
+    lines.push(game.name.clone());
+    lines.push(format!("  Steam app {}, depot {}, manifest {}", game.app_id, game.depot_id, game.manifest_id));
+    lines.push("DepotDownloader".to_owned());
+    lines.push(format!("  {}", manifest.depot_downloader.url));
+    let goldberg = &manifest.goldberg;
+    lines.push(format!("Goldberg ({}, {:?} build)", goldberg.provider.display_name(), goldberg.build));
+    lines.push(format!("  {} ({})", goldberg.url, goldberg.dll_path));
+    if let Some(fork) = &goldberg.gbe_fork {
+        lines.push(format!("  https://github.com/{}/{}/releases, asset \"{}\"", fork.owner, fork.repo, fork.asset));
+    }
+    lines.push(format!("MelonLoader {}", manifest.melonloader.version));
+    lines.push(format!("  {}", manifest.melonloader.url));
+    for spec in manifest.mods() {
+        lines.push(format!("{} (latest release)", spec.name));
+        lines.push(format!(
+            "  https://github.com/{}/{}/releases, asset \"{}\"",
+            spec.owner, spec.repo, spec.asset
+        ));
+    }
+    lines
+}
@@ -0,0 +1,59 @@
+// adopt.rs
+//! Taking over components that were installed by hand.
+//!
+//! A game folder can already hold MelonLoader or a mod such as Custom Avatar Loader copied in
+//! manually, with nothing in `install.json` saying so. Installing on top as if they were absent
+//! would report a fresh install and skip the update prompt, so they are recognised by their
+//! files, fingerprinted (SHA-256 of the main DLL), and recorded. From then on they are updated
+//! like anything the installer put there itself.
+//!
+//! An adopted component's file list is left empty: which files came with it is unknown, and
+//! guessing wrong would let the next update delete the user's own files.
+
+use crate::game;
+use crate::manifest::ModSpec;
+use crate::melonloader;
+use crate::state::InstallState;
+use crate::transaction::sha256_file;
+use std::io;
+use std::path::Path;
+
+/// A component found in the game folder and recorded in the install state.
+pub struct Adopted {
+    pub component: String,
+    /// Empty when the version couldn't be told from the files.
+    pub version: String,
+}
+
+/// Records manually installed components in `root` that `install.json` doesn't know about.
+/// `mods` are the mods the install pipeline manages, recognised by their DLL.
+pub fn adopt_manual_installs(root: &Path, mods: &[&ModSpec]) -> io::Result<Vec<Adopted>> {
+    let mut state = InstallState::load(root)?;
+    let mut adopted = Vec::new();
+
+    let melon_dll = root.join("version.dll");
+    if state.component("MelonLoader").is_none() && melon_dll.is_file() && root.join("MelonLoader").is_dir() {
+        let version = melonloader_version(root).unwrap_or_default();
+        state.adopt("MelonLoader", &version, sha256_file(&melon_dll)?);
+        adopted.push(Adopted { component: "MelonLoader".to_owned(), version });
+    }
+
+    for spec in mods {
+        let dll = game::relative(root, &spec.dll);
+        if state.component(&spec.component).is_none() && dll.is_file() {
+            state.adopt(&spec.component, "", sha256_file(&dll)?);
+            adopted.push(Adopted { component: spec.component.clone(), version: String::new() });
+        }
+    }
+
+    if !adopted.is_empty() {
+        state.save(root)?;
+    }
+    Ok(adopted)
+}
+
+/// The version of MelonLoader's assembly, else the one its last log names, if the game has been
+/// started with it.
+fn melonloader_version(root: &Path) -> Option<String> {
+    melonloader::detect_version(root).or_else(|| melonloader::logged_version(&melonloader::log_path(root)))
+}
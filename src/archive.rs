@@ -0,0 +1,432 @@
+// archive.rs
+//! Archive extraction for downloaded components and mods.
+//!
+//! Zip, 7z, tar.gz, and tar.zst archives are supported; the format is detected from the file's
+//! signature rather than its name, since downloads are saved under temp names.
+//!
+//! Archives come from third parties, so an entry is only written if its path stays inside the
+//! destination: `..`, absolute paths, and drive or UNC prefixes are rejected outright, and
+//! symlink entries are skipped rather than recreated or followed.
+//!
+//! Entries are validated and their directories created up front, then file contents are
+//! written while a files/bytes counter is shown in the progress pane (or on one console line).
+//! Zip entries are decompressed by a small pool of threads, each with its own handle on the
+//! archive; 7z and tar archives are compressed as one stream, so they are decoded in a single
+//! pass (tar is read twice: once to validate, once to write).
+//!
+//! An [`ExtractFilter`] limits extraction to entries matching include/exclude globs, so pulling
+//! one DLL out of a large archive doesn't write the rest of it to disk.
+
+use crate::progress::Progress;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use sevenz_rust::{Password, SevenZArchiveEntry, SevenZReader};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use zip::ZipArchive;
+
+/// Unix file-type bits for a symbolic link, as stored in a zip entry's external attributes.
+const S_IFLNK: u32 = 0o120000;
+const S_IFMT: u32 = 0o170000;
+
+/// Windows attribute bits as stored by 7-Zip: a reparse point, and the flag marking that the
+/// high 16 bits hold a Unix mode.
+const FILE_ATTRIBUTE_REPARSE_POINT: u32 = 0x400;
+const FILE_ATTRIBUTE_UNIX_EXTENSION: u32 = 0x8000;
+
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+const SEVEN_ZIP_SIGNATURE: &[u8] = b"7z\xBC\xAF\x27\x1C";
+const GZIP_SIGNATURE: &[u8] = b"\x1F\x8B";
+const ZSTD_SIGNATURE: &[u8] = b"\x28\xB5\x2F\xFD";
+
+/// Upper bound on extraction threads; beyond this the disk, not the CPU, is the limit.
+const MAX_WORKERS: usize = 8;
+
+type WorkerError = Box<dyn Error + Send + Sync>;
+
+/// A file entry that passed validation and is waiting to be written.
+struct PendingFile {
+    index: usize,
+    outpath: PathBuf,
+}
+
+/// Archive formats the installer can unpack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveFormat {
+    Zip,
+    SevenZip,
+    TarGz,
+    TarZst,
+}
+
+/// Which archive entries to extract, by path relative to the archive root.
+///
+/// Patterns are `/`-separated globs matched case-insensitively; `*` stays within one path
+/// component and `**` spans any number. An entry is extracted if it matches an include pattern
+/// (or there are none) and matches no exclude pattern.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractFilter {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
+
+impl ExtractFilter {
+    /// A filter that extracts everything.
+    pub fn all() -> ExtractFilter {
+        ExtractFilter::default()
+    }
+
+    pub fn new(include: &[&str], exclude: &[&str]) -> Result<ExtractFilter, Box<dyn Error>> {
+        Ok(ExtractFilter { include: build_globs(include)?, exclude: build_globs(exclude)? })
+    }
+
+    fn matches(&self, relative: &Path) -> bool {
+        let path = relative.to_string_lossy().replace('\\', "/");
+        self.include.as_ref().is_none_or(|globs| globs.is_match(&path))
+            && !self.exclude.as_ref().is_some_and(|globs| globs.is_match(&path))
+    }
+}
+
+fn build_globs(patterns: &[&str]) -> Result<Option<GlobSet>, Box<dyn Error>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(
+            GlobBuilder::new(pattern)
+                .case_insensitive(true)
+                .literal_separator(true)
+                .build()?,
+        );
+    }
+    Ok(Some(builder.build()?))
+}
+
+/// Extracts the archive at `archive_path` into `destination`, whichever supported format it is.
+pub fn extract_archive(archive_path: &Path, destination: &Path) -> Result<(), Box<dyn Error>> {
+    extract_archive_filtered(archive_path, destination, &ExtractFilter::all())
+}
+
+/// Like [`extract_archive`], but only writes the entries `filter` accepts.
+pub fn extract_archive_filtered(
+    archive_path: &Path,
+    destination: &Path,
+    filter: &ExtractFilter,
+) -> Result<(), Box<dyn Error>> {
+    match detect_format(archive_path)? {
+        Some(ArchiveFormat::Zip) => extract_zip(archive_path, destination, filter),
+        Some(ArchiveFormat::SevenZip) => extract_7z(archive_path, destination, filter),
+        Some(format @ (ArchiveFormat::TarGz | ArchiveFormat::TarZst)) => {
+            extract_tar(archive_path, destination, format, filter)
+        }
+        None => Err(t!("err-archive-unsupported", file = archive_path.display()).into()),
+    }
+}
+
+/// Identifies an archive by its leading signature bytes.
+fn detect_format(path: &Path) -> io::Result<Option<ArchiveFormat>> {
+    let mut header = [0u8; 6];
+    let mut file = File::open(path)?;
+    let read = file.read(&mut header)?;
+    let header = &header[..read];
+    Ok(if header.starts_with(ZIP_SIGNATURE) {
+        Some(ArchiveFormat::Zip)
+    } else if header.starts_with(SEVEN_ZIP_SIGNATURE) {
+        Some(ArchiveFormat::SevenZip)
+    } else if header.starts_with(GZIP_SIGNATURE) {
+        Some(ArchiveFormat::TarGz)
+    } else if header.starts_with(ZSTD_SIGNATURE) {
+        Some(ArchiveFormat::TarZst)
+    } else {
+        None
+    })
+}
+
+/// Extracts a zip file (at zip_path) to the specified destination directory.
+fn extract_zip(zip_path: &Path, destination: &Path, filter: &ExtractFilter) -> Result<(), Box<dyn Error>> {
+    let mut archive = ZipArchive::new(File::open(zip_path)?)?;
+    fs::create_dir_all(destination)?;
+    let root = destination.canonicalize()?;
+
+    // Validate every entry before writing anything, so a bad archive leaves no partial output.
+    let mut pending = Vec::new();
+    let mut total_bytes = 0u64;
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index)?;
+        if file.unix_mode().is_some_and(|mode| mode & S_IFMT == S_IFLNK) {
+            continue;
+        }
+        let Some(relative) = entry_path(file.name())? else {
+            continue;
+        };
+        if !filter.matches(&relative) {
+            continue;
+        }
+        let outpath = root.join(relative);
+        if file.is_dir() {
+            create_dir_within(&root, &outpath, file.name())?;
+        } else {
+            if let Some(p) = outpath.parent() {
+                create_dir_within(&root, p, file.name())?;
+            }
+            total_bytes += file.size();
+            pending.push(PendingFile { index, outpath });
+        }
+    }
+    drop(archive);
+
+    let progress = Progress::new("extract-progress", pending.len(), total_bytes);
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    let first_error: Mutex<Option<WorkerError>> = Mutex::new(None);
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(MAX_WORKERS)
+        .min(pending.len())
+        .max(1);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let result = extract_worker(zip_path, &pending, &next, &failed, &progress);
+                if let Err(ex) = result {
+                    failed.store(true, Ordering::Relaxed);
+                    let mut slot = first_error.lock().unwrap_or_else(|e| e.into_inner());
+                    slot.get_or_insert(ex);
+                }
+            });
+        }
+    });
+    progress.finish();
+
+    match first_error.into_inner().unwrap_or_else(|e| e.into_inner()) {
+        Some(ex) => Err(ex.to_string().into()),
+        None => Ok(()),
+    }
+}
+
+/// Extracts a 7z file (at archive_path) to the specified destination directory.
+fn extract_7z(archive_path: &Path, destination: &Path, filter: &ExtractFilter) -> Result<(), Box<dyn Error>> {
+    let mut reader = SevenZReader::open(archive_path, Password::empty())?;
+    fs::create_dir_all(destination)?;
+    let root = destination.canonicalize()?;
+
+    // Validate every entry before writing anything, as for zip.
+    let mut total_files = 0;
+    let mut total_bytes = 0u64;
+    for entry in &reader.archive().files {
+        if is_7z_link(entry) {
+            continue;
+        }
+        let Some(relative) = entry_path(entry.name())? else {
+            continue;
+        };
+        if !filter.matches(&relative) {
+            continue;
+        }
+        let outpath = root.join(relative);
+        if entry.is_directory() {
+            create_dir_within(&root, &outpath, entry.name())?;
+        } else {
+            if let Some(p) = outpath.parent() {
+                create_dir_within(&root, p, entry.name())?;
+            }
+            total_files += 1;
+            total_bytes += entry.size();
+        }
+    }
+
+    let progress = Progress::new("extract-progress", total_files, total_bytes);
+    let mut failure: Option<Box<dyn Error>> = None;
+    let result = reader.for_each_entries(|entry, data| {
+        if entry.is_directory() {
+            return Ok(true);
+        }
+        if is_7z_link(entry) {
+            // Not recreated, but its target is still in the stream the later entries share.
+            io::copy(data, &mut io::sink())?;
+            return Ok(true);
+        }
+        // Already validated above; this only rebuilds the path.
+        let outpath = match entry_path(entry.name()) {
+            Ok(Some(relative)) if filter.matches(&relative) => root.join(relative),
+            Ok(_) => {
+                // The entries of a solid block share one stream; unread bytes would shift the rest.
+                io::copy(data, &mut io::sink())?;
+                return Ok(true);
+            }
+            Err(ex) => {
+                failure = Some(ex);
+                return Ok(false);
+            }
+        };
+        let mut outfile = File::create(&outpath)?;
+        let written = io::copy(data, &mut outfile)?;
+        progress.add(written);
+        Ok(true)
+    });
+    progress.finish();
+    result?;
+    match failure {
+        Some(ex) => Err(ex),
+        None => Ok(()),
+    }
+}
+
+/// Extracts a compressed tarball (at archive_path) to the specified destination directory.
+fn extract_tar(
+    archive_path: &Path,
+    destination: &Path,
+    format: ArchiveFormat,
+    filter: &ExtractFilter,
+) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(destination)?;
+    let root = destination.canonicalize()?;
+
+    // A tar stream can't be listed without decompressing it, so validate in a first pass.
+    let mut total_files = 0;
+    let mut total_bytes = 0u64;
+    let mut archive = tar::Archive::new(open_tar_stream(archive_path, format)?);
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let kind = entry.header().entry_type();
+        if !kind.is_file() && !kind.is_dir() {
+            // Links, devices, and the like are never recreated.
+            continue;
+        }
+        let Some(relative) = entry_path(&name)? else {
+            continue;
+        };
+        if !filter.matches(&relative) {
+            continue;
+        }
+        let outpath = root.join(relative);
+        if kind.is_dir() {
+            create_dir_within(&root, &outpath, &name)?;
+        } else {
+            if let Some(p) = outpath.parent() {
+                create_dir_within(&root, p, &name)?;
+            }
+            total_files += 1;
+            total_bytes += entry.size();
+        }
+    }
+
+    let progress = Progress::new("extract-progress", total_files, total_bytes);
+    let stream = open_tar_stream(archive_path, format)?;
+    let result = write_tar_entries(stream, &root, filter, &progress);
+    progress.finish();
+    result
+}
+
+fn write_tar_entries(
+    stream: Box<dyn Read>,
+    root: &Path,
+    filter: &ExtractFilter,
+    progress: &Progress,
+) -> Result<(), Box<dyn Error>> {
+    let mut archive = tar::Archive::new(stream);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path()?.to_string_lossy().into_owned();
+        let Some(relative) = entry_path(&name)? else {
+            continue;
+        };
+        if !filter.matches(&relative) {
+            continue;
+        }
+        let mut outfile = File::create(root.join(relative))?;
+        let written = io::copy(&mut entry, &mut outfile)?;
+        progress.add(written);
+    }
+    Ok(())
+}
+
+fn open_tar_stream(path: &Path, format: ArchiveFormat) -> io::Result<Box<dyn Read>> {
+    let file = io::BufReader::new(File::open(path)?);
+    Ok(match format {
+        ArchiveFormat::TarZst => Box::new(zstd::Decoder::with_buffer(file)?),
+        _ => Box::new(flate2::read::GzDecoder::new(file)),
+    })
+}
+
+fn is_7z_link(entry: &SevenZArchiveEntry) -> bool {
+    if !entry.has_windows_attributes {
+        return false;
+    }
+    let attributes = entry.windows_attributes;
+    attributes & FILE_ATTRIBUTE_REPARSE_POINT != 0
+        || (attributes & FILE_ATTRIBUTE_UNIX_EXTENSION != 0 && (attributes >> 16) & S_IFMT == S_IFLNK)
+}
+
+/// Pulls entries off the shared queue until it is empty or another worker has failed.
+fn extract_worker(
+    zip_path: &Path,
+    pending: &[PendingFile],
+    next: &AtomicUsize,
+    failed: &AtomicBool,
+    progress: &Progress,
+) -> Result<(), WorkerError> {
+    let mut archive = ZipArchive::new(File::open(zip_path)?)?;
+    while !failed.load(Ordering::Relaxed) {
+        let Some(entry) = pending.get(next.fetch_add(1, Ordering::Relaxed)) else {
+            break;
+        };
+        let mut file = archive.by_index(entry.index)?;
+        let mut outfile = File::create(&entry.outpath)?;
+        let written = io::copy(&mut file, &mut outfile)?;
+        progress.add(written);
+    }
+    Ok(())
+}
+
+/// Turns an archive entry name into a relative path, refusing anything that could escape. An
+/// entry naming the archive's root itself, like the `./` that `tar -C dir .` writes first, has
+/// no path of its own: `None`, and nothing to extract.
+fn entry_path(name: &str) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    // Zip names use `/`, but Windows tools sometimes write `\`; treat both as separators.
+    let normalized = name.replace('\\', "/");
+    let mut relative = PathBuf::new();
+    for component in Path::new(&normalized).components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                return Err(t!("err-archive-unsafe-path", entry = name).into());
+            }
+        }
+    }
+    if normalized.contains(':') {
+        // `C:foo` parses as a normal component on some inputs, and `:` also opens NTFS streams.
+        return Err(t!("err-archive-unsafe-path", entry = name).into());
+    }
+    Ok(Some(relative).filter(|relative| !relative.as_os_str().is_empty()))
+}
+
+/// Creates `dir` and its missing parents, unless a junction already on disk would put them
+/// outside `root`: the deepest part of `dir` that exists is checked before anything is created.
+fn create_dir_within(root: &Path, dir: &Path, entry: &str) -> Result<(), Box<dyn Error>> {
+    let existing = dir.ancestors().find(|ancestor| ancestor.exists()).unwrap_or(root);
+    ensure_within(root, existing, entry)?;
+    fs::create_dir_all(dir)?;
+    // A junction created in between could still redirect the write elsewhere.
+    ensure_within(root, dir, entry)
+}
+
+/// Fails unless `path`, with links resolved, is `root` or lies under it.
+fn ensure_within(root: &Path, path: &Path, entry: &str) -> Result<(), Box<dyn Error>> {
+    if path.canonicalize()?.starts_with(root) {
+        Ok(())
+    } else {
+        Err(t!("err-archive-unsafe-path", entry = entry).into())
+    }
+}
@@ -0,0 +1,93 @@
+// assets.rs
+//! Choosing which asset of a GitHub release to download.
+//!
+//! Asset names come from the manifest (or `installer.toml`) as patterns rather than exact names,
+//! so a project renaming `Mod.zip` to `Mod-v2.1.zip` doesn't silently break installs. A pattern
+//! is a case-insensitive glob (`CustomAvatarLoader*.zip`; a plain name is a glob that matches
+//! only itself) or, with a `re:` prefix, a case-insensitive regex that must match the whole name.
+//!
+//! When several assets match, a build for 64-bit Windows wins over one that doesn't say, and
+//! both win over builds for other architectures.
+
+use globset::GlobBuilder;
+use regex::RegexBuilder;
+
+/// Used when the manifest doesn't name an asset: any archive format the installer can extract.
+pub const DEFAULT_PATTERN: &str = "*.{zip,7z,tar.gz,tgz,tar.zst}";
+
+const REGEX_PREFIX: &str = "re:";
+
+/// Name fragments that mark a build for the architecture DesktopMate runs on.
+const PREFERRED_ARCH: &[&str] = &["x86_64", "x64", "amd64", "win64"];
+/// Name fragments that mark a build for some other architecture.
+const OTHER_ARCH: &[&str] = &["arm64", "aarch64", "armv7", "x86", "i686", "win32", "32bit"];
+
+/// A compiled asset name pattern.
+pub struct AssetPattern {
+    source: String,
+    matcher: Matcher,
+}
+
+enum Matcher {
+    Glob(globset::GlobMatcher),
+    Regex(regex::Regex),
+}
+
+impl AssetPattern {
+    /// Compiles `pattern`; the error names the pattern and says what is wrong with it.
+    pub fn new(pattern: &str) -> Result<AssetPattern, String> {
+        let matcher = match pattern.strip_prefix(REGEX_PREFIX) {
+            Some(regex) => RegexBuilder::new(&format!("^(?:{})$", regex))
+                .case_insensitive(true)
+                .build()
+                .map(Matcher::Regex)
+                .map_err(|e| format!("{}: {}", pattern, e))?,
+            None => GlobBuilder::new(pattern)
+                .case_insensitive(true)
+                .literal_separator(true)
+                .build()
+                .map(|glob| Matcher::Glob(glob.compile_matcher()))
+                .map_err(|e| format!("{}: {}", pattern, e))?,
+        };
+        Ok(AssetPattern { source: pattern.to_owned(), matcher })
+    }
+
+    pub fn is_match(&self, name: &str) -> bool {
+        match &self.matcher {
+            Matcher::Glob(glob) => glob.is_match(name),
+            Matcher::Regex(regex) => regex.is_match(name),
+        }
+    }
+
+    /// Of the names matching the pattern, the one best suited to this machine. Ties keep the
+    /// release's own order.
+    pub fn select<'a, I>(&self, names: I) -> Option<&'a str>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        names
+            .into_iter()
+            .filter(|name| self.is_match(name))
+            .enumerate()
+            .min_by_key(|(index, name)| (arch_rank(name), *index))
+            .map(|(_, name)| name)
+    }
+}
+
+impl std::fmt::Display for AssetPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// 0 for a 64-bit Windows build, 1 when the name doesn't say, 2 for another architecture.
+fn arch_rank(name: &str) -> u8 {
+    let name = name.to_ascii_lowercase();
+    if PREFERRED_ARCH.iter().any(|arch| name.contains(arch)) {
+        0
+    } else if OTHER_ARCH.iter().any(|arch| name.contains(arch)) {
+        2
+    } else {
+        1
+    }
+}
@@ -0,0 +1,228 @@
+// backup.rs
+//! Backups of the user's own data before the installer changes an existing install.
+//!
+//! Mod settings (`UserData`, which holds `MelonPreferences.cfg`), the `Mods` folder, the avatar
+//! library, and Goldberg's save folder are not files the installer can download again. Each
+//! backup is one zip in the `Backups` folder next to the installer, with one top-level folder per
+//! source and a `backup.json` index recording where each source came from.
+//!
+//! After each new backup, [`prune`] deletes the ones beyond the configured count or age, so the
+//! folder doesn't grow without bound over months of updates.
+//!
+//! [`restore`] puts selected sources back. Folders that lived in the game folder go into the
+//! install being restored to, wherever it is now; anything else goes back to where it was.
+
+use crate::archive::{extract_archive_filtered, ExtractFilter};
+use crate::config::ConflictPolicy;
+use crate::copy::{copy_directory, CopyReport};
+use crate::progress::Progress;
+use crate::temp::TempPath;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+pub const BACKUPS_DIR: &str = "Backups";
+/// Name of the index inside every backup archive.
+pub const INDEX_NAME: &str = "backup.json";
+/// Backups kept when `installer.toml` doesn't say.
+pub const DEFAULT_KEEP: usize = 10;
+/// The avatar library, relative to the game folder.
+const AVATARS_DIR: &str = "Avatars";
+
+/// A folder worth backing up.
+pub struct Source {
+    /// Top-level folder name inside the archive.
+    pub name: &'static str,
+    pub path: PathBuf,
+}
+
+/// `backup.json`: what a backup holds and where it came from.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupIndex {
+    /// RFC 3339 time the backup was made.
+    pub created: String,
+    pub game_path: PathBuf,
+    pub sources: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub name: String,
+    /// Where the folder was when the backup was made.
+    pub path: PathBuf,
+}
+
+/// The user-data folders of the install in `game_dir` that exist and aren't empty.
+pub fn sources(game_dir: &Path, app_id: &str) -> Vec<Source> {
+    let mut sources = vec![
+        Source { name: "UserData", path: game_dir.join("UserData") },
+        Source { name: "Mods", path: game_dir.join("Mods") },
+        Source { name: "Avatars", path: game_dir.join(AVATARS_DIR) },
+    ];
+    // Goldberg keeps saves in the roaming profile, per app ID.
+    if let Some(appdata) = dirs::config_dir() {
+        sources.push(Source { name: "GoldbergSaves", path: appdata.join("Goldberg SteamEmu Saves").join(app_id) });
+    }
+    sources
+        .into_iter()
+        .filter(|source| fs::read_dir(&source.path).is_ok_and(|mut entries| entries.next().is_some()))
+        .collect()
+}
+
+/// Zips `sources` into a new timestamped archive in `backups_dir` and returns its path.
+pub fn create(backups_dir: &Path, game_dir: &Path, sources: &[Source]) -> io::Result<PathBuf> {
+    fs::create_dir_all(backups_dir)?;
+    let now = Local::now();
+    let path = backups_dir.join(format!("DesktopMate-{}.zip", now.format("%Y%m%d-%H%M%S")));
+    let partial = PathBuf::from(format!("{}.partial", path.display()));
+
+    let (mut files, mut bytes) = (0, 0);
+    for source in sources {
+        let (source_files, source_bytes) = crate::copy::measure(&source.path)?;
+        files += source_files;
+        bytes += source_bytes;
+    }
+    let progress = Progress::new("backup-progress", files, bytes);
+    let result = (|| {
+        let mut zip = ZipWriter::new(File::create(&partial)?);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let index = BackupIndex {
+            created: now.to_rfc3339(),
+            game_path: game_dir.to_path_buf(),
+            sources: sources
+                .iter()
+                .map(|source| IndexEntry { name: source.name.to_owned(), path: source.path.clone() })
+                .collect(),
+        };
+        zip.start_file(INDEX_NAME, options)?;
+        zip.write_all(&serde_json::to_vec_pretty(&index).map_err(io::Error::other)?)?;
+        for source in sources {
+            add_dir(&mut zip, options, &source.path, source.name, &progress)?;
+        }
+        zip.finish()?;
+        Ok(())
+    })();
+    progress.finish();
+    if let Err(ex) = result {
+        let _ = fs::remove_file(&partial);
+        return Err(ex);
+    }
+    // Only a finished archive gets the `.zip` name, so a crash never leaves a truncated backup.
+    fs::rename(&partial, &path)?;
+    Ok(path)
+}
+
+fn add_dir(
+    zip: &mut ZipWriter<File>,
+    options: FileOptions,
+    dir: &Path,
+    name: &str,
+    progress: &Progress,
+) -> io::Result<()> {
+    zip.add_directory(format!("{}/", name), options)?;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let entry_name = format!("{}/{}", name, entry.file_name().to_string_lossy());
+        if file_type.is_dir() {
+            add_dir(zip, options, &entry.path(), &entry_name, progress)?;
+        } else if file_type.is_file() {
+            zip.start_file(entry_name, options)?;
+            let copied = io::copy(&mut File::open(entry.path())?, zip)?;
+            progress.add(copied);
+        }
+    }
+    Ok(())
+}
+
+/// Every backup in `backups_dir` with a readable index, newest first.
+pub fn list(backups_dir: &Path) -> Vec<(PathBuf, BackupIndex)> {
+    let Ok(entries) = fs::read_dir(backups_dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<(PathBuf, BackupIndex)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("zip")))
+        .filter_map(|path| read_index(&path).ok().map(|index| (path, index)))
+        .collect();
+    backups.sort_by(|a, b| b.1.created.cmp(&a.1.created));
+    backups
+}
+
+/// Deletes all but the newest `keep` backups in `backups_dir`, and any older than `max_age_days`
+/// except the newest one. Leftovers of interrupted backups go too. Returns the deleted paths.
+pub fn prune(backups_dir: &Path, keep: usize, max_age_days: Option<u64>) -> Vec<PathBuf> {
+    let now = Local::now();
+    let mut removed = Vec::new();
+    for (position, (path, index)) in list(backups_dir).into_iter().enumerate() {
+        let age_days = DateTime::parse_from_rfc3339(&index.created)
+            .map(|created| (now.fixed_offset() - created).num_days())
+            .unwrap_or(0);
+        let too_old = max_age_days.is_some_and(|max| position > 0 && age_days > max as i64);
+        if (position >= keep.max(1) || too_old) && fs::remove_file(&path).is_ok() {
+            removed.push(path);
+        }
+    }
+    if let Ok(entries) = fs::read_dir(backups_dir) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.extension().is_some_and(|ext| ext == "partial") && fs::remove_file(&path).is_ok() {
+                removed.push(path);
+            }
+        }
+    }
+    removed
+}
+
+/// Reads `backup.json` out of a backup archive.
+pub fn read_index(archive: &Path) -> io::Result<BackupIndex> {
+    let mut zip = ZipArchive::new(File::open(archive)?)?;
+    let entry = zip.by_name(INDEX_NAME)?;
+    serde_json::from_reader(entry).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Where `entry` is restored to when restoring into the install at `game_dir`.
+pub fn restore_target(index: &BackupIndex, entry: &IndexEntry, game_dir: &Path) -> PathBuf {
+    match entry.path.strip_prefix(&index.game_path) {
+        Ok(relative) => game_dir.join(relative),
+        Err(_) => entry.path.clone(),
+    }
+}
+
+/// Restores the sources named in `names` from `archive` into the install at `game_dir`,
+/// overwriting files with the backed-up copies. Fails before writing anything if a target's
+/// parent folder doesn't exist.
+pub fn restore(
+    archive: &Path,
+    index: &BackupIndex,
+    names: &[&str],
+    game_dir: &Path,
+) -> Result<Vec<(PathBuf, CopyReport)>, Box<dyn Error>> {
+    let entries: Vec<&IndexEntry> = index.sources.iter().filter(|entry| names.contains(&entry.name.as_str())).collect();
+    let targets: Vec<PathBuf> = entries.iter().map(|entry| restore_target(index, entry, game_dir)).collect();
+    for target in &targets {
+        if !target.parent().is_some_and(Path::is_dir) {
+            return Err(t!("err-restore-target-missing", path = target.display().to_string()).into());
+        }
+    }
+
+    let include: Vec<String> = entries.iter().map(|entry| format!("{}/**", entry.name)).collect();
+    let include: Vec<&str> = include.iter().map(String::as_str).collect();
+    let extracted = TempPath::dir("restore")?;
+    extract_archive_filtered(archive, &extracted, &ExtractFilter::new(&include, &[])?)?;
+
+    let mut reports = Vec::new();
+    for (entry, target) in entries.iter().zip(targets) {
+        let source = extracted.join(&entry.name);
+        if source.is_dir() {
+            let report = copy_directory(&source, &target, ConflictPolicy::Overwrite)?;
+            reports.push((target, report));
+        }
+    }
+    Ok(reports)
+}
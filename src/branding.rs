@@ -0,0 +1,56 @@
+// branding.rs
+//! The banner title and extra lines, which community repacks can set in the `[branding]` table
+//! of `installer.toml` instead of patching the source.
+//!
+//! The title replaces the translated "DesktopMate Installer" in the console banner, the window
+//! title, and the full-screen header; the extra lines (a community name, a support Discord link)
+//! are shown under it in both interfaces.
+
+use crate::config::BrandingConfig;
+use std::sync::Mutex;
+use unicode_width::UnicodeWidthStr;
+
+/// Banner width when `installer.toml` doesn't set one.
+const DEFAULT_WIDTH: usize = 45;
+
+static BRANDING: Mutex<Option<BrandingConfig>> = Mutex::new(None);
+
+/// Makes `config` this run's branding.
+pub fn set(config: &BrandingConfig) {
+    *BRANDING.lock().unwrap_or_else(|e| e.into_inner()) = Some(config.clone());
+}
+
+/// The installer's title.
+pub fn title() -> String {
+    current().title.filter(|title| !title.trim().is_empty()).unwrap_or_else(|| t!("app-title"))
+}
+
+/// Lines shown under the title.
+pub fn extra_lines() -> Vec<String> {
+    current().lines
+}
+
+/// The console banner: a rule, the title and extra lines centred, and another rule. Padding
+/// counts display columns, not chars, so CJK titles stay centred.
+pub fn banner() -> Vec<String> {
+    let branding = current();
+    let mut text = vec![title()];
+    text.extend(branding.lines);
+    let widest = text.iter().map(|line| UnicodeWidthStr::width(line.as_str())).max().unwrap_or(0);
+    let width = branding.width.unwrap_or(DEFAULT_WIDTH).max(widest);
+
+    let rule = "=".repeat(width);
+    let mut banner = vec![rule.clone()];
+    for line in text {
+        let line_width = UnicodeWidthStr::width(line.as_str());
+        let left = width.saturating_sub(line_width) / 2;
+        let right = width.saturating_sub(line_width + left);
+        banner.push(format!("{}{}{}", " ".repeat(left), line, " ".repeat(right)));
+    }
+    banner.push(rule);
+    banner
+}
+
+fn current() -> BrandingConfig {
+    BRANDING.lock().unwrap_or_else(|e| e.into_inner()).clone().unwrap_or_default()
+}
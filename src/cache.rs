@@ -0,0 +1,131 @@
+// cache.rs
+//! Downloaded archives kept in `%LOCALAPPDATA%\DesktopMateInstaller\downloads`, so running the
+//! installer again, or installing into a second folder, copies them instead of fetching them.
+//!
+//! Files are stored under their SHA-256, and `index.json` maps each URL to the hash of what it
+//! served: two URLs serving the same file share one copy, and a copy that was damaged since is
+//! noticed and dropped before it is used. URLs naming a moving target (`.../latest/...`) aren't
+//! cached, since the same URL serves a newer file once there is one. `cache prune` deletes
+//! copies that haven't been used for a while.
+
+use crate::transaction::sha256_file;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const INDEX_FILE: &str = "index.json";
+
+#[derive(Default, Serialize, Deserialize)]
+struct Index {
+    /// By URL.
+    entries: BTreeMap<String, Entry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    sha256: String,
+    /// RFC 3339 time the copy was last stored or used.
+    last_used: String,
+}
+
+/// What [`prune`] removed.
+#[derive(Debug, Default)]
+pub struct Pruned {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Copies the cached download of `url` to `output_path`. Returns whether there was one.
+pub fn restore(url: &str, output_path: &Path) -> bool {
+    let Some(dir) = cache_dir().filter(|_| is_cacheable(url)) else {
+        return false;
+    };
+    let mut index = load_index(&dir);
+    let Some(entry) = index.entries.get_mut(url) else {
+        return false;
+    };
+    let file = dir.join(&entry.sha256);
+    if !sha256_file(&file).is_ok_and(|actual| actual == entry.sha256) {
+        let _ = fs::remove_file(&file);
+        index.entries.remove(url);
+        let _ = save_index(&dir, &index);
+        return false;
+    }
+    if fs::copy(&file, output_path).is_err() {
+        return false;
+    }
+    entry.last_used = Local::now().to_rfc3339();
+    let _ = save_index(&dir, &index);
+    true
+}
+
+/// Keeps a copy of the file just downloaded from `url` at `path`.
+pub fn store(url: &str, path: &Path) -> io::Result<()> {
+    let Some(dir) = cache_dir().filter(|_| is_cacheable(url)) else {
+        return Ok(());
+    };
+    fs::create_dir_all(&dir)?;
+    let sha256 = sha256_file(path)?;
+    let file = dir.join(&sha256);
+    if !file.is_file() {
+        fs::copy(path, &file)?;
+    }
+    let mut index = load_index(&dir);
+    index.entries.insert(url.to_owned(), Entry { sha256, last_used: Local::now().to_rfc3339() });
+    save_index(&dir, &index)
+}
+
+/// Deletes cached files not used within `max_age` (all of them with `None`), and any file the
+/// index doesn't know.
+pub fn prune(max_age: Option<Duration>) -> io::Result<Pruned> {
+    let mut pruned = Pruned::default();
+    let Some(dir) = cache_dir().filter(|dir| dir.is_dir()) else {
+        return Ok(pruned);
+    };
+    let mut index = load_index(&dir);
+    let now = Local::now();
+    index.entries.retain(|_, entry| {
+        let age = DateTime::parse_from_rfc3339(&entry.last_used)
+            .ok()
+            .and_then(|used| (now - used.with_timezone(&Local)).to_std().ok());
+        matches!((max_age, age), (Some(max_age), Some(age)) if age <= max_age)
+    });
+    let kept: HashSet<&str> = index.entries.values().map(|entry| entry.sha256.as_str()).collect();
+    for item in fs::read_dir(&dir)?.flatten() {
+        let name = item.file_name().to_string_lossy().into_owned();
+        if name == INDEX_FILE || kept.contains(name.as_str()) {
+            continue;
+        }
+        let size = item.metadata().map_or(0, |meta| meta.len());
+        if fs::remove_file(item.path()).is_ok() {
+            pruned.files += 1;
+            pruned.bytes += size;
+        }
+    }
+    save_index(&dir, &index)?;
+    Ok(pruned)
+}
+
+/// `%LOCALAPPDATA%\DesktopMateInstaller\downloads`.
+pub fn cache_dir() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("DesktopMateInstaller").join("downloads"))
+}
+
+fn is_cacheable(url: &str) -> bool {
+    !url.to_ascii_lowercase().contains("/latest/")
+}
+
+fn load_index(dir: &Path) -> Index {
+    fs::read_to_string(dir.join(INDEX_FILE))
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn save_index(dir: &Path, index: &Index) -> io::Result<()> {
+    fs::write(dir.join(INDEX_FILE), serde_json::to_string_pretty(index).map_err(io::Error::other)?)
+}
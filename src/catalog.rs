@@ -0,0 +1,127 @@
+// catalog.rs
+//! The maintainers' list of DesktopMate mods besides Custom Avatar Loader.
+//!
+//! `manifest/catalog.json` describes each mod like a manifest `mod` step (name, component,
+//! GitHub owner and repository, asset pattern, DLL) plus a one-line description and the
+//! components it needs. Like the component manifest, the current copy is fetched from the
+//! repository and the bundled one is used when that fails, so a mod can be listed without
+//! shipping a new binary.
+//!
+//! `--mods` shows the catalog as a checklist. The mods picked are saved with the other answers,
+//! and every run then installs and updates them as extra `mod` steps after the manifest's own,
+//! each after the mods it depends on.
+
+use crate::manifest::{ComponentManifest, ModSpec, REMOTE_BASE_URL};
+use crate::proxy;
+use crate::retry;
+use crate::write_log;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Highest catalog schema this build understands.
+const SUPPORTED_SCHEMA: u32 = 1;
+
+const BUNDLED: &str = include_str!("../manifest/catalog.json");
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Catalog {
+    schema: u32,
+    revision: String,
+    #[serde(default)]
+    pub mods: Vec<CatalogMod>,
+}
+
+/// One mod the catalog offers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogMod {
+    #[serde(flatten)]
+    pub spec: ModSpec,
+    /// What the mod does, in a line.
+    #[serde(default)]
+    pub description: String,
+    /// Components (catalog mods or the manifest's) that must be installed with it.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// The catalog for this run, preferring the remote copy over the bundled one.
+pub async fn load(log_file: &Path) -> Catalog {
+    match fetch_remote().await {
+        Ok(catalog) => {
+            let _ = write_log(log_file, &format!("Using remote mod catalog (revision {}).", catalog.revision));
+            catalog
+        }
+        Err(reason) => {
+            let catalog = bundled();
+            let _ = write_log(
+                log_file,
+                &format!("Remote mod catalog unavailable ({}); using bundled revision {}.", reason, catalog.revision),
+            );
+            catalog
+        }
+    }
+}
+
+fn bundled() -> Catalog {
+    serde_json::from_str(BUNDLED).expect("bundled mod catalog is valid")
+}
+
+async fn fetch_remote() -> Result<Catalog, String> {
+    let url = format!("{}/catalog.json", REMOTE_BASE_URL);
+    let client = proxy::client().build().map_err(|e| e.to_string())?;
+    let resp = retry::get(&client, &url).await.map_err(|e| e.to_string())?;
+    let catalog: Catalog = resp.json().await.map_err(|e| e.to_string())?;
+    if catalog.schema > SUPPORTED_SCHEMA {
+        return Err(format!("schema {} is newer than supported schema {}", catalog.schema, SUPPORTED_SCHEMA));
+    }
+    Ok(catalog)
+}
+
+impl Catalog {
+    /// Adds install steps for the catalog mods `components` and what they depend on to
+    /// `manifest`, dependencies first, before its shortcuts step. Mods the manifest installs
+    /// already are skipped. Returns the names added and the components that are neither in the
+    /// catalog nor in the manifest, which are left out along with what needs them.
+    pub fn add_steps(&self, manifest: &mut ComponentManifest, components: &[String]) -> (Vec<String>, Vec<String>) {
+        let mut ordered: Vec<&ModSpec> = Vec::new();
+        let mut unknown = Vec::new();
+        for component in components {
+            let mut resolved = ordered.clone();
+            match self.resolve(manifest, component, &mut resolved, &mut Vec::new()) {
+                Ok(()) => ordered = resolved,
+                Err(missing) => unknown.push(missing),
+            }
+        }
+        let names = ordered.iter().map(|spec| spec.name.clone()).collect();
+        manifest.add_mod_steps(ordered.into_iter().cloned());
+        (names, unknown)
+    }
+
+    /// Appends `component` to `ordered` after its dependencies. `visiting` holds the chain being
+    /// resolved, so a dependency cycle ends instead of recursing forever.
+    fn resolve<'a>(
+        &'a self,
+        manifest: &ComponentManifest,
+        component: &str,
+        ordered: &mut Vec<&'a ModSpec>,
+        visiting: &mut Vec<String>,
+    ) -> Result<(), String> {
+        let known = |spec: &ModSpec| spec.component.eq_ignore_ascii_case(component);
+        if manifest.mods().into_iter().any(known) || ordered.iter().any(|spec| known(spec)) {
+            return Ok(());
+        }
+        if visiting.iter().any(|name| name.eq_ignore_ascii_case(component)) {
+            return Ok(());
+        }
+        let Some(entry) = self.mods.iter().find(|entry| known(&entry.spec)) else {
+            return Err(component.to_owned());
+        };
+        visiting.push(component.to_owned());
+        for dependency in &entry.dependencies {
+            self.resolve(manifest, dependency, ordered, visiting)?;
+        }
+        visiting.pop();
+        ordered.push(&entry.spec);
+        Ok(())
+    }
+}
@@ -0,0 +1,326 @@
+// cli.rs
+//! The command line, parsed once at startup.
+//!
+//! Subcommands pick what a run does, so batch files can drive the installer without answering
+//! prompts: `install` is the usual guided setup (and what a double-click runs), `update` brings an
+//! existing install up to date with the answers saved last time, `verify` checks it, `repair`
+//! reinstalls its damaged components and re-downloads damaged game files, and `uninstall` removes
+//! it.
+//! The older `--flag` modes still work alongside them. Flag names are matched case-insensitively,
+//! as they were before clap parsed them.
+//!
+//! `--unattended` never waits for input: every question takes its default answer at once, and the
+//! answers flags like `--backup no` or `--shortcuts none` set become those defaults (in a console
+//! run they are preselected instead). A question with no default fails the run.
+
+use crate::shortcuts::Variant;
+use clap::builder::BoolishValueParser;
+use clap::{Parser, Subcommand};
+use std::env;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+static ARGS: OnceLock<Cli> = OnceLock::new();
+
+#[derive(Debug, Parser)]
+#[command(name = "DesktopMateInstaller", about = "Installs DesktopMate with MelonLoader and mods.")]
+#[command(disable_version_flag = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Settings file to use instead of the installer.toml next to the installer.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+    /// HTTP, HTTPS, or SOCKS5 proxy for every download, e.g. `http://proxy:8080`.
+    #[arg(long, global = true, value_name = "URL")]
+    pub proxy: Option<String>,
+    /// Also trust the certificates in this file (a PEM bundle or DER), e.g. a TLS-inspecting proxy's.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub ca_cert: Option<PathBuf>,
+    /// Depot manifest of the game to download, or `latest` for the build Steam currently serves.
+    #[arg(long, global = true, value_name = "ID|latest")]
+    pub manifest: Option<String>,
+    /// Steam branch of the game to download, e.g. `beta`; `public` returns to the default one.
+    #[arg(long, global = true, value_name = "NAME")]
+    pub branch: Option<String>,
+    /// Content servers DepotDownloader uses at once.
+    #[arg(long, global = true, value_name = "N")]
+    pub max_servers: Option<u32>,
+    /// Chunks DepotDownloader downloads at once; fewer helps on a slow connection.
+    #[arg(long, global = true, value_name = "N")]
+    pub max_downloads: Option<u32>,
+    /// Steam cell (region) whose content servers DepotDownloader prefers.
+    #[arg(long, global = true, value_name = "ID")]
+    pub cell_id: Option<u32>,
+    /// Have DepotDownloader checksum every game file, as `repair` does.
+    #[arg(long, global = true)]
+    pub validate: bool,
+    /// UI language, e.g. `ja` or `de`.
+    #[arg(long, global = true, value_name = "LANGUAGE")]
+    pub lang: Option<String>,
+    /// Take a question's default answer after this many seconds without input.
+    #[arg(long, global = true, value_name = "SECONDS")]
+    pub prompt_timeout: Option<u64>,
+    /// Ignore saved GitHub responses.
+    #[arg(long, global = true)]
+    pub refresh: bool,
+    /// Use the classic console prompts instead of the full-screen wizard.
+    #[arg(long, global = true)]
+    pub no_tui: bool,
+    /// Don't check for a newer installer.
+    #[arg(long, global = true)]
+    pub no_self_update: bool,
+    /// Install archives whose hash doesn't match the pinned one, after a warning.
+    #[arg(long, global = true)]
+    pub allow_hash_mismatch: bool,
+    /// Read the Steam login from this file instead of asking for it.
+    #[arg(long, global = true, value_name = "FILE")]
+    pub credentials_file: Option<PathBuf>,
+
+    /// Never wait for input; questions take their default answers.
+    #[arg(long, global = true)]
+    pub unattended: bool,
+    /// Install folder, instead of the one used last time.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub install_dir: Option<String>,
+    /// Steam account; the password is read from DMI_STEAM_PASSWORD, else asked for.
+    #[arg(long, global = true, value_name = "NAME")]
+    pub steam_user: Option<String>,
+    /// Confirm owning the game on Steam, which a first run otherwise asks.
+    #[arg(long, global = true)]
+    pub confirm_ownership: bool,
+    /// Answer to "download the newer game build?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub update_game: Option<bool>,
+    /// Answer to "install the mod update?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub update_mods: Option<bool>,
+    /// Answer to "back up user data first?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub backup: Option<bool>,
+    /// Which desktop shortcuts to create.
+    #[arg(long, global = true, value_enum)]
+    pub shortcuts: Option<Variant>,
+    /// Answer to "pin to Start?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub pin_to_start: Option<bool>,
+    /// Answer to "create a safe-mode shortcut?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub safe_mode_shortcut: Option<bool>,
+    /// Answer to "start the game briefly to check that MelonLoader loads?".
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub check_injection: Option<bool>,
+    /// Answer to "undo this run's changes?" after a failure.
+    #[arg(long, global = true, value_name = "YES|NO", value_parser = BoolishValueParser::new())]
+    pub rollback: Option<bool>,
+
+    /// Print the installer version and what this run would install.
+    #[arg(long)]
+    pub version: bool,
+    /// Print the install log.
+    #[arg(long)]
+    pub logs: bool,
+    /// With --logs, only the last N lines.
+    #[arg(long, value_name = "N", requires = "logs")]
+    pub tail: Option<usize>,
+    /// With --logs, only the most recent run.
+    #[arg(long, requires = "logs")]
+    pub last_run: bool,
+    /// With --logs, hide lines less severe than `warning` or `error`.
+    #[arg(long, requires = "logs")]
+    pub level: Option<String>,
+    /// Build an offline kit in FOLDER.
+    #[arg(long, value_name = "FOLDER", num_args = 0..=1)]
+    pub package: Option<Option<String>>,
+    /// Record the current archives' hashes in FILE.
+    #[arg(long, value_name = "FILE", num_args = 0..=1)]
+    pub pin_hashes: Option<Option<String>>,
+    /// Recreate the shortcuts for the install in PATH.
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub fix_shortcuts: Option<Option<String>>,
+    /// Exclude PATH from Microsoft Defender scans.
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub defender_exclusion: Option<Option<String>>,
+    /// Same as `verify --game-files`.
+    #[arg(long = "verify", value_name = "PATH", num_args = 0..=1)]
+    pub verify_files: Option<Option<String>>,
+    /// Restore a user data backup.
+    #[arg(long, value_name = "BACKUP", num_args = 0..=1)]
+    pub restore: Option<Option<String>>,
+    /// Report available updates for the install in PATH and offer to install them.
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub check_updates: Option<Option<String>>,
+    /// Keep checking the install in PATH for updates once a day.
+    #[arg(long, value_name = "PATH", num_args = 0..=1)]
+    pub watch: Option<Option<String>>,
+    /// Pick mods to install besides Custom Avatar Loader from the maintainers' catalog.
+    #[arg(long)]
+    pub mods: bool,
+    /// Pick the MelonLoader release to install from a list, or name it (a tag or `latest`).
+    #[arg(long, value_name = "VERSION", num_args = 0..=1)]
+    pub melonloader: Option<Option<String>>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Install or update everything, asking the setup questions (the default).
+    Install {
+        /// Install folder to suggest.
+        path: Option<String>,
+    },
+    /// Update an existing install with the answers saved last time, without asking.
+    Update {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+    },
+    /// Check an existing install, re-hashing the installed files, without changing it.
+    Verify {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+        /// Also compare the game files with Steam's depot manifest (needs a Steam login).
+        #[arg(long)]
+        game_files: bool,
+    },
+    /// Reinstall damaged components, the patch, and shortcuts, and re-download damaged game files.
+    Repair {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+    },
+    /// Undo the Goldberg patch, restoring the game's own steam_api64.dll, to play through Steam.
+    SteamMode {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+    },
+    /// Remove MelonLoader, for a vanilla game or a clean reinstall; mods' folders are kept.
+    RemoveMelonloader {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+        /// Don't ask for confirmation.
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Manage mods beyond the ones the installer sets up.
+    Mod {
+        #[command(subcommand)]
+        action: ModCommand,
+    },
+    /// Save and switch between named sets of enabled mods.
+    Profile {
+        #[command(subcommand)]
+        action: ProfileCommand,
+    },
+    /// Manage the cache of downloaded archives.
+    Cache {
+        #[command(subcommand)]
+        action: CacheCommand,
+    },
+    /// Delete the install and its shortcuts, offering to back up user data first.
+    Uninstall {
+        /// Install folder; defaults to the one used last time.
+        path: Option<String>,
+        /// Don't ask for confirmation; user data is backed up.
+        #[arg(long)]
+        yes: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ModCommand {
+    /// Install a mod from GitHub, Thunderstore or GameBanana and keep it updated with the rest.
+    Install {
+        /// The repository, e.g. `https://github.com/owner/repo` or `owner/repo`, a Thunderstore
+        /// package, e.g. `Namespace-Name` or its page, or a GameBanana mod page or ID.
+        url: String,
+    },
+    /// Check installed mods for newer releases, show their release notes, and install the ones
+    /// confirmed.
+    Update {
+        /// The mod to check; `--all` checks every one.
+        #[arg(required_unless_present = "all")]
+        name: Option<String>,
+        /// Check every installed mod.
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+        /// Install every update found without asking.
+        #[arg(long)]
+        yes: bool,
+    },
+    /// Put back an earlier version of a mod, kept when it was updated.
+    Rollback {
+        /// The mod's name.
+        name: String,
+        /// The version to put back; defaults to the newest kept one that isn't installed.
+        #[arg(long, value_name = "VERSION")]
+        to: Option<String>,
+    },
+    /// Search Thunderstore's DesktopMate packages.
+    Search {
+        /// Text in the package's name or description.
+        query: String,
+    },
+    /// Move a mod's files to `Mods.disabled`, so MelonLoader no longer loads it.
+    Disable {
+        /// The mod's name, or the name of its DLL in `Mods`.
+        name: String,
+    },
+    /// Move a disabled mod's files back to `Mods`.
+    Enable {
+        /// The mod's name, or the name of its DLL.
+        name: String,
+    },
+    /// List the versions of a Thunderstore package.
+    Versions {
+        /// The package, e.g. `Namespace-Name`.
+        package: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProfileCommand {
+    /// Save the mods and UserLibs enabled now as a profile.
+    Save {
+        /// E.g. `streaming` or `minimal`.
+        name: String,
+    },
+    /// Enable exactly the files of a profile, disabling the rest.
+    Switch {
+        name: String,
+    },
+    /// List the saved profiles.
+    List,
+    /// Delete a profile, leaving the files as they are.
+    Delete {
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Delete cached archives that haven't been used for a while.
+    Prune {
+        /// Keep archives used within this many days.
+        #[arg(long, value_name = "DAYS", default_value_t = 30)]
+        days: u64,
+        /// Delete every cached archive.
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+/// This run's command line. Parsing fails the run with clap's usage message.
+pub fn args() -> &'static Cli {
+    ARGS.get_or_init(|| Cli::parse_from(env::args_os().map(lowercase_flag)))
+}
+
+/// Lowercases the name of a `--flag` or `--flag=value` argument.
+fn lowercase_flag(arg: OsString) -> OsString {
+    match arg.to_str() {
+        Some(text) if text.starts_with("--") => match text.split_once('=') {
+            Some((name, value)) => format!("{}={}", name.to_ascii_lowercase(), value).into(),
+            None => text.to_ascii_lowercase().into(),
+        },
+        _ => arg,
+    }
+}
@@ -0,0 +1,118 @@
+// components.rs
+//! Which files each installed component put into the game folder.
+//!
+//! The lists are part of the install state (`install.json`). When a component is updated, files
+//! the old version shipped but the new one doesn't are deleted, so stale DLLs don't linger and
+//! get loaded next to their replacements.
+//!
+//! Each file is also hashed as it is recorded, so `verify` can tell which components were
+//! damaged or deleted since (see [`check`]) and `repair` reinstalls just those. A disabled mod's
+//! files are checked where `mod disable` moved them.
+
+use crate::mods;
+use crate::state::InstallState;
+use crate::transaction::{self, sha256_file};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A component with files that are gone or no longer match their recorded hash.
+pub struct Damage {
+    pub component: String,
+    pub missing: Vec<String>,
+    pub modified: Vec<String>,
+}
+
+/// Records that `component` at `version` consists of `files` (relative to `root`), hashing
+/// them as they are now.
+pub fn save(root: &Path, component: &str, version: &str, files: &[String]) -> io::Result<()> {
+    let mut hashes = BTreeMap::new();
+    for file in files {
+        let path = full_path(root, file);
+        if path.is_file() {
+            hashes.insert(file.clone(), sha256_file(&path)?);
+        }
+    }
+    let mut state = InstallState::load(root)?;
+    state.set_component(component, version, files.to_vec(), hashes);
+    state.save(root)
+}
+
+/// Records that `component`, just saved with [`save`], was downloaded from `url` as an archive
+/// with hash `archive_sha256`.
+pub fn record_source(root: &Path, component: &str, url: &str, archive_sha256: Option<String>) -> io::Result<()> {
+    let mut state = InstallState::load(root)?;
+    state.set_source(component, url, archive_sha256);
+    state.save(root)
+}
+
+/// Hashes every recorded file in `root` again and returns the components with missing or
+/// changed files. Components recorded without hashes are not checked; see [`unhashed`].
+pub fn check(root: &Path) -> io::Result<Vec<Damage>> {
+    let state = InstallState::load(root)?;
+    let mut damaged = Vec::new();
+    for (name, component) in state.components() {
+        let mut damage = Damage { component: name.clone(), missing: Vec::new(), modified: Vec::new() };
+        for (file, expected) in &component.sha256 {
+            let path = if state.is_disabled(file) {
+                full_path(root, &mods::disabled_location(file))
+            } else {
+                full_path(root, file)
+            };
+            if !path.is_file() {
+                damage.missing.push(file.clone());
+            } else if !sha256_file(&path).is_ok_and(|actual| actual.eq_ignore_ascii_case(expected)) {
+                damage.modified.push(file.clone());
+            }
+        }
+        if !damage.missing.is_empty() || !damage.modified.is_empty() {
+            damaged.push(damage);
+        }
+    }
+    Ok(damaged)
+}
+
+/// Components in `root` with a file list but no hashes, which [`check`] can't vouch for.
+pub fn unhashed(root: &Path) -> io::Result<Vec<String>> {
+    let state = InstallState::load(root)?;
+    Ok(state
+        .components()
+        .filter(|(_, component)| !component.files.is_empty() && component.sha256.is_empty())
+        .map(|(name, _)| name.clone())
+        .collect())
+}
+
+/// Deletes files the previously installed `component` listed that are not in `files`.
+/// Returns the deleted paths. Without a previous list nothing is deleted.
+pub fn remove_dropped(root: &Path, component: &str, files: &[String]) -> io::Result<Vec<PathBuf>> {
+    let state = InstallState::load(root)?;
+    let Some(previous) = state.component(component) else {
+        return Ok(Vec::new());
+    };
+    let current: HashSet<String> = files.iter().map(|f| f.to_lowercase()).collect();
+    let mut removed = Vec::new();
+    for file in &previous.files {
+        if current.contains(&file.to_lowercase()) {
+            continue;
+        }
+        let stale = full_path(root, file);
+        if stale.is_file() {
+            transaction::record_delete(&stale)?;
+            fs::remove_file(&stale)?;
+            removed.push(stale);
+        }
+    }
+    Ok(removed)
+}
+
+/// `path` relative to `root` in the `/`-separated form used in the lists.
+pub fn relative_name(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let parts: Vec<_> = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect();
+    Some(parts.join("/"))
+}
+
+fn full_path(root: &Path, name: &str) -> PathBuf {
+    name.split('/').fold(root.to_path_buf(), |path, part| path.join(part))
+}
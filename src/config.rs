@@ -0,0 +1,279 @@
+// config.rs
+//! Optional `installer.toml` read from next to the executable, or from the file `--config`
+//! names.
+//!
+//! Every key is optional; a missing file next to the executable means "all defaults". Besides
+//! behaviour, it can preset what a repeat install would otherwise be asked or look up: the install
+//! folder, the Steam identifiers of the game, where each mod comes from, the proxy, and which
+//! interface to use.
+
+use crate::cli;
+use crate::manifest::{ComponentManifest, FileFilter, GoldbergBuild, GoldbergProvider, Step};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = "installer.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Release channel used by the self-updater and the component manifest.
+    pub channel: Channel,
+    /// UI language tag (e.g. `ja`); overrides the Windows locale. `--lang` overrides this.
+    pub language: Option<String>,
+    /// What to do when a mod update would replace a file that differs from the incoming one.
+    pub on_conflict: ConflictPolicy,
+    /// Replaces the manifest's Custom Avatar Loader asset pattern, for when a release renames it.
+    pub avatar_loader_asset: Option<String>,
+    /// MelonLoader release to install instead of the manifest's, or `latest`. See
+    /// [`crate::melonloader`].
+    pub melonloader_version: Option<String>,
+    /// `preview` also offers MelonLoader's prereleases.
+    pub melonloader_channel: Channel,
+    /// Thunderstore community mods are searched and installed from, instead of DesktopMate's.
+    pub thunderstore_community: Option<String>,
+    /// Goldberg emulator to install, `mr-goldberg` or `gbe-fork`, overriding the manifest's.
+    pub goldberg_provider: Option<GoldbergProvider>,
+    /// `regular` or `experimental` gbe_fork DLL, overriding the manifest's.
+    pub goldberg_build: Option<GoldbergBuild>,
+    /// DepotDownloader release to use instead of the latest, e.g. `3.4.0`.
+    pub depot_downloader_version: Option<String>,
+    /// Minutes a GitHub release lookup is reused before asking again; 0 always asks.
+    pub release_cache_minutes: Option<u64>,
+    /// How many times a download or request is tried before giving up (default 4).
+    pub network_attempts: Option<u32>,
+    /// How many user data backups to keep; older ones are deleted after each new backup.
+    pub keep_backups: Option<usize>,
+    /// How many earlier versions of each mod to keep for `mod rollback` (default 3, 0 = none).
+    pub keep_mod_versions: Option<usize>,
+    /// Backups older than this many days are deleted too (the newest one is always kept).
+    pub backup_max_age_days: Option<u64>,
+    /// Seconds a question with a default answer waits before taking it; unset or 0 waits forever.
+    pub prompt_timeout_seconds: Option<u64>,
+    /// Display name of the shortcut without console; set here, the console prompt isn't shown.
+    pub shortcut_name: Option<String>,
+    /// Display name of the shortcut with MelonLoader's console.
+    pub console_shortcut_name: Option<String>,
+    /// Component manifest for another game to install instead of DesktopMate, relative to the
+    /// installer. See `manifest/stable.json` and the `game` section for the fields.
+    pub game_manifest: Option<PathBuf>,
+    /// Install folder offered when none was used before, instead of the manifest's.
+    pub install_path: Option<String>,
+    /// HTTP, HTTPS, or SOCKS5 proxy for every download, e.g. `http://proxy.example:8080`.
+    /// `--proxy` overrides this. See [`crate::proxy`].
+    pub proxy: Option<String>,
+    /// Certificates (a PEM bundle or DER) to trust besides the system's, relative to the installer.
+    /// `--ca-cert` overrides this.
+    pub ca_cert: Option<PathBuf>,
+    /// The full-screen wizard (default) or the classic console prompts, like `--no-tui`.
+    pub interface: Interface,
+    /// Download mirrors (`[mirrors]` table): a URL starting with a key is also tried with the key
+    /// replaced by each of its values, in order, when the original fails.
+    pub mirrors: BTreeMap<String, Vec<String>>,
+    /// Connection settings passed to DepotDownloader (`[depot_downloader]` table).
+    pub depot_downloader: DepotDownloaderConfig,
+    /// Steam identifiers replacing the manifest's (`[game]` table).
+    pub game: GameOverrides,
+    /// Where mods come from, by component name (`[mods.<component>]` tables).
+    pub mods: HashMap<String, ModOverrides>,
+    /// Console colors by role (`[theme]` table).
+    pub theme: ThemeConfig,
+    /// Banner title and extra lines for community repacks (`[branding]` table).
+    pub branding: BrandingConfig,
+}
+
+/// The `[game]` table: Steam identifiers to use instead of the manifest's, e.g. to install a
+/// build from a depot manifest the component manifest doesn't pin yet.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct GameOverrides {
+    pub app_id: Option<String>,
+    pub depot_id: Option<String>,
+    pub manifest_id: Option<String>,
+    pub branch: Option<String>,
+    /// Download every depot file, ignoring the manifest's `game.files` filter.
+    pub all_files: bool,
+}
+
+/// The `[depot_downloader]` table: DepotDownloader's download options, for slow, flaky, or
+/// distant connections. See [`crate::depot_tool::set_options`].
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct DepotDownloaderConfig {
+    /// Content servers to use at once (`-max-servers`).
+    pub max_servers: Option<u32>,
+    /// Chunks to download at once (`-max-downloads`).
+    pub max_downloads: Option<u32>,
+    /// Steam cell (region) whose content servers to prefer (`-cellid`).
+    pub cell_id: Option<u32>,
+    /// Checksum every game file on each download, not just on `repair` (`-validate`).
+    pub validate: bool,
+}
+
+/// A `[mods.<component>]` table: the GitHub repository and asset pattern of one mod, for forks
+/// and renamed repositories.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ModOverrides {
+    pub owner: Option<String>,
+    pub repo: Option<String>,
+    pub asset: Option<String>,
+}
+
+/// The `[branding]` table. See [`crate::branding`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BrandingConfig {
+    /// Replaces the translated installer title.
+    pub title: Option<String>,
+    /// Width of the console banner in columns; widened to fit the longest line.
+    pub width: Option<usize>,
+    /// Lines shown under the title, such as a community name or a support link.
+    pub lines: Vec<String>,
+}
+
+/// The `[theme]` table: a preset, optionally with some roles recolored. See [`crate::theme`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub preset: ThemePreset,
+    pub heading: Option<String>,
+    pub info: Option<String>,
+    pub success: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Starting point for the console colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemePreset {
+    /// Cyan headings, blue progress, green, yellow, and red.
+    #[default]
+    Default,
+    /// Bright colors only, for terminal schemes where the defaults are hard to read.
+    HighContrast,
+}
+
+/// How the setup questions are asked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interface {
+    /// The full-screen wizard, where the console supports it.
+    #[default]
+    Wizard,
+    /// One question after another.
+    Console,
+}
+
+/// Which releases the installer follows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    /// Published releases only.
+    #[default]
+    Stable,
+    /// Also prereleases, for people testing new installer features early.
+    Preview,
+}
+
+/// How mod files already in the game directory are treated when an update ships them again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictPolicy {
+    /// Replace the file.
+    Overwrite,
+    /// Keep the user's file and don't install the new one.
+    Skip,
+    /// Rename the user's file to `<name>.<timestamp>.bak`, then install the new one.
+    #[default]
+    Backup,
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Stable => write!(f, "stable"),
+            Channel::Preview => write!(f, "preview"),
+        }
+    }
+}
+
+impl Config {
+    /// Loads `explicit` (from `--config`), or else `installer.toml` from `base_dir`, falling back
+    /// to defaults when the latter doesn't exist. A missing `explicit` file is an error.
+    pub fn load(base_dir: &Path, explicit: Option<&Path>) -> Result<Config, String> {
+        let path = match explicit {
+            Some(path) => path.to_path_buf(),
+            None => base_dir.join(CONFIG_FILE_NAME),
+        };
+        if explicit.is_none() && !path.exists() {
+            return Ok(Config::default());
+        }
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Applies `depot_downloader_version`, `goldberg_provider`, `goldberg_build`,
+    /// `avatar_loader_asset`, and the `[game]` and `[mods]` tables to `manifest`, then `--branch`
+    /// and `--manifest`, which win over the file. Returns a line for the log per value replaced.
+    pub fn override_manifest(&self, manifest: &mut ComponentManifest) -> Vec<String> {
+        let mut replaced = Vec::new();
+        if let Some(version) = self.depot_downloader_version.as_ref() {
+            replaced.push(format!("DepotDownloader version pinned by installer.toml: {}", version));
+            manifest.depot_downloader.version = Some(version.clone());
+        }
+        if let Some(provider) = self.goldberg_provider.filter(|provider| *provider != manifest.goldberg.provider) {
+            replaced.push(format!("Goldberg provider set by installer.toml: {:?}", provider));
+            manifest.goldberg.provider = provider;
+        }
+        if let Some(build) = self.goldberg_build.filter(|build| *build != manifest.goldberg.build) {
+            replaced.push(format!("Goldberg build set by installer.toml: {:?}", build));
+            manifest.goldberg.build = build;
+        }
+        let mut set = |field: &mut String, value: &Option<String>, what: String| {
+            if let Some(value) = value.as_ref().filter(|value| **value != *field) {
+                replaced.push(format!("{} overridden by installer.toml: {}", what, value));
+                field.clone_from(value);
+            }
+        };
+        let game = &mut manifest.game;
+        set(&mut game.app_id, &self.game.app_id, "Game app ID".to_owned());
+        set(&mut game.depot_id, &self.game.depot_id, "Game depot ID".to_owned());
+        set(&mut game.manifest_id, &self.game.manifest_id, "Game manifest ID".to_owned());
+        set(
+            &mut manifest.avatar_loader.asset,
+            &self.avatar_loader_asset,
+            "Custom Avatar Loader asset pattern".to_owned(),
+        );
+
+        let steps = manifest.steps.iter_mut().filter_map(|step| match step {
+            Step::Mod(spec) => Some(spec),
+            _ => None,
+        });
+        for spec in std::iter::once(&mut manifest.avatar_loader).chain(steps) {
+            if let Some(source) = self.mods.get(&spec.component) {
+                set(&mut spec.owner, &source.owner, format!("{} repository owner", spec.name));
+                set(&mut spec.repo, &source.repo, format!("{} repository", spec.name));
+                set(&mut spec.asset, &source.asset, format!("{} asset pattern", spec.name));
+            }
+        }
+        if self.game.all_files && !manifest.game.files.is_empty() {
+            replaced.push("Game file filter disabled by installer.toml".to_owned());
+            manifest.game.files = FileFilter::default();
+        }
+        let branch = cli::args().branch.as_ref().or(self.game.branch.as_ref());
+        if let Some(branch) = branch.filter(|branch| manifest.game.branch.as_ref() != Some(*branch)) {
+            replaced.push(format!("Game branch set to {}", branch));
+            manifest.game.branch = Some(branch.clone());
+        }
+        if let Some(id) = cli::args().manifest.as_ref().filter(|id| **id != manifest.game.manifest_id) {
+            replaced.push(format!("Game manifest ID overridden by --manifest: {}", id));
+            manifest.game.manifest_id.clone_from(id);
+        }
+        replaced
+    }
+}
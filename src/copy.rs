@@ -0,0 +1,143 @@
+// copy.rs
+//! Copying extracted mod files into the game directory.
+//!
+//! Users sometimes edit files that a mod update ships again (configs, patched DLLs), so what
+//! happens to a destination file that differs from the incoming one is governed by a
+//! [`ConflictPolicy`]. Identical files are left alone under every policy. Symlinks in the source
+//! are never followed, and a symlink or junction at the destination is replaced rather than
+//! written through.
+
+use crate::config::ConflictPolicy;
+use crate::progress::Progress;
+use crate::transaction;
+use chrono::Local;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// What a [`copy_directory`] call did, for the log.
+#[derive(Debug, Default)]
+pub struct CopyReport {
+    pub copied: usize,
+    pub unchanged: usize,
+    /// Destination files kept because they differed and the policy was `skip`.
+    pub skipped: Vec<PathBuf>,
+    /// Backups made of differing destination files before they were replaced.
+    pub backups: Vec<PathBuf>,
+    /// Source symlinks that were not copied.
+    pub links_ignored: Vec<PathBuf>,
+    /// Destination path of every source file, whatever happened to it.
+    pub files: Vec<PathBuf>,
+}
+
+/// Recursively copies a directory from source to destination.
+pub fn copy_directory(source: &Path, destination: &Path, policy: ConflictPolicy) -> io::Result<CopyReport> {
+    let (files, bytes) = measure(source)?;
+    let progress = Progress::new("copy-progress", files, bytes);
+    let mut report = CopyReport::default();
+    let result = copy_tree(source, destination, policy, &progress, &mut report);
+    progress.finish();
+    result.map(|()| report)
+}
+
+fn copy_tree(
+    source: &Path,
+    destination: &Path,
+    policy: ConflictPolicy,
+    progress: &Progress,
+    report: &mut CopyReport,
+) -> io::Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let path = entry.path();
+        let dest_path = destination.join(entry.file_name());
+        if file_type.is_symlink() {
+            report.links_ignored.push(path);
+        } else if file_type.is_dir() {
+            copy_tree(&path, &dest_path, policy, progress, report)?;
+        } else {
+            copy_file(&path, &dest_path, policy, report)?;
+            progress.add(entry.metadata()?.len());
+            report.files.push(dest_path);
+        }
+    }
+    Ok(())
+}
+
+fn copy_file(source: &Path, destination: &Path, policy: ConflictPolicy, report: &mut CopyReport) -> io::Result<()> {
+    if let Ok(existing) = fs::symlink_metadata(destination) {
+        if existing.file_type().is_symlink() {
+            // Replace the link itself; writing through it could land anywhere on disk.
+            transaction::record_write(destination)?;
+            fs::remove_dir(destination).or_else(|_| fs::remove_file(destination))?;
+        } else if same_contents(source, destination)? {
+            report.unchanged += 1;
+            return Ok(());
+        } else {
+            match policy {
+                ConflictPolicy::Overwrite => {}
+                ConflictPolicy::Skip => {
+                    report.skipped.push(destination.to_path_buf());
+                    return Ok(());
+                }
+                ConflictPolicy::Backup => {
+                    let backup = backup_path(destination);
+                    transaction::record_write(destination)?;
+                    transaction::record_write(&backup)?;
+                    fs::rename(destination, &backup)?;
+                    report.backups.push(backup);
+                }
+            }
+        }
+    }
+    transaction::record_write(destination)?;
+    fs::copy(source, destination)?;
+    report.copied += 1;
+    Ok(())
+}
+
+/// `<name>.<timestamp>.bak` next to `path`. The `.bak` suffix keeps MelonLoader from loading
+/// a backed-up DLL as a mod.
+fn backup_path(path: &Path) -> PathBuf {
+    let stamp = Local::now().format("%Y%m%d-%H%M%S");
+    PathBuf::from(format!("{}.{}.bak", path.display(), stamp))
+}
+
+fn same_contents(a: &Path, b: &Path) -> io::Result<bool> {
+    if fs::metadata(a)?.len() != fs::metadata(b)?.len() {
+        return Ok(false);
+    }
+    let (mut a, mut b) = (File::open(a)?, File::open(b)?);
+    let (mut buf_a, mut buf_b) = (vec![0u8; 64 * 1024], vec![0u8; 64 * 1024]);
+    loop {
+        let read = a.read(&mut buf_a)?;
+        if read == 0 {
+            return Ok(true);
+        }
+        b.read_exact(&mut buf_b[..read])?;
+        if buf_a[..read] != buf_b[..read] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Counts regular files and their total size under `dir`, not following symlinks.
+pub fn measure(dir: &Path) -> io::Result<(usize, u64)> {
+    let mut files = 0;
+    let mut bytes = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            let (sub_files, sub_bytes) = measure(&entry.path())?;
+            files += sub_files;
+            bytes += sub_bytes;
+        } else if file_type.is_file() {
+            files += 1;
+            bytes += entry.metadata()?.len();
+        }
+    }
+    Ok((files, bytes))
+}
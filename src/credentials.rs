@@ -0,0 +1,144 @@
+// credentials.rs
+//! Steam login read from a file, for provisioning lab machines without typing it in.
+//!
+//! `--credentials-file <path>` names a netrc-style file:
+//!
+//! ```text
+//! machine steam
+//!     login     my_steam_name
+//!     password  my_steam_password
+//! ```
+//!
+//! A `machine steam` entry is used if present, otherwise a `default` entry or bare
+//! `login`/`password` tokens. `#` starts a comment. The file is refused while its ACL lets
+//! Everyone, Users, Authenticated Users, or Guests read it, and neither the password nor the
+//! file's contents are ever logged.
+//!
+//! Scripts that can't write a file pass `--steam-user <name>` and put the password in the
+//! `DMI_STEAM_PASSWORD` environment variable instead.
+
+use std::error::Error;
+use std::fs;
+use std::os::windows::ffi::OsStrExt;
+use std::path::Path;
+use std::ptr;
+use winapi::ctypes::c_void;
+use winapi::shared::sddl::ConvertSidToStringSidW;
+use winapi::um::accctrl::SE_FILE_OBJECT;
+use winapi::um::aclapi::GetNamedSecurityInfoW;
+use winapi::um::securitybaseapi::GetAce;
+use winapi::um::winbase::LocalFree;
+use winapi::um::winnt::{
+    ACCESS_ALLOWED_ACE, ACCESS_ALLOWED_ACE_TYPE, DACL_SECURITY_INFORMATION, FILE_READ_DATA, GENERIC_ALL,
+    GENERIC_READ, PACL, PSECURITY_DESCRIPTOR,
+};
+
+/// Environment variable holding the password for `--steam-user`.
+pub const PASSWORD_VAR: &str = "DMI_STEAM_PASSWORD";
+/// Environment variable holding the password of a protected Steam branch.
+pub const BRANCH_PASSWORD_VAR: &str = "DMI_BRANCH_PASSWORD";
+
+/// Well-known groups that cover other people on the machine, by SID.
+const BROAD_GROUPS: &[(&str, &str)] = &[
+    ("S-1-1-0", "Everyone"),
+    ("S-1-5-11", "Authenticated Users"),
+    ("S-1-5-32-545", "Users"),
+    ("S-1-5-32-546", "Guests"),
+];
+
+/// Reads the Steam username and password from `path`.
+pub fn load(path: &Path) -> Result<(String, String), Box<dyn Error>> {
+    if let Some(group) = readable_by_others(path)? {
+        return Err(t!("err-credentials-permissions", path = path.display().to_string(), group = group).into());
+    }
+    let text = fs::read_to_string(path)
+        .map_err(|e| t!("err-credentials-read", path = path.display().to_string(), error = e))?;
+    parse(&text).ok_or_else(|| t!("err-credentials-incomplete", path = path.display().to_string()).into())
+}
+
+/// The login and password of the `steam` entry, else the default one.
+fn parse(text: &str) -> Option<(String, String)> {
+    let tokens: Vec<&str> = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(str::split_whitespace)
+        .collect();
+
+    // (machine, login, password); `None` for the default entry.
+    let mut entries: Vec<(Option<&str>, Option<&str>, Option<&str>)> = vec![(None, None, None)];
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match token.to_ascii_lowercase().as_str() {
+            "machine" => entries.push((Some(tokens.next()?), None, None)),
+            "default" => entries.push((None, None, None)),
+            "login" => entries.last_mut()?.1 = Some(tokens.next()?),
+            "password" => entries.last_mut()?.2 = Some(tokens.next()?),
+            _ => {}
+        }
+    }
+    let complete = |entry: &&(Option<&str>, Option<&str>, Option<&str>)| entry.1.is_some() && entry.2.is_some();
+    let entry = entries
+        .iter()
+        .filter(complete)
+        .find(|entry| entry.0.is_some_and(|machine| machine.eq_ignore_ascii_case("steam")))
+        .or_else(|| entries.iter().filter(complete).find(|entry| entry.0.is_none()))?;
+    Some((entry.1?.to_owned(), entry.2?.to_owned()))
+}
+
+/// A broad group the file's ACL lets read it, if any. A file without a DACL is open to
+/// everyone.
+fn readable_by_others(path: &Path) -> Result<Option<&'static str>, Box<dyn Error>> {
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+    unsafe {
+        let mut dacl: PACL = ptr::null_mut();
+        let mut descriptor: PSECURITY_DESCRIPTOR = ptr::null_mut();
+        let status = GetNamedSecurityInfoW(
+            wide.as_ptr(),
+            SE_FILE_OBJECT,
+            DACL_SECURITY_INFORMATION,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut dacl,
+            ptr::null_mut(),
+            &mut descriptor,
+        );
+        if status != 0 {
+            return Err(t!(
+                "err-credentials-read",
+                path = path.display().to_string(),
+                error = std::io::Error::from_raw_os_error(status as i32)
+            )
+            .into());
+        }
+        let mut found = None;
+        if dacl.is_null() {
+            found = Some(BROAD_GROUPS[0].1);
+        } else {
+            for index in 0..u32::from((*dacl).AceCount) {
+                let mut ace: *mut c_void = ptr::null_mut();
+                if GetAce(dacl, index, &mut ace) == 0 {
+                    continue;
+                }
+                let ace = &*(ace as *const ACCESS_ALLOWED_ACE);
+                if ace.Header.AceType != ACCESS_ALLOWED_ACE_TYPE
+                    || ace.Mask & (FILE_READ_DATA | GENERIC_READ | GENERIC_ALL) == 0
+                {
+                    continue;
+                }
+                let mut sid_string = ptr::null_mut();
+                if ConvertSidToStringSidW(&ace.SidStart as *const u32 as *mut c_void, &mut sid_string) == 0 {
+                    continue;
+                }
+                let len = (0..).take_while(|&i| *sid_string.add(i) != 0).count();
+                let sid = String::from_utf16_lossy(std::slice::from_raw_parts(sid_string, len));
+                LocalFree(sid_string as *mut c_void);
+                if let Some((_, group)) = BROAD_GROUPS.iter().find(|(known, _)| *known == sid) {
+                    found = Some(*group);
+                    break;
+                }
+            }
+        }
+        LocalFree(descriptor);
+        Ok(found)
+    }
+}
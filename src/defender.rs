@@ -0,0 +1,76 @@
+// defender.rs
+//! Antivirus interference with the Goldberg patch.
+//!
+//! Goldberg's `steam_api64.dll` is routinely flagged as a crack and quarantined, often a few
+//! seconds after it is written, which used to surface only as a game that wouldn't start. The
+//! patch is therefore checked again shortly after it is applied and at the start of every later
+//! run (against the hash recorded in `install.json` as the `Goldberg` component). When it has
+//! vanished or changed, the user is told their antivirus most likely took it and pointed at
+//! `--defender-exclusion`, which excludes the game folder from Microsoft Defender.
+
+use crate::components;
+use crate::game;
+use crate::state::InstallState;
+use crate::transaction::sha256_file;
+use base64::Engine;
+use std::error::Error;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+/// Name of the patch's entry in the install state; its version is the DLL's SHA-256.
+pub const COMPONENT: &str = "Goldberg";
+
+/// How long an antivirus gets to react before the patch is checked again.
+const RECHECK_DELAY: Duration = Duration::from_secs(5);
+
+/// Runs the elevated command passed in `DMI_EXCLUSION_COMMAND` and exits with its exit code.
+/// Declining the UAC prompt makes `Start-Process` throw, which exits with 1.
+const ELEVATE_SCRIPT: &str = r#"
+$Process = Start-Process powershell -Verb RunAs -Wait -PassThru -WindowStyle Hidden -ArgumentList '-NoProfile', '-EncodedCommand', $env:DMI_EXCLUSION_COMMAND;
+exit $Process.ExitCode
+"#;
+
+/// Records that the patch in `root` is the DLL with hash `sha256`.
+pub fn record_patch(root: &Path, sha256: &str) -> io::Result<()> {
+    let files: Vec<String> = components::relative_name(root, &game::steam_api(root)).into_iter().collect();
+    components::save(root, COMPONENT, sha256, &files)
+}
+
+/// Whether the patch in `root` is still the DLL with hash `sha256`.
+pub fn patch_intact(root: &Path, sha256: &str) -> bool {
+    sha256_file(&game::steam_api(root)).is_ok_and(|actual| actual.eq_ignore_ascii_case(sha256))
+}
+
+/// Waits for an antivirus to react to the freshly written patch, then checks it is still there.
+pub async fn recheck_patch(root: &Path, sha256: &str) -> bool {
+    tokio::time::sleep(RECHECK_DELAY).await;
+    patch_intact(root, sha256)
+}
+
+/// True when an earlier run recorded a patch in `root` that has since vanished or changed.
+pub fn patch_lost_since_last_run(root: &Path) -> bool {
+    let Ok(state) = InstallState::load(root) else {
+        return false;
+    };
+    match state.version(COMPONENT) {
+        Some(sha256) if !sha256.is_empty() => !patch_intact(root, sha256),
+        _ => false,
+    }
+}
+
+/// Excludes `path` from Microsoft Defender scans, asking for elevation through UAC.
+pub fn add_exclusion(path: &Path) -> Result<(), Box<dyn Error>> {
+    let command = format!("Add-MpPreference -ExclusionPath '{}'", path.display().to_string().replace('\'', "''"));
+    // -EncodedCommand takes base64 of UTF-16LE, which sidesteps quoting the path twice.
+    let utf16: Vec<u8> = command.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let status = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", ELEVATE_SCRIPT])
+        .env("DMI_EXCLUSION_COMMAND", base64::engine::general_purpose::STANDARD.encode(utf16))
+        .status()?;
+    if !status.success() {
+        return Err(t!("err-defender-exclusion", code = status.code().unwrap_or(-1)).into());
+    }
+    Ok(())
+}
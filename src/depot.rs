@@ -0,0 +1,94 @@
+// depot.rs
+//! Making sense of DepotDownloader's output.
+//!
+//! DepotDownloader exits with code 1 for nearly everything, so the exit code alone says nothing
+//! useful. Its output is scanned for the few failures users can do something about, and those
+//! are explained in plain language instead.
+//!
+//! The Steam password isn't passed as `-password`, where any process listing would show it.
+//! DepotDownloader asks for it instead, and its questions (the password, a Steam Guard code) are
+//! printed without a line break; [`next_output`] picks them out so the installer can answer.
+
+use std::fmt;
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// How long output ending in `:` has to stay unfinished to count as a question.
+const PROMPT_IDLE: Duration = Duration::from_millis(300);
+
+/// A recognised reason DepotDownloader failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagnosis {
+    /// The account has no license for DesktopMate or its depot.
+    NotOwned,
+    /// Steam refused the username, password, or Steam Guard code.
+    LoginRejected,
+}
+
+/// Output fragments and what they mean, matched case-insensitively.
+const PATTERNS: &[(&str, Diagnosis)] = &[
+    ("is not available from this account", Diagnosis::NotOwned),
+    ("no valid depot key", Diagnosis::NotOwned),
+    ("no subscription", Diagnosis::NotOwned),
+    ("accessdenied", Diagnosis::NotOwned),
+    ("invalidpassword", Diagnosis::LoginRejected),
+    ("invalidloginauthcode", Diagnosis::LoginRejected),
+    ("twofactorcodemismatch", Diagnosis::LoginRejected),
+    ("unable to login to steam", Diagnosis::LoginRejected),
+];
+
+/// A piece of DepotDownloader output.
+pub enum Output {
+    Line(String),
+    /// A question, printed without a line break, that DepotDownloader waits to have answered.
+    Prompt(String),
+}
+
+/// Reads the next line or question from `reader`. `pending` keeps what was read beyond it for
+/// the next call. `None` means the output has ended.
+pub async fn next_output<R: AsyncRead + Unpin>(reader: &mut R, pending: &mut Vec<u8>) -> io::Result<Option<Output>> {
+    let mut chunk = [0; 4096];
+    loop {
+        if let Some(end) = pending.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = pending.drain(..=end).collect();
+            return Ok(Some(Output::Line(text(&line))));
+        }
+        let read = if text(pending).ends_with(':') {
+            match tokio::time::timeout(PROMPT_IDLE, reader.read(&mut chunk)).await {
+                Ok(read) => read?,
+                Err(_) => return Ok(Some(Output::Prompt(text(&std::mem::take(pending))))),
+            }
+        } else {
+            reader.read(&mut chunk).await?
+        };
+        if read == 0 {
+            return Ok((!pending.is_empty()).then(|| Output::Line(text(&std::mem::take(pending)))));
+        }
+        pending.extend_from_slice(&chunk[..read]);
+    }
+}
+
+/// Whether a question DepotDownloader asks is for the account password.
+pub fn is_password_prompt(prompt: &str) -> bool {
+    prompt.to_lowercase().contains("password")
+}
+
+fn text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).trim_end().to_owned()
+}
+
+/// What a line of DepotDownloader output says went wrong, if it is a failure we recognise.
+pub fn diagnose(line: &str) -> Option<Diagnosis> {
+    let line = line.to_lowercase();
+    PATTERNS.iter().find(|(pattern, _)| line.contains(pattern)).map(|(_, diagnosis)| *diagnosis)
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnosis::NotOwned => write!(f, "{}", t!("depot-not-owned")),
+            Diagnosis::LoginRejected => write!(f, "{}", t!("depot-login-rejected")),
+        }
+    }
+}
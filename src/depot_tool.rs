@@ -0,0 +1,81 @@
+// depot_tool.rs
+//! Which release of DepotDownloader sits next to the installer, and which one should.
+//!
+//! The release tag is written to `DepotDownloader\.dmi-version` when the tool is extracted.
+//! Without a pinned version, each run compares it with SteamRE's latest release and offers the
+//! upgrade; a copy extracted by an older installer has no recorded version and is offered one
+//! too. With `depot_downloader.version` in the manifest (or `depot_downloader_version` in
+//! `installer.toml`) that release is downloaded instead of the latest, and a different one
+//! already there is replaced without asking, so installs are reproducible. Either way the
+//! release is downloaded from its own tag's URL once known, so the download cache keeps it.
+//!
+//! How DepotDownloader downloads (how many servers and chunks at once, which Steam cell, whether
+//! to checksum every file) comes from the `[depot_downloader]` table of `installer.toml` and the
+//! matching flags; see [`set_options`].
+
+use crate::assets::AssetPattern;
+use crate::config::DepotDownloaderConfig;
+use crate::github::{get_latest_release, LookupError};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+const OWNER: &str = "SteamRE";
+const REPO: &str = "DepotDownloader";
+const ASSET: &str = "DepotDownloader-windows-x64.zip";
+/// Release tags look like `DepotDownloader_3.4.0`.
+const TAG_PREFIX: &str = "DepotDownloader_";
+const VERSION_FILE: &str = ".dmi-version";
+
+static OPTIONS: Mutex<DepotDownloaderConfig> =
+    Mutex::new(DepotDownloaderConfig { max_servers: None, max_downloads: None, cell_id: None, validate: false });
+
+/// Sets the download options passed to DepotDownloader from now on.
+pub fn set_options(options: DepotDownloaderConfig) {
+    *OPTIONS.lock().unwrap_or_else(|e| e.into_inner()) = options;
+}
+
+/// DepotDownloader arguments for the download options set; none when they are all left alone.
+pub fn option_args() -> Vec<String> {
+    let options = *OPTIONS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut args = Vec::new();
+    for (flag, value) in [
+        ("-max-servers", options.max_servers),
+        ("-max-downloads", options.max_downloads),
+        ("-cellid", options.cell_id),
+    ] {
+        if let Some(value) = value {
+            args.extend([flag.to_owned(), value.to_string()]);
+        }
+    }
+    if options.validate {
+        args.push("-validate".to_owned());
+    }
+    args
+}
+
+/// The release extracted in `dir`, if it was recorded.
+pub fn installed_version(dir: &Path) -> Option<String> {
+    let version = fs::read_to_string(dir.join(VERSION_FILE)).ok()?;
+    Some(version.trim().to_owned()).filter(|version| !version.is_empty())
+}
+
+/// Records that `version` was just extracted into `dir`.
+pub fn record_version(dir: &Path, version: &str) -> io::Result<()> {
+    fs::write(dir.join(VERSION_FILE), version)
+}
+
+/// The version of SteamRE's latest release.
+pub async fn latest_version(log_file: &Path) -> Result<String, LookupError> {
+    let pattern = AssetPattern::new(ASSET).expect("the DepotDownloader asset pattern is valid");
+    let release = get_latest_release(OWNER, REPO, &pattern, log_file).await?;
+    Ok(release.tag_name.strip_prefix(TAG_PREFIX).unwrap_or(&release.tag_name).to_owned())
+}
+
+/// `url` pointing at release `version` instead of the latest one. URLs that don't follow
+/// GitHub's `releases/latest/download/` layout are left as they are.
+pub fn url_for_version(url: &str, version: &str) -> String {
+    let tag = format!("releases/download/{}{}/", TAG_PREFIX, version.strip_prefix(TAG_PREFIX).unwrap_or(version));
+    url.replacen("releases/latest/download/", &tag, 1)
+}
@@ -0,0 +1,104 @@
+// dotnet.rs
+//! Checking that DepotDownloader's .NET runtime is installed before starting it.
+//!
+//! The `windows-x64` DepotDownloader build carries its own runtime, but the framework-dependent
+//! build (from a mirror or a kit, say) needs a shared .NET runtime, and without it exits with a
+//! message about `hostfxr` that explains nothing. Such a build has a
+//! `DepotDownloader.runtimeconfig.json` naming the frameworks it needs. They are looked up in
+//! `%ProgramFiles%\dotnet\shared` (or `DOTNET_ROOT`), accepting, like .NET itself does by default,
+//! the same major version with an equal or later minor one.
+//!
+//! When one is missing, the installer offers to download Microsoft's runtime installer and run
+//! it; the download must carry a valid Authenticode signature.
+
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A shared framework a program needs, as its `runtimeconfig.json` names it.
+#[derive(Debug, Clone)]
+pub struct Framework {
+    /// E.g. `Microsoft.NETCore.App`.
+    pub name: String,
+    /// Lowest acceptable version, e.g. `8.0.0`.
+    pub version: String,
+}
+
+impl Framework {
+    /// Microsoft's download of the installer for this framework's runtime, if it is one of the
+    /// standard ones.
+    pub fn installer_url(&self) -> Option<String> {
+        let file = match self.name.as_str() {
+            "Microsoft.NETCore.App" => "dotnet-runtime-win-x64.exe",
+            "Microsoft.WindowsDesktop.App" => "windowsdesktop-runtime-win-x64.exe",
+            "Microsoft.AspNetCore.App" => "aspnetcore-runtime-win-x64.exe",
+            _ => return None,
+        };
+        let (major, minor) = major_minor(&self.version)?;
+        Some(format!("https://aka.ms/dotnet/{}.{}/{}", major, minor, file))
+    }
+}
+
+/// The frameworks the program in `dir` needs that aren't installed. A self-contained program
+/// (without a `runtimeconfig.json`) needs none.
+pub fn missing_frameworks(dir: &Path) -> Vec<Framework> {
+    required(dir).into_iter().filter(|framework| !is_installed(framework)).collect()
+}
+
+fn required(dir: &Path) -> Vec<Framework> {
+    let Some(config) = fs::read_dir(dir).ok().and_then(|entries| {
+        entries
+            .flatten()
+            .map(|entry| entry.path())
+            .find(|path| path.to_string_lossy().to_lowercase().ends_with(".runtimeconfig.json"))
+    }) else {
+        return Vec::new();
+    };
+    let Some(value) = fs::read_to_string(config).ok().and_then(|text| serde_json::from_str::<Value>(&text).ok())
+    else {
+        return Vec::new();
+    };
+    let options = &value["runtimeOptions"];
+    let frameworks = match &options["frameworks"] {
+        Value::Array(frameworks) => frameworks.clone(),
+        _ => vec![options["framework"].clone()],
+    };
+    frameworks
+        .iter()
+        .filter_map(|framework| {
+            Some(Framework {
+                name: framework["name"].as_str()?.to_owned(),
+                version: framework["version"].as_str()?.to_owned(),
+            })
+        })
+        .collect()
+}
+
+fn is_installed(framework: &Framework) -> bool {
+    let Some(wanted) = major_minor(&framework.version) else {
+        return true;
+    };
+    roots().iter().any(|root| {
+        let Ok(entries) = fs::read_dir(root.join("shared").join(&framework.name)) else {
+            return false;
+        };
+        entries.flatten().any(|entry| {
+            major_minor(&entry.file_name().to_string_lossy())
+                .is_some_and(|(major, minor)| major == wanted.0 && minor >= wanted.1)
+        })
+    })
+}
+
+/// Folders a .NET installation may live in.
+fn roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    roots.extend(env::var_os("DOTNET_ROOT").map(PathBuf::from));
+    roots.extend(env::var_os("ProgramFiles").map(|dir| PathBuf::from(dir).join("dotnet")));
+    roots
+}
+
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split(['.', '-']);
+    Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+}
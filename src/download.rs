@@ -0,0 +1,204 @@
+// download.rs
+//! HTTP downloads, streamed to disk and resumable.
+//!
+//! The body is written chunk by chunk to a partial file in `%TEMP%\dmi_partial`, named after the
+//! URL, and only moved to its destination once complete, so a large archive never sits in memory
+//! whole. When the connection drops, the partial file stays, and the next attempt at the same URL
+//! (a retried step, or the next run) asks for just the rest with a `Range` request.
+//!
+//! The `ETag` (or `Last-Modified`) of the first response goes along as `If-Range`, so a server
+//! whose file changed in between sends the whole new file instead. So does one that doesn't
+//! support ranges; either way the download starts over without a separate request. A resumed
+//! request asks for the body uncompressed, since the partial file holds the file's own bytes.
+//!
+//! Two runs downloading the same URL at once would write the same partial file. The one that
+//! starts first holds `<hash>.lock` next to it, opened without sharing, for as long as it writes;
+//! the other downloads to a partial file of its own instead.
+//!
+//! Transient failures are retried with [`crate::retry`]'s backoff, each retry resuming where the
+//! last attempt stopped. When a URL still fails (or is simply gone), its mirrors are tried in
+//! turn: the ones the manifest lists for that asset and the URL prefixes `installer.toml` maps to
+//! other hosts.
+//!
+//! Finished downloads are also kept in the [`crate::cache`], which later downloads of the same
+//! URL are copied from.
+
+use crate::cache;
+use crate::kit;
+use crate::proxy;
+use crate::retry::{self, Transient};
+use crate::{color_echo, ConsoleColor};
+use reqwest::header::{HeaderMap, ACCEPT_ENCODING, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+use reqwest::{Response, StatusCode};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::os::windows::fs::OpenOptionsExt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use tokio::io::AsyncWriteExt;
+use winapi::shared::winerror::ERROR_SHARING_VIOLATION;
+
+const PARTIAL_DIR: &str = "dmi_partial";
+
+/// URL prefixes and what else to try in their place, in the order they were added.
+static MIRRORS: Mutex<Vec<(String, Vec<String>)>> = Mutex::new(Vec::new());
+
+/// Makes a failed download from a URL starting with `prefix` try it again with the prefix
+/// replaced by each of `mirrors`. A whole URL is a prefix of itself.
+pub fn add_mirrors(prefix: &str, mirrors: &[String]) {
+    if !mirrors.is_empty() {
+        MIRRORS.lock().unwrap_or_else(|e| e.into_inner()).push((prefix.to_owned(), mirrors.to_vec()));
+    }
+}
+
+/// Downloads a file from the given URL and writes it to the specified path. A body shorter or
+/// longer than the server's `Content-Length` is an error.
+pub async fn download_file(url: &str, output_path: &Path) -> Result<(), Box<dyn Error>> {
+    if let Some(local) = kit::local_copy(url) {
+        fs::copy(local?, output_path)?;
+        return Ok(());
+    }
+    if cache::restore(url, output_path) {
+        return Ok(());
+    }
+    let mut result = download_with_retries(url, output_path).await;
+    for mirror in mirrors_for(url) {
+        let Err(ex) = &result else { break };
+        let message = t!("download-trying-mirror", error = ex.to_string(), url = mirror.as_str());
+        color_echo(ConsoleColor::Yellow, &message);
+        result = download_with_retries(&mirror, output_path).await;
+    }
+    if result.is_ok() {
+        // The cache only saves time; failing to fill it isn't worth failing the download.
+        let _ = cache::store(url, output_path);
+    }
+    result
+}
+
+async fn download_with_retries(url: &str, output_path: &Path) -> Result<(), Box<dyn Error>> {
+    retry::with_backoff(url, || fetch(url, output_path), |ex| retry::is_transient(ex.as_ref())).await
+}
+
+/// The mirrors of `url`, in the order they are tried.
+fn mirrors_for(url: &str) -> Vec<String> {
+    let mirrors = MIRRORS.lock().unwrap_or_else(|e| e.into_inner());
+    mirrors
+        .iter()
+        .filter_map(|(prefix, mirrors)| Some((url.strip_prefix(prefix.as_str())?, mirrors)))
+        .flat_map(|(rest, mirrors)| mirrors.iter().map(move |mirror| format!("{}{}", mirror, rest)))
+        .collect()
+}
+
+/// One attempt at downloading `url`, resuming a partial file an earlier one left.
+async fn fetch(url: &str, output_path: &Path) -> Result<(), Box<dyn Error>> {
+    // Held until the download is done with the partial file.
+    let (partial, _lock) = partial_path(url)?;
+    let validator_path = partial.with_extension("validator");
+    let mut resume_from = fs::metadata(&partial).map_or(0, |meta| meta.len());
+    // Without a validator there's no telling whether the partial file is still the same file.
+    let validator = fs::read_to_string(&validator_path).ok().filter(|_| resume_from > 0);
+
+    let mut resp = request(url, resume_from, validator.as_deref()).await?;
+    if resp.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        // The partial file is as long as the file or longer, so it can't be a prefix of it.
+        resume_from = 0;
+        resp = request(url, 0, None).await?;
+    }
+    if !resp.status().is_success() {
+        let message = t!("err-http", status = resp.status());
+        if retry::is_transient_status(resp.status()) {
+            return Err(Box::new(Transient(message)));
+        }
+        return Err(message.into());
+    }
+
+    let resuming = resume_from > 0 && resp.status() == StatusCode::PARTIAL_CONTENT;
+    let mut file = if resuming {
+        tokio::fs::OpenOptions::new().append(true).open(&partial).await?
+    } else {
+        resume_from = 0;
+        match validator_of(resp.headers()) {
+            Some(validator) => fs::write(&validator_path, validator)?,
+            None => remove_if_present(&validator_path)?,
+        }
+        tokio::fs::File::create(&partial).await?
+    };
+    let expected = resp.content_length().map(|length| resume_from + length);
+    let mut received = resume_from;
+    // A dropped connection fails here and leaves the partial file for the next attempt.
+    while let Some(chunk) = resp.chunk().await? {
+        file.write_all(&chunk).await?;
+        received += chunk.len() as u64;
+    }
+    file.flush().await?;
+    drop(file);
+
+    if let Some(expected) = expected.filter(|expected| *expected != received) {
+        // A short body can still be resumed; a long one means the partial file was wrong.
+        if received > expected {
+            remove_if_present(&partial)?;
+            remove_if_present(&validator_path)?;
+        }
+        let message = t!("err-download-incomplete", received = received, expected = expected);
+        return Err(Box::new(Transient(message)));
+    }
+    // `%TEMP%` may be on another volume than the destination.
+    if fs::rename(&partial, output_path).is_err() {
+        fs::copy(&partial, output_path)?;
+        fs::remove_file(&partial)?;
+    }
+    remove_if_present(&validator_path)?;
+    Ok(())
+}
+
+async fn request(url: &str, resume_from: u64, validator: Option<&str>) -> Result<Response, reqwest::Error> {
+    let client = proxy::client().build()?;
+    let mut request = client.get(url);
+    if let Some(validator) = validator {
+        request = request
+            .header(RANGE, format!("bytes={}-", resume_from))
+            .header(IF_RANGE, validator.trim())
+            // A range of the compressed body isn't a range of the file.
+            .header(ACCEPT_ENCODING, "identity");
+    }
+    request.send().await
+}
+
+/// What identifies this version of the file to `If-Range`.
+fn validator_of(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(ETAG).or_else(|| headers.get(LAST_MODIFIED))?;
+    // A weak ETag can't be used with `If-Range`.
+    value.to_str().ok().filter(|value| !value.starts_with("W/")).map(str::to_owned)
+}
+
+/// `%TEMP%\dmi_partial\<hash of url>.part`, creating the folder, and its lock. While another run
+/// holds the lock, `<hash>-<process id>.part` and no lock.
+fn partial_path(url: &str) -> io::Result<(PathBuf, Option<fs::File>)> {
+    let dir = env::temp_dir().join(PARTIAL_DIR);
+    fs::create_dir_all(&dir)?;
+    let hash: String = Sha256::digest(url.as_bytes()).iter().take(16).map(|b| format!("{:02x}", b)).collect();
+    let lock = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .share_mode(0)
+        .open(dir.join(format!("{}.lock", hash)));
+    match lock {
+        Ok(lock) => Ok((dir.join(format!("{}.part", hash)), Some(lock))),
+        Err(ex) if ex.raw_os_error() == Some(ERROR_SHARING_VIOLATION as i32) => {
+            Ok((dir.join(format!("{}-{}.part", hash, process::id())), None))
+        }
+        Err(ex) => Err(ex),
+    }
+}
+
+fn remove_if_present(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(ex) if ex.kind() != io::ErrorKind::NotFound => Err(ex),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,100 @@
+// estimate.rs
+//! How much a run is about to download, shown before anything starts.
+//!
+//! The game depot's size comes from `game.download_size` in the component manifest (Steam only
+//! reports it after logging in). The other archives are sized by a HEAD request, or from the
+//! GitHub release for Custom Avatar Loader. Anything whose size can't be learned is listed as
+//! unknown rather than counted as zero.
+
+use crate::assets::AssetPattern;
+use crate::github::get_latest_release;
+use crate::manifest::ComponentManifest;
+use crate::proxy;
+use reqwest::header::CONTENT_LENGTH;
+use reqwest::Client;
+use std::path::Path;
+use std::time::Duration;
+
+/// The component that is the game itself, which is only downloaded into an empty folder.
+pub const GAME: &str = "DesktopMate";
+
+#[derive(Debug, Clone, Default)]
+pub struct Estimate {
+    /// Each download and its size in bytes, if known.
+    pub parts: Vec<(&'static str, Option<u64>)>,
+}
+
+impl Estimate {
+    /// Bytes of the parts with a known size, and the names of the rest. The game is left out
+    /// unless `with_game`.
+    pub fn total(&self, with_game: bool) -> (u64, Vec<&'static str>) {
+        let mut bytes = 0;
+        let mut unknown = Vec::new();
+        for (name, size) in self.parts.iter().filter(|(name, _)| with_game || *name != GAME) {
+            match size {
+                Some(size) => bytes += size,
+                None => unknown.push(*name),
+            }
+        }
+        (bytes, unknown)
+    }
+
+    /// The total in MB, plus what it doesn't include.
+    pub fn size_text(&self, with_game: bool) -> String {
+        let (bytes, unknown) = self.total(with_game);
+        let mut text = t!("download-size", mb = format!("{:.0}", bytes as f64 / 1_048_576.0));
+        if !unknown.is_empty() {
+            text.push(' ');
+            text.push_str(&t!("download-size-unknown", items = unknown.join(", ")));
+        }
+        text
+    }
+
+    /// One line for the console.
+    pub fn describe(&self, with_game: bool) -> String {
+        t!("download-estimate", size = self.size_text(with_game))
+    }
+}
+
+/// Sizes everything a run might download. DepotDownloader only counts when it isn't already next
+/// to the installer.
+pub async fn estimate(base_dir: &Path, manifest: &ComponentManifest, log_file: &Path) -> Estimate {
+    let client = proxy::client()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .ok();
+    let head = |url: String| {
+        let client = client.clone();
+        async move { content_length(client?, &url).await }
+    };
+    let avatar_loader = async {
+        let pattern = AssetPattern::new(&manifest.avatar_loader.asset).ok()?;
+        let release = get_latest_release(&manifest.avatar_loader.owner, &manifest.avatar_loader.repo, &pattern, log_file)
+            .await
+            .ok()?;
+        release.download_size
+    };
+    let (depot_downloader, goldberg, melonloader, avatar_loader) = tokio::join!(
+        head(manifest.depot_downloader.url.clone()),
+        head(manifest.goldberg.url.clone()),
+        head(manifest.melonloader.url.clone()),
+        avatar_loader,
+    );
+
+    let mut parts = vec![(GAME, manifest.game.download_size)];
+    if !base_dir.join("DepotDownloader").join("DepotDownloader.exe").exists() {
+        parts.push(("DepotDownloader", depot_downloader));
+    }
+    parts.push(("Goldberg", goldberg));
+    parts.push(("MelonLoader", melonloader));
+    parts.push(("Custom Avatar Loader", avatar_loader));
+    Estimate { parts }
+}
+
+async fn content_length(client: Client, url: &str) -> Option<u64> {
+    let resp = client.head(url).send().await.ok()?;
+    if !resp.status().is_success() {
+        return None;
+    }
+    resp.headers().get(CONTENT_LENGTH)?.to_str().ok()?.parse().ok().filter(|&size| size > 0)
+}
@@ -0,0 +1,130 @@
+// events.rs
+//! Structured copy of the log for tools: `DesktopMate_Install.jsonl` beside the text log.
+//!
+//! Every line is one JSON object:
+//!
+//! ```text
+//! {"timestamp":"2025-02-14T10:31:07.120+01:00","run":"20250214T103102-4812","level":"error",
+//!  "event":"message","step":"goldberg","details":{"message":"ERROR: ..."}}
+//! ```
+//!
+//! `run` is the same for every line one installer process writes, so a run can be picked out
+//! of the ever-growing file. `step` is the install step running at the time (`null` outside the
+//! install). Each text log line is mirrored as a `message` event whose level is read from its
+//! `ERROR:` / `WARNING:` prefix; `run-started`, `step-started`, and `run-finished` mark the
+//! structure around them. Writing this file is best effort and never fails the text log.
+
+use crate::redact;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Mutex, OnceLock};
+
+static RUN: OnceLock<String> = OnceLock::new();
+static STEP: Mutex<Option<String>> = Mutex::new(None);
+
+/// Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Serialize)]
+struct Event<'a> {
+    timestamp: String,
+    run: &'a str,
+    level: Level,
+    event: &'a str,
+    step: Option<String>,
+    details: Value,
+}
+
+/// One line of the structured log, as read back.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Entry {
+    pub timestamp: String,
+    pub run: String,
+    pub level: Level,
+    pub event: String,
+    pub step: Option<String>,
+    pub details: Value,
+}
+
+/// The structured log that goes with the text log at `log_file`.
+pub fn path_for(log_file: &Path) -> PathBuf {
+    log_file.with_extension("jsonl")
+}
+
+/// Identifies this installer process in every event.
+pub fn run_id() -> &'static str {
+    RUN.get_or_init(|| format!("{}-{}", Local::now().format("%Y%m%dT%H%M%S"), process::id()))
+}
+
+/// Mirrors a text log line, taking its level from the `ERROR:` / `WARNING:` prefix.
+pub fn message(log_file: &Path, message: &str) {
+    let level = if message.starts_with("ERROR") {
+        Level::Error
+    } else if message.starts_with("WARNING") {
+        Level::Warning
+    } else {
+        Level::Info
+    };
+    let _ = record(log_file, level, "message", json!({ "message": message }));
+}
+
+/// Records the start of a run with the installer version and command line.
+pub fn run_started(log_file: &Path) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let details = json!({ "version": env!("CARGO_PKG_VERSION"), "args": args });
+    let _ = record(log_file, Level::Info, "run-started", details);
+}
+
+/// Records that install step `id` is now running; later events carry it as their `step`.
+pub fn begin_step(log_file: &Path, id: &str) {
+    *lock() = Some(id.to_owned());
+    let _ = record(log_file, Level::Info, "step-started", json!({}));
+}
+
+/// Records how the install ended; `error` is `None` on success.
+pub fn run_finished(log_file: &Path, error: Option<&str>) {
+    let (level, details) = match error {
+        None => (Level::Info, json!({ "outcome": "success" })),
+        Some(error) => (Level::Error, json!({ "outcome": "failed", "error": redact::scrub(error) })),
+    };
+    let _ = record(log_file, level, "run-finished", details);
+    *lock() = None;
+}
+
+/// Every readable entry of the structured log beside `log_file`, oldest first. Lines that don't
+/// parse (say, cut off by a crash) are skipped.
+pub fn read(log_file: &Path) -> io::Result<Vec<Entry>> {
+    let text = fs::read_to_string(path_for(log_file))?;
+    Ok(text.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+/// Appends one event to the structured log beside `log_file`.
+fn record(log_file: &Path, level: Level, event: &str, details: Value) -> io::Result<()> {
+    let event = Event {
+        timestamp: Local::now().to_rfc3339(),
+        run: run_id(),
+        level,
+        event,
+        step: lock().clone(),
+        details,
+    };
+    let line = serde_json::to_string(&event).map_err(io::Error::other)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path_for(log_file))?;
+    writeln!(file, "{}", line)
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<String>> {
+    STEP.lock().unwrap_or_else(|e| e.into_inner())
+}
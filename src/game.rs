@@ -0,0 +1,59 @@
+// game.rs
+//! The game this run installs, and where its files live.
+//!
+//! Everything game-specific (Steam ids, executable, data folder, where Goldberg's DLL goes, the
+//! default install folder) comes from the `game` section of the component manifest, which
+//! defaults to DesktopMate. Pointing `game_manifest` in `installer.toml` at another manifest
+//! lets the same installer set up a different MelonLoader-moddable Unity game.
+//!
+//! The definition is set once the manifest is known; code that only has an install folder reads
+//! it through the functions here instead of taking the manifest along.
+
+use crate::config::Channel;
+use crate::manifest::{self, GameSpec};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+static GAME: Mutex<Option<GameSpec>> = Mutex::new(None);
+
+/// Makes `spec` the game this run installs.
+pub fn set(spec: &GameSpec) {
+    *GAME.lock().unwrap_or_else(|e| e.into_inner()) = Some(spec.clone());
+}
+
+/// The current game definition; DesktopMate's until [`set`] is called.
+pub fn current() -> GameSpec {
+    GAME.lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_else(|| manifest::bundled(Channel::Stable).game)
+}
+
+pub fn name() -> String {
+    current().name
+}
+
+/// The game's executable in the install at `root`.
+pub fn executable(root: &Path) -> PathBuf {
+    root.join(current().executable)
+}
+
+/// Unity's data folder in the install at `root`.
+pub fn data_dir(root: &Path) -> PathBuf {
+    root.join(current().data_dir)
+}
+
+/// Whether the game's files are present in `root`.
+pub fn is_installed(root: &Path) -> bool {
+    data_dir(root).exists()
+}
+
+/// Where Goldberg's `steam_api64.dll` goes in the install at `root`.
+pub fn steam_api(root: &Path) -> PathBuf {
+    relative(root, &current().steam_api)
+}
+
+/// `path` (`/`-separated, relative to `root`) as a path under `root`.
+pub fn relative(root: &Path, path: &str) -> PathBuf {
+    path.split('/').fold(root.to_path_buf(), |path, part| path.join(part))
+}
@@ -0,0 +1,71 @@
+// game_build.rs
+//! Which Steam build of DesktopMate a folder holds, and whether a newer one is available.
+//!
+//! The depot manifest DepotDownloader fetched is recorded in `install.json` as the `DesktopMate`
+//! component. The build to compare against is the one the component manifest pins rather than
+//! whatever Steam currently serves, since the manifest only moves once Goldberg and MelonLoader
+//! are known to work with the new build. Folders set up before builds were recorded have no
+//! known build and are never reported as outdated.
+//!
+//! The Steam branch (`beta`, say) is recorded alongside, so later runs and `update` stay on it
+//! until a different one is asked for; switching branches counts as an update.
+//!
+//! A manifest (or `--manifest`) asking for the `latest` build records just that: which build
+//! Steam served isn't known without logging in, so such an install is only reported as outdated
+//! after switching back to a pinned build. `repair` brings it up to Steam's current build.
+
+use crate::game;
+use crate::manifest::GameSpec;
+use crate::state::InstallState;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+/// Name of the game's entry in the install state.
+pub const COMPONENT: &str = "DesktopMate";
+/// Steam's default branch, the one installs use without `-beta`.
+pub const PUBLIC_BRANCH: &str = "public";
+
+/// The depot manifest id of the game installed in `root`, if known.
+pub fn installed(root: &Path) -> Option<String> {
+    let state = InstallState::load(root).ok()?;
+    state.version(COMPONENT).filter(|build| !build.is_empty()).map(str::to_owned)
+}
+
+/// The Steam branch the game in `root` was downloaded from; `None` for the public one.
+pub fn installed_branch(root: &Path) -> Option<String> {
+    let state = InstallState::load(root).ok()?;
+    state.component(COMPONENT)?.branch.clone()
+}
+
+/// The branch to download into `root`: the one `game` asks for, or else the one installed there.
+/// `None` means the public branch.
+pub fn branch(root: &Path, game: &GameSpec) -> Option<String> {
+    game.branch.clone().or_else(|| installed_branch(root)).filter(|branch| !is_public(branch))
+}
+
+/// Records that the game in `root` is now depot manifest `build` from `branch`.
+pub fn record(root: &Path, build: &str, branch: Option<&str>) -> io::Result<()> {
+    let mut state = InstallState::load(root)?;
+    state.set_component(COMPONENT, build, Vec::new(), BTreeMap::new());
+    state.set_branch(COMPONENT, branch);
+    state.save(root)
+}
+
+/// The installed build, if the game in `root` is present and differs from the build `game`
+/// pins, or comes from another branch than the one it asks for.
+pub fn update_available(root: &Path, game: &GameSpec) -> Option<String> {
+    if !game::is_installed(root) {
+        return None;
+    }
+    let installed_branch = installed_branch(root).filter(|branch| !is_public(branch));
+    let other_branch = game
+        .branch
+        .as_ref()
+        .is_some_and(|wanted| Some(wanted.as_str()).filter(|wanted| !is_public(wanted)) != installed_branch.as_deref());
+    installed(root).filter(|build| *build != game.manifest_id || other_branch)
+}
+
+fn is_public(branch: &str) -> bool {
+    branch.eq_ignore_ascii_case(PUBLIC_BRANCH)
+}
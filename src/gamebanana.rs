@@ -0,0 +1,115 @@
+// gamebanana.rs
+//! Mods published on GameBanana, where several DesktopMate mods and skins are only hosted.
+//!
+//! `mod install` takes a GameBanana mod page (`https://gamebanana.com/mods/<id>`) or just its
+//! ID. The mod's profile (`/apiv11/Mod/<id>/ProfilePage`) lists its files; the newest one whose
+//! name matches the mod's asset pattern is downloaded and installed like any other mod's
+//! archive. The mod is registered with `"source": "gamebanana"`, the submitter as `owner` and
+//! the ID as `repo`, so later runs update it from its newest file. The version is the one the
+//! page states, or the file's name when it states none.
+
+use crate::assets::{self, AssetPattern};
+use crate::github::{LookupError, ReleaseInfo};
+use crate::manifest::{ModSource, ModSpec};
+use crate::proxy;
+use crate::retry;
+use serde::Deserialize;
+
+const HOST: &str = "https://gamebanana.com";
+
+/// A mod's GameBanana profile, as much of it as the installer uses.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    #[serde(rename = "_idRow")]
+    pub id: u64,
+    #[serde(rename = "_sName")]
+    pub name: String,
+    #[serde(rename = "_sVersion", default)]
+    pub version: String,
+    #[serde(rename = "_aSubmitter")]
+    pub submitter: Option<Submitter>,
+    #[serde(rename = "_aFiles", default)]
+    pub files: Vec<File>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Submitter {
+    #[serde(rename = "_sName")]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct File {
+    #[serde(rename = "_sFile")]
+    pub name: String,
+    #[serde(rename = "_nFilesize")]
+    pub size: Option<u64>,
+    /// What the uploader wrote about the file, often what changed.
+    #[serde(rename = "_sDescription", default)]
+    pub description: String,
+    /// Unix time the file was uploaded.
+    #[serde(rename = "_tsDateAdded", default)]
+    pub added: i64,
+    #[serde(rename = "_sDownloadUrl")]
+    pub download_url: String,
+}
+
+/// The mod ID a GameBanana reference names: a mod page such as
+/// `https://gamebanana.com/mods/123456` (or its download page), or the ID itself.
+pub fn parse_mod(text: &str) -> Option<u64> {
+    let text = text.trim().trim_end_matches('/');
+    let rest = ["https://", "http://"].iter().find_map(|scheme| text.strip_prefix(scheme)).unwrap_or(text);
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let Some(path) = rest.strip_prefix("gamebanana.com/") else {
+        return text.parse().ok();
+    };
+    let parts: Vec<&str> = path.split('/').collect();
+    match parts.as_slice() {
+        ["mods", id] | ["mods", "download", id] => id.split(['#', '?']).next()?.parse().ok(),
+        _ => None,
+    }
+}
+
+/// The mod `profile` describes. Its DLL is unknown until it is installed.
+pub fn spec_for(profile: &Profile) -> ModSpec {
+    ModSpec {
+        name: profile.name.clone(),
+        component: format!("GameBanana-{}", profile.id),
+        owner: profile.submitter.as_ref().map_or_else(|| "GameBanana".to_owned(), |submitter| submitter.name.clone()),
+        repo: profile.id.to_string(),
+        asset: assets::DEFAULT_PATTERN.to_owned(),
+        dll: String::new(),
+        source: ModSource::GameBanana,
+    }
+}
+
+/// The profile of mod `id`.
+pub async fn profile(id: u64) -> Result<Profile, LookupError> {
+    let url = format!("{}/apiv11/Mod/{}/ProfilePage", HOST, id);
+    let client = proxy::client().build().map_err(|e| LookupError::Network(e.to_string()))?;
+    let resp = retry::get(&client, &url).await.map_err(|e| match e.status() {
+        Some(status) => LookupError::Http(status),
+        None => LookupError::Network(e.to_string()),
+    })?;
+    resp.json().await.map_err(|e| LookupError::Network(e.to_string()))
+}
+
+/// The newest file of the mod `spec` is that matches `pattern`, as a release. No file matching
+/// leaves the download URL empty, as for a GitHub release.
+pub async fn latest_release(spec: &ModSpec, pattern: &AssetPattern) -> Result<ReleaseInfo, LookupError> {
+    let id = spec.repo.parse().map_err(|_| LookupError::NoRelease)?;
+    let profile = profile(id).await?;
+    let newest = profile.files.iter().filter(|file| pattern.is_match(&file.name)).max_by_key(|file| file.added);
+    let tag_name = match newest {
+        _ if !profile.version.trim().is_empty() => profile.version.trim().to_owned(),
+        Some(file) => file.name.clone(),
+        None => return Err(LookupError::NoRelease),
+    };
+    Ok(ReleaseInfo {
+        tag_name,
+        download_url: newest.map(|file| file.download_url.clone()).unwrap_or_default(),
+        asset_names: profile.files.iter().map(|file| file.name.clone()).collect(),
+        download_size: newest.and_then(|file| file.size),
+        notes: newest.map(|file| file.description.clone()).unwrap_or_default(),
+    })
+}
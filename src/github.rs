@@ -0,0 +1,351 @@
+// github.rs
+//! GitHub release lookups.
+//!
+//! Unauthenticated API calls are limited to 60 an hour per IP, which a few retries or a shared
+//! connection use up quickly. Responses are read for the `X-RateLimit-*` headers: a short wait
+//! (or a `Retry-After`) is sat out, a long one is reported with the time the limit resets. Every
+//! successful response is also kept on disk, so when GitHub can't be asked the last known answer
+//! is used instead of skipping the component.
+//!
+//! A saved response younger than the cache TTL is used without asking GitHub at all, so running
+//! the installer several times in a row doesn't spend the quota. `--refresh` skips that.
+//!
+//! Within a run each URL is asked at most once: [`prefetch`] queries every repository the run
+//! will need concurrently at startup, and later lookups are answered from what it got.
+
+use crate::assets::AssetPattern;
+use crate::proxy;
+use crate::retry;
+use crate::{color_echo, config, write_log, ConsoleColor};
+use chrono::{DateTime, Local, TimeZone};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Longest rate-limit wait sat out before giving up on the live API.
+const MAX_WAIT: Duration = Duration::from_secs(30);
+/// Remaining-request count below which the quota is logged.
+const LOW_QUOTA: u32 = 10;
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// How old a saved response may be and still be used without asking GitHub; `None` always asks.
+static CACHE_TTL: Mutex<Option<Duration>> = Mutex::new(Some(DEFAULT_CACHE_TTL));
+
+/// Responses (or failures) already seen this run, by URL.
+static SESSION: Mutex<Option<HashMap<String, Result<String, LookupError>>>> = Mutex::new(None);
+
+/// Sets how long saved responses are trusted. `None` (from `--refresh`) always queries GitHub.
+pub fn set_cache_ttl(ttl: Option<Duration>) {
+    *CACHE_TTL.lock().unwrap_or_else(|e| e.into_inner()) = ttl;
+}
+
+/// How long saved responses are trusted; `None` always asks.
+pub fn cache_ttl() -> Option<Duration> {
+    *CACHE_TTL.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Structure to store GitHub release info.
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    /// The release notes, in Markdown.
+    #[serde(default)]
+    body: Option<String>,
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    name: String,
+    browser_download_url: String,
+    #[serde(default)]
+    size: u64,
+}
+
+/// Helper structure for release info.
+pub struct ReleaseInfo {
+    pub tag_name: String,
+    /// Empty when no asset matched the pattern.
+    pub download_url: String,
+    /// Every asset in the release, for explaining a failed match.
+    pub asset_names: Vec<String>,
+    /// Size of the matched asset in bytes, when GitHub reported one.
+    pub download_size: Option<u64>,
+    /// What changed in the release, as its page describes it; empty when it doesn't.
+    pub notes: String,
+}
+
+/// Why release information couldn't be fetched.
+#[derive(Debug, Clone)]
+pub enum LookupError {
+    RateLimited { reset: DateTime<Local> },
+    Http(StatusCode),
+    Network(String),
+    /// The response parsed, but held no usable release.
+    NoRelease,
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            LookupError::RateLimited { reset } => {
+                t!("github-rate-limited", time = reset.format("%H:%M:%S").to_string())
+            }
+            LookupError::Http(status) => t!("err-http", status = status.to_string()),
+            LookupError::Network(error) => t!("github-unreachable", error = error.as_str()),
+            LookupError::NoRelease => t!("github-no-release"),
+        };
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+/// Retrieves the latest release info from GitHub.
+pub async fn get_latest_release(
+    owner: &str,
+    repo: &str,
+    asset_pattern: &AssetPattern,
+    log_file: &Path,
+) -> Result<ReleaseInfo, LookupError> {
+    get_channel_release(owner, repo, asset_pattern, config::Channel::Stable, log_file).await
+}
+
+/// Retrieves the newest release on the given channel. `preview` also considers prereleases,
+/// which GitHub's `releases/latest` endpoint never returns.
+pub async fn get_channel_release(
+    owner: &str,
+    repo: &str,
+    asset_pattern: &AssetPattern,
+    channel: config::Channel,
+    log_file: &Path,
+) -> Result<ReleaseInfo, LookupError> {
+    // An offline kit brings its own copy of the release.
+    if let Some(release) = crate::kit::release(owner, repo) {
+        return Ok(release);
+    }
+    let body = fetch_once(&release_url(owner, repo, channel), &cache_name(owner, repo, channel), log_file).await?;
+    let release = if channel == config::Channel::Stable {
+        serde_json::from_str::<GitHubRelease>(&body).map_err(|_| LookupError::NoRelease)?
+    } else {
+        let releases: Vec<GitHubRelease> = serde_json::from_str(&body).map_err(|_| LookupError::NoRelease)?;
+        // The list is newest-first; drafts are only visible to maintainers but skip them anyway.
+        releases.into_iter().find(|release| !release.draft).ok_or(LookupError::NoRelease)?
+    };
+    Ok(release_info(release, repo, asset_pattern))
+}
+
+/// Every recent release of `owner/repo`, newest first; prereleases only with
+/// `include_prereleases`.
+pub async fn list_releases(
+    owner: &str,
+    repo: &str,
+    asset_pattern: &AssetPattern,
+    include_prereleases: bool,
+    log_file: &Path,
+) -> Result<Vec<ReleaseInfo>, LookupError> {
+    let channel = config::Channel::Preview;
+    let body = fetch_once(&release_url(owner, repo, channel), &cache_name(owner, repo, channel), log_file).await?;
+    let releases: Vec<GitHubRelease> = serde_json::from_str(&body).map_err(|_| LookupError::NoRelease)?;
+    Ok(releases
+        .into_iter()
+        .filter(|release| !release.draft && (include_prereleases || !release.prerelease))
+        .map(|release| release_info(release, repo, asset_pattern))
+        .collect())
+}
+
+/// Queries every `(owner, repo, channel)` at once so the lookups later in the run don't wait on
+/// GitHub one after another.
+pub async fn prefetch(repos: &[(&str, &str, config::Channel)], log_file: &Path) {
+    let mut tasks = tokio::task::JoinSet::new();
+    for &(owner, repo, channel) in repos {
+        let url = release_url(owner, repo, channel);
+        let cache_name = cache_name(owner, repo, channel);
+        let log_file = log_file.to_path_buf();
+        tasks.spawn(async move {
+            let _ = fetch_once(&url, &cache_name, &log_file).await;
+        });
+    }
+    while tasks.join_next().await.is_some() {}
+}
+
+fn release_url(owner: &str, repo: &str, channel: config::Channel) -> String {
+    match channel {
+        config::Channel::Stable => format!("https://api.github.com/repos/{}/{}/releases/latest", owner, repo),
+        config::Channel::Preview => format!("https://api.github.com/repos/{}/{}/releases?per_page=20", owner, repo),
+    }
+}
+
+/// Picks the download asset out of a release.
+fn release_info(release: GitHubRelease, repo: &str, asset_pattern: &AssetPattern) -> ReleaseInfo {
+    let asset_names: Vec<String> = release.assets.iter().map(|asset| asset.name.clone()).collect();
+    let selected = asset_pattern.select(asset_names.iter().map(String::as_str));
+    let asset = release.assets.iter().find(|asset| Some(asset.name.as_str()) == selected);
+    let mut download_url = asset.map(|asset| asset.browser_download_url.clone()).unwrap_or_default();
+    let download_size = asset.map(|asset| asset.size).filter(|&size| size > 0);
+    // Fallback for MelonLoader.
+    if download_url.is_empty() && repo.eq_ignore_ascii_case("MelonLoader") {
+        download_url = "https://github.com/LavaGang/MelonLoader/releases/latest/download/MelonLoader.x64.zip".to_owned();
+    }
+    let notes = release.body.unwrap_or_default();
+    ReleaseInfo { tag_name: release.tag_name, download_url, asset_names, download_size, notes }
+}
+
+/// [`fetch`], unless this run already has an answer for `url`.
+async fn fetch_once(url: &str, cache_name: &str, log_file: &Path) -> Result<String, LookupError> {
+    if let Some(result) = session().as_ref().and_then(|seen| seen.get(url).cloned()) {
+        return result;
+    }
+    let result = fetch(url, cache_name, log_file).await;
+    session().get_or_insert_with(HashMap::new).insert(url.to_owned(), result.clone());
+    result
+}
+
+fn session() -> std::sync::MutexGuard<'static, Option<HashMap<String, Result<String, LookupError>>>> {
+    SESSION.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// GETs `url` from the API, falling back to the cached copy of the last good response when the
+/// live one can't be had.
+async fn fetch(url: &str, cache_name: &str, log_file: &Path) -> Result<String, LookupError> {
+    let cache_file = cache_dir().map(|dir| dir.join(cache_name));
+    if let Some(body) = cache_file.as_deref().and_then(fresh_cache) {
+        let _ = write_log(log_file, &format!("Using cached GitHub response for {}.", url));
+        return Ok(body);
+    }
+    match fetch_live(url, log_file).await {
+        Ok(body) => {
+            if let Some(cache_file) = &cache_file {
+                let _ = fs::create_dir_all(cache_file.parent().unwrap_or(Path::new(".")));
+                let _ = fs::write(cache_file, &body);
+            }
+            Ok(body)
+        }
+        Err(error) => {
+            let _ = write_log(log_file, &format!("GitHub request for {} failed: {:?}", url, error));
+            let Some(cache_file) = cache_file else {
+                return Err(error);
+            };
+            let (Ok(body), Ok(modified)) = (
+                fs::read_to_string(&cache_file),
+                fs::metadata(&cache_file).and_then(|meta| meta.modified()),
+            ) else {
+                return Err(error);
+            };
+            let cached_at = DateTime::<Local>::from(modified).format("%Y-%m-%d %H:%M").to_string();
+            color_echo(
+                ConsoleColor::Yellow,
+                &t!("github-using-cache", reason = error.to_string(), time = cached_at.as_str()),
+            );
+            let _ = write_log(
+                log_file,
+                &format!("Using cached GitHub response from {} ({}).", cached_at, cache_file.display()),
+            );
+            Ok(body)
+        }
+    }
+}
+
+/// The saved response in `cache_file`, if it is younger than the cache TTL.
+fn fresh_cache(cache_file: &Path) -> Option<String> {
+    let ttl = (*CACHE_TTL.lock().unwrap_or_else(|e| e.into_inner()))?;
+    let modified = fs::metadata(cache_file).and_then(|meta| meta.modified()).ok()?;
+    let age = SystemTime::now().duration_since(modified).ok()?;
+    if age > ttl {
+        return None;
+    }
+    fs::read_to_string(cache_file).ok()
+}
+
+/// One API request, retried when GitHub asks for a short wait or has a transient failure. Failures
+/// other than rate limits back off as [`retry`] does.
+async fn fetch_live(url: &str, log_file: &Path) -> Result<String, LookupError> {
+    let client = proxy::client()
+        .build()
+        .map_err(|e| LookupError::Network(e.to_string()))?;
+    let mut attempt = 1;
+    loop {
+        let sent = client.get(url).header("Accept", "application/vnd.github+json").send().await;
+        let resp = match sent {
+            Ok(resp) => resp,
+            Err(ex) if attempt < retry::attempts() && retry::is_transient(&ex) => {
+                let wait = retry::delay(attempt);
+                let _ = write_log(log_file, &format!("GitHub unreachable ({}); retrying in {} s.", ex, wait.as_secs()));
+                tokio::time::sleep(wait).await;
+                attempt += 1;
+                continue;
+            }
+            Err(ex) => return Err(LookupError::Network(ex.to_string())),
+        };
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        if let Some(remaining) = header_u32(&headers, "x-ratelimit-remaining").filter(|&n| n < LOW_QUOTA) {
+            let _ = write_log(log_file, &format!("GitHub API requests left this hour: {}", remaining));
+        }
+        if status.is_success() {
+            return resp.text().await.map_err(|e| LookupError::Network(e.to_string()));
+        }
+
+        let error = match rate_limit_reset(status, &headers) {
+            Some(reset) => LookupError::RateLimited { reset },
+            None => LookupError::Http(status),
+        };
+        let wait = match &error {
+            LookupError::RateLimited { reset } => (*reset - Local::now()).to_std().unwrap_or_default(),
+            _ if retry::is_transient_status(status) => retry::delay(attempt),
+            _ => return Err(error),
+        };
+        if attempt >= retry::attempts() || wait > MAX_WAIT {
+            return Err(error);
+        }
+        let _ = write_log(
+            log_file,
+            &format!("GitHub returned {}; retrying in {} s.", status, wait.as_secs()),
+        );
+        tokio::time::sleep(wait).await;
+        attempt += 1;
+    }
+}
+
+/// When a response is a rate-limit refusal, the time the limit lifts.
+fn rate_limit_reset(status: StatusCode, headers: &HeaderMap) -> Option<DateTime<Local>> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    // Secondary limits say how long to wait; the primary one says when the hour rolls over.
+    if let Some(seconds) = header_u32(headers, "retry-after") {
+        return Some(Local::now() + chrono::Duration::seconds(seconds.into()));
+    }
+    if header_u32(headers, "x-ratelimit-remaining") != Some(0) {
+        return None;
+    }
+    let reset = header_u32(headers, "x-ratelimit-reset")?;
+    Local.timestamp_opt(reset.into(), 0).single()
+}
+
+fn header_u32(headers: &HeaderMap, name: &str) -> Option<u32> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// `%LOCALAPPDATA%\DesktopMateInstaller\github`.
+fn cache_dir() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("DesktopMateInstaller").join("github"))
+}
+
+fn cache_name(owner: &str, repo: &str, channel: config::Channel) -> String {
+    let kind = match channel {
+        config::Channel::Stable => "latest",
+        config::Channel::Preview => "releases",
+    };
+    format!("{}_{}_{}.json", owner, repo, kind).to_lowercase()
+}
@@ -0,0 +1,91 @@
+// gitlab.rs
+//! The latest Goldberg build from GitLab CI.
+//!
+//! Goldberg has no releases; its builds are CI job artifacts, and the manifest's `goldberg.url`
+//! names one job, whose artifacts GitLab will eventually delete. When the manifest also names
+//! the project, branch, and job in `goldberg.gitlab`, the jobs API is asked for the newest
+//! successful run of that job that still has artifacts, and its download replaces the URL. The
+//! pinned URL stays behind it as a mirror.
+//!
+//! The answer is saved in `%LOCALAPPDATA%\DesktopMateInstaller\gitlab` and reused for as long as
+//! GitHub lookups are (see [`crate::github`]); when GitLab can't be asked, the saved answer is
+//! used however old it is, and without one the pinned URL.
+
+use crate::github;
+use crate::manifest::GitLabJob;
+use crate::proxy;
+use crate::retry;
+use crate::write_log;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const HOST: &str = "https://gitlab.com";
+
+#[derive(Debug, Deserialize)]
+struct Job {
+    id: u64,
+    name: String,
+    #[serde(rename = "ref")]
+    ref_name: String,
+    /// Absent once the artifacts expired or were deleted.
+    artifacts_file: Option<serde_json::Value>,
+}
+
+/// The artifact download of the newest successful `job`, or `None` when neither GitLab nor a
+/// saved answer has one.
+pub async fn latest_artifact(job: &GitLabJob, log_file: &Path) -> Option<String> {
+    let cache_file = cache_dir().map(|dir| dir.join(cache_name(job)));
+    let cached = cache_file.as_deref().and_then(|path| fs::read_to_string(path).ok());
+    let age = cache_file
+        .as_deref()
+        .and_then(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if let (Some(url), Some(age), Some(ttl)) = (&cached, age, github::cache_ttl()) {
+        if age <= ttl {
+            let _ = write_log(log_file, &format!("Using saved GitLab answer for {}: {}", job.job, url));
+            return Some(url.clone());
+        }
+    }
+    match retry::with_backoff("GitLab jobs", || fetch(job), |ex| retry::is_transient(ex)).await {
+        Ok(Some(url)) => {
+            if let Some(cache_file) = &cache_file {
+                let _ = fs::create_dir_all(cache_file.parent().unwrap_or(Path::new(".")));
+                let _ = fs::write(cache_file, &url);
+            }
+            Some(url)
+        }
+        Ok(None) => {
+            let _ = write_log(log_file, &format!("WARNING: GitLab has no {} job with artifacts.", job.job));
+            cached
+        }
+        Err(ex) => {
+            let _ = write_log(log_file, &format!("WARNING: GitLab jobs lookup failed: {}", ex));
+            cached
+        }
+    }
+}
+
+async fn fetch(job: &GitLabJob) -> Result<Option<String>, reqwest::Error> {
+    let url = format!(
+        "{}/api/v4/projects/{}/jobs?scope[]=success&per_page=100",
+        HOST,
+        job.project.replace('/', "%2F")
+    );
+    let jobs: Vec<Job> = proxy::client().build()?.get(url).send().await?.error_for_status()?.json().await?;
+    // Jobs come newest first.
+    Ok(jobs
+        .into_iter()
+        .find(|found| found.name == job.job && found.ref_name == job.ref_name && found.artifacts_file.is_some())
+        .map(|found| format!("{}/{}/-/jobs/{}/artifacts/download", HOST, job.project, found.id)))
+}
+
+/// `%LOCALAPPDATA%\DesktopMateInstaller\gitlab`.
+fn cache_dir() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("DesktopMateInstaller").join("gitlab"))
+}
+
+fn cache_name(job: &GitLabJob) -> String {
+    format!("{}_{}_{}.txt", job.project.replace('/', "_"), job.ref_name, job.job).to_lowercase()
+}
@@ -0,0 +1,90 @@
+// goldberg.rs
+//! Where the Goldberg patch comes from.
+//!
+//! Mr. Goldberg's emulator, built by GitLab CI, hasn't been developed for years; Detanup01's
+//! gbe_fork carries it on with GitHub releases. `goldberg.provider` in the manifest (or
+//! `goldberg_provider` in `installer.toml`) picks one, and the other is tried when it can't be
+//! downloaded or its archive lacks the DLL. gbe_fork ships a regular and an experimental
+//! `steam_api64.dll`; `goldberg.build` (or `goldberg_build`) picks which.
+
+use crate::assets::AssetPattern;
+use crate::github::get_latest_release;
+use crate::manifest::{GoldbergBuild, GoldbergProvider, GoldbergSpec};
+use crate::pins;
+use crate::write_log;
+use std::path::Path;
+
+/// One emulator build the patch can be taken from.
+#[derive(Debug, Clone)]
+pub struct Source {
+    pub provider: GoldbergProvider,
+    pub url: String,
+    /// Path of `steam_api64.dll` inside the archive, `/`-separated.
+    pub dll_path: String,
+    /// SHA-256 the manifest gives for the archive.
+    pub sha256: Option<String>,
+    /// Name and version the archive's pins are filed under.
+    pub component: &'static str,
+    pub version: Option<String>,
+}
+
+impl GoldbergProvider {
+    pub fn display_name(self) -> &'static str {
+        match self {
+            GoldbergProvider::MrGoldberg => "Mr. Goldberg's emulator",
+            GoldbergProvider::GbeFork => "gbe_fork",
+        }
+    }
+}
+
+/// The builds to try, the chosen provider's first. A provider whose download can't be found is
+/// left out and logged.
+pub async fn sources(spec: &GoldbergSpec, log_file: &Path) -> Vec<Source> {
+    let mut providers = vec![GoldbergProvider::MrGoldberg, GoldbergProvider::GbeFork];
+    providers.sort_by_key(|provider| *provider != spec.provider);
+    let mut sources = Vec::new();
+    for provider in providers {
+        match provider {
+            GoldbergProvider::MrGoldberg => sources.push(Source {
+                provider,
+                url: spec.url.clone(),
+                dll_path: spec.dll_path.clone(),
+                sha256: spec.sha256.clone(),
+                component: pins::GOLDBERG,
+                version: None,
+            }),
+            GoldbergProvider::GbeFork => {
+                let Some(fork) = &spec.gbe_fork else { continue };
+                let release = match AssetPattern::new(&fork.asset) {
+                    Ok(pattern) => get_latest_release(&fork.owner, &fork.repo, &pattern, log_file)
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Err(ex) => Err(ex.to_string()),
+                };
+                match release {
+                    Ok(release) if !release.download_url.is_empty() => sources.push(Source {
+                        provider,
+                        url: release.download_url,
+                        dll_path: match spec.build {
+                            GoldbergBuild::Regular => fork.regular_dll.clone(),
+                            GoldbergBuild::Experimental => fork.experimental_dll.clone(),
+                        },
+                        sha256: None,
+                        component: pins::GBE_FORK,
+                        version: Some(release.tag_name),
+                    }),
+                    Ok(release) => {
+                        let _ = write_log(
+                            log_file,
+                            &format!("WARNING: gbe_fork {} has no asset matching {}.", release.tag_name, fork.asset),
+                        );
+                    }
+                    Err(ex) => {
+                        let _ = write_log(log_file, &format!("WARNING: gbe_fork release lookup failed: {}", ex));
+                    }
+                }
+            }
+        }
+    }
+    sources
+}
@@ -0,0 +1,93 @@
+// health.rs
+//! Post-install health check.
+//!
+//! Confirms the pieces the earlier steps were supposed to leave behind are actually there and
+//! intact, so a broken install is reported by the installer instead of by the game at launch.
+
+use crate::game;
+use crate::manifest::ModSpec;
+use crate::state::InstallState;
+use crate::transaction::sha256_file;
+use std::path::Path;
+use std::process::Command;
+
+/// The outcome of one check.
+pub struct CheckResult {
+    /// Localized description of what was checked.
+    pub name: String,
+    pub passed: bool,
+    /// Why it failed, when that adds something to `name`.
+    pub detail: Option<String>,
+}
+
+impl CheckResult {
+    fn new(name: String, outcome: Result<(), String>) -> CheckResult {
+        match outcome {
+            Ok(()) => CheckResult { name, passed: true, detail: None },
+            Err(detail) => CheckResult { name, passed: false, detail: Some(detail) },
+        }
+    }
+}
+
+/// Runs every check against the install in `target_path`. `goldberg_sha256` is the hash of the
+/// patch DLL this run installed, or `None` when the pipeline has no Goldberg step; `mods` are the
+/// mods it installed, of which the disabled ones aren't checked.
+pub fn run(target_path: &Path, goldberg_sha256: Option<&str>, mods: &[&ModSpec], shortcuts: &[&Path]) -> Vec<CheckResult> {
+    let mut results = vec![CheckResult::new(t!("health-game-exe"), file_exists(&game::executable(target_path)))];
+    if let Some(expected) = goldberg_sha256 {
+        results.push(CheckResult::new(t!("health-goldberg"), goldberg_matches(target_path, expected)));
+    }
+    results.push(CheckResult::new(t!("health-melonloader"), file_exists(&target_path.join("version.dll"))));
+    let state = InstallState::load(target_path).unwrap_or_default();
+    for spec in mods.iter().filter(|spec| !state.is_component_disabled(&spec.component)) {
+        results.push(CheckResult::new(
+            t!("health-mod", mod = spec.name.as_str(), dll = spec.dll.as_str()),
+            file_exists(&game::relative(target_path, &spec.dll)),
+        ));
+    }
+    for shortcut in shortcuts {
+        let name = t!(
+            "health-shortcut",
+            name = shortcut.file_name().unwrap_or_default().to_string_lossy()
+        );
+        results.push(CheckResult::new(name, shortcut_resolves(shortcut)));
+    }
+    results
+}
+
+fn file_exists(path: &Path) -> Result<(), String> {
+    if path.is_file() {
+        Ok(())
+    } else {
+        Err(t!("health-missing", path = path.display()))
+    }
+}
+
+fn goldberg_matches(target_path: &Path, expected: &str) -> Result<(), String> {
+    let dll = game::steam_api(target_path);
+    file_exists(&dll)?;
+    let actual = sha256_file(&dll).map_err(|e| e.to_string())?;
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(t!("health-hash-mismatch"))
+    }
+}
+
+/// Asks the shell what a `.lnk` points at and checks that the target exists.
+fn shortcut_resolves(shortcut: &Path) -> Result<(), String> {
+    file_exists(shortcut)?;
+    let script = format!(
+        "(New-Object -ComObject WScript.Shell).CreateShortcut('{}').TargetPath",
+        shortcut.display().to_string().replace('\'', "''")
+    );
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .map_err(|e| e.to_string())?;
+    let target = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    if !output.status.success() || target.is_empty() {
+        return Err(t!("health-shortcut-unreadable"));
+    }
+    file_exists(Path::new(&target))
+}
@@ -0,0 +1,152 @@
+// i18n.rs
+//! Localized user-facing text.
+//!
+//! Strings live in Fluent resources under `locales/<lang>/installer.ftl`, compiled into the
+//! binary. The language follows the Windows user locale; anything a translation lacks falls
+//! back to English. Log file entries stay in English so support can read them.
+//!
+//! Use the `t!` macro: `t!("install-dir-set", path = target_path)`.
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::sync::OnceLock;
+use unic_langid::LanguageIdentifier;
+use winapi::um::winnls::GetUserDefaultLocaleName;
+use winapi::um::winnt::LOCALE_NAME_MAX_LENGTH;
+
+/// Shipped translations: (language tag, Fluent source). English must stay first.
+const LOCALES: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en/installer.ftl")),
+    ("ja", include_str!("../locales/ja/installer.ftl")),
+    ("zh-CN", include_str!("../locales/zh-CN/installer.ftl")),
+    ("de", include_str!("../locales/de/installer.ftl")),
+    ("es", include_str!("../locales/es/installer.ftl")),
+];
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+
+struct Localizer {
+    language: &'static str,
+    primary: FluentBundle<FluentResource>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Localizer {
+    fn new(language: &'static str) -> Localizer {
+        Localizer {
+            language,
+            primary: build_bundle(language),
+            fallback: build_bundle(LOCALES[0].0),
+        }
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        format_from(&self.primary, id, args)
+            .or_else(|| format_from(&self.fallback, id, args))
+            .unwrap_or_else(|| id.to_owned())
+    }
+}
+
+fn build_bundle(language: &str) -> FluentBundle<FluentResource> {
+    let source = LOCALES
+        .iter()
+        .find(|(tag, _)| *tag == language)
+        .map_or(LOCALES[0].1, |(_, source)| *source);
+    let langid: LanguageIdentifier = language.parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+    // Unicode isolation marks show up as garbage in the Windows console.
+    bundle.set_use_isolating(false);
+    let resource = match FluentResource::try_new(source.to_owned()) {
+        Ok(resource) => resource,
+        // Keep whatever parsed; broken entries fall back to English.
+        Err((resource, _)) => resource,
+    };
+    let _ = bundle.add_resource(resource);
+    bundle
+}
+
+fn format_from(
+    bundle: &FluentBundle<FluentResource>,
+    id: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if errors.is_empty() {
+        Some(text.into_owned())
+    } else {
+        None
+    }
+}
+
+fn localizer() -> &'static Localizer {
+    LOCALIZER.get_or_init(|| Localizer::new(match_language(&system_locale())))
+}
+
+/// Selects the UI language. `requested` (from `--lang` or the config) wins over the Windows
+/// locale; an unsupported value falls back to the locale and is returned as the error.
+/// Must run before the first message is formatted.
+pub fn init(requested: Option<&str>) -> Result<(), String> {
+    let (language, result) = match requested.map(str::trim).filter(|r| !r.is_empty()) {
+        Some(requested) => match supported_language(requested) {
+            Some(language) => (language, Ok(())),
+            None => (match_language(&system_locale()), Err(requested.to_owned())),
+        },
+        None => (match_language(&system_locale()), Ok(())),
+    };
+    let _ = LOCALIZER.set(Localizer::new(language));
+    result
+}
+
+/// Tags of every shipped translation.
+pub fn available_languages() -> Vec<&'static str> {
+    LOCALES.iter().map(|(tag, _)| *tag).collect()
+}
+
+/// Formats the message `id`. Prefer the `t!` macro.
+pub fn tr(id: &str, args: Option<&FluentArgs>) -> String {
+    localizer().format(id, args)
+}
+
+/// Language tag of the active translation (e.g. `ja`).
+pub fn current_language() -> &'static str {
+    localizer().language
+}
+
+/// Maps a BCP 47 locale name to the closest shipped translation, defaulting to English.
+fn match_language(locale: &str) -> &'static str {
+    supported_language(locale).unwrap_or(LOCALES[0].0)
+}
+
+/// Finds the shipped translation for a language tag such as `ja`, `ja-JP`, or `zh_TW`.
+fn supported_language(tag: &str) -> Option<&'static str> {
+    let primary = tag.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+    match primary.as_str() {
+        // Only Simplified Chinese ships so far; it is closer than English for zh-TW/zh-HK.
+        "zh" => Some("zh-CN"),
+        other => LOCALES.iter().map(|(tag, _)| *tag).find(|tag| *tag == other),
+    }
+}
+
+/// The Windows user locale name, e.g. `ja-JP`.
+fn system_locale() -> String {
+    let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH];
+    let len = unsafe { GetUserDefaultLocaleName(buffer.as_mut_ptr(), buffer.len() as i32) };
+    if len <= 1 {
+        return String::new();
+    }
+    String::from_utf16_lossy(&buffer[..len as usize - 1])
+}
+
+/// Looks up a localized message, optionally with named arguments.
+macro_rules! t {
+    ($id:expr) => {
+        $crate::i18n::tr($id, None)
+    };
+    ($id:expr, $($key:ident = $value:expr),+ $(,)?) => {{
+        let mut args = ::fluent_bundle::FluentArgs::new();
+        $( args.set(stringify!($key), $value.to_string()); )+
+        $crate::i18n::tr($id, Some(&args))
+    }};
+}
@@ -0,0 +1,63 @@
+// injection.rs
+//! Checking that MelonLoader actually loads into the game.
+//!
+//! A MelonLoader whose files are all in place can still fail to inject: antivirus software
+//! blocking the proxy DLL, a missing Visual C++ runtime, or a MelonLoader release the game's
+//! Unity version doesn't work with. Users otherwise find out when their mods don't show up.
+//! After an install the installer can start the game for a moment (without MelonLoader's
+//! console) and watch for `MelonLoader\Latest.log`: MelonLoader writes it, headed by its version,
+//! as soon as it is loaded. The game is closed again once the log turns up, or when it doesn't
+//! within [`TIMEOUT`].
+
+use crate::game;
+use crate::melonloader;
+use crate::shortcuts;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How long the game gets to start MelonLoader.
+pub const TIMEOUT: Duration = Duration::from_secs(60);
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What starting the game showed.
+#[derive(Debug)]
+pub enum Outcome {
+    /// MelonLoader wrote its log, naming this version.
+    Injected { version: String },
+    /// The game exited before MelonLoader wrote anything, with this exit code.
+    GameExited { code: Option<i32> },
+    /// The game ran for [`TIMEOUT`] without MelonLoader writing its log.
+    NoLog,
+}
+
+/// Starts the game in `root`, waits for MelonLoader's log and closes the game again.
+pub fn check(root: &Path) -> io::Result<Outcome> {
+    let log = melonloader::log_path(root);
+    let started = SystemTime::now();
+    let mut child = Command::new(game::executable(root))
+        .arg(shortcuts::HIDE_CONSOLE_ARG)
+        .current_dir(root)
+        .spawn()?;
+    let outcome = loop {
+        // A log from an earlier start doesn't count.
+        let fresh = fs::metadata(&log).and_then(|meta| meta.modified()).is_ok_and(|modified| modified >= started);
+        if let Some(version) = fresh.then(|| melonloader::logged_version(&log)).flatten() {
+            break Outcome::Injected { version };
+        }
+        if let Some(status) = child.try_wait()? {
+            return Ok(Outcome::GameExited { code: status.code() });
+        }
+        if started.elapsed().unwrap_or_default() >= TIMEOUT {
+            break Outcome::NoLog;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    // The game was only started for the check; it may already have quit on its own.
+    let _ = child.kill();
+    let _ = child.wait();
+    Ok(outcome)
+}
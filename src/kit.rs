@@ -0,0 +1,218 @@
+// kit.rs
+//! Offline kits: a folder holding the installer and everything it downloads, for communities
+//! that pass around a ready-to-run copy.
+//!
+//! `--package <folder>` builds one:
+//!
+//! ```text
+//! <folder>\
+//!     DesktopMateInstaller.exe
+//!     DepotDownloader\            (already extracted, so it isn't fetched again)
+//!     kit\kit.json                (which URL each archive stands in for, and its SHA-256)
+//!     kit\manifest.json           (the component manifest the archives match)
+//!     kit\files\*.zip             (Goldberg, MelonLoader, Custom Avatar Loader)
+//!     kit\SHA256SUMS.txt
+//!     installer.toml.example      (answers for unattended runs)
+//!     steam-credentials.example   (for --credentials-file)
+//! ```
+//!
+//! An installer started from a kit uses the kit's manifest, serves downloads and the Custom
+//! Avatar Loader release lookup from `kit\files`, and refuses an archive whose checksum no
+//! longer matches. The game itself is never included: it still comes from Steam with the
+//! user's own account.
+
+use crate::copy::copy_directory;
+use crate::config::ConflictPolicy;
+use crate::github::ReleaseInfo;
+use crate::manifest::{self, ComponentManifest};
+use crate::transaction::sha256_file;
+use crate::{color_echo, download_file, write_log, ConsoleColor};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const KIT_DIR: &str = "kit";
+const INDEX_FILE: &str = "kit.json";
+const MANIFEST_FILE: &str = "manifest.json";
+const FILES_DIR: &str = "files";
+const SUMS_FILE: &str = "SHA256SUMS.txt";
+
+const ANSWERS_TEMPLATE: &str = r#"# Rename to installer.toml to answer the installer's questions ahead of time.
+
+# Seconds a question with a default answer waits before taking it (0 waits forever).
+# prompt_timeout_seconds = 30
+
+# UI language: "en", "ja", "zh-CN", "de", or "es".
+# language = "en"
+
+# Desktop shortcut names.
+# shortcut_name = "DesktopMate"
+# console_shortcut_name = "DesktopMate (Console)"
+
+# What a mod update does with a file you changed: "backup", "skip", or "overwrite".
+# on_conflict = "backup"
+"#;
+
+const CREDENTIALS_TEMPLATE: &str = r#"# Fill in, keep private, and pass with --credentials-file <path>.
+# The installer refuses this file while other users of the PC can read it.
+machine steam
+    login     your_steam_name
+    password  your_steam_password
+"#;
+
+static KIT: Mutex<Option<Kit>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Kit {
+    /// Installer version that built the kit.
+    installer_version: String,
+    created_at: String,
+    files: Vec<KitFile>,
+    /// The Custom Avatar Loader release the kit holds.
+    avatar_loader: KitRelease,
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KitFile {
+    url: String,
+    file: String,
+    sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KitRelease {
+    owner: String,
+    repo: String,
+    tag_name: String,
+    url: String,
+}
+
+/// Looks for a kit next to the installer in `base_dir`. If there is one, it serves this run's
+/// downloads from now on and its manifest is returned.
+pub fn open(base_dir: &Path, log_file: &Path) -> Option<ComponentManifest> {
+    let root = base_dir.join(KIT_DIR);
+    let index = fs::read_to_string(root.join(INDEX_FILE)).ok()?;
+    let loaded = serde_json::from_str::<Kit>(&index).map_err(|e| e.to_string()).and_then(|kit| {
+        let text = fs::read_to_string(root.join(MANIFEST_FILE)).map_err(|e| e.to_string())?;
+        Ok((kit, manifest::parse(&text)?))
+    });
+    match loaded {
+        Ok((mut kit, manifest)) => {
+            let _ = write_log(
+                log_file,
+                &format!(
+                    "Using offline kit built {} by installer {} (manifest revision {}).",
+                    kit.created_at, kit.installer_version, manifest.revision
+                ),
+            );
+            kit.root = root;
+            *lock() = Some(kit);
+            Some(manifest)
+        }
+        Err(reason) => {
+            let _ = write_log(log_file, &format!("WARNING: Ignoring unreadable offline kit: {}", reason));
+            None
+        }
+    }
+}
+
+/// The kit's copy of `url`, checked against its recorded SHA-256. `None` without a kit or when
+/// the kit doesn't have it.
+pub fn local_copy(url: &str) -> Option<Result<PathBuf, String>> {
+    let (root, entry) = {
+        let kit = lock();
+        let kit = kit.as_ref()?;
+        (kit.root.clone(), kit.files.iter().find(|file| file.url == url)?.clone())
+    };
+    let path = root.join(FILES_DIR).join(&entry.file);
+    Some(match sha256_file(&path) {
+        Ok(hash) if hash.eq_ignore_ascii_case(&entry.sha256) => Ok(path),
+        Ok(_) => Err(t!("err-kit-checksum", file = entry.file.as_str())),
+        Err(ex) => Err(t!("err-kit-missing", file = entry.file.as_str(), error = ex)),
+    })
+}
+
+/// The release the kit holds for `owner/repo`, standing in for a GitHub lookup.
+pub fn release(owner: &str, repo: &str) -> Option<ReleaseInfo> {
+    let kit = lock();
+    let release = &kit.as_ref()?.avatar_loader;
+    if !release.owner.eq_ignore_ascii_case(owner) || !release.repo.eq_ignore_ascii_case(repo) {
+        return None;
+    }
+    let file = file_name(&release.url);
+    Some(ReleaseInfo {
+        tag_name: release.tag_name.clone(),
+        download_url: release.url.clone(),
+        asset_names: vec![file],
+        download_size: None,
+        notes: String::new(),
+    })
+}
+
+/// Builds a kit in `dest` from `manifest`, copying DepotDownloader from `depot_downloader_dir`
+/// and downloading the rest. `avatar_loader` is the release to include.
+pub async fn build(
+    dest: &Path,
+    manifest: &ComponentManifest,
+    avatar_loader: &ReleaseInfo,
+    depot_downloader_dir: &Path,
+    log_file: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let root = dest.join(KIT_DIR);
+    let files_dir = root.join(FILES_DIR);
+    fs::create_dir_all(&files_dir)?;
+
+    let exe = env::current_exe()?;
+    fs::copy(&exe, dest.join(exe.file_name().unwrap_or_default()))?;
+    copy_directory(depot_downloader_dir, &dest.join("DepotDownloader"), ConflictPolicy::Overwrite)?;
+
+    let mut files = Vec::new();
+    for url in [&manifest.goldberg.url, &manifest.melonloader.url, &avatar_loader.download_url] {
+        let mut file = file_name(url);
+        if files.iter().any(|kit_file: &KitFile| kit_file.file.eq_ignore_ascii_case(&file)) {
+            file = format!("{}-{}", files.len(), file);
+        }
+        let path = files_dir.join(&file);
+        color_echo(ConsoleColor::Blue, &t!("package-fetching", file = file.as_str()));
+        write_log(log_file, &format!("Packaging {} from {}", file, url))?;
+        download_file(url, &path).await?;
+        files.push(KitFile { url: url.clone(), sha256: sha256_file(&path)?, file });
+    }
+
+    let sums: String = files.iter().map(|file| format!("{} *{}\r\n", file.sha256, file.file)).collect();
+    fs::write(root.join(SUMS_FILE), sums)?;
+    fs::write(root.join(MANIFEST_FILE), serde_json::to_string_pretty(manifest).map_err(io::Error::other)?)?;
+    let kit = Kit {
+        installer_version: env!("CARGO_PKG_VERSION").to_owned(),
+        created_at: Local::now().to_rfc3339(),
+        files,
+        avatar_loader: KitRelease {
+            owner: manifest.avatar_loader.owner.clone(),
+            repo: manifest.avatar_loader.repo.clone(),
+            tag_name: avatar_loader.tag_name.clone(),
+            url: avatar_loader.download_url.clone(),
+        },
+        root: PathBuf::new(),
+    };
+    fs::write(root.join(INDEX_FILE), serde_json::to_string_pretty(&kit).map_err(io::Error::other)?)?;
+    fs::write(dest.join("installer.toml.example"), ANSWERS_TEMPLATE)?;
+    fs::write(dest.join("steam-credentials.example"), CREDENTIALS_TEMPLATE)?;
+    Ok(())
+}
+
+/// The last path segment of `url`.
+fn file_name(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    path.rsplit('/').next().filter(|name| !name.is_empty()).unwrap_or("download.zip").to_owned()
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Kit>> {
+    KIT.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,87 @@
+// logs.rs
+//! `--logs`: the install log, pretty-printed from the structured log so the error from the last
+//! attempt can be found without opening the raw, ever-growing text file.
+//!
+//! `--last-run` keeps only the most recent run, `--level warning|error` drops less severe lines,
+//! and `--tail <n>` keeps the last `n` lines of what is left. The flags combine.
+
+use crate::events::{self, Entry, Level};
+use crate::{color_echo, ConsoleColor};
+use chrono::DateTime;
+use std::io;
+use std::path::Path;
+
+/// Which entries `--logs` prints.
+pub struct Filter {
+    pub tail: Option<usize>,
+    pub last_run: bool,
+    pub min_level: Level,
+}
+
+/// Reads a `--level` value.
+pub fn parse_level(name: &str) -> Option<Level> {
+    match name.to_ascii_lowercase().as_str() {
+        "info" => Some(Level::Info),
+        "warn" | "warning" => Some(Level::Warning),
+        "error" => Some(Level::Error),
+        _ => None,
+    }
+}
+
+/// Prints the entries of the log at `log_file` that pass `filter`.
+pub fn show(log_file: &Path, filter: &Filter) -> io::Result<()> {
+    let mut entries = match events::read(log_file) {
+        Ok(entries) => entries,
+        Err(ex) if ex.kind() == io::ErrorKind::NotFound => {
+            let path = events::path_for(log_file);
+            color_echo(ConsoleColor::Yellow, &t!("logs-missing", path = path.display()));
+            return Ok(());
+        }
+        Err(ex) => return Err(ex),
+    };
+    if filter.last_run {
+        let last = entries.last().map(|entry| entry.run.clone());
+        entries.retain(|entry| Some(&entry.run) == last.as_ref());
+    }
+    entries.retain(|entry| entry.level >= filter.min_level);
+    if let Some(tail) = filter.tail {
+        entries.drain(..entries.len().saturating_sub(tail));
+    }
+
+    if entries.is_empty() {
+        println!("{}", t!("logs-empty"));
+    }
+    for entry in &entries {
+        print_entry(entry);
+    }
+    Ok(())
+}
+
+fn print_entry(entry: &Entry) {
+    let time = DateTime::parse_from_rfc3339(&entry.timestamp)
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|_| entry.timestamp.clone());
+    let detail = |key: &str| entry.details.get(key).and_then(|value| value.as_str()).unwrap_or_default().to_owned();
+    match entry.event.as_str() {
+        "run-started" => {
+            println!();
+            color_echo(ConsoleColor::Cyan, &t!("logs-run-started", time = time, version = detail("version")));
+        }
+        "step-started" => {
+            let step = entry.step.clone().unwrap_or_default();
+            color_echo(ConsoleColor::Blue, &t!("logs-step", time = time, step = step));
+        }
+        "run-finished" if entry.level == Level::Error => {
+            color_echo(ConsoleColor::Red, &t!("logs-run-failed", time = time, error = detail("error")));
+        }
+        "run-finished" => color_echo(ConsoleColor::Green, &t!("logs-run-succeeded", time = time)),
+        _ => {
+            let line = format!("{}  {}", time, detail("message"));
+            match entry.level {
+                Level::Error => color_echo(ConsoleColor::Red, &line),
+                Level::Warning => color_echo(ConsoleColor::Yellow, &line),
+                Level::Info => println!("{}", line),
+            }
+        }
+    }
+}
@@ -4,27 +4,127 @@
 
 use chrono::Local;
 use colored::*;
-use crossterm::{
-    event::{self, Event, KeyCode},
-    terminal::{disable_raw_mode, enable_raw_mode},
-};
-use reqwest::Client;
-use serde::Deserialize;
 use std::env;
 use std::error::Error;
 use std::ffi::OsStr;
-use std::fs::{self, File, OpenOptions};
+use std::fs::{self, OpenOptions};
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use archive::{extract_archive, extract_archive_filtered, ExtractFilter};
+use assets::AssetPattern;
+use copy::copy_directory;
+use download::download_file;
+use github::get_latest_release;
+use staging::Stage;
 use std::process::Stdio;
-use tokio::io::{AsyncBufReadExt, BufReader as AsyncBufReader};
+use std::time::Duration;
+use temp::TempPath;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader as AsyncBufReader};
 use tokio::process::Command;
-use zip::ZipArchive;
 use winapi::um::wincon::SetConsoleTitleW;
 use winapi::um::winnt::LPCWSTR;
 
+// Declared first so `t!` is in scope for every module below.
+#[macro_use]
+mod i18n;
+mod about;
+mod adopt;
+mod archive;
+mod assets;
+mod backup;
+mod branding;
+mod cache;
+mod catalog;
+mod cli;
+mod components;
+mod config;
+mod copy;
+mod credentials;
+mod defender;
+mod depot;
+mod depot_tool;
+mod dotnet;
+mod download;
+mod estimate;
+mod events;
+mod game;
+mod game_build;
+mod gamebanana;
+mod github;
+mod gitlab;
+mod goldberg;
+mod health;
+mod injection;
+mod kit;
+mod logs;
+mod manifest;
+mod melonloader;
+mod mod_history;
+mod mods;
+mod notify;
+mod pins;
+mod profiles;
+mod progress;
+mod prompt;
+mod proxy;
+mod receipt;
+mod redact;
+mod resume;
+mod retry;
+mod self_update;
+mod settings;
+mod shortcuts;
+mod signature;
+mod staging;
+mod state;
+mod steam;
+mod temp;
+mod theme;
+mod thunderstore;
+mod transaction;
+mod tui;
+mod unpatch;
+mod updates;
+mod verify;
+mod version;
+
+/// Answers gathered up front (by the wizard) or lazily (by the classic prompts).
+struct InstallOptions {
+    target_path: String,
+    /// Steam username/password; `None` means prompt when the depot download needs them.
+    steam_credentials: Option<(String, String)>,
+    /// Whether to accept a Custom Avatar Loader update; `None` means ask when one is found.
+    update_avatar_loader: Option<bool>,
+    /// Whether to back up user data before changing an existing install; `None` means ask.
+    backup_user_data: Option<bool>,
+    /// Backups to keep, and the age in days after which they are deleted.
+    keep_backups: Option<usize>,
+    backup_max_age_days: Option<u64>,
+    /// How mod files that differ from the incoming ones are handled.
+    conflict_policy: config::ConflictPolicy,
+    /// Which desktop shortcuts to create, under which names.
+    shortcuts: shortcuts::Variant,
+    shortcut_names: shortcuts::Names,
+    /// Names the shortcuts had last time, so renamed ones don't linger.
+    previous_shortcut_names: Option<shortcuts::Names>,
+    /// Also put the no-console shortcut in the Start menu and pin it there.
+    pin_to_start: bool,
+    /// Also create the shortcut that starts the game without mods.
+    safe_mode_shortcut: bool,
+    /// Download the pinned game build even though game files are present.
+    update_game: bool,
+    /// Have DepotDownloader check every game file and re-download damaged ones.
+    repair: bool,
+    /// Components to reinstall even though they are at the wanted version, because their files
+    /// are damaged.
+    reinstall: Vec<String>,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    // A bad command line stops here, with clap's usage message.
+    let args = cli::args();
+    transaction::roll_back_on_panic();
     // Determine our base directory (where the exe is located)
     let exe_path = env::current_exe()?;
     let base_dir = exe_path.parent().unwrap_or(Path::new("."));
@@ -33,225 +133,2523 @@ async fn main() -> Result<(), Box<dyn Error>> {
     if let Some(parent) = log_file.parent() {
         fs::create_dir_all(parent)?;
     }
-    write_log(&log_file, "------------------------------------------------------------")?;
-    write_log(
-        &log_file,
-        &format!("{} - Starting DesktopMate Installer", Local::now()),
-    )?;
 
-    // Set console title.
-    set_console_title("DesktopMate Installer");
-
-    // Display symmetrical banner
-    const BANNER_WIDTH: usize = 45;
-    let banner_line = "=".repeat(BANNER_WIDTH);
-    let title = "DesktopMate Installer";
-    let padding = (BANNER_WIDTH.saturating_sub(title.len())) / 2;
-    let banner_title = format!("{:padding$}{}{:padding$}", "", title, "", padding = padding);
-
-    color_echo(ConsoleColor::Cyan, &banner_line);
-    color_echo(ConsoleColor::Cyan, &banner_title);
-    color_echo(ConsoleColor::Cyan, &banner_line);
-    println!();
+    // Load the config before printing anything so its language setting applies to every message.
+    let (config, config_error) = match config::Config::load(base_dir, args.config.as_deref()) {
+        Ok(config) => (config, None),
+        Err(ex) => (config::Config::default(), Some(ex)),
+    };
+    let unknown_colors = theme::apply(&config.theme);
+    branding::set(&config.branding);
+    let requested_language = args.lang.clone().or_else(|| config.language.clone());
+    let language_error = i18n::init(requested_language.as_deref()).err();
+
+    // `--logs` only reads the log, so it runs before this run adds anything to it.
+    if args.logs {
+        let requested_level = args.level.clone();
+        let min_level = requested_level.as_deref().map_or(Some(events::Level::Info), logs::parse_level);
+        if min_level.is_none() {
+            color_echo(ConsoleColor::Yellow, &t!("logs-bad-level", level = requested_level.unwrap_or_default()));
+        }
+        let filter = logs::Filter {
+            tail: args.tail,
+            last_run: args.last_run,
+            min_level: min_level.unwrap_or(events::Level::Info),
+        };
+        if let Err(ex) = logs::show(&log_file, &filter) {
+            color_echo(ConsoleColor::Red, &t!("logs-unreadable", error = ex));
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    // `cache prune` doesn't touch an install either.
+    if let Some(cli::Command::Cache { action: cli::CacheCommand::Prune { days, all } }) = &args.command {
+        let max_age = (!all).then(|| Duration::from_secs(days.saturating_mul(24 * 60 * 60)));
+        match cache::prune(max_age) {
+            Ok(pruned) => color_echo(
+                ConsoleColor::Green,
+                &t!("cache-pruned", files = pruned.files, mb = format!("{:.1}", pruned.bytes as f64 / 1_048_576.0)),
+            ),
+            Err(ex) => color_echo(ConsoleColor::Red, &t!("cache-prune-failed", error = ex)),
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    write_log(&log_file, "------------------------------------------------------------")?;
+    events::run_started(&log_file);
+    write_log(
+        &log_file,
+        &format!("{} - Starting DesktopMate Installer", Local::now()),
+    )?;
+    write_log(&log_file, &format!("UI language: {}", i18n::current_language()))?;
+
+    // Set console title.
+    set_console_title(&branding::title());
+
+    if let Some(ex) = config_error {
+        color_echo(ConsoleColor::Yellow, &t!("config-ignored", error = ex));
+        write_log(&log_file, &format!("Ignoring installer.toml: {}", ex))?;
+    }
+    if let Some(url) = args.proxy.as_ref().or(config.proxy.as_ref()) {
+        match proxy::set(url) {
+            Ok(()) => write_log(&log_file, &format!("Using proxy {}", url))?,
+            Err(ex) => {
+                color_echo(ConsoleColor::Yellow, &t!("proxy-invalid", error = ex.as_str()));
+                write_log(&log_file, &format!("WARNING: Ignoring proxy: {}", ex))?;
+            }
+        }
+    }
+    if let Some(path) = args.ca_cert.clone().or_else(|| config.ca_cert.as_ref().map(|path| base_dir.join(path))) {
+        match proxy::set_ca_cert(&path) {
+            Ok(()) => write_log(&log_file, &format!("Trusting the certificate in {}", path.display()))?,
+            Err(ex) => {
+                color_echo(ConsoleColor::Yellow, &t!("ca-cert-invalid", error = ex.as_str()));
+                write_log(&log_file, &format!("WARNING: Ignoring CA certificate: {}", ex))?;
+            }
+        }
+    }
+    if let Some(requested) = language_error {
+        color_echo(
+            ConsoleColor::Yellow,
+            &t!("language-unsupported", language = requested, available = i18n::available_languages().join(", ")),
+        );
+        write_log(&log_file, &format!("Unsupported language requested: {}", requested))?;
+    }
+    for name in unknown_colors {
+        color_echo(ConsoleColor::Yellow, &t!("theme-color-unknown", color = name.as_str()));
+        write_log(&log_file, &format!("Ignoring unknown theme color: {}", name))?;
+    }
+
+    // `--prompt-timeout <seconds>` overrides installer.toml; either lets unattended runs continue.
+    let prompt_timeout = args.prompt_timeout.or(config.prompt_timeout_seconds);
+    prompt::set_timeout(prompt_timeout.filter(|seconds| *seconds > 0).map(Duration::from_secs));
+
+    // `--refresh` ignores saved GitHub responses; they are still used if GitHub can't be reached.
+    github::set_cache_ttl(if args.refresh {
+        None
+    } else {
+        Some(config.release_cache_minutes.map_or(github::DEFAULT_CACHE_TTL, |minutes| {
+            Duration::from_secs(minutes.saturating_mul(60))
+        }))
+    });
+
+    retry::set_attempts(config.network_attempts.unwrap_or(retry::DEFAULT_ATTEMPTS));
+
+    // The DepotDownloader flags override their `[depot_downloader]` settings one by one.
+    let mut depot_options = config.depot_downloader;
+    depot_options.max_servers = args.max_servers.or(depot_options.max_servers);
+    depot_options.max_downloads = args.max_downloads.or(depot_options.max_downloads);
+    depot_options.cell_id = args.cell_id.or(depot_options.cell_id);
+    depot_options.validate |= args.validate;
+    depot_tool::set_options(depot_options);
+    thunderstore::set_community(config.thunderstore_community.clone());
+    mod_history::set_keep(config.keep_mod_versions);
+
+    // Fetch the manifest and ask GitHub about every repository this run may need in one go. The
+    // bundled manifest names the mod repositories; if the remote one moved them, those lookups
+    // simply happen later.
+    let mut bundled = manifest::bundled(config.channel);
+    config.override_manifest(&mut bundled);
+    let mut repos = vec![(
+        bundled.avatar_loader.owner.as_str(),
+        bundled.avatar_loader.repo.as_str(),
+        config::Channel::Stable,
+    )];
+    if self_update::is_enabled() {
+        repos.push((self_update::UPDATE_OWNER, self_update::UPDATE_REPO, config.channel));
+    }
+    // A kit next to the installer pins the manifest its archives were packaged for; a game
+    // definition named in installer.toml stands in for DesktopMate's manifest.
+    let local_manifest = match (kit::open(base_dir, &log_file), &config.game_manifest) {
+        (Some(manifest), _) => Some(manifest),
+        (None, Some(path)) => match manifest::from_file(&base_dir.join(path)) {
+            Ok(manifest) => {
+                write_log(&log_file, &format!("Using game definition {} for {}.", path.display(), manifest.game.name))?;
+                Some(manifest)
+            }
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &t!("game-manifest-invalid", error = ex));
+                write_log(&log_file, &format!("ERROR: Game definition not usable: {}", ex))?;
+                pause_and_exit().await;
+                return Ok(());
+            }
+        },
+        (None, None) => None,
+    };
+    let (mut manifest, (), ()) = tokio::join!(
+        async {
+            match local_manifest {
+                Some(manifest) => manifest,
+                None => manifest::load(config.channel, &log_file).await,
+            }
+        },
+        github::prefetch(&repos, &log_file),
+        pins::load(&log_file),
+    );
+    for line in config.override_manifest(&mut manifest) {
+        write_log(&log_file, &line)?;
+    }
+    // The newest Goldberg CI build, unless the manifest pins the archive's hash or a kit holds it.
+    let goldberg_job = manifest.goldberg.gitlab.clone().filter(|_| {
+        manifest.goldberg.sha256.is_none() && kit::local_copy(&manifest.goldberg.url).is_none()
+    });
+    if let Some(job) = goldberg_job {
+        if let Some(url) = gitlab::latest_artifact(&job, &log_file).await.filter(|url| *url != manifest.goldberg.url) {
+            let line = format!("Using the latest Goldberg build {} (pinned: {}).", url, manifest.goldberg.url);
+            write_log(&log_file, &line)?;
+            let pinned = std::mem::replace(&mut manifest.goldberg.url, url);
+            manifest.goldberg.mirrors.insert(0, pinned);
+        }
+    }
+
+    // installer.toml's mirrors come first; they are the ones picked for this machine.
+    for (prefix, mirrors) in &config.mirrors {
+        download::add_mirrors(prefix, mirrors);
+    }
+    download::add_mirrors(&manifest.depot_downloader.url, &manifest.depot_downloader.mirrors);
+    download::add_mirrors(&manifest.goldberg.url, &manifest.goldberg.mirrors);
+    download::add_mirrors(&manifest.melonloader.url, &manifest.melonloader.mirrors);
+
+    // `--version` reports what this run would use, so it comes before anything is changed.
+    if args.version {
+        for line in about::report(&manifest, config.channel) {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    // Offer a newer (signed) installer before asking anything else.
+    if let Err(ex) = self_update::check_and_apply(&log_file, config.channel).await {
+        color_echo(ConsoleColor::Yellow, &t!("update-check-failed", error = ex));
+        write_log(&log_file, &format!("Installer update check failed: {}", ex))?;
+    }
+
+    game::set(&manifest.game);
+
+    if let Some(dest) = &args.package {
+        match dest {
+            Some(dest) => {
+                write_log(&log_file, &format!("Building offline kit in {}", dest))?;
+                match build_package(base_dir, &log_file, &manifest, Path::new(dest)).await {
+                    Ok(()) => color_echo(ConsoleColor::Green, &t!("package-done", path = dest.as_str())),
+                    Err(ex) => {
+                        color_echo(ConsoleColor::Red, &t!("package-failed", error = ex));
+                        write_log(&log_file, &format!("ERROR: Building the offline kit failed: {}", ex))?;
+                    }
+                }
+            }
+            None => color_echo(ConsoleColor::Red, &t!("package-needs-folder")),
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    if let Some(dest) = &args.pin_hashes {
+        match dest {
+            Some(dest) => {
+                write_log(&log_file, &format!("Recording pinned hashes in {}", dest))?;
+                match pins::record(Path::new(dest), &manifest, &log_file).await {
+                    Ok(()) => color_echo(ConsoleColor::Green, &t!("pin-recorded", path = dest.as_str())),
+                    Err(ex) => {
+                        color_echo(ConsoleColor::Red, &t!("pin-record-failed", error = ex));
+                        write_log(&log_file, &format!("ERROR: Recording pinned hashes failed: {}", ex))?;
+                    }
+                }
+            }
+            None => color_echo(ConsoleColor::Red, &t!("pin-needs-file")),
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    let default_path = config.install_path.clone().unwrap_or_else(|| manifest.game.default_path.clone());
+    let default_path = default_path.as_str();
+
+    if let Some(path) = &args.fix_shortcuts {
+        let saved = settings::Settings::load().unwrap_or_default();
+        let target_path = path
+            .clone()
+            .or(saved.target_path)
+            .unwrap_or_else(|| default_path.to_owned());
+        // Asking for a repair means wanting shortcuts, even after choosing none at install time.
+        let variant = match saved.shortcuts {
+            Some(shortcuts::Variant::None) | None => shortcuts::Variant::Both,
+            Some(variant) => variant,
+        };
+        let names = shortcut_names(&config, saved.shortcut_names.as_ref());
+        write_log(&log_file, &format!("Recreating shortcuts for {}", target_path))?;
+        let pin = saved.pin_to_start == Some(true) && variant.has_no_console();
+        let safe_mode = saved.safe_mode_shortcut == Some(true);
+        let previous = saved.shortcut_names.as_ref();
+        if let Err(ex) = fix_shortcuts(&log_file, &target_path, variant, safe_mode, &names, previous, pin) {
+            color_echo(ConsoleColor::Red, &t!("fix-shortcuts-failed", error = ex));
+            write_log(&log_file, &format!("ERROR: Recreating shortcuts failed: {}", ex))?;
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    if let Some(path) = &args.defender_exclusion {
+        let saved = settings::Settings::load().unwrap_or_default();
+        let target_path = path.clone().or(saved.target_path)
+            .unwrap_or_else(|| default_path.to_owned());
+        color_echo(ConsoleColor::Blue, &t!("defender-adding", path = target_path.as_str()));
+        write_log(&log_file, &format!("Adding a Microsoft Defender exclusion for {}", target_path))?;
+        match defender::add_exclusion(Path::new(&target_path)) {
+            Ok(()) => {
+                color_echo(ConsoleColor::Green, &t!("defender-added"));
+                write_log(&log_file, "Defender exclusion added.")?;
+            }
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &t!("defender-failed", error = ex));
+                write_log(&log_file, &format!("ERROR: Adding the Defender exclusion failed: {}", ex))?;
+            }
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    // Provisioning scripts can hand over the Steam login in a file instead of typing it in.
+    let file_credentials = match &args.credentials_file {
+        Some(path) => match credentials::load(path) {
+            Ok(credentials) => {
+                write_log(&log_file, &format!("Steam credentials read from {}", path.display()))?;
+                Some(credentials)
+            }
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &t!("credentials-failed", error = ex));
+                write_log(&log_file, &format!("ERROR: Credentials file not used: {}", ex))?;
+                pause_and_exit().await;
+                return Ok(());
+            }
+        },
+        None => None,
+    };
+    // Or `--steam-user`, with the password in an environment variable.
+    let file_credentials = match (file_credentials, &args.steam_user) {
+        (None, Some(user)) => match env::var(credentials::PASSWORD_VAR) {
+            Ok(password) => Some((user.clone(), password)),
+            Err(_) if args.unattended => {
+                color_echo(ConsoleColor::Red, &t!("credentials-no-password", var = credentials::PASSWORD_VAR));
+                write_log(&log_file, &format!("ERROR: --steam-user given without {}.", credentials::PASSWORD_VAR))?;
+                pause_and_exit_with(1).await;
+                return Ok(());
+            }
+            Err(_) => Some((user.clone(), prompt::password(&t!("prompt-steam-password"))?)),
+        },
+        (credentials, _) => credentials,
+    };
+    if let Some((_, password)) = &file_credentials {
+        redact::add(password);
+    }
+
+    if let Some(requested) = &args.restore {
+        if let Err(ex) = restore_backup(base_dir, &log_file, requested.as_deref()) {
+            color_echo(ConsoleColor::Red, &t!("restore-failed", error = ex));
+            write_log(&log_file, &format!("ERROR: Restore failed: {}", ex))?;
+        }
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    // No saved settings means this machine hasn't been set up yet: explain, and ask about ownership.
+    let saved = settings::Settings::load();
+    let first_run = saved.is_none();
+    let mut saved = saved.unwrap_or_default();
+    if let Some(dir) = &args.install_dir {
+        saved.target_path = Some(dir.clone());
+    }
+
+    // `--melonloader` picks the MelonLoader release for this and later runs; installer.toml wins.
+    if let Some(requested) = &args.melonloader {
+        match choose_melonloader(&log_file, requested.as_deref(), config.melonloader_channel, &manifest).await {
+            Ok(version) => {
+                write_log(&log_file, &format!("MelonLoader release chosen: {}", version))?;
+                saved.melonloader_version = Some(version);
+                save_settings(&log_file, saved.clone())?;
+            }
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &t!("melon-choose-failed", error = ex));
+                write_log(&log_file, &format!("ERROR: Choosing a MelonLoader release failed: {}", ex))?;
+                pause_and_exit_with(1).await;
+                return Ok(());
+            }
+        }
+    }
+    if let Some(requested) = config.melonloader_version.as_ref().or(saved.melonloader_version.as_ref()) {
+        let channel = config.melonloader_channel;
+        match melonloader::select(&mut manifest.melonloader, requested, channel, &log_file).await {
+            Ok(Some(line)) => write_log(&log_file, &line)?,
+            Ok(None) => {}
+            Err(ex) => {
+                let message = t!("melon-version-unavailable", version = requested.as_str(), error = ex.as_str());
+                color_echo(ConsoleColor::Yellow, &message);
+                write_log(&log_file, &format!("WARNING: MelonLoader {} unavailable: {}", requested, ex))?;
+            }
+        }
+    }
+
+    // `--mods` picks catalog mods for this and later runs; each run installs the ones picked.
+    if args.mods || !saved.extra_mods.is_empty() {
+        let catalog = catalog::load(&log_file).await;
+        if args.mods {
+            match choose_mods(&catalog, &saved.extra_mods) {
+                Ok(picked) => {
+                    write_log(&log_file, &format!("Catalog mods chosen: {}", picked.join(", ")))?;
+                    saved.extra_mods = picked;
+                    save_settings(&log_file, saved.clone())?;
+                }
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("catalog-choose-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Choosing catalog mods failed: {}", ex))?;
+                    pause_and_exit_with(1).await;
+                    return Ok(());
+                }
+            }
+        }
+        let (added, unknown) = catalog.add_steps(&mut manifest, &saved.extra_mods);
+        if !added.is_empty() {
+            write_log(&log_file, &format!("Installing catalog mods: {}", added.join(", ")))?;
+        }
+        for component in unknown {
+            color_echo(ConsoleColor::Yellow, &t!("catalog-mod-unknown", component = component.as_str()));
+            write_log(&log_file, &format!("WARNING: {} is no longer in the mod catalog; skipped.", component))?;
+        }
+    }
+
+    if let Some(path) = &args.check_updates {
+        let target_path = path
+            .clone()
+            .or_else(|| saved.target_path.clone())
+            .unwrap_or_else(|| default_path.to_owned());
+        write_log(&log_file, &format!("Checking for updates in {}", target_path))?;
+        let outdated = check_updates(&log_file, &manifest, &target_path).await?;
+        if !outdated {
+            color_echo(ConsoleColor::Green, &t!("check-updates-none"));
+            pause_and_exit().await;
+            return Ok(());
+        }
+        if !prompt::yes_no(&t!("check-updates-install"), Some(true))? {
+            write_log(&log_file, "Updates found but not installed.")?;
+            pause_and_exit().await;
+            return Ok(());
+        }
+        // Carry on with the regular setup, defaulting to the folder just checked.
+        saved.target_path = Some(target_path);
+    }
+
+    if let Some(path) = &args.watch {
+        let target_path = path
+            .clone()
+            .or_else(|| saved.target_path.clone())
+            .unwrap_or_else(|| default_path.to_owned());
+        write_log(&log_file, &format!("Watching {} for updates", target_path))?;
+        return Ok(watch_for_updates(&log_file, &config, &target_path).await?);
+    }
+
+    // `--verify` predates the subcommands and means `verify --game-files`.
+    let verify = match (&args.command, &args.verify_files) {
+        (Some(cli::Command::Verify { path, game_files }), _) => Some((path.clone(), *game_files)),
+        (_, Some(path)) => Some((path.clone(), true)),
+        _ => None,
+    };
+    if let Some((path, game_files)) = verify {
+        let target_path = chosen_target(path.as_ref(), &saved, default_path);
+        write_log(&log_file, &format!("Verifying the install in {}", target_path))?;
+        let verified = verify_install(base_dir, &log_file, &manifest, &target_path, game_files, file_credentials).await;
+        let code = match verified {
+            Ok(true) => 0,
+            Ok(false) => 1,
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &t!("verify-failed", error = ex));
+                write_log(&log_file, &format!("ERROR: Verification failed: {}", ex))?;
+                1
+            }
+        };
+        pause_and_exit_with(code).await;
+        return Ok(());
+    }
+
+    match &args.command {
+        Some(cli::Command::Update { path }) => {
+            let target_path = chosen_target(path.as_ref(), &saved, default_path);
+            write_log(&log_file, &format!("Updating the install in {}", target_path))?;
+            let code = match check_updates(&log_file, &manifest, &target_path).await {
+                Ok(false) => {
+                    color_echo(ConsoleColor::Green, &t!("check-updates-none"));
+                    0
+                }
+                Ok(true) => {
+                    let options = saved_options(&config, &saved, &manifest, target_path, file_credentials);
+                    run_without_questions(base_dir, &log_file, &manifest, &options).await?
+                }
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("update-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Update check failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Repair { path }) => {
+            let target_path = chosen_target(path.as_ref(), &saved, default_path);
+            color_echo(ConsoleColor::Cyan, &t!("repair-starting", path = target_path.as_str()));
+            write_log(&log_file, &format!("Repairing the install in {}", target_path))?;
+            // Components whose files can't be checked are reinstalled, as every one used to be.
+            let (damaged, unhashed) = check_component_files(&log_file, Path::new(&target_path))?;
+            let reinstall: Vec<String> = damaged.into_iter().chain(unhashed).collect();
+            if reinstall.is_empty() {
+                color_echo(ConsoleColor::Green, &t!("repair-components-intact"));
+            } else {
+                color_echo(ConsoleColor::Blue, &t!("repair-reinstalling", components = reinstall.join(", ")));
+            }
+            write_log(&log_file, &format!("Components to reinstall: {:?}", reinstall))?;
+            let options = InstallOptions {
+                repair: true,
+                reinstall,
+                ..saved_options(&config, &saved, &manifest, target_path, file_credentials)
+            };
+            let code = run_without_questions(base_dir, &log_file, &manifest, &options).await?;
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Uninstall { path, yes }) => {
+            let target_path = chosen_target(path.as_ref(), &saved, default_path);
+            write_log(&log_file, &format!("Uninstalling {}", target_path))?;
+            // Confirming on the command line also means backing up without asking.
+            let options = InstallOptions {
+                backup_user_data: yes.then_some(true),
+                ..saved_options(&config, &saved, &manifest, target_path, None)
+            };
+            let code = match uninstall(base_dir, &log_file, &manifest, &options, *yes) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("uninstall-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Uninstall failed: {}", ex))?;
+                    1
+                }
+            };
+            if code == 0 && saved.target_path.as_deref() == Some(options.target_path.as_str()) {
+                save_settings(&log_file, settings::Settings { target_path: None, ..saved })?;
+            }
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::SteamMode { path }) => {
+            let target_path = chosen_target(path.as_ref(), &saved, default_path);
+            write_log(&log_file, &format!("Switching {} to Steam mode", target_path))?;
+            let code = match switch_to_steam_mode(&log_file, &target_path) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("steam-mode-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Switching to Steam mode failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::RemoveMelonloader { path, yes }) => {
+            let target_path = chosen_target(path.as_ref(), &saved, default_path);
+            write_log(&log_file, &format!("Removing MelonLoader from {}", target_path))?;
+            let code = match remove_melonloader(&log_file, &target_path, *yes) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("melon-remove-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Removing MelonLoader failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod { action: cli::ModCommand::Install { url } }) => {
+            let target_path = chosen_target(None, &saved, default_path);
+            write_log(&log_file, &format!("Installing the mod at {} into {}", url, target_path))?;
+            let code = match install_mod_from_url(&log_file, &manifest, &target_path, url, config.on_conflict).await {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("mod-install-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Installing the mod failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod {
+            action: action @ (cli::ModCommand::Enable { name } | cli::ModCommand::Disable { name }),
+        }) => {
+            let target_path = chosen_target(None, &saved, default_path);
+            let enabled = matches!(action, cli::ModCommand::Enable { .. });
+            let code = match toggle_mod(&log_file, &manifest, &target_path, name, enabled) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("mod-toggle-failed", mod = name.as_str(), error = ex));
+                    write_log(&log_file, &format!("ERROR: Enabling or disabling {} failed: {}", name, ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Profile { action }) => {
+            let target_path = chosen_target(None, &saved, default_path);
+            let code = match manage_profile(&log_file, &target_path, action) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("profile-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Profile command failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod { action: cli::ModCommand::Update { name, yes, .. } }) => {
+            let target_path = chosen_target(None, &saved, default_path);
+            let result =
+                update_mods(&log_file, &manifest, &target_path, name.as_deref(), *yes, config.on_conflict).await;
+            let code = match result {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("mod-updates-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Updating mods failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod { action: cli::ModCommand::Rollback { name, to } }) => {
+            let target_path = chosen_target(None, &saved, default_path);
+            let code = match rollback_mod(&log_file, &manifest, &target_path, name, to.as_deref()) {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("mod-rollback-failed", mod = name.as_str(), error = ex));
+                    write_log(&log_file, &format!("ERROR: Rolling back {} failed: {}", name, ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod { action: cli::ModCommand::Search { query } }) => {
+            let code = match search_thunderstore(&log_file, query).await {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("thunderstore-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Thunderstore search failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Mod { action: cli::ModCommand::Versions { package } }) => {
+            let code = match list_thunderstore_versions(&log_file, package).await {
+                Ok(()) => 0,
+                Err(ex) => {
+                    color_echo(ConsoleColor::Red, &t!("thunderstore-failed", error = ex));
+                    write_log(&log_file, &format!("ERROR: Listing Thunderstore versions failed: {}", ex))?;
+                    1
+                }
+            };
+            pause_and_exit_with(code).await;
+            return Ok(());
+        }
+        Some(cli::Command::Install { path: Some(path) }) => saved.target_path = Some(path.clone()),
+        _ => {}
+    }
+    if first_run {
+        write_log(&log_file, "First run on this machine; showing guided setup.")?;
+    }
+
+    // Sized up front so users on metered connections can back out before anything downloads.
+    let estimate = estimate::estimate(base_dir, &manifest, &log_file).await;
+    let sizes: Vec<String> = estimate
+        .parts
+        .iter()
+        .map(|(name, size)| match size {
+            Some(size) => format!("{} {} bytes", name, size),
+            None => format!("{} unknown", name),
+        })
+        .collect();
+    write_log(&log_file, &format!("Download estimate: {}", sizes.join(", ")))?;
+
+    // Interactive users get the full-screen wizard; redirected consoles and `interface = "console"`
+    // keep the linear prompts.
+    if config.interface == config::Interface::Wizard && tui::is_supported() {
+        let mut ui = tui::Tui::enter()?;
+        let defaults = tui::WizardAnswers {
+            target_path: saved.target_path.clone().unwrap_or_else(|| default_path.to_owned()),
+            steam_credentials: file_credentials.clone(),
+            update_avatar_loader: args.update_mods.or(saved.update_avatar_loader).unwrap_or(true),
+            backup_user_data: args.backup.or(saved.backup_user_data).unwrap_or(true),
+            shortcuts: args.shortcuts.or(saved.shortcuts).unwrap_or_default(),
+            pin_to_start: args.pin_to_start.or(saved.pin_to_start).unwrap_or(false),
+            safe_mode_shortcut: args.safe_mode_shortcut.or(saved.safe_mode_shortcut).unwrap_or(false),
+        };
+        let answers = match ui.run_wizard(defaults, first_run, estimate, &manifest.game)? {
+            Some(answers) => answers,
+            None => {
+                drop(ui);
+                write_log(&log_file, "Setup wizard cancelled by user.")?;
+                return Ok(());
+            }
+        };
+        write_log(
+            &log_file,
+            &format!("Installation directory set to: {}", answers.target_path),
+        )?;
+        let names = shortcut_names(&config, saved.shortcut_names.as_ref());
+        save_settings(
+            &log_file,
+            settings::Settings {
+                ownership_confirmed: true,
+                target_path: Some(answers.target_path.clone()),
+                update_avatar_loader: Some(answers.update_avatar_loader),
+                backup_user_data: Some(answers.backup_user_data),
+                shortcuts: Some(answers.shortcuts),
+                shortcut_names: Some(names.clone()),
+                pin_to_start: Some(answers.pin_to_start),
+                safe_mode_shortcut: Some(answers.safe_mode_shortcut),
+                melonloader_version: saved.melonloader_version.clone(),
+                extra_mods: saved.extra_mods.clone(),
+            },
+        )?;
+        let update_game = args.update_game != Some(false)
+            && game_build::update_available(Path::new(&answers.target_path), &manifest.game).is_some();
+        let options = InstallOptions {
+            target_path: answers.target_path,
+            steam_credentials: answers.steam_credentials,
+            update_avatar_loader: Some(answers.update_avatar_loader),
+            backup_user_data: Some(answers.backup_user_data),
+            keep_backups: config.keep_backups,
+            backup_max_age_days: config.backup_max_age_days,
+            conflict_policy: config.on_conflict,
+            shortcuts: answers.shortcuts,
+            shortcut_names: names,
+            previous_shortcut_names: saved.shortcut_names,
+            pin_to_start: answers.pin_to_start,
+            safe_mode_shortcut: answers.safe_mode_shortcut,
+            update_game,
+            repair: false,
+            reinstall: Vec::new(),
+        };
+        let session = ui.start_progress();
+        let result = run_install(base_dir, &log_file, &manifest, &options).await;
+        if let Err(ex) = &result {
+            write_log(&log_file, &format!("ERROR: Installation failed: {}", ex))?;
+        }
+        events::run_finished(&log_file, result.as_ref().err().map(ToString::to_string).as_deref());
+        let failed = result.is_err();
+        session.finish(result.map_err(|ex| ex.to_string()));
+        if failed {
+            offer_rollback(&log_file)?;
+        } else {
+            offer_injection_check(&options.target_path, &log_file)?;
+            offer_launch(&options.target_path, &log_file)?;
+        }
+        return Ok(());
+    }
+
+    // Display symmetrical banner.
+    for line in branding::banner() {
+        color_echo(ConsoleColor::Cyan, &line);
+    }
+    println!();
+
+    if first_run {
+        println!("{}", t!("wizard-welcome-intro"));
+        for item in tui::WELCOME_ITEMS {
+            println!("  - {}", t!(item));
+        }
+        println!();
+        let owned = args.confirm_ownership
+            || (!args.unattended && prompt::yes_no(&t!("first-run-ownership-prompt"), None)?);
+        if !owned {
+            color_echo(ConsoleColor::Yellow, &t!("first-run-ownership-declined"));
+            if args.unattended {
+                color_echo(ConsoleColor::Yellow, &t!("unattended-ownership-hint"));
+            }
+            write_log(&log_file, "Game ownership not confirmed; exiting.")?;
+            pause_and_exit().await;
+            return Ok(());
+        }
+        println!();
+    }
+
+    // Prompt for installation path (default: the last one used, else C:\Games\DesktopMate)
+    let default_path = saved.target_path.as_deref().unwrap_or(default_path);
+    let input = prompt::line(&t!("prompt-install-path", path = default_path), Some(default_path))?;
+    let target_path = if input.is_empty() {
+        default_path.to_owned()
+    } else {
+        input.to_owned()
+    };
+    color_echo(ConsoleColor::Green, &t!("install-dir-set", path = target_path));
+    write_log(
+        &log_file,
+        &format!("Installation directory set to: {}", target_path),
+    )?;
+
+    let latest_build = manifest.game.manifest_id.as_str();
+    let update_game = match game_build::update_available(Path::new(&target_path), &manifest.game) {
+        Some(installed) => {
+            color_echo(
+                ConsoleColor::Yellow,
+                &t!("game-update-available", installed = installed.as_str(), latest = latest_build),
+            );
+            let update = prompt::yes_no(&t!("game-update-prompt"), Some(args.update_game.unwrap_or(true)))?;
+            write_log(
+                &log_file,
+                &format!("Game build {} installed, {} available; updating: {}", installed, latest_build, update),
+            )?;
+            update
+        }
+        None => false,
+    };
+
+    let variants = shortcuts::Variant::ALL;
+    let labels: Vec<String> = variants.iter().map(|variant| variant.label()).collect();
+    let preferred = args.shortcuts.or(saved.shortcuts);
+    let remembered = variants.iter().position(|variant| Some(*variant) == preferred).unwrap_or(0);
+    let choice = prompt::choose(&t!("shortcuts-prompt"), &labels, &t!("shortcuts-choose"), Some(remembered))?;
+    let shortcut_variant = variants[choice];
+    write_log(&log_file, &format!("Shortcuts to create: {:?}", shortcut_variant))?;
+
+    // Names set in installer.toml aren't asked for.
+    let mut names = shortcut_names(&config, saved.shortcut_names.as_ref());
+    let ask_name = |name: &mut String, configured: bool, wanted: bool| -> io::Result<()> {
+        if wanted && !configured {
+            let answer = prompt::line(&t!("shortcuts-name-prompt", name = name.as_str()), Some(name))?;
+            if !answer.is_empty() {
+                *name = answer;
+            }
+        }
+        Ok(())
+    };
+    use shortcuts::Variant;
+    ask_name(
+        &mut names.no_console,
+        config.shortcut_name.is_some(),
+        shortcut_variant.has_no_console(),
+    )?;
+    ask_name(
+        &mut names.console,
+        config.console_shortcut_name.is_some(),
+        matches!(shortcut_variant, Variant::Console | Variant::Both),
+    )?;
+    let previous_names = saved.shortcut_names.clone();
+    let pin_to_start = shortcut_variant.has_no_console()
+        && prompt::yes_no(&t!("pin-prompt"), Some(args.pin_to_start.or(saved.pin_to_start).unwrap_or(false)))?;
+    let safe_mode = args.safe_mode_shortcut.or(saved.safe_mode_shortcut).unwrap_or(false);
+    let safe_mode_shortcut = prompt::yes_no(&t!("safe-mode-prompt"), Some(safe_mode))?;
+
+    save_settings(
+        &log_file,
+        settings::Settings {
+            ownership_confirmed: true,
+            target_path: Some(target_path.clone()),
+            shortcuts: Some(shortcut_variant),
+            shortcut_names: Some(names.clone()),
+            pin_to_start: Some(pin_to_start),
+            safe_mode_shortcut: Some(safe_mode_shortcut),
+            ..saved
+        },
+    )?;
+
+    let with_game = update_game || !game::is_installed(Path::new(&target_path));
+    color_echo(ConsoleColor::Cyan, &estimate.describe(with_game));
+    if !prompt::yes_no(&t!("download-estimate-continue"), Some(true))? {
+        write_log(&log_file, "User stopped after seeing the download estimate.")?;
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    let options = InstallOptions {
+        target_path,
+        steam_credentials: file_credentials,
+        update_avatar_loader: None,
+        backup_user_data: None,
+        keep_backups: config.keep_backups,
+        backup_max_age_days: config.backup_max_age_days,
+        conflict_policy: config.on_conflict,
+        shortcuts: shortcut_variant,
+        shortcut_names: names,
+        previous_shortcut_names: previous_names,
+        pin_to_start,
+        safe_mode_shortcut,
+        update_game,
+        repair: false,
+        reinstall: Vec::new(),
+    };
+    if let Err(ex) = run_install(base_dir, &log_file, &manifest, &options).await {
+        color_echo(ConsoleColor::Red, &t!("install-failed", error = ex));
+        write_log(&log_file, &format!("ERROR: Installation failed: {}", ex))?;
+        events::run_finished(&log_file, Some(&ex.to_string()));
+        offer_rollback(&log_file)?;
+        pause_and_exit().await;
+        return Ok(());
+    }
+
+    events::run_finished(&log_file, None);
+    print_summary(&options.target_path, &manifest);
+    offer_injection_check(&options.target_path, &log_file)?;
+    offer_launch(&options.target_path, &log_file)?;
+    println!("{}", t!("install-complete-exit"));
+    pause_and_exit().await;
+    Ok(())
+}
+
+/// The install folder a mode works on: the one on its command line, else the one used last time,
+/// else the manifest's default.
+fn chosen_target(path: Option<&String>, saved: &settings::Settings, default_path: &str) -> String {
+    path.or(saved.target_path.as_ref()).cloned().unwrap_or_else(|| default_path.to_owned())
+}
+
+/// Shortcut names from `installer.toml`, else the ones used last time, else the defaults.
+fn shortcut_names(config: &config::Config, saved: Option<&shortcuts::Names>) -> shortcuts::Names {
+    let base = saved.cloned().unwrap_or_default();
+    shortcuts::Names {
+        no_console: config.shortcut_name.clone().unwrap_or(base.no_console),
+        console: config.console_shortcut_name.clone().unwrap_or(base.console),
+    }
+}
+
+/// Options for the install in `target_path` from the answers saved last time (or given on the
+/// command line), for the subcommands that don't ask the setup questions. Available updates are
+/// accepted unless the command line declines them.
+fn saved_options(
+    config: &config::Config,
+    saved: &settings::Settings,
+    manifest: &manifest::ComponentManifest,
+    target_path: String,
+    steam_credentials: Option<(String, String)>,
+) -> InstallOptions {
+    let args = cli::args();
+    let update_game = args.update_game != Some(false)
+        && game_build::update_available(Path::new(&target_path), &manifest.game).is_some();
+    InstallOptions {
+        target_path,
+        steam_credentials,
+        update_avatar_loader: Some(args.update_mods.unwrap_or(true)),
+        backup_user_data: Some(args.backup.or(saved.backup_user_data).unwrap_or(true)),
+        keep_backups: config.keep_backups,
+        backup_max_age_days: config.backup_max_age_days,
+        conflict_policy: config.on_conflict,
+        shortcuts: args.shortcuts.or(saved.shortcuts).unwrap_or_default(),
+        shortcut_names: shortcut_names(config, saved.shortcut_names.as_ref()),
+        previous_shortcut_names: saved.shortcut_names.clone(),
+        pin_to_start: args.pin_to_start.or(saved.pin_to_start).unwrap_or(false),
+        safe_mode_shortcut: args.safe_mode_shortcut.or(saved.safe_mode_shortcut).unwrap_or(false),
+        update_game,
+        repair: false,
+        reinstall: Vec::new(),
+    }
+}
+
+/// Runs the install for `update` and `repair`. There is nobody to ask about a rollback, so a
+/// failed run is rolled back straight away. Returns the exit code.
+async fn run_without_questions(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
+) -> io::Result<i32> {
+    match run_install(base_dir, log_file, manifest, options).await {
+        Ok(()) => {
+            events::run_finished(log_file, None);
+            print_summary(&options.target_path, manifest);
+            Ok(0)
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Red, &t!("install-failed", error = ex));
+            write_log(log_file, &format!("ERROR: Installation failed: {}", ex))?;
+            events::run_finished(log_file, Some(&ex.to_string()));
+            if transaction::has_changes() {
+                roll_back_run(log_file)?;
+            } else {
+                transaction::commit();
+            }
+            Ok(1)
+        }
+    }
+}
+
+/// `uninstall` mode: deletes the install folder and the shortcuts pointing into it, after offering
+/// to back up user data. Unless `confirmed`, asks first. A folder without the game in it is never
+/// deleted.
+fn uninstall(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
+    confirmed: bool,
+) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(&options.target_path);
+    if !target.is_dir() {
+        color_echo(ConsoleColor::Yellow, &t!("uninstall-nothing", path = options.target_path.as_str()));
+        write_log(log_file, "Nothing to uninstall.")?;
+        return Ok(());
+    }
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = options.target_path.as_str()).into());
+    }
+    if !confirmed && !prompt::yes_no(&t!("uninstall-prompt", path = options.target_path.as_str()), Some(false))? {
+        write_log(log_file, "Uninstall cancelled by user.")?;
+        return Ok(());
+    }
+    back_up_user_data(base_dir, log_file, manifest, options)?;
+
+    for shortcut in shortcuts::remove_all(&options.shortcut_names)? {
+        write_log(log_file, &format!("Removed shortcut {}", shortcut.display()))?;
+    }
+    color_echo(ConsoleColor::Blue, &t!("uninstall-removing", path = options.target_path.as_str()));
+    fs::remove_dir_all(target)?;
+    color_echo(ConsoleColor::Green, &t!("uninstall-done"));
+    write_log(log_file, &format!("Removed {}", target.display()))?;
+    Ok(())
+}
+
+/// Restores the game's own `steam_api64.dll` in `target_path` and removes Goldberg's files.
+fn switch_to_steam_mode(log_file: &Path, target_path: &str) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    color_echo(ConsoleColor::Blue, &t!("steam-mode-restoring"));
+    for removed in unpatch::restore_original(target)? {
+        write_log(log_file, &format!("Removed Goldberg file {}", removed.display()))?;
+    }
+    color_echo(ConsoleColor::Green, &t!("steam-mode-done"));
+    write_log(log_file, "Original steam_api64.dll restored; Goldberg removed.")?;
+    Ok(())
+}
+
+/// `mod install`: installs the latest release of the mod `url` names (a GitHub repository, a
+/// Thunderstore package, or a GameBanana mod) into `target_path` and registers it there. A
+/// failure offers to undo what was copied.
+async fn install_mod_from_url(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    url: &str,
+    conflict_policy: config::ConflictPolicy,
+) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    // A Thunderstore package brings the packages it depends on, which are installed first.
+    let specs = if let Some(id) = gamebanana::parse_mod(url) {
+        vec![gamebanana::spec_for(&gamebanana::profile(id).await?)]
+    } else if let Some((namespace, name)) = thunderstore::parse_package(url) {
+        let package = thunderstore::find(&namespace, &name, log_file).await?;
+        let (packages, missing) = thunderstore::with_dependencies(package, log_file).await?;
+        for dependency in missing {
+            color_echo(ConsoleColor::Yellow, &t!("thunderstore-dependency-missing", package = dependency.as_str()));
+            write_log(log_file, &format!("WARNING: Dependency {} is not on Thunderstore.", dependency))?;
+        }
+        packages.iter().map(thunderstore::spec_for).collect()
+    } else if let Some((owner, repo)) = mods::parse_github_url(url) {
+        vec![mods::spec_for(manifest, &owner, &repo)]
+    } else {
+        return Err(t!("err-mod-url", url = url).into());
+    };
+    let journal = transaction::begin(target)?;
+    write_log(log_file, &format!("Recording changes in {}", journal.display()))?;
+    let result = async {
+        for mut spec in specs {
+            install_or_update_mod(target_path, log_file, &spec, Some(true), conflict_policy, false).await?;
+            if spec.dll.is_empty() {
+                let dll = mods::main_dll(target, &spec.component)?;
+                spec.dll = dll.ok_or_else(|| t!("err-mod-not-installed", mod = spec.name.as_str()))?;
+            }
+            mods::register(target, spec.clone())?;
+            color_echo(ConsoleColor::Green, &t!("mod-registered", mod = spec.name.as_str()));
+            write_log(log_file, &format!("Registered {} ({}/{}) for updates.", spec.name, spec.owner, spec.repo))?;
+        }
+        Ok::<(), Box<dyn Error>>(())
+    }
+    .await;
+    if let Err(ex) = result {
+        offer_rollback(log_file)?;
+        return Err(ex);
+    }
+    transaction::commit();
+    Ok(())
+}
+
+/// `mod update`: checks the mods installed in `target_path` (just `name`, or all of them) for
+/// newer releases, lists them with their release notes, and installs the ones confirmed, or
+/// all of them with `yes`. A failure offers to undo what was copied.
+async fn update_mods(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    name: Option<&str>,
+    yes: bool,
+    conflict_policy: config::ConflictPolicy,
+) -> Result<(), Box<dyn Error>> {
+    // Release notes longer than this are cut short; the release page has the rest.
+    const NOTES_LINES: usize = 12;
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    color_echo(ConsoleColor::Blue, &t!("mod-updates-checking"));
+    let mut checks = mods::check_updates(target, manifest, log_file).await?;
+    if let Some(name) = name {
+        checks.retain(|check| {
+            check.spec.name.eq_ignore_ascii_case(name) || check.spec.component.eq_ignore_ascii_case(name)
+        });
+        if checks.is_empty() {
+            return Err(t!("err-mod-unknown", mod = name).into());
+        }
+    }
+    if checks.is_empty() {
+        println!("{}", t!("mod-updates-no-mods"));
+        return Ok(());
+    }
+
+    let header = [t!("mod-updates-column-mod"), t!("mod-updates-column-installed"), t!("mod-updates-column-latest")];
+    let rows: Vec<[String; 3]> = checks
+        .iter()
+        .map(|check| {
+            let installed = if check.installed.is_empty() { t!("summary-unknown") } else { check.installed.clone() };
+            let latest = match &check.latest {
+                Ok(release) => release.tag_name.clone(),
+                Err(_) => t!("mod-updates-lookup-failed"),
+            };
+            [check.spec.name.clone(), installed, latest]
+        })
+        .collect();
+    let widths: Vec<usize> = (0..3)
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].chars().count()).max().unwrap_or(0))
+        .collect();
+    let line = |row: &[String; 3]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("  {}", cells.join("  ").trim_end())
+    };
+    println!("{}", line(&header));
+    for (check, row) in checks.iter().zip(&rows) {
+        match &check.latest {
+            Err(ex) => {
+                color_echo(ConsoleColor::Red, &line(row));
+                write_log(log_file, &format!("WARNING: Could not look up {}: {}", check.spec.name, ex))?;
+            }
+            Ok(_) if check.is_update() => color_echo(ConsoleColor::Yellow, &line(row)),
+            Ok(_) => println!("{}", line(row)),
+        }
+        write_log(log_file, &format!("{}: installed {}, latest {}", row[0], row[1], row[2]))?;
+    }
+
+    let updates: Vec<&mods::UpdateCheck> = checks.iter().filter(|check| check.is_update()).collect();
+    if updates.is_empty() {
+        color_echo(ConsoleColor::Green, &t!("mod-updates-none"));
+        return Ok(());
+    }
+    for check in &updates {
+        let Ok(release) = &check.latest else { continue };
+        println!();
+        let heading = t!("mod-updates-notes", mod = check.spec.name.as_str(), version = release.tag_name.as_str());
+        color_echo(ConsoleColor::Cyan, &heading);
+        let notes: Vec<&str> = release.notes.lines().map(str::trim_end).filter(|line| !line.is_empty()).collect();
+        if notes.is_empty() {
+            println!("    {}", t!("mod-updates-no-notes"));
+        }
+        for note in notes.iter().take(NOTES_LINES) {
+            println!("    {}", note);
+        }
+        if notes.len() > NOTES_LINES {
+            println!("    ...");
+        }
+    }
+    println!();
+
+    let mut chosen = Vec::new();
+    for check in updates {
+        if yes || prompt::yes_no(&t!("mod-update-prompt", mod = check.spec.name.as_str()), Some(true))? {
+            chosen.push(check);
+        } else {
+            write_log(log_file, &format!("Skipping the {} update.", check.spec.name))?;
+        }
+    }
+    if chosen.is_empty() {
+        return Ok(());
+    }
+    let journal = transaction::begin(target)?;
+    write_log(log_file, &format!("Recording changes in {}", journal.display()))?;
+    let result = async {
+        for check in &chosen {
+            install_or_update_mod(target_path, log_file, &check.spec, Some(true), conflict_policy, false).await?;
+        }
+        Ok::<(), Box<dyn Error>>(())
+    }
+    .await;
+    if let Err(ex) = result {
+        offer_rollback(log_file)?;
+        return Err(ex);
+    }
+    transaction::commit();
+    color_echo(ConsoleColor::Green, &t!("mod-updates-done", count = chosen.len()));
+    Ok(())
+}
+
+/// `mod rollback`: puts back the kept version `to` of the mod `name` in `target_path`, or the
+/// newest kept one that isn't installed. A failure offers to undo what was copied.
+fn rollback_mod(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    name: &str,
+    to: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    let state = state::InstallState::load(target)?;
+    let component =
+        mods::installed_component(&state, manifest, name).ok_or_else(|| t!("err-mod-unknown", mod = name))?;
+    if state.is_component_disabled(&component) {
+        return Err(t!("err-mod-rollback-disabled", mod = name).into());
+    }
+    let installed = state.version(&component).unwrap_or_default().to_owned();
+    let kept = mod_history::list(target, &component)?;
+    let chosen = match to {
+        Some(to) => kept.iter().find(|saved| version::same(&saved.component.version, to)),
+        None => kept.iter().find(|saved| !version::same(&saved.component.version, &installed)),
+    };
+    let Some(saved) = chosen else {
+        if kept.is_empty() {
+            return Err(t!("err-mod-nothing-kept", mod = name).into());
+        }
+        let versions: Vec<&str> = kept.iter().map(|saved| saved.component.version.as_str()).collect();
+        return Err(t!("err-mod-no-kept-version", mod = name, versions = versions.join(", ")).into());
+    };
+    let journal = transaction::begin(target)?;
+    write_log(log_file, &format!("Recording changes in {}", journal.display()))?;
+    match mod_history::restore(target, &component, saved) {
+        Ok(files) => {
+            transaction::commit();
+            let version = saved.component.version.as_str();
+            let from = installed.as_str();
+            color_echo(ConsoleColor::Green, &t!("mod-rolled-back", mod = name, version = version, from = from));
+            write_log(log_file, &format!(
+                "Rolled {} back from {} to {} (saved {}): {}",
+                name, installed, version, saved.saved_at, files.join(", ")
+            ))?;
+            Ok(())
+        }
+        Err(ex) => {
+            offer_rollback(log_file)?;
+            Err(ex.into())
+        }
+    }
+}
+
+/// `mod enable` and `mod disable`: moves the files of the mod `name` in `target_path` back to
+/// `Mods`, or out of it.
+fn toggle_mod(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    name: &str,
+    enabled: bool,
+) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    let moved = if enabled { mods::enable(target, manifest, name)? } else { mods::disable(target, manifest, name)? };
+    let key = match (enabled, moved.is_empty()) {
+        (true, false) => "mod-enabled",
+        (true, true) => "mod-already-enabled",
+        (false, false) => "mod-disabled",
+        (false, true) => "mod-already-disabled",
+    };
+    color_echo(ConsoleColor::Green, &t!(key, mod = name));
+    for file in &moved {
+        println!("  {}", file);
+    }
+    let action = if enabled { "Enabled" } else { "Disabled" };
+    write_log(log_file, &format!("{} {}: moved {}", action, name, moved.join(", ")))?;
+    Ok(())
+}
+
+/// `profile`: saves, switches to, lists or deletes mod profiles of the install in `target_path`.
+fn manage_profile(log_file: &Path, target_path: &str, action: &cli::ProfileCommand) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    match action {
+        cli::ProfileCommand::Save { name } => {
+            let files = profiles::save(target, name)?;
+            color_echo(ConsoleColor::Green, &t!("profile-saved", profile = name.as_str(), files = files.len()));
+            write_log(log_file, &format!("Saved profile {}: {}", name, files.join(", ")))?;
+        }
+        cli::ProfileCommand::Switch { name } => {
+            let switch = profiles::switch(target, name)?;
+            for file in &switch.disabled {
+                println!("  - {}", file);
+            }
+            for file in &switch.enabled {
+                println!("  + {}", file);
+            }
+            for file in &switch.missing {
+                color_echo(ConsoleColor::Yellow, &t!("profile-file-missing", file = file.as_str()));
+            }
+            color_echo(ConsoleColor::Green, &t!("profile-switched", profile = name.as_str()));
+            write_log(log_file, &format!(
+                "Switched to profile {}: enabled {}; disabled {}; missing {}",
+                name,
+                switch.enabled.join(", "),
+                switch.disabled.join(", "),
+                switch.missing.join(", "),
+            ))?;
+        }
+        cli::ProfileCommand::List => {
+            let state = state::InstallState::load(target)?;
+            let mut any = false;
+            for (name, files) in state.profiles() {
+                any = true;
+                let active = state.active_profile().is_some_and(|active| active.eq_ignore_ascii_case(name));
+                let line = t!("profile-entry", profile = name.as_str(), files = files.len());
+                println!("{} {}", if active { "*" } else { " " }, line);
+            }
+            if !any {
+                println!("{}", t!("profile-none"));
+            }
+        }
+        cli::ProfileCommand::Delete { name } => {
+            if !profiles::delete(target, name)? {
+                return Err(t!("err-profile-unknown", profile = name.as_str()).into());
+            }
+            color_echo(ConsoleColor::Green, &t!("profile-deleted", profile = name.as_str()));
+            write_log(log_file, &format!("Deleted profile {}", name))?;
+        }
+    }
+    Ok(())
+}
+
+/// `mod search`: lists the Thunderstore packages matching `query`.
+async fn search_thunderstore(log_file: &Path, query: &str) -> Result<(), Box<dyn Error>> {
+    let packages = thunderstore::search(query, log_file).await?;
+    if packages.is_empty() {
+        color_echo(ConsoleColor::Yellow, &t!("thunderstore-no-results", query = query));
+        return Ok(());
+    }
+    for package in &packages {
+        let Some(latest) = package.latest() else { continue };
+        let version = latest.version_number.as_str();
+        let name = package.full_name.as_str();
+        color_echo(ConsoleColor::Cyan, &t!("thunderstore-result", package = name, version = version));
+        if !latest.description.is_empty() {
+            println!("    {}", latest.description);
+        }
+    }
+    println!("{}", t!("thunderstore-install-hint"));
+    Ok(())
+}
+
+/// `mod versions`: lists the versions of the Thunderstore package `package`, newest first.
+async fn list_thunderstore_versions(log_file: &Path, package: &str) -> Result<(), Box<dyn Error>> {
+    let Some((namespace, name)) = thunderstore::parse_package(package) else {
+        return Err(t!("err-mod-url", url = package).into());
+    };
+    let package = thunderstore::find(&namespace, &name, log_file).await?;
+    color_echo(ConsoleColor::Cyan, &package.full_name);
+    for version in package.versions.iter().filter(|version| version.is_active) {
+        let dependencies = version.dependencies.join(", ");
+        if dependencies.is_empty() {
+            println!("  {}", version.version_number);
+        } else {
+            println!("  {}  ({})", version.version_number, t!("thunderstore-needs", packages = dependencies));
+        }
+    }
+    Ok(())
+}
+
+/// Deletes MelonLoader from `target_path`, leaving the game and the mods' folders.
+fn remove_melonloader(log_file: &Path, target_path: &str, confirmed: bool) -> Result<(), Box<dyn Error>> {
+    let target = Path::new(target_path);
+    if !game::is_installed(target) {
+        return Err(t!("err-no-game", path = target_path).into());
+    }
+    if !confirmed && !prompt::yes_no(&t!("melon-remove-prompt", path = target_path), Some(false))? {
+        write_log(log_file, "MelonLoader removal cancelled by user.")?;
+        return Ok(());
+    }
+    color_echo(ConsoleColor::Blue, &t!("melon-removing"));
+    let removed = melonloader::remove(target)?;
+    for path in &removed {
+        write_log(log_file, &format!("Removed MelonLoader file {}", path.display()))?;
+    }
+    if removed.is_empty() {
+        color_echo(ConsoleColor::Yellow, &t!("melon-remove-nothing"));
+        write_log(log_file, "No MelonLoader files found.")?;
+    } else {
+        color_echo(ConsoleColor::Green, &t!("melon-remove-done"));
+        write_log(log_file, "MelonLoader removed.")?;
+    }
+    Ok(())
+}
+
+/// Puts the no-console shortcut in the Start menu and pins it. A failure is only a warning, as
+/// the desktop shortcuts are in place either way. Returns the Start menu shortcut if made.
+fn pin_to_start(
+    log_file: &Path,
+    target_path: &str,
+    names: &shortcuts::Names,
+    previous: Option<&shortcuts::Names>,
+) -> io::Result<Option<PathBuf>> {
+    match shortcuts::pin_to_start(target_path, names, previous) {
+        Ok(path) => {
+            color_echo(ConsoleColor::Green, &t!("pin-done", name = names.no_console.as_str()));
+            write_log(log_file, &format!("Added Start menu shortcut {} and requested pinning.", path.display()))?;
+            Ok(Some(path))
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Yellow, &t!("pin-failed", error = ex));
+            write_log(log_file, &format!("WARNING: Pinning to Start failed: {}", ex))?;
+            Ok(None)
+        }
+    }
+}
+
+/// `--mods`: the components of the catalog mods picked, with `current` preselected.
+fn choose_mods(catalog: &catalog::Catalog, current: &[String]) -> io::Result<Vec<String>> {
+    if catalog.mods.is_empty() {
+        color_echo(ConsoleColor::Yellow, &t!("catalog-empty"));
+        return Ok(current.to_vec());
+    }
+    let options: Vec<String> = catalog
+        .mods
+        .iter()
+        .map(|entry| {
+            if entry.description.is_empty() {
+                entry.spec.name.clone()
+            } else {
+                format!("{} - {}", entry.spec.name, entry.description)
+            }
+        })
+        .collect();
+    let selected: Vec<usize> = catalog
+        .mods
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| current.iter().any(|component| component.eq_ignore_ascii_case(&entry.spec.component)))
+        .map(|(index, _)| index)
+        .collect();
+    let picked = prompt::checklist(&t!("catalog-heading"), &options, &selected, &t!("catalog-choose"))?;
+    Ok(picked.into_iter().map(|index| catalog.mods[index].spec.component.clone()).collect())
+}
+
+/// `--melonloader`: the release named, or else the one picked from LavaGang's list.
+async fn choose_melonloader(
+    log_file: &Path,
+    requested: Option<&str>,
+    channel: config::Channel,
+    manifest: &manifest::ComponentManifest,
+) -> Result<String, Box<dyn Error>> {
+    if let Some(requested) = requested {
+        return Ok(requested.to_owned());
+    }
+    let releases = melonloader::releases(channel, log_file).await?;
+    let mut options = vec![melonloader::LATEST.to_owned()];
+    options.extend(releases.iter().map(|release| {
+        if version::same(&release.tag_name, &manifest.melonloader.version) {
+            t!("melon-version-recommended", version = release.tag_name.as_str())
+        } else {
+            release.tag_name.clone()
+        }
+    }));
+    let current = releases.iter().position(|release| version::same(&release.tag_name, &manifest.melonloader.version));
+    let index = prompt::choose(&t!("melon-versions"), &options, &t!("melon-choose"), current.map(|index| index + 1))?;
+    Ok(match index {
+        0 => melonloader::LATEST.to_owned(),
+        index => releases[index - 1].tag_name.clone(),
+    })
+}
+
+/// Remembers this run's setup answers as the next run's defaults. Failing to is only logged.
+fn save_settings(log_file: &Path, settings: settings::Settings) -> io::Result<()> {
+    if let Err(ex) = settings.save() {
+        write_log(log_file, &format!("WARNING: Could not save settings: {}", ex))?;
+    }
+    Ok(())
+}
+
+/// `--fix-shortcuts [path]` mode: recreates the desktop shortcuts for the install at
+/// `target_path`, and removes copies stranded on a desktop folder Windows no longer uses.
+fn fix_shortcuts(
+    log_file: &Path,
+    target_path: &str,
+    variant: shortcuts::Variant,
+    safe_mode: bool,
+    names: &shortcuts::Names,
+    previous: Option<&shortcuts::Names>,
+    pin: bool,
+) -> Result<(), Box<dyn Error>> {
+    if !game::executable(Path::new(target_path)).is_file() {
+        return Err(t!("err-fix-shortcuts-no-game", path = target_path).into());
+    }
+    let desktop = dirs::desktop_dir().ok_or_else(|| t!("err-no-desktop"))?;
+    transaction::begin(Path::new(target_path))?;
+    let result = (|| -> Result<(), Box<dyn Error>> {
+        for path in shortcuts::remove_elsewhere(&desktop, names)? {
+            color_echo(ConsoleColor::Yellow, &t!("fix-shortcuts-removed", path = path.display().to_string()));
+            write_log(log_file, &format!("Removed stranded shortcut {}", path.display()))?;
+        }
+        for path in shortcuts::create(&desktop, target_path, variant, safe_mode, names, previous)? {
+            color_echo(ConsoleColor::Green, &t!("fix-shortcuts-created", path = path.display().to_string()));
+            write_log(log_file, &format!("Recreated shortcut {}", path.display()))?;
+        }
+        if pin {
+            pin_to_start(log_file, target_path, names, previous)?;
+        }
+        Ok(())
+    })();
+    match result {
+        Ok(()) => {
+            transaction::commit();
+            Ok(())
+        }
+        Err(ex) => {
+            offer_rollback(log_file)?;
+            Err(ex)
+        }
+    }
+}
+
+/// After a successful run, offers to start the game briefly to see whether MelonLoader loads.
+/// The outcome is reported; the run has succeeded either way.
+fn offer_injection_check(target_path: &str, log_file: &Path) -> io::Result<()> {
+    let default = cli::args().check_injection.unwrap_or(false);
+    if !prompt::yes_no(&t!("injection-prompt", seconds = injection::TIMEOUT.as_secs()), Some(default))? {
+        return Ok(());
+    }
+    color_echo(ConsoleColor::Blue, &t!("injection-running"));
+    write_log(log_file, "Starting the game to check MelonLoader injection.")?;
+    match injection::check(Path::new(target_path)) {
+        Ok(injection::Outcome::Injected { version }) => {
+            color_echo(ConsoleColor::Green, &t!("injection-ok", version = version.as_str()));
+            write_log(log_file, &format!("MelonLoader {} loaded into the game.", version))?;
+        }
+        Ok(injection::Outcome::GameExited { code }) => {
+            let code = code.map_or_else(|| "?".to_owned(), |code| code.to_string());
+            color_echo(ConsoleColor::Red, &t!("injection-game-exited", code = code.as_str()));
+            write_log(log_file, &format!("WARNING: The game exited ({}) before MelonLoader loaded.", code))?;
+        }
+        Ok(injection::Outcome::NoLog) => {
+            color_echo(ConsoleColor::Red, &t!("injection-no-log", seconds = injection::TIMEOUT.as_secs()));
+            write_log(log_file, "WARNING: MelonLoader wrote no log; injection appears to have failed.")?;
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Yellow, &t!("injection-failed", error = ex));
+            write_log(log_file, &format!("WARNING: Could not run the injection check: {}", ex))?;
+        }
+    }
+    Ok(())
+}
+
+/// After a successful run, offers to start the game the way either shortcut would.
+fn offer_launch(target_path: &str, log_file: &Path) -> io::Result<()> {
+    let options = [t!("launch-console"), t!("launch-no-console"), t!("launch-no")];
+    let choice = prompt::choose(&t!("launch-prompt"), &options, &t!("launch-choose"), Some(2))?;
+    let arguments: &[&str] = match choice {
+        0 => &[],
+        1 => &[shortcuts::HIDE_CONSOLE_ARG],
+        _ => return Ok(()),
+    };
+    let exe_path = game::executable(Path::new(target_path));
+    match std::process::Command::new(&exe_path).args(arguments).current_dir(target_path).spawn() {
+        Ok(_) => {
+            color_echo(ConsoleColor::Green, &t!("launch-started"));
+            write_log(log_file, &format!("Launched {} {}", exe_path.display(), arguments.join(" ")))?;
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Red, &t!("launch-failed", error = ex));
+            write_log(log_file, &format!("ERROR: Could not launch the game: {}", ex))?;
+        }
+    }
+    Ok(())
+}
+
+/// After a failed run, asks whether to undo the files this run changed.
+fn offer_rollback(log_file: &Path) -> io::Result<()> {
+    if !transaction::has_changes() {
+        transaction::commit();
+        return Ok(());
+    }
+    if !prompt::yes_no(&t!("rollback-prompt"), Some(cli::args().rollback.unwrap_or(true)))? {
+        transaction::commit();
+        color_echo(ConsoleColor::Yellow, &t!("rollback-declined"));
+        write_log(log_file, "User kept the changes from the failed run.")?;
+        return Ok(());
+    }
+    roll_back_run(log_file)
+}
+
+/// Undoes every change this run recorded.
+fn roll_back_run(log_file: &Path) -> io::Result<()> {
+    match transaction::roll_back() {
+        Ok(count) => {
+            color_echo(ConsoleColor::Green, &t!("rollback-done", count = count));
+            write_log(log_file, &format!("Rolled back {} change(s) from the failed run.", count))?;
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Red, &t!("rollback-incomplete", error = ex));
+            write_log(log_file, &format!("ERROR: Rollback incomplete: {}", ex))?;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the end-of-run report of what is now installed where.
+fn print_summary(target_path: &str, manifest: &manifest::ComponentManifest) {
+    let state = state::InstallState::load(Path::new(target_path)).unwrap_or_default();
+    let read_version = |component: &str| state.version(component).map_or_else(|| t!("summary-unknown"), str::to_owned);
+    println!();
+    color_echo(ConsoleColor::Cyan, &t!("summary-title"));
+    println!("  {}", t!("summary-install-dir", path = target_path));
+    println!("  {}", t!("summary-game-build", manifest = manifest.game.manifest_id));
+    println!("  {}", t!("summary-melonloader", version = read_version("MelonLoader")));
+    for spec in manifest.mods() {
+        println!("  {}", t!("summary-mod", mod = spec.name.as_str(), version = read_version(&spec.component)));
+    }
+    println!();
+}
+
+/// Runs every installation step in order, stopping at the first failure.
+async fn run_install(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
+) -> Result<(), Box<dyn Error>> {
+    let target_path = options.target_path.as_str();
+
+    // Ensure target directory exists.
+    fs::create_dir_all(target_path)?;
+    // Mods added with `mod install` are kept up to date along with the manifest's.
+    let manifest = &mods::with_registered(manifest, Path::new(target_path))?;
+    let journal = transaction::begin(Path::new(target_path))?;
+    write_log(log_file, &format!("Recording changes in {}", journal.display()))?;
+    for component in state::migrate_legacy(Path::new(target_path))? {
+        write_log(log_file, &format!("Moved the recorded {} version into {}.", component, state::STATE_FILE))?;
+    }
+    for found in adopt::adopt_manual_installs(Path::new(target_path), &manifest.mods())? {
+        let version = if found.version.is_empty() { t!("summary-unknown") } else { found.version.clone() };
+        color_echo(ConsoleColor::Blue, &t!("adopt-found", component = found.component, version = version.as_str()));
+        write_log(
+            log_file,
+            &format!("Adopted manually installed {} (version: {}).", found.component, version),
+        )?;
+    }
+    if defender::patch_lost_since_last_run(Path::new(target_path)) {
+        color_echo(ConsoleColor::Yellow, &t!("av-patch-lost"));
+        color_echo(ConsoleColor::Yellow, &t!("av-exclusion-hint"));
+        write_log(log_file, "WARNING: The Goldberg DLL recorded last run is missing or changed; likely quarantined.")?;
+    }
+    back_up_user_data(base_dir, log_file, manifest, options)?;
+
+    tui::set_plan(planned_steps(manifest));
+    // A repair is meant to redo every step, so it doesn't pick up an unfinished run.
+    let mut progress = if options.repair {
+        resume::Progress::fresh(manifest)
+    } else {
+        resume::Progress::load(Path::new(target_path), manifest)
+    };
+    if progress.has_completed_steps() {
+        color_echo(ConsoleColor::Cyan, &t!("resume-continuing"));
+        write_log(log_file, "Resuming an unfinished install.")?;
+    }
+    let mut outputs = StepOutputs::default();
+    let mut skipped = Vec::new();
+    for step in &manifest.steps {
+        let name = sidebar_steps(manifest, step).last().map(tui::InstallStep::label).unwrap_or_default();
+        if progress.is_completed(step) {
+            color_echo(ConsoleColor::Yellow, &t!("resume-step-done", step = name.as_str()));
+            write_log(log_file, &format!("Step {} completed by an earlier run; skipping.", name))?;
+            if let manifest::Step::Goldberg = step {
+                let state = state::InstallState::load(Path::new(target_path))?;
+                outputs.goldberg_sha256 = state.version(defender::COMPONENT).map(str::to_owned);
+            }
+            continue;
+        }
+        loop {
+            let ex = match run_step(base_dir, log_file, manifest, options, step, &mut outputs).await {
+                Ok(()) => {
+                    if let Err(ex) = progress.complete(Path::new(target_path), step) {
+                        write_log(log_file, &format!("WARNING: Could not record the finished step: {}", ex))?;
+                    }
+                    break;
+                }
+                Err(ex) => ex,
+            };
+            match recover_from_failure(log_file, step, &name, ex.as_ref())? {
+                Recovery::Retry => write_log(log_file, &format!("Retrying {} after: {}", name, ex))?,
+                Recovery::Skip => {
+                    color_echo(ConsoleColor::Yellow, &t!("step-failed-continuing", step = name.as_str(), error = ex));
+                    write_log(log_file, &format!("WARNING: Step {} failed; skipped: {}", name, ex))?;
+                    skipped.push(name);
+                    break;
+                }
+                Recovery::Abort => return Err(ex),
+            }
+        }
+    }
+    transaction::commit();
+    if let Err(ex) = resume::clear(Path::new(target_path)) {
+        write_log(log_file, &format!("WARNING: Could not delete {}: {}", resume::PROGRESS_FILE, ex))?;
+    }
+
+    // Check that everything the earlier steps installed is actually in place.
+    begin_step(log_file, tui::InstallStep::HealthCheck);
+    color_echo(ConsoleColor::Blue, &t!("health-running"));
+    write_log(log_file, "Running post-install health check.")?;
+    let results = health::run(
+        Path::new(target_path),
+        outputs.goldberg_sha256.as_deref(),
+        &manifest.mods(),
+        &outputs.created_shortcuts.iter().map(PathBuf::as_path).collect::<Vec<_>>(),
+    );
+    report_health(log_file, &results)?;
+    if !skipped.is_empty() {
+        color_echo(ConsoleColor::Yellow, &t!("steps-skipped", steps = skipped.join(", ")));
+    }
+
+    // A receipt for support; failing to write it doesn't fail the install.
+    match receipt::write(Path::new(target_path), manifest) {
+        Ok(path) => write_log(log_file, &format!("Install receipt written to {}", path.display()))?,
+        Err(ex) => write_log(log_file, &format!("WARNING: Could not write the install receipt: {}", ex))?,
+    }
+    Ok(())
+}
+
+/// Prints and logs the health check `results`, returning how many checks failed.
+fn report_health(log_file: &Path, results: &[health::CheckResult]) -> io::Result<usize> {
+    for result in results {
+        let line = match &result.detail {
+            Some(detail) => format!("{} ({})", result.name, detail),
+            None => result.name.clone(),
+        };
+        if result.passed {
+            color_echo(ConsoleColor::Green, &t!("health-pass", check = line));
+        } else {
+            color_echo(ConsoleColor::Red, &t!("health-fail", check = line));
+        }
+        write_log(log_file, &format!("Health check {}: {}", if result.passed { "PASS" } else { "FAIL" }, line))?;
+    }
+    let failed = results.iter().filter(|result| !result.passed).count();
+    if failed > 0 {
+        color_echo(ConsoleColor::Yellow, &t!("health-some-failed", count = failed));
+    } else {
+        color_echo(ConsoleColor::Green, &t!("health-all-passed"));
+    }
+    Ok(failed)
+}
+
+/// Re-hashes the files the installer recorded in `root` and reports the ones that are missing
+/// or changed. Returns the damaged components, and those recorded without hashes, whose files
+/// can't be checked.
+fn check_component_files(log_file: &Path, root: &Path) -> io::Result<(Vec<String>, Vec<String>)> {
+    color_echo(ConsoleColor::Blue, &t!("integrity-checking"));
+    let damaged = components::check(root)?;
+    for damage in &damaged {
+        color_echo(
+            ConsoleColor::Red,
+            &t!(
+                "integrity-damaged",
+                component = damage.component.as_str(),
+                missing = damage.missing.len(),
+                modified = damage.modified.len(),
+            ),
+        );
+        for file in &damage.missing {
+            color_echo(ConsoleColor::Red, &t!("verify-missing", file = file.as_str()));
+            write_log(log_file, &format!("Integrity Missing: {} ({})", file, damage.component))?;
+        }
+        for file in &damage.modified {
+            color_echo(ConsoleColor::Red, &t!("verify-modified", file = file.as_str()));
+            write_log(log_file, &format!("Integrity Modified: {} ({})", file, damage.component))?;
+        }
+    }
+    if damaged.is_empty() {
+        color_echo(ConsoleColor::Green, &t!("integrity-ok"));
+        write_log(log_file, "Every recorded component file matches its hash.")?;
+    }
+    let unhashed = components::unhashed(root)?;
+    if !unhashed.is_empty() {
+        color_echo(ConsoleColor::Yellow, &t!("integrity-unhashed", components = unhashed.join(", ")));
+        write_log(log_file, &format!("Components recorded without hashes: {}", unhashed.join(", ")))?;
+    }
+    Ok((damaged.into_iter().map(|damage| damage.component).collect(), unhashed))
+}
+
+/// The sidebar steps for the manifest's pipeline, ending with the health check.
+fn planned_steps(manifest: &manifest::ComponentManifest) -> Vec<tui::InstallStep> {
+    let mut planned: Vec<tui::InstallStep> =
+        manifest.steps.iter().flat_map(|step| sidebar_steps(manifest, step)).collect();
+    planned.push(tui::InstallStep::HealthCheck);
+    planned
+}
+
+/// The sidebar steps one pipeline step shows as.
+fn sidebar_steps(manifest: &manifest::ComponentManifest, step: &manifest::Step) -> Vec<tui::InstallStep> {
+    match step {
+        manifest::Step::Game => vec![tui::InstallStep::DepotDownloader, tui::InstallStep::GameFiles],
+        manifest::Step::Goldberg => vec![tui::InstallStep::GoldbergPatch],
+        manifest::Step::MelonLoader => vec![tui::InstallStep::MelonLoader],
+        manifest::Step::AvatarLoader | manifest::Step::Mod(_) => {
+            let name = manifest.mod_for(step).map(|spec| spec.name.clone()).unwrap_or_default();
+            vec![tui::InstallStep::Mod(name)]
+        }
+        manifest::Step::Shortcuts => vec![tui::InstallStep::Shortcuts],
+    }
+}
+
+/// What the steps hand on to the health check.
+#[derive(Default)]
+struct StepOutputs {
+    goldberg_sha256: Option<String>,
+    created_shortcuts: Vec<PathBuf>,
+}
+
+/// What to do about a failed step.
+enum Recovery {
+    Retry,
+    Skip,
+    Abort,
+}
+
+/// Asks whether to retry, skip, or abort after `step` failed with `error`; aborting rolls the
+/// run back straight away. Without a console or in an unattended run there is nobody to ask, so
+/// an optional step is skipped and a critical one aborts, leaving the rollback to the caller.
+fn recover_from_failure(
+    log_file: &Path,
+    step: &manifest::Step,
+    name: &str,
+    error: &dyn Error,
+) -> io::Result<Recovery> {
+    if !prompt::is_interactive() || cli::args().unattended {
+        return Ok(if step.is_critical() { Recovery::Abort } else { Recovery::Skip });
+    }
+    let _console = tui::suspend();
+    let options = [t!("recovery-retry"), t!("recovery-skip"), t!("recovery-abort")];
+    // A timed-out question must not retry forever, so the default gives up on the step.
+    let default = if step.is_critical() { 2 } else { 1 };
+    let heading = t!("recovery-heading", step = name, error = error.to_string());
+    let choice = prompt::choose(&heading, &options, &t!("recovery-choose"), Some(default))?;
+    write_log(log_file, &format!("Step {} failed; user chose: {}", name, ["retry", "skip", "abort"][choice]))?;
+    Ok(match choice {
+        0 => Recovery::Retry,
+        1 => Recovery::Skip,
+        _ => {
+            roll_back_run(log_file)?;
+            Recovery::Abort
+        }
+    })
+}
+
+/// Runs one pipeline step.
+async fn run_step(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
+    step: &manifest::Step,
+    outputs: &mut StepOutputs,
+) -> Result<(), Box<dyn Error>> {
+    let target_path = options.target_path.as_str();
+    match step {
+        manifest::Step::Game => {
+            begin_step(log_file, tui::InstallStep::DepotDownloader);
+            let depot_downloader_exe = ensure_depot_downloader(base_dir, log_file, manifest).await?;
+            begin_step(log_file, tui::InstallStep::GameFiles);
+            install_game(log_file, manifest, options, &depot_downloader_exe).await?;
+        }
+        manifest::Step::Goldberg => {
+            begin_step(log_file, tui::InstallStep::GoldbergPatch);
+            outputs.goldberg_sha256 = Some(apply_goldberg_patch(target_path, log_file, manifest).await?);
+        }
+        manifest::Step::MelonLoader => {
+            begin_step(log_file, tui::InstallStep::MelonLoader);
+            let reinstall = options.reinstall.iter().any(|component| component == "MelonLoader");
+            update_melonloader_if_needed(target_path, log_file, &manifest.melonloader, reinstall).await?;
+        }
+        manifest::Step::AvatarLoader | manifest::Step::Mod(_) => {
+            let Some(spec) = manifest.mod_for(step) else { return Ok(()) };
+            begin_step(log_file, tui::InstallStep::Mod(spec.name.clone()));
+            let accept_update = options.update_avatar_loader;
+            let reinstall = options.reinstall.contains(&spec.component);
+            install_or_update_mod(target_path, log_file, spec, accept_update, options.conflict_policy, reinstall)
+                .await?;
+        }
+        manifest::Step::Shortcuts => {
+            begin_step(log_file, tui::InstallStep::Shortcuts);
+            outputs.created_shortcuts = create_shortcuts(log_file, options)?;
+        }
+    }
+    Ok(())
+}
+
+/// Marks `step` as running on the progress screen and in the structured log.
+fn begin_step(log_file: &Path, step: tui::InstallStep) {
+    events::begin_step(log_file, &step.id());
+    tui::begin_step(step);
+}
+
+/// Downloads the game depot unless it is already installed and no update was accepted.
+async fn install_game(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
+    depot_downloader_exe: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let target_path = options.target_path.as_str();
+    let fresh = !game::is_installed(Path::new(target_path));
+    if fresh && !options.repair && reuse_steam_copy(log_file, manifest, target_path)? {
+        return Ok(());
+    }
+    if !game::is_installed(Path::new(target_path)) || options.update_game || options.repair {
+        if options.repair {
+            color_echo(ConsoleColor::Blue, &t!("game-validating"));
+            write_log(log_file, "Validating the game files.")?;
+        } else if options.update_game {
+            color_echo(ConsoleColor::Blue, &t!("game-updating", build = manifest.game.manifest_id.as_str()));
+            write_log(log_file, &format!("Updating the game to build {}.", manifest.game.manifest_id))?;
+        }
+        let (steam_user, steam_pass) = match &options.steam_credentials {
+            Some(credentials) => credentials.clone(),
+            None => prompt_steam_credentials()?,
+        };
+        write_log(log_file, "Steam credentials collected.")?;
+
+        // Build DepotDownloader arguments.
+        let game = &manifest.game;
+        let option_args = depot_tool::option_args();
+        let mut dd_args = vec![
+            "-app", &game.app_id,
+            "-depot", &game.depot_id,
+            "-username", &steam_user,
+            "-dir", target_path,
+        ];
+        if let Some(manifest_id) = game.pinned_manifest() {
+            dd_args.extend(["-manifest", manifest_id]);
+        }
+        let branch = game_build::branch(Path::new(target_path), game);
+        let branch_password = env::var(credentials::BRANCH_PASSWORD_VAR).ok();
+        if let Some(branch) = &branch {
+            dd_args.extend(["-beta", branch]);
+            if let Some(password) = &branch_password {
+                redact::add(password);
+                dd_args.extend(["-betapassword", password]);
+            }
+        }
+        dd_args.extend(option_args.iter().map(String::as_str));
+        // Leaves out the depot files the manifest marks optional.
+        let filelist = TempPath::file("depot_filelist", "txt");
+        let filelist_str = filelist.to_string_lossy().into_owned();
+        if let Some(list) = game.files.filelist() {
+            fs::write(&filelist, list)?;
+            dd_args.extend(["-filelist", &filelist_str]);
+        }
+        if options.repair && !dd_args.contains(&"-validate") {
+            // Checksums every file instead of trusting the ones already present.
+            dd_args.push("-validate");
+        }
+        color_echo(ConsoleColor::Blue, &t!("depot-downloading"));
+        write_log(log_file, &format!("Running DepotDownloader with arguments: {}", dd_args.join(" ")))?;
+
+        // DepotDownloader may ask for a Steam Guard code, so give the console back for the question.
+        let (dd_exit, diagnosis) = {
+            let _console = tui::suspend();
+            run_depot_downloader(depot_downloader_exe, &dd_args, &steam_pass).await?
+        };
+        if dd_exit != 0 {
+            color_echo(ConsoleColor::Red, &t!("depot-failed", code = dd_exit));
+            write_log(log_file, &format!("ERROR: DepotDownloader failed (exit code {}).", dd_exit))?;
+            if let Some(diagnosis) = diagnosis {
+                write_log(log_file, &format!("DepotDownloader failure recognised as {:?}.", diagnosis))?;
+                return Err(diagnosis.to_string().into());
+            }
+            return Err(t!("err-depot-exit", code = dd_exit).into());
+        }
+        color_echo(ConsoleColor::Green, &t!("depot-complete"));
+        write_log(log_file, "Depot download complete.")?;
+        game_build::record(Path::new(target_path), &game.manifest_id, branch.as_deref())?;
+    } else {
+        color_echo(ConsoleColor::Yellow, &t!("depot-skipped"));
+        write_log(log_file, "DesktopMate files already exist; skipping download.")?;
+    }
+    Ok(())
+}
+
+/// Offers to copy the game from the Steam client's library into an empty `target_path`. Returns
+/// whether the copy is the build `manifest` asks for, so DepotDownloader isn't needed; after
+/// copying an older build, DepotDownloader only fetches what changed.
+fn reuse_steam_copy(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+) -> Result<bool, Box<dyn Error>> {
+    let game = &manifest.game;
+    let Some(copy) = steam::find(&game.app_id, &game.depot_id) else {
+        return Ok(false);
+    };
+    let target = Path::new(target_path);
+    if fs::canonicalize(&copy.path).ok() == fs::canonicalize(target).ok() {
+        return Ok(false);
+    }
+    let build = copy.manifest_id.clone().unwrap_or_default();
+    write_log(log_file, &format!("Steam copy of the game found in {} (build {}).", copy.path.display(), build))?;
+    let accepted = {
+        let _console = tui::suspend();
+        let question = t!("steam-copy-prompt", path = copy.path.display().to_string(), build = build.as_str());
+        prompt::yes_no(&question, Some(true))?
+    };
+    if !accepted {
+        write_log(log_file, "User declined copying the game from Steam.")?;
+        return Ok(false);
+    }
+    color_echo(ConsoleColor::Blue, &t!("steam-copy-copying"));
+    match steam::copy_game(&copy.path, target) {
+        Ok(files) => {
+            color_echo(ConsoleColor::Green, &t!("steam-copy-done", count = files));
+            write_log(log_file, &format!("Copied {} game files from {}.", files, copy.path.display()))?;
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Yellow, &t!("steam-copy-failed", error = ex.to_string()));
+            write_log(log_file, &format!("WARNING: Copying the game from Steam failed: {}", ex))?;
+            return Ok(false);
+        }
+    }
+    if copy.manifest_id.is_none() || copy.manifest_id.as_deref() != game.pinned_manifest() {
+        return Ok(false);
+    }
+    let branch = game_build::branch(target, game);
+    game_build::record(target, &game.manifest_id, branch.as_deref())?;
+    color_echo(ConsoleColor::Green, &t!("depot-complete"));
+    Ok(true)
+}
+
+/// Replaces the game's `steam_api64.dll` with Goldberg's, returning the installed DLL's SHA-256.
+async fn apply_goldberg_patch(
+    target_path: &str,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+) -> Result<String, Box<dyn Error>> {
+    let target_dll = game::steam_api(Path::new(target_path));
 
-    // Prompt for installation path (default: C:\Games\DesktopMate)
-    let default_path = r"C:\Games\DesktopMate";
-    print!("Enter installation path (default: {}): ", default_path);
-    io::stdout().flush()?;
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    let input = input.trim();
-    let target_path = if input.is_empty() {
-        default_path.to_owned()
-    } else {
-        input.to_owned()
+    // The chosen emulator first, then the other one if it can't be had.
+    let mut fetched = None;
+    let mut source_url = String::new();
+    let mut failure: Option<Box<dyn Error>> = None;
+    for source in goldberg::sources(&manifest.goldberg, log_file).await {
+        if let Some(ex) = &failure {
+            let provider = source.provider.display_name();
+            let message = t!("goldberg-provider-fallback", error = ex.to_string(), provider = provider);
+            color_echo(ConsoleColor::Yellow, &message);
+            write_log(log_file, &format!("WARNING: Goldberg download failed ({}); trying {}.", ex, provider))?;
+        }
+        match fetch_goldberg(&source, log_file).await {
+            Ok(found) => {
+                fetched = Some(found);
+                source_url = source.url.clone();
+                break;
+            }
+            Err(ex) => failure = Some(ex),
+        }
+    }
+    let Some((_extract_path, patch_dll)) = fetched else {
+        return Err(failure.unwrap_or_else(|| t!("err-goldberg-dll").into()));
     };
-    color_echo(ConsoleColor::Green, &format!("Installation directory: {}", target_path));
-    write_log(
-        &log_file,
-        &format!("Installation directory set to: {}", target_path),
-    )?;
 
-    // Ensure target directory exists.
-    fs::create_dir_all(&target_path)?;
+    let goldberg_sha256 = transaction::sha256_file(&patch_dll)?;
+    if let Some(target_dll_dir) = target_dll.parent() {
+        // Keeps the game's own DLL, so the patch can be undone.
+        match unpatch::back_up_original(Path::new(target_path), &goldberg_sha256) {
+            Ok(Some(backup)) => {
+                write_log(log_file, &format!("Original steam_api64.dll backed up to {}.", backup.display()))?
+            }
+            Ok(None) => {}
+            Err(ex) => {
+                write_log(log_file, &format!("ERROR: Could not back up the original steam_api64.dll: {}", ex))?;
+                return Err(t!("err-steam-api-backup", error = ex.to_string()).into());
+            }
+        }
+        let stage = Stage::new(Path::new(target_path))?;
+        let staged_dir = stage.path().join(target_dll_dir.strip_prefix(target_path)?);
+        fs::create_dir_all(&staged_dir)?;
+        fs::copy(&patch_dll, staged_dir.join(target_dll.file_name().unwrap_or_default()))?;
+        stage.promote()?;
+        // Antivirus often quarantines the DLL a few seconds after it appears.
+        color_echo(ConsoleColor::Blue, &t!("goldberg-rechecking"));
+        if !defender::recheck_patch(Path::new(target_path), &goldberg_sha256).await {
+            color_echo(ConsoleColor::Red, &t!("av-patch-removed"));
+            color_echo(ConsoleColor::Yellow, &t!("av-exclusion-hint"));
+            write_log(log_file, "ERROR: The Goldberg DLL vanished or changed right after it was applied.")?;
+            return Err(t!("err-av-quarantined").into());
+        }
+        defender::record_patch(Path::new(target_path), &goldberg_sha256)?;
+        // The archive is gone by now; the DLL's own hash is recorded with the patch.
+        components::record_source(Path::new(target_path), defender::COMPONENT, &source_url, None)?;
+        color_echo(ConsoleColor::Green, &t!("goldberg-applied"));
+        write_log(log_file, "Goldberg patch applied.")?;
+    } else {
+        color_echo(ConsoleColor::Red, &t!("goldberg-no-target"));
+        write_log(log_file, "ERROR: target directory is null or empty.")?;
+        return Err(t!("err-goldberg-target").into());
+    }
+    Ok(goldberg_sha256)
+}
+
+/// Downloads the emulator build from `source` and extracts its `steam_api64.dll`, returning the
+/// scratch folder and the DLL in it.
+async fn fetch_goldberg(source: &goldberg::Source, log_file: &Path) -> Result<(TempPath, PathBuf), Box<dyn Error>> {
+    let goldberg_zip = TempPath::file("goldberg", "zip");
+    let extract_path = TempPath::dir("goldberg_extracted")?;
+    let patch_dll = game::relative(&extract_path, &source.dll_path);
+
+    color_echo(ConsoleColor::Blue, &t!("goldberg-downloading"));
+    write_log(log_file, &format!("Downloading {} from {}.", source.provider.display_name(), source.url))?;
+    download_file(&source.url, &goldberg_zip).await?;
+    pins::verify(source.component, source.version.as_deref(), source.sha256.as_deref(), &goldberg_zip, log_file)?;
+    // Only the replacement DLL is needed; skip the rest of the emulator build.
+    let goldberg_filter = ExtractFilter::new(&[source.dll_path.as_str()], &[])?;
+    extract_archive_filtered(&goldberg_zip, &extract_path, &goldberg_filter)?;
+    if !patch_dll.is_file() {
+        color_echo(ConsoleColor::Red, &t!("goldberg-dll-missing"));
+        let provider = source.provider.display_name();
+        write_log(log_file, &format!("ERROR: {} missing in the {} archive.", source.dll_path, provider))?;
+        return Err(t!("err-goldberg-dll").into());
+    }
+    Ok((extract_path, patch_dll))
+}
 
-    // Ensure DepotDownloader.exe is available.
+/// Creates the desktop shortcuts (and Start menu pin) the user asked for, returning their paths.
+fn create_shortcuts(log_file: &Path, options: &InstallOptions) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let target_path = options.target_path.as_str();
+    let created = if options.shortcuts == shortcuts::Variant::None && !options.safe_mode_shortcut {
+        color_echo(ConsoleColor::Yellow, &t!("shortcuts-skipped"));
+        write_log(log_file, "No shortcuts requested; skipping.")?;
+        Vec::new()
+    } else {
+        color_echo(ConsoleColor::Blue, &t!("shortcuts-creating"));
+        write_log(log_file, "Creating desktop shortcuts.")?;
+        let desktop = match dirs::desktop_dir() {
+            Some(d) => d,
+            None => {
+                color_echo(ConsoleColor::Red, &t!("shortcuts-no-desktop"));
+                return Err(t!("err-no-desktop").into());
+            }
+        };
+        let created = shortcuts::create(
+            &desktop,
+            target_path,
+            options.shortcuts,
+            options.safe_mode_shortcut,
+            &options.shortcut_names,
+            options.previous_shortcut_names.as_ref(),
+        )?;
+        color_echo(ConsoleColor::Green, &t!("shortcuts-created"));
+        write_log(log_file, "Shortcuts created.")?;
+        let mut created = created;
+        if options.pin_to_start {
+            let previous = options.previous_shortcut_names.as_ref();
+            created.extend(pin_to_start(log_file, target_path, &options.shortcut_names, previous)?);
+        }
+        created
+    };
+    Ok(created)
+}
+
+/// Downloads DepotDownloader next to the installer unless the wanted release is already there,
+/// returning the exe path. See [`depot_tool`] for which release that is.
+async fn ensure_depot_downloader(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+) -> Result<PathBuf, Box<dyn Error>> {
     let depot_downloader_dir = base_dir.join("DepotDownloader");
     let depot_downloader_exe = depot_downloader_dir.join("DepotDownloader.exe");
-    let depot_downloader_zip = env::temp_dir().join("DepotDownloader.zip");
-    let depot_downloader_url = "https://github.com/SteamRE/DepotDownloader/releases/latest/download/DepotDownloader-windows-x64.zip";
+    let depot_downloader_zip = TempPath::file("DepotDownloader", "zip");
+    let spec = &manifest.depot_downloader;
 
-    if !depot_downloader_exe.exists() {
-        color_echo(ConsoleColor::Yellow, "DepotDownloader.exe not found! Downloading now...");
-        write_log(&log_file, "DepotDownloader not found. Initiating download.")?;
+    // The release being downloaded, when known, and whether it replaces the one there.
+    let mut version = spec.version.clone();
+    let mut replace = false;
+    if depot_downloader_exe.exists() {
+        let installed = depot_tool::installed_version(&depot_downloader_dir);
+        let is_installed = |wanted: &str| installed.as_deref().is_some_and(|v| version::same(v, wanted));
+        let installed_label = installed.clone().unwrap_or_else(|| t!("dd-version-unknown"));
+        match &spec.version {
+            Some(pinned) if is_installed(pinned) => {}
+            Some(pinned) => {
+                color_echo(
+                    ConsoleColor::Yellow,
+                    &t!("dd-switching", installed = installed_label.as_str(), version = pinned.as_str()),
+                );
+                write_log(log_file, &format!("Replacing DepotDownloader {} with pinned {}.", installed_label, pinned))?;
+                replace = true;
+            }
+            None => match depot_tool::latest_version(log_file).await {
+                Ok(latest) if is_installed(&latest) => {}
+                Ok(latest) => {
+                    let question =
+                        t!("dd-upgrade-prompt", installed = installed_label.as_str(), latest = latest.as_str());
+                    replace = {
+                        let _console = tui::suspend();
+                        prompt::yes_no(&question, Some(true))?
+                    };
+                    write_log(
+                        log_file,
+                        &format!(
+                            "DepotDownloader {} installed, {} available; upgrading: {}",
+                            installed_label, latest, replace
+                        ),
+                    )?;
+                    version = Some(latest);
+                }
+                Err(ex) => {
+                    write_log(log_file, &format!("WARNING: Could not check for a DepotDownloader update: {}", ex))?
+                }
+            },
+        }
+    } else if version.is_none() {
+        version = depot_tool::latest_version(log_file).await.ok();
+    }
+    // The release's own URL rather than `latest`, so the download is cached under that release.
+    let depot_downloader_url = match &version {
+        Some(version) => depot_tool::url_for_version(&spec.url, version),
+        None => spec.url.clone(),
+    };
 
-        if let Err(ex) = download_file(depot_downloader_url, &depot_downloader_zip).await {
-            color_echo(
-                ConsoleColor::Red,
-                &format!("ERROR: Failed to download DepotDownloader! {}", ex),
-            );
-            write_log(&log_file, "ERROR: DepotDownloader download failed.")?;
-            pause_and_exit().await;
-            return Ok(());
+    if !depot_downloader_exe.exists() || replace {
+        if !replace {
+            color_echo(ConsoleColor::Yellow, &t!("dd-missing"));
+            write_log(log_file, "DepotDownloader not found. Initiating download.")?;
         }
 
-        color_echo(ConsoleColor::Green, "Extracting DepotDownloader...");
-        write_log(&log_file, "Extracting DepotDownloader.")?;
-        if let Err(ex) = extract_zip(&depot_downloader_zip, &depot_downloader_dir) {
-            color_echo(
-                ConsoleColor::Red,
-                &format!("ERROR: Failed to extract DepotDownloader! {}", ex),
-            );
-            write_log(&log_file, "ERROR: DepotDownloader extraction failed.")?;
-            pause_and_exit().await;
-            return Ok(());
+        if let Err(ex) = download_file(&depot_downloader_url, &depot_downloader_zip).await {
+            color_echo(ConsoleColor::Red, &t!("dd-download-failed", error = ex));
+            write_log(log_file, "ERROR: DepotDownloader download failed.")?;
+            return Err(t!("err-dd-download").into());
         }
-        fs::remove_file(&depot_downloader_zip)?;
+        let expected = manifest.depot_downloader.sha256.as_deref();
+        pins::verify(pins::DEPOT_DOWNLOADER, None, expected, &depot_downloader_zip, log_file)?;
 
-        if !depot_downloader_exe.exists() {
-            color_echo(
-                ConsoleColor::Red,
-                "ERROR: DepotDownloader.exe still not found after extraction!",
-            );
-            write_log(&log_file, "ERROR: DepotDownloader.exe still missing.")?;
-            pause_and_exit().await;
-            return Ok(());
-        } else {
-            color_echo(ConsoleColor::Green, "DepotDownloader downloaded and extracted successfully.");
-            write_log(&log_file, "DepotDownloader ready.")?;
+        color_echo(ConsoleColor::Green, &t!("dd-extracting"));
+        write_log(log_file, "Extracting DepotDownloader.")?;
+        // Extracted next to the folder first, so a failed extraction leaves the working copy alone.
+        let staged = TempPath::dir_in(base_dir, ".dmi-depot-staging")?;
+        if let Err(ex) = extract_archive(&depot_downloader_zip, &staged) {
+            color_echo(ConsoleColor::Red, &t!("dd-extract-failed", error = ex));
+            write_log(log_file, "ERROR: DepotDownloader extraction failed.")?;
+            return Err(t!("err-dd-extract").into());
+        }
+        drop(depot_downloader_zip);
+        if !staged.join("DepotDownloader.exe").exists() {
+            color_echo(ConsoleColor::Red, &t!("dd-still-missing"));
+            write_log(log_file, "ERROR: DepotDownloader.exe still missing.")?;
+            return Err(t!("err-dd-missing").into());
         }
-    }
 
-    // STEP 1: Download the DesktopMate depot if needed.
-    let desktop_mate_data_path = Path::new(&target_path).join("DesktopMate_Data");
-    if !desktop_mate_data_path.exists() {
-        // Prompt for Steam credentials.
-        let steam_user = loop {
-            print!("Enter your Steam username: ");
-            io::stdout().flush()?;
-            let mut user_input = String::new();
-            io::stdin().read_line(&mut user_input)?;
-            let trimmed = user_input.trim().to_string();
-            if !trimmed.is_empty() {
-                break trimmed;
+        // The old copy is only deleted once the new one is in its place, and put back otherwise.
+        let previous = base_dir.join("DepotDownloader.dmi-old");
+        let had_previous = depot_downloader_dir.exists();
+        if had_previous {
+            let _ = fs::remove_dir_all(&previous);
+            fs::rename(&depot_downloader_dir, &previous)?;
+        }
+        if let Err(ex) = fs::rename(&staged, &depot_downloader_dir) {
+            if had_previous {
+                fs::rename(&previous, &depot_downloader_dir)?;
+            }
+            return Err(ex.into());
+        }
+        if had_previous {
+            let _ = fs::remove_dir_all(&previous);
+        }
+        color_echo(ConsoleColor::Green, &t!("dd-ready"));
+        write_log(log_file, "DepotDownloader ready.")?;
+        if let Some(version) = &version {
+            if let Err(ex) = depot_tool::record_version(&depot_downloader_dir, version) {
+                write_log(log_file, &format!("WARNING: Could not record the DepotDownloader version: {}", ex))?;
             }
-            println!("Steam username is required.");
+        }
+    }
+    let publisher = manifest.depot_downloader.publisher.as_deref();
+    signature::audit(pins::DEPOT_DOWNLOADER, &depot_downloader_dir, &["DepotDownloader.exe"], publisher, log_file);
+    ensure_dotnet(log_file, &depot_downloader_dir).await?;
+    Ok(depot_downloader_exe)
+}
+
+/// Makes sure the .NET runtime the DepotDownloader in `dir` needs is installed, offering to run
+/// Microsoft's installer for a missing one.
+async fn ensure_dotnet(log_file: &Path, dir: &Path) -> Result<(), Box<dyn Error>> {
+    const MANUAL_URL: &str = "https://dotnet.microsoft.com/download/dotnet";
+    const DOTNET_PUBLISHER: &str = "Microsoft Corporation";
+    for framework in dotnet::missing_frameworks(dir) {
+        let runtime = format!("{} {}", framework.name, framework.version);
+        color_echo(ConsoleColor::Red, &t!("dotnet-missing", runtime = runtime.as_str()));
+        write_log(log_file, &format!("ERROR: DepotDownloader needs {}, which isn't installed.", runtime))?;
+        let missing = || t!("err-dotnet-missing", runtime = runtime.as_str(), url = MANUAL_URL);
+        let Some(url) = framework.installer_url() else {
+            return Err(missing().into());
         };
+        let accepted = {
+            let _console = tui::suspend();
+            prompt::yes_no(&t!("dotnet-install-prompt"), Some(true))?
+        };
+        if !accepted {
+            write_log(log_file, "User declined installing the .NET runtime.")?;
+            return Err(missing().into());
+        }
 
-        let steam_pass = read_password("Enter your Steam password: ")?;
-        write_log(&log_file, "Steam credentials collected.")?;
+        color_echo(ConsoleColor::Blue, &t!("dotnet-downloading"));
+        write_log(log_file, &format!("Downloading the .NET runtime installer from {}", url))?;
+        let installer = TempPath::file("dotnet-runtime", "exe");
+        download_file(&url, &installer).await?;
+        // It installs system-wide, so any valid signature won't do: it has to be Microsoft's.
+        match signature::check(&installer) {
+            signature::Signature::Valid { publisher } if publisher == DOTNET_PUBLISHER => {}
+            signature::Signature::Valid { publisher } => {
+                write_log(log_file, &format!("ERROR: The .NET runtime installer is signed by {}.", publisher))?;
+                return Err(t!("err-dotnet-wrong-publisher", publisher = publisher).into());
+            }
+            _ => {
+                write_log(log_file, "ERROR: The .NET runtime installer has no valid signature.")?;
+                return Err(t!("err-dotnet-unsigned").into());
+            }
+        }
+        color_echo(ConsoleColor::Blue, &t!("dotnet-installing"));
+        let status = Command::new(&*installer).args(["/install", "/passive", "/norestart"]).status().await?;
+        write_log(log_file, &format!("The .NET runtime installer exited with {}.", status))?;
+    }
+    if let Some(framework) = dotnet::missing_frameworks(dir).first() {
+        let runtime = format!("{} {}", framework.name, framework.version);
+        return Err(t!("err-dotnet-missing", runtime = runtime.as_str(), url = MANUAL_URL).into());
+    }
+    Ok(())
+}
 
-        // Build DepotDownloader arguments.
-        let app_id = "3301060";
-        let depot_id = "3301061";
-        let manifest_id = "2467897585300615012";
-        let dd_args = vec![
-            "-app", app_id,
-            "-depot", depot_id,
-            "-manifest", manifest_id,
-            "-username", &steam_user,
-            "-password", &steam_pass,
-            "-dir", &target_path,
-        ];
-        let dd_arg_string = dd_args.join(" ");
-        color_echo(ConsoleColor::Blue, "Downloading DesktopMate depot (via DepotDownloader)...");
-        write_log(&log_file, &format!("Running DepotDownloader with arguments: {}", dd_arg_string))?;
+/// `--package <folder>` mode: builds an offline kit of the installer and every archive it
+/// downloads in `dest`.
+async fn build_package(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    dest: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let depot_downloader_exe = ensure_depot_downloader(base_dir, log_file, manifest).await?;
+    let pattern = AssetPattern::new(&manifest.avatar_loader.asset)?;
+    let release = get_latest_release(&manifest.avatar_loader.owner, &manifest.avatar_loader.repo, &pattern, log_file)
+        .await?;
+    if release.download_url.is_empty() {
+        return Err(t!("err-no-matching-asset", pattern = pattern.to_string()).into());
+    }
+    let depot_downloader_dir = depot_downloader_exe.parent().unwrap_or(base_dir);
+    kit::build(dest, manifest, &release, depot_downloader_dir, log_file).await
+}
 
-        let dd_exit = run_depot_downloader(&depot_downloader_exe, &dd_args).await?;
-        if dd_exit != 0 {
-            color_echo(
-                ConsoleColor::Red,
-                &format!("ERROR: DepotDownloader encountered an error. Exit code = {}", dd_exit),
-            );
-            write_log(&log_file, &format!("ERROR: DepotDownloader failed (exit code {}).", dd_exit))?;
-            pause_and_exit().await;
-            return Ok(());
-        }
-        color_echo(ConsoleColor::Green, "Depot download complete.");
-        write_log(&log_file, "Depot download complete.")?;
-    } else {
-        color_echo(ConsoleColor::Yellow, "DesktopMate files already exist. Skipping depot download.");
-        write_log(&log_file, "DesktopMate files already exist; skipping download.")?;
-    }
-
-    // STEP 2: Apply Goldberg Offline Patch.
-    let goldberg_url = "https://gitlab.com/Mr_Goldberg/goldberg_emulator/-/jobs/4247811310/artifacts/download";
-    let goldberg_zip = env::temp_dir().join(format!("goldberg_{}.zip", uuid::Uuid::new_v4()));
-    let extract_path = env::temp_dir().join("goldberg_extracted");
-    let patch_dll = extract_path.join("experimental").join("steam_api64.dll");
-    let target_dll = Path::new(&target_path)
-        .join("DesktopMate_Data")
-        .join("Plugins")
-        .join("x86_64")
-        .join("steam_api64.dll");
-
-    color_echo(ConsoleColor::Blue, "Downloading Goldberg patch...");
-    write_log(&log_file, "Downloading Goldberg emulator patch from GitLab.")?;
-    download_file(goldberg_url, &goldberg_zip).await?;
-
-    if extract_path.exists() {
-        fs::remove_dir_all(&extract_path)?;
-    }
-    fs::create_dir_all(&extract_path)?;
-    extract_zip(&goldberg_zip, &extract_path)?;
-    fs::remove_file(&goldberg_zip)?;
-
-    if patch_dll.exists() {
-        if let Some(target_dll_dir) = target_dll.parent() {
-            fs::create_dir_all(target_dll_dir)?;
-            fs::copy(&patch_dll, &target_dll)?;
-            color_echo(ConsoleColor::Green, "Goldberg patch applied successfully.");
-            write_log(&log_file, "Goldberg patch applied.")?;
-        } else {
-            color_echo(
-                ConsoleColor::Red,
-                "ERROR: Unable to determine target directory for Goldberg patch DLL.",
-            );
-            write_log(&log_file, "ERROR: target directory is null or empty.")?;
-            pause_and_exit().await;
-            return Ok(());
+/// `--check-updates [path]` mode: compares the game build and the mods installed in
+/// `target_path` with what is available. Returns whether anything is out of date.
+async fn check_updates(
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+) -> Result<bool, Box<dyn Error>> {
+    let statuses = updates::check(Path::new(target_path), manifest, log_file).await?;
+    for status in &statuses {
+        let color = if status.outdated { ConsoleColor::Yellow } else { ConsoleColor::Green };
+        color_echo(color, &status.describe());
+        write_log(
+            log_file,
+            &format!("{}: installed {:?}, available {:?}", status.component, status.installed, status.latest),
+        )?;
+    }
+    Ok(statuses.iter().any(|status| status.outdated))
+}
+
+/// `--watch [path]` mode: checks the install at `target_path` for updates now and then once a
+/// day for as long as it runs. Each update is announced once, in the console and as a toast.
+async fn watch_for_updates(log_file: &Path, config: &config::Config, target_path: &str) -> io::Result<()> {
+    const INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+    color_echo(ConsoleColor::Cyan, &t!("watch-started", path = target_path));
+    let mut announced: Vec<updates::Status> = Vec::new();
+    loop {
+        // Reloaded each time, so a build pinned since the last check is noticed.
+        let mut manifest = manifest::load(config.channel, log_file).await;
+        config.override_manifest(&mut manifest);
+        let stamp = Local::now().format("%Y-%m-%d %H:%M");
+        match updates::check(Path::new(target_path), &manifest, log_file).await {
+            Ok(statuses) => {
+                let new: Vec<updates::Status> = statuses
+                    .into_iter()
+                    .filter(|status| status.outdated && !announced.contains(status))
+                    .collect();
+                if new.is_empty() {
+                    write_log(log_file, "Watch: no new updates.")?;
+                } else {
+                    let lines: Vec<String> = new.iter().map(updates::Status::describe).collect();
+                    for line in &lines {
+                        color_echo(ConsoleColor::Yellow, &format!("[{}] {}", stamp, line));
+                    }
+                    write_log(log_file, &format!("Watch: updates found: {}", lines.join("; ")))?;
+                    if let Err(ex) = notify::toast(&t!("watch-toast-title"), &lines.join("\n")) {
+                        write_log(log_file, &format!("WARNING: Could not show a notification: {}", ex))?;
+                    }
+                    announced.extend(new);
+                }
+            }
+            Err(ex) => {
+                color_echo(ConsoleColor::Yellow, &format!("[{}] {}", stamp, t!("watch-check-failed", error = ex)));
+                write_log(log_file, &format!("WARNING: Watch update check failed: {}", ex))?;
+            }
         }
-    } else {
-        color_echo(ConsoleColor::Red, "ERROR: steam_api64.dll not found in the patch archive!");
-        write_log(&log_file, "ERROR: steam_api64.dll missing in goldberg archive.")?;
-        pause_and_exit().await;
-        return Ok(());
+        tokio::time::sleep(INTERVAL).await;
     }
+}
 
-    // STEP 3: Install MelonLoader v0.6.6 by downloading and extracting its ZIP.
-    update_melonloader_if_needed(&target_path, &log_file).await?;
+/// `verify` mode: runs the health check on the install in `target_path` and, with `game_files`,
+/// compares the game files with the depot manifest. Changes nothing; returns whether everything
+/// checked out.
+async fn verify_install(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    game_files: bool,
+    credentials: Option<(String, String)>,
+) -> Result<bool, Box<dyn Error>> {
+    let state = state::InstallState::load(Path::new(target_path))?;
+    let goldberg_sha256 = state.version(defender::COMPONENT).filter(|sha256| !sha256.is_empty());
+    for (name, component) in state.components() {
+        let Some(source) = &component.source_url else { continue };
+        let line = t!(
+            "verify-component",
+            component = name.as_str(),
+            version = component.version.as_str(),
+            source = source.as_str(),
+        );
+        println!("  {}", line);
+        let archive = component.archive_sha256.as_deref().unwrap_or("-");
+        write_log(log_file, &format!("{} {} from {} (archive SHA-256 {})", name, component.version, source, archive))?;
+    }
+    color_echo(ConsoleColor::Blue, &t!("health-running"));
+    write_log(log_file, "Running health check.")?;
+    let results = health::run(Path::new(target_path), goldberg_sha256, &manifest.mods(), &[]);
+    let mut healthy = report_health(log_file, &results)? == 0;
+    healthy &= check_component_files(log_file, Path::new(target_path))?.0.is_empty();
+    if game_files {
+        healthy &= verify_game_files(base_dir, log_file, manifest, target_path, credentials).await? == 0;
+    }
+    Ok(healthy)
+}
 
-    // STEP 4: Install or update Custom Avatar Loader mod.
-    install_or_update_custom_avatar_loader(&target_path, &log_file).await?;
+/// Checks the installed game files against the depot manifest without changing anything.
+/// Returns how many files are missing or modified.
+async fn verify_game_files(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    target_path: &str,
+    credentials: Option<(String, String)>,
+) -> Result<usize, Box<dyn Error>> {
+    let depot_downloader_exe = ensure_depot_downloader(base_dir, log_file, manifest).await?;
+    let (steam_user, steam_pass) = match credentials {
+        Some(credentials) => credentials,
+        None => prompt_steam_credentials()?,
+    };
 
-    // STEP 5: Create Desktop Shortcuts.
-    color_echo(ConsoleColor::Blue, "Creating desktop shortcuts...");
-    write_log(&log_file, "Creating desktop shortcuts.")?;
-    let desktop = match dirs::desktop_dir() {
-        Some(d) => d,
-        None => {
-            color_echo(ConsoleColor::Red, "ERROR: Cannot determine Desktop directory.");
-            pause_and_exit().await;
-            return Ok(());
+    // Have DepotDownloader write just the manifest listing into scratch space.
+    let manifest_dir = TempPath::dir("depot_manifest")?;
+    let manifest_dir_str = manifest_dir.to_string_lossy().into_owned();
+    let game = &manifest.game;
+    let mut dd_args = vec![
+        "-app", &game.app_id,
+        "-depot", &game.depot_id,
+        "-username", &steam_user,
+        "-dir", &manifest_dir_str,
+        "-manifest-only",
+    ];
+    if let Some(manifest_id) = game.pinned_manifest() {
+        dd_args.extend(["-manifest", manifest_id]);
+    }
+    color_echo(ConsoleColor::Blue, &t!("verify-fetching-manifest"));
+    write_log(log_file, "Fetching depot manifest for verification.")?;
+    let (dd_exit, diagnosis) = run_depot_downloader(&depot_downloader_exe, &dd_args, &steam_pass).await?;
+    if dd_exit != 0 {
+        return Err(match diagnosis {
+            Some(diagnosis) => diagnosis.to_string().into(),
+            None => t!("err-depot-exit", code = dd_exit).into(),
+        });
+    }
+    let listing = fs::read_dir(&*manifest_dir)?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_lowercase();
+            name.starts_with("manifest_") && name.ends_with(".txt")
+        })
+        .ok_or_else(|| t!("err-verify-no-manifest"))?;
+    let mut files = verify::parse_manifest(&fs::read_to_string(listing)?);
+    // Files the manifest's filter left out were never downloaded, so they aren't missing.
+    files.retain(|file| game.files.includes(&file.name));
+    if files.is_empty() {
+        return Err(t!("err-verify-no-manifest").into());
+    }
+
+    color_echo(ConsoleColor::Blue, &t!("verify-checking", count = files.len()));
+    let discrepancies = verify::compare(Path::new(target_path), &files);
+    let mut damaged = 0;
+    for item in &discrepancies {
+        let (color, line) = match item.status {
+            verify::FileStatus::Missing => (ConsoleColor::Red, t!("verify-missing", file = item.name)),
+            verify::FileStatus::Modified => (ConsoleColor::Red, t!("verify-modified", file = item.name)),
+            verify::FileStatus::Patched => (ConsoleColor::Cyan, t!("verify-patched", file = item.name)),
+        };
+        if item.status != verify::FileStatus::Patched {
+            damaged += 1;
+        }
+        color_echo(color, &line);
+        write_log(log_file, &format!("Verify {:?}: {}", item.status, item.name))?;
+    }
+    if damaged == 0 {
+        color_echo(ConsoleColor::Green, &t!("verify-ok", count = files.len()));
+    } else {
+        color_echo(ConsoleColor::Yellow, &t!("verify-damaged", count = damaged));
+    }
+    write_log(log_file, &format!("Verification finished: {} file(s) checked, {} damaged.", files.len(), damaged))?;
+    Ok(damaged)
+}
+
+/// Prompts for the Steam account used by DepotDownloader.
+fn prompt_steam_credentials() -> io::Result<(String, String)> {
+    let steam_user = loop {
+        let input = prompt::line(&t!("prompt-steam-user"), None)?;
+        if !input.is_empty() {
+            break input;
         }
+        println!("{}", t!("steam-user-required"));
     };
-    let exe_path = Path::new(&target_path).join("DesktopMate.exe");
-    let shortcut_console = desktop.join("DesktopMate_Console.lnk");
-    let shortcut_no_console = desktop.join("DesktopMate_NoConsole.lnk");
-    // Use PowerShell to create shortcuts.
-    create_shortcut(&shortcut_console, &exe_path, &target_path, "")?;
-    create_shortcut(
-        &shortcut_no_console,
-        &exe_path,
-        &target_path,
-        "melonloader.hideconsole",
-    )?;
-    color_echo(ConsoleColor::Green, "Desktop shortcuts created successfully.");
-    write_log(&log_file, "Shortcuts created.")?;
 
-    println!("Installation complete. Press any key to exit.");
-    pause_and_exit().await;
-    Ok(())
+    let steam_pass = prompt::password(&t!("prompt-steam-password"))?;
+    Ok((steam_user, steam_pass))
 }
 
 /// Sets the console title using the Windows API.
@@ -263,7 +2661,8 @@ fn set_console_title(title: &str) {
     }
 }
 
-/// Writes a colored message to the console.
+/// A message's color role, named after its default color; [`theme`] picks the actual one.
+#[derive(Debug, Clone, Copy)]
 enum ConsoleColor {
     Cyan,
     Green,
@@ -272,82 +2671,115 @@ enum ConsoleColor {
     Red,
 }
 
+/// Writes a colored message to the console.
 fn color_echo(color: ConsoleColor, message: &str) {
-    match color {
-        ConsoleColor::Cyan => println!("{}", message.cyan()),
-        ConsoleColor::Green => println!("{}", message.green()),
-        ConsoleColor::Yellow => println!("{}", message.yellow()),
-        ConsoleColor::Blue => println!("{}", message.blue()),
-        ConsoleColor::Red => println!("{}", message.red()),
+    if tui::forward(Some(color), message) {
+        return;
     }
+    println!("{}", message.color(theme::color(color)));
 }
 
-/// Appends a message to the log file.
+/// Appends a message to the log file, with secrets masked by [`redact::scrub`].
+/// The structured log next to it gets the same line as a `message` event.
 fn write_log(log_file: &Path, message: &str) -> io::Result<()> {
+    let message = redact::scrub(message);
     let mut file = OpenOptions::new().create(true).append(true).open(log_file)?;
     writeln!(file, "{} - {}", Local::now(), message)?;
+    events::message(log_file, &message);
     Ok(())
 }
 
-/// Downloads a file from the given URL and writes it to the specified path.
-async fn download_file(url: &str, output_path: &Path) -> Result<(), Box<dyn Error>> {
-    let client = Client::builder().user_agent("DesktopMateInstaller").build()?;
-    let resp = client.get(url).send().await?;
-    if !resp.status().is_success() {
-        return Err(format!("HTTP error: {}", resp.status()).into());
-    }
-    let bytes = resp.bytes().await?;
-    fs::write(output_path, &bytes)?;
-    Ok(())
-}
-
-/// Extracts a zip file (at zip_path) to the specified destination directory.
-fn extract_zip(zip_path: &Path, destination: &Path) -> Result<(), Box<dyn Error>> {
-    let file = File::open(zip_path)?;
-    let mut archive = ZipArchive::new(file)?;
-    for i in 0..archive.len() {
-        let mut file = archive.by_index(i)?;
-        // Use `mangled_name()` instead of the deprecated `sanitized_name()`
-        let outpath = destination.join(file.mangled_name());
-        if file.name().ends_with('/') {
-            fs::create_dir_all(&outpath)?;
-        } else {
-            if let Some(p) = outpath.parent() {
-                fs::create_dir_all(p)?;
-            }
-            let mut outfile = File::create(&outpath)?;
-            std::io::copy(&mut file, &mut outfile)?;
-        }
-    }
-    Ok(())
-}
-
-/// Runs DepotDownloader.exe with the provided arguments and logs output.
-async fn run_depot_downloader(exe_path: &Path, args: &[&str]) -> Result<i32, Box<dyn Error>> {
+/// Runs DepotDownloader.exe with the provided arguments and logs output. `password` answers its
+/// password question through stdin, so it never shows up in the arguments; other questions, such
+/// as a Steam Guard code, are passed on to the user.
+async fn run_depot_downloader(
+    exe_path: &Path,
+    args: &[&str],
+    password: &str,
+) -> Result<(i32, Option<depot::Diagnosis>), Box<dyn Error>> {
     let mut cmd = Command::new(exe_path);
     cmd.args(args)
+        .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
+    proxy::apply_to(&mut cmd);
+    redact::add(password);
     let mut child = cmd.spawn()?;
-    let stdout = child.stdout.take().unwrap();
+    let mut stdin = child.stdin.take();
+    let mut stdout = child.stdout.take().unwrap();
     let stderr = child.stderr.take().unwrap();
 
-    let mut stdout_reader = AsyncBufReader::new(stdout).lines();
     let mut stderr_reader = AsyncBufReader::new(stderr).lines();
 
+    // The first failure recognised in either stream explains a non-zero exit.
+    let diagnosis = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let note = |diagnosis: &std::sync::Mutex<Option<depot::Diagnosis>>, line: &str| {
+        let mut diagnosis = diagnosis.lock().unwrap_or_else(|e| e.into_inner());
+        if diagnosis.is_none() {
+            *diagnosis = depot::diagnose(line);
+        }
+    };
+
     let log_file = env::current_exe()?.parent().unwrap().join("DesktopMate_Install.log");
     let stdout_log = log_file.clone();
+    let stdout_diagnosis = diagnosis.clone();
+    let mut password = Some(password.to_owned());
     let stdout_handle = tokio::spawn(async move {
-        while let Ok(Some(line)) = stdout_reader.next_line().await {
-            println!("{}", line);
+        let mut pending = Vec::new();
+        while let Ok(Some(output)) = depot::next_output(&mut stdout, &mut pending).await {
+            let line = match output {
+                depot::Output::Line(line) => line,
+                depot::Output::Prompt(question) => {
+                    let _ = write_log(&stdout_log, &format!("[DD] {}", question));
+                    let secret = depot::is_password_prompt(&question);
+                    // Only the password prompt uses up the stored password; a Steam Guard code asked first doesn't.
+                    let stored = if secret { password.take() } else { None };
+                    let answer = match stored {
+                        Some(password) => Ok(password),
+                        None => {
+                            let question = format!("{} ", question);
+                            tokio::task::spawn_blocking(move || {
+                                if secret {
+                                    prompt::password(&question)
+                                } else {
+                                    prompt::line(&question, None)
+                                }
+                            })
+                            .await
+                            .unwrap_or_else(|ex| Err(io::Error::other(ex)))
+                        }
+                    };
+                    // Without an answer, closing stdin makes DepotDownloader give up on the question.
+                    if let Ok(answer) = &answer {
+                        // A Steam Guard code is a secret too, if a short-lived one.
+                        redact::add(answer);
+                    }
+                    let written = match (&answer, stdin.as_mut()) {
+                        (Ok(answer), Some(input)) => input.write_all(format!("{}\n", answer).as_bytes()).await,
+                        _ => Ok(()),
+                    };
+                    if answer.is_err() || written.is_err() {
+                        stdin = None;
+                    }
+                    continue;
+                }
+            };
+            note(&stdout_diagnosis, &line);
+            if !tui::forward(None, &line) {
+                println!("{}", line);
+            }
             let _ = write_log(&stdout_log, &format!("[DD] {}", line));
         }
     });
 
     let stderr_log = log_file.clone();
+    let stderr_diagnosis = diagnosis.clone();
     let stderr_handle = tokio::spawn(async move {
         while let Ok(Some(line)) = stderr_reader.next_line().await {
-            println!("{}", line.red());
+            note(&stderr_diagnosis, &line);
+            if !tui::forward(Some(ConsoleColor::Red), &line) {
+                println!("{}", line.red());
+            }
             let _ = write_log(&stderr_log, &format!("[DD-ERR] {}", line));
         }
     });
@@ -355,285 +2787,436 @@ async fn run_depot_downloader(exe_path: &Path, args: &[&str]) -> Result<i32, Box
     let status = child.wait().await?;
     let _ = stdout_handle.await;
     let _ = stderr_handle.await;
-    Ok(status.code().unwrap_or(-1))
+    let diagnosis = *diagnosis.lock().unwrap_or_else(|e| e.into_inner());
+    Ok((status.code().unwrap_or(-1), diagnosis))
+}
+
+/// Waits for any key press and then exits.
+async fn pause_and_exit() {
+    pause_and_exit_with(0).await;
+}
+
+/// Waits for any key press and then exits with `code`, which batch files see as `%ERRORLEVEL%`.
+async fn pause_and_exit_with(code: i32) {
+    println!("{}", t!("press-any-key"));
+    let _ = prompt::any_key();
+    std::process::exit(code);
 }
 
-/// Uses PowerShell to create a Windows shortcut.
-fn create_shortcut(
-    shortcut_path: &Path,
-    target_path: &Path,
-    working_directory: &str,
-    arguments: &str,
+/// Offers to zip the user's data out of an existing install before anything in it changes.
+fn back_up_user_data(
+    base_dir: &Path,
+    log_file: &Path,
+    manifest: &manifest::ComponentManifest,
+    options: &InstallOptions,
 ) -> Result<(), Box<dyn Error>> {
-    // Build a PowerShell command to create the shortcut via WScript.Shell.
-    let script = format!(
-        r#"
-$WshShell = New-Object -ComObject WScript.Shell;
-$Shortcut = $WshShell.CreateShortcut("{0}");
-$Shortcut.TargetPath = "{1}";
-$Shortcut.WorkingDirectory = "{2}";
-{3}
-$Shortcut.Save();
-"#,
-        shortcut_path.display(),
-        target_path.display(),
-        working_directory,
-        if arguments.trim().is_empty() {
-            "".to_string()
-        } else {
-            format!(r#"$Shortcut.Arguments = "{}";"#, arguments)
+    let target = Path::new(&options.target_path);
+    let sources = backup::sources(target, &manifest.game.app_id);
+    if sources.is_empty() {
+        return Ok(());
+    }
+    let names: Vec<&str> = sources.iter().map(|source| source.name).collect();
+    let accepted = match options.backup_user_data {
+        Some(answer) => answer,
+        None => {
+            let default = cli::args().backup.unwrap_or(true);
+            prompt::yes_no(&t!("backup-prompt", items = names.join(", ")), Some(default))?
         }
-    );
-    // Spawn PowerShell to run the script.
-    let status = std::process::Command::new("powershell")
-        .args(&["-NoProfile", "-Command", &script])
-        .status()?;
-    if !status.success() {
-        return Err("Failed to create shortcut".into());
+    };
+    if !accepted {
+        write_log(log_file, "User declined the user data backup.")?;
+        return Ok(());
+    }
+    color_echo(ConsoleColor::Blue, &t!("backup-creating"));
+    let archive = backup::create(&base_dir.join(backup::BACKUPS_DIR), target, &sources).inspect_err(|ex| {
+        color_echo(ConsoleColor::Red, &t!("backup-failed", error = ex.to_string()));
+    })?;
+    color_echo(ConsoleColor::Green, &t!("backup-created", path = archive.display().to_string()));
+    write_log(log_file, &format!("Backed up {} to {}", names.join(", "), archive.display()))?;
+
+    let keep = options.keep_backups.unwrap_or(backup::DEFAULT_KEEP);
+    let pruned = backup::prune(&base_dir.join(backup::BACKUPS_DIR), keep, options.backup_max_age_days);
+    for path in &pruned {
+        write_log(log_file, &format!("Deleted old backup {}", path.display()))?;
+    }
+    if !pruned.is_empty() {
+        color_echo(ConsoleColor::Blue, &t!("backup-pruned", count = pruned.len()));
     }
     Ok(())
 }
 
-/// Waits for any key press and then exits.
-async fn pause_and_exit() {
-    println!("Press any key to exit...");
-    enable_raw_mode().unwrap();
-    loop {
-        if event::poll(std::time::Duration::from_millis(500)).unwrap() {
-            if let Event::Key(_) = event::read().unwrap() {
-                break;
+/// `--restore [backup]` mode: picks a backup (by name, or from a list) and restores the chosen
+/// parts of it into an install, as one run that can be rolled back.
+fn restore_backup(base_dir: &Path, log_file: &Path, requested: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let read_answer = |question: String| prompt::line(&question, None);
+
+    let backups_dir = base_dir.join(backup::BACKUPS_DIR);
+    let mut backups = backup::list(&backups_dir);
+    let (archive, index) = match requested {
+        Some(name) if Path::new(name).is_file() => (PathBuf::from(name), backup::read_index(Path::new(name))?),
+        Some(name) => {
+            let position = backups.iter().position(|(path, _)| {
+                path.file_name().is_some_and(|file| file.eq_ignore_ascii_case(name))
+                    || path.file_stem().is_some_and(|stem| stem.eq_ignore_ascii_case(name))
+            });
+            match position {
+                Some(position) => backups.swap_remove(position),
+                None => return Err(t!("err-restore-not-found", name = name).into()),
             }
         }
-    }
-    disable_raw_mode().unwrap();
-    std::process::exit(0);
-}
+        None => {
+            if backups.is_empty() {
+                color_echo(ConsoleColor::Yellow, &t!("restore-none", path = backups_dir.display().to_string()));
+                return Ok(());
+            }
+            let labels: Vec<String> = backups
+                .iter()
+                .map(|(path, index)| {
+                    let names: Vec<&str> = index.sources.iter().map(|entry| entry.name.as_str()).collect();
+                    format!("{}  [{}]", path.file_name().unwrap_or_default().to_string_lossy(), names.join(", "))
+                })
+                .collect();
+            let position = prompt::choose(&t!("restore-available"), &labels, &t!("restore-choose"), None)?;
+            backups.swap_remove(position)
+        }
+    };
 
-/// Reads a password from the console while masking input with asterisks.
-fn read_password(prompt: &str) -> io::Result<String> {
-    print!("{}", prompt);
-    io::stdout().flush()?;
-    let mut password = String::new();
-    enable_raw_mode()?;
-    loop {
-        if let Event::Key(key_event) = event::read()? {
-            match key_event.code {
-                KeyCode::Enter => {
-                    println!();
-                    break;
-                }
-                KeyCode::Backspace => {
-                    if !password.is_empty() {
-                        password.pop();
-                        print!("\r{} \r", "*".repeat(password.len()));
-                        io::stdout().flush()?;
-                    }
-                }
-                KeyCode::Char(c) => {
-                    password.push(c);
-                    print!("*");
-                    io::stdout().flush()?;
-                }
-                _ => {}
+    println!("{}", t!("restore-contents", created = index.created.as_str()));
+    for (number, entry) in index.sources.iter().enumerate() {
+        println!("  {}) {}  ({})", number + 1, entry.name, entry.path.display());
+    }
+    let answer = read_answer(t!("restore-choose-items"))?;
+    let mut names = Vec::new();
+    if answer.is_empty() {
+        names.extend(index.sources.iter().map(|entry| entry.name.as_str()));
+    } else {
+        for part in answer.split(',') {
+            match part.trim().parse::<usize>().ok().and_then(|n| index.sources.get(n.wrapping_sub(1))) {
+                Some(entry) => names.push(entry.name.as_str()),
+                None => return Err(t!("err-invalid-choice", choice = part.trim()).into()),
             }
         }
     }
-    disable_raw_mode()?;
-    Ok(password)
-}
-
-/// Structure to store GitHub release info.
-#[derive(Debug, Deserialize)]
-struct GitHubRelease {
-    tag_name: String,
-    assets: Vec<GitHubAsset>,
-}
 
-#[derive(Debug, Deserialize)]
-struct GitHubAsset {
-    name: String,
-    browser_download_url: String,
-}
-
-/// Helper structure for release info.
-struct ReleaseInfo {
-    tag_name: String,
-    download_url: String,
-}
-
-/// Retrieves the latest release info from GitHub.
-async fn get_latest_release(
-    owner: &str,
-    repo: &str,
-    asset_name_filter: Option<&str>,
-) -> Option<ReleaseInfo> {
-    let url = format!("https://api.github.com/repos/{}/{}/releases/latest", owner, repo);
-    let client = Client::builder().user_agent("DesktopMateInstaller").build().ok()?;
-    let resp = client.get(&url).send().await.ok()?;
-    if !resp.status().is_success() {
-        return None;
+    let default_target = index.game_path.display().to_string();
+    let answer = prompt::line(&t!("restore-target-prompt", path = default_target.as_str()), Some(&default_target))?;
+    let game_dir = PathBuf::from(if answer.is_empty() { default_target } else { answer });
+    if !game_dir.is_dir() {
+        return Err(t!("err-restore-target-missing", path = game_dir.display().to_string()).into());
     }
-    let release: GitHubRelease = resp.json().await.ok()?;
-    let mut download_url = String::new();
-    for asset in release.assets {
-        if let Some(filter) = asset_name_filter {
-            if asset.name.eq_ignore_ascii_case(filter) {
-                download_url = asset.browser_download_url;
-                break;
+
+    write_log(
+        log_file,
+        &format!("Restoring {} from {} into {}", names.join(", "), archive.display(), game_dir.display()),
+    )?;
+    transaction::begin(&game_dir)?;
+    match backup::restore(&archive, &index, &names, &game_dir) {
+        Ok(reports) => {
+            transaction::commit();
+            for (target, report) in reports {
+                color_echo(
+                    ConsoleColor::Green,
+                    &t!("restore-done", count = report.copied + report.unchanged, path = target.display().to_string()),
+                );
+                log_copy_report(log_file, &target.display().to_string(), &report)?;
             }
-        } else if asset.name.to_lowercase().ends_with(".zip") {
-            download_url = asset.browser_download_url;
-            break;
+            Ok(())
+        }
+        Err(ex) => {
+            color_echo(ConsoleColor::Red, &t!("restore-failed", error = ex.to_string()));
+            write_log(log_file, &format!("ERROR: Restore failed: {}", ex))?;
+            offer_rollback(log_file)?;
+            Ok(())
         }
     }
-    // Fallback for MelonLoader.
-    if download_url.is_empty() && repo.eq_ignore_ascii_case("MelonLoader") {
-        download_url = "https://github.com/LavaGang/MelonLoader/releases/latest/download/MelonLoader.x64.zip".to_owned();
-    }
-    Some(ReleaseInfo { tag_name: release.tag_name, download_url })
 }
 
-/// Installs MelonLoader version 0.6.6 by downloading and extracting its ZIP into the game directory.
-async fn update_melonloader_if_needed(target_path: &str, log_file: &Path) -> Result<(), Box<dyn Error>> {
-    let version_file = Path::new(target_path).join("MelonLoader.version");
-    let installed_version = if version_file.exists() {
-        fs::read_to_string(&version_file)?.trim().to_string()
-    } else {
-        String::new()
-    };
+/// Installs the manifest's MelonLoader version by downloading and extracting its ZIP into the game directory.
+/// With `reinstall`, does so even when that version is already installed.
+async fn update_melonloader_if_needed(
+    target_path: &str,
+    log_file: &Path,
+    melonloader: &manifest::MelonLoaderSpec,
+    reinstall: bool,
+) -> Result<(), Box<dyn Error>> {
+    if let Some((recorded, detected)) = melonloader::reconcile(Path::new(target_path))? {
+        let message = t!("melon-version-corrected", recorded = recorded.as_str(), detected = detected.as_str());
+        color_echo(ConsoleColor::Yellow, &message);
+        write_log(
+            log_file,
+            &format!("install.json recorded MelonLoader {}, but MelonLoader.dll is {}.", recorded, detected),
+        )?;
+    }
+    let state = state::InstallState::load(Path::new(target_path))?;
+    let installed_version = state.version("MelonLoader").unwrap_or_default().to_owned();
 
-    let desired_version = "v0.6.6";
-    if installed_version == desired_version {
-        color_echo(ConsoleColor::Green, &format!("MelonLoader is up-to-date (version {}).", installed_version));
+    let desired_version = melonloader.version.as_str();
+    if version::same(&installed_version, desired_version) && !reinstall {
+        color_echo(ConsoleColor::Green, &t!("melon-up-to-date", version = installed_version));
         write_log(log_file, &format!("MelonLoader up-to-date (version {}).", installed_version))?;
         return Ok(());
     }
+    if version::is_newer(&installed_version, desired_version) {
+        // Newer MelonLoader builds have broken mods before; the manifest pins the one known to work.
+        color_echo(
+            ConsoleColor::Yellow,
+            &t!("melon-downgrading", installed = installed_version.as_str(), version = desired_version),
+        );
+        write_log(
+            log_file,
+            &format!("Replacing newer MelonLoader {} with supported {}.", installed_version, desired_version),
+        )?;
+    }
 
-    color_echo(ConsoleColor::Yellow, &format!("Installing MelonLoader {}...", desired_version));
+    color_echo(ConsoleColor::Yellow, &t!("melon-installing", version = desired_version));
     write_log(log_file, &format!("Downloading MelonLoader {} zip.", desired_version))?;
 
-    let melon_zip_url = "https://github.com/LavaGang/MelonLoader/releases/download/v0.6.6/MelonLoader.x64.zip";
-    let melon_zip_path = env::temp_dir().join("MelonLoader.x64.zip");
+    let melon_zip_url = melonloader.url.as_str();
+    let melon_zip_path = TempPath::file("MelonLoader.x64", "zip");
     download_file(melon_zip_url, &melon_zip_path).await?;
+    let expected = melonloader.sha256.as_deref();
+    pins::verify(pins::MELONLOADER, Some(desired_version), expected, &melon_zip_path, log_file)?;
 
-    color_echo(ConsoleColor::Blue, "Extracting MelonLoader contents to game directory...");
+    color_echo(ConsoleColor::Blue, &t!("melon-extracting"));
     write_log(log_file, "Extracting MelonLoader contents to game directory.")?;
-    extract_zip(&melon_zip_path, Path::new(target_path))?;
-    fs::remove_file(&melon_zip_path)?;
-    fs::write(&version_file, desired_version)?;
-    color_echo(ConsoleColor::Green, "MelonLoader installed successfully.");
+    // Assemble the new MelonLoader beside the game and swap it in only once it looks complete.
+    let stage = Stage::new(Path::new(target_path))?;
+    extract_archive(&melon_zip_path, stage.path())?;
+    let archive_sha256 = transaction::sha256_file(&melon_zip_path)?;
+    drop(melon_zip_path);
+    if !stage.path().join("version.dll").exists() || !stage.path().join("MelonLoader").is_dir() {
+        color_echo(ConsoleColor::Red, &t!("melon-incomplete"));
+        write_log(log_file, "ERROR: MelonLoader archive is missing version.dll or the MelonLoader folder.")?;
+        return Err(t!("err-melon-incomplete").into());
+    }
+    signature::audit(
+        pins::MELONLOADER,
+        stage.path(),
+        &["version.dll", "MelonLoader/net6/MelonLoader.dll", "MelonLoader/net35/MelonLoader.dll"],
+        melonloader.publisher.as_deref(),
+        log_file,
+    );
+    let delta = stage.promote_delta("MelonLoader", desired_version)?;
+    components::record_source(Path::new(target_path), "MelonLoader", melon_zip_url, Some(archive_sha256))?;
+    write_log(
+        log_file,
+        &format!(
+            "MelonLoader files: {} written, {} unchanged, {} removed.",
+            delta.written, delta.unchanged, delta.removed
+        ),
+    )?;
+    color_echo(ConsoleColor::Green, &t!("melon-installed"));
     write_log(log_file, "MelonLoader installed successfully.")?;
     Ok(())
 }
 
-/// Installs or updates the Custom Avatar Loader mod.
-/// It now checks for both the "Mods" and "UserLibs" folders and copies them into the game directory.
-async fn install_or_update_custom_avatar_loader(target_path: &str, log_file: &Path) -> Result<(), Box<dyn Error>> {
-    let version_file = Path::new(target_path).join("CustomAvatarLoader.version");
-    let installed_version = if version_file.exists() {
-        fs::read_to_string(&version_file)?.trim().to_string()
-    } else {
-        String::new()
-    };
+/// Installs or updates a mod from the newest release its source offers (GitHub, Thunderstore, or
+/// GameBanana, see [`mods::latest_release`]); with `reinstall`, also when it is already up to
+/// date. The release's `Mods` and `UserLibs` folders are copied into the game directory.
+async fn install_or_update_mod(
+    target_path: &str,
+    log_file: &Path,
+    spec: &manifest::ModSpec,
+    accept_update: Option<bool>,
+    conflict_policy: config::ConflictPolicy,
+    reinstall: bool,
+) -> Result<(), Box<dyn Error>> {
+    let state = state::InstallState::load(Path::new(target_path))?;
+    let installed_version = state.version(&spec.component).unwrap_or_default().to_owned();
+    // Updating would put its files back in `Mods`.
+    if state.is_component_disabled(&spec.component) {
+        color_echo(ConsoleColor::Yellow, &t!("mod-disabled-skipped", mod = spec.name.as_str()));
+        write_log(log_file, &format!("{} is disabled; not checking for updates.", spec.name))?;
+        return Ok(());
+    }
 
-    color_echo(ConsoleColor::Blue, "Checking for Custom Avatar Loader mod updates...");
-    write_log(log_file, "Checking for Custom Avatar Loader mod updates.")?;
-    if let Some(latest_release) = get_latest_release("YusufOzmen01", "desktopmate-custom-avatar-loader", Some("CustomAvatarLoader.zip")).await {
-        if installed_version == latest_release.tag_name {
-            color_echo(ConsoleColor::Green, &format!("Custom Avatar Loader mod is up-to-date (version {}).", installed_version));
-            write_log(log_file, &format!("Custom Avatar Loader mod up-to-date (version {}).", installed_version))?;
-        } else {
-            if installed_version.is_empty() {
-                color_echo(ConsoleColor::Yellow, "Custom Avatar Loader mod not installed. Installing now...");
-                write_log(log_file, "Custom Avatar Loader mod not installed. Installing.")?;
-            } else {
-                color_echo(ConsoleColor::Yellow, &format!(
-                    "Custom Avatar Loader mod update available: Installed version: {}, Latest version: {}",
-                    installed_version, latest_release.tag_name
+    color_echo(ConsoleColor::Blue, &t!("mod-checking", mod = spec.name.as_str()));
+    write_log(log_file, &format!("Checking for {} updates.", spec.name))?;
+    let asset_pattern = AssetPattern::new(&spec.asset)?;
+    match mods::latest_release(spec, &asset_pattern, log_file).await {
+        Ok(latest_release) => {
+            if version::same(&installed_version, &latest_release.tag_name) && !reinstall {
+                color_echo(ConsoleColor::Green, &t!("mod-up-to-date", mod = spec.name.as_str(), version = installed_version));
+                write_log(log_file, &format!("{} up-to-date (version {}).", spec.name, installed_version))?;
+            } else if version::is_newer(&installed_version, &latest_release.tag_name) {
+                color_echo(ConsoleColor::Yellow, &t!(
+                    "mod-newer-installed", mod = spec.name.as_str(),
+                    installed = installed_version.as_str(),
+                    latest = latest_release.tag_name.as_str(),
                 ));
                 write_log(log_file, &format!(
-                    "Custom Avatar Loader mod update available: Installed version: {}, Latest version: {}",
-                    installed_version, latest_release.tag_name
+                    "{} {} is newer than the latest release {}; leaving it in place.",
+                    spec.name, installed_version, latest_release.tag_name
                 ))?;
-                print!("Do you want to update Custom Avatar Loader mod? (Y/N): ");
-                io::stdout().flush()?;
-                let mut response = String::new();
-                io::stdin().read_line(&mut response)?;
-                if response.trim().to_uppercase() != "Y" {
-                    color_echo(ConsoleColor::Yellow, "Skipping Custom Avatar Loader mod update.");
-                    write_log(log_file, "User opted to skip Custom Avatar Loader mod update.")?;
-                    return Ok(());
-                }
-            }
-            let mod_zip = env::temp_dir().join(format!("custom_avatar_{}.zip", uuid::Uuid::new_v4()));
-            color_echo(ConsoleColor::Blue, "Downloading Custom Avatar Loader mod...");
-            write_log(log_file, &format!("Downloading Custom Avatar Loader mod from {}", latest_release.download_url))?;
-            download_file(&latest_release.download_url, &mod_zip).await.map_err(|e| {
-                color_echo(ConsoleColor::Red, &format!("ERROR: Failed to download Custom Avatar Loader mod: {}", e));
-                write_log(log_file, "ERROR: Custom Avatar Loader mod download failed.").unwrap();
-                e
-            })?;
-            let extract_path = env::temp_dir().join("custom_avatar_loader_extracted");
-            if extract_path.exists() {
-                fs::remove_dir_all(&extract_path)?;
-            }
-            fs::create_dir_all(&extract_path)?;
-            extract_zip(&mod_zip, &extract_path)?;
-            fs::remove_file(&mod_zip)?;
-
-            // If the ZIP contains a single folder, use it as the root.
-            let root_extracted = {
-                let dirs: Vec<_> = fs::read_dir(&extract_path)?
-                    .filter_map(Result::ok)
-                    .filter(|entry| entry.path().is_dir())
-                    .collect();
-                if dirs.len() == 1 {
-                    dirs[0].path()
+            } else {
+                if state.component(&spec.component).is_none() {
+                    color_echo(ConsoleColor::Yellow, &t!("mod-not-installed", mod = spec.name.as_str()));
+                    write_log(log_file, &format!("{} not installed. Installing.", spec.name))?;
+                } else if reinstall {
+                    color_echo(ConsoleColor::Blue, &t!(
+                        "mod-reinstalling", mod = spec.name.as_str(),
+                        version = latest_release.tag_name.as_str(),
+                    ));
+                    write_log(log_file, &format!("Reinstalling {} {}.", spec.name, latest_release.tag_name))?;
                 } else {
-                    extract_path.clone()
+                    // A manually installed copy adopted without a known version.
+                    let installed_version = if installed_version.is_empty() {
+                        t!("summary-unknown")
+                    } else {
+                        installed_version.clone()
+                    };
+                    color_echo(ConsoleColor::Yellow, &t!(
+                        "mod-update-available", mod = spec.name.as_str(),
+                        installed = installed_version,
+                        latest = latest_release.tag_name,
+                    ));
+                    write_log(log_file, &format!(
+                        "{} update available: Installed version: {}, Latest version: {}",
+                        spec.name, installed_version, latest_release.tag_name
+                    ))?;
+                    let accepted = match accept_update {
+                        Some(answer) => answer,
+                        None => {
+                            let default = cli::args().update_mods.unwrap_or(true);
+                            prompt::yes_no(&t!("mod-update-prompt", mod = spec.name.as_str()), Some(default))?
+                        }
+                    };
+                    if !accepted {
+                        color_echo(ConsoleColor::Yellow, &t!("mod-update-skipped", mod = spec.name.as_str()));
+                        write_log(log_file, &format!("User opted to skip {} update.", spec.name))?;
+                        return Ok(());
+                    }
                 }
-            };
+                if latest_release.download_url.is_empty() {
+                    color_echo(ConsoleColor::Red, &t!("mod-no-matching-asset", mod = spec.name.as_str(), pattern = asset_pattern.to_string()));
+                    write_log(log_file, &format!(
+                        "ERROR: No asset of {} {} matches '{}'. Assets: {}",
+                        spec.name, latest_release.tag_name, asset_pattern, latest_release.asset_names.join(", ")
+                    ))?;
+                    return Err(t!("err-no-matching-asset", pattern = asset_pattern.to_string()).into());
+                }
+                let mod_zip = TempPath::file(&spec.component, "zip");
+                color_echo(ConsoleColor::Blue, &t!("mod-downloading", mod = spec.name.as_str()));
+                write_log(log_file, &format!("Downloading {} from {}", spec.name, latest_release.download_url))?;
+                download_file(&latest_release.download_url, &mod_zip).await.map_err(|e| {
+                    color_echo(ConsoleColor::Red, &t!("mod-download-failed", mod = spec.name.as_str(), error = e));
+                    write_log(log_file, &format!("ERROR: {} download failed.", spec.name)).unwrap();
+                    e
+                })?;
+                pins::verify(&spec.component, Some(&latest_release.tag_name), None, &mod_zip, log_file)?;
+                let extract_path = TempPath::dir(&format!("{}_extracted", spec.component))?;
+                extract_archive(&mod_zip, &extract_path)?;
+                let archive_sha256 = transaction::sha256_file(&mod_zip)?;
+                drop(mod_zip);
 
-            let mut copied_something = false;
-            let mods_source = root_extracted.join("Mods");
-            if mods_source.exists() {
-                copy_directory(&mods_source, &Path::new(target_path).join("Mods"))?;
-                copied_something = true;
-            }
-            let userlibs_source = root_extracted.join("UserLibs");
-            if userlibs_source.exists() {
-                copy_directory(&userlibs_source, &Path::new(target_path).join("UserLibs"))?;
-                copied_something = true;
-            }
-            fs::remove_dir_all(&extract_path)?;
-            if !copied_something {
-                color_echo(ConsoleColor::Red, "ERROR: Neither 'Mods' nor 'UserLibs' directory found in the extracted archive!");
-                write_log(log_file, "ERROR: Extracted mod archive does not contain expected 'Mods' or 'UserLibs' directories.")?;
-                pause_and_exit().await;
+                // If the ZIP contains a single folder, use it as the root.
+                let root_extracted = {
+                    let dirs: Vec<_> = fs::read_dir(&extract_path)?
+                        .filter_map(Result::ok)
+                        .filter(|entry| entry.path().is_dir())
+                        .collect();
+                    if dirs.len() == 1 {
+                        dirs[0].path()
+                    } else {
+                        extract_path.to_path_buf()
+                    }
+                };
+
+                // Thunderstore packages and some releases ship the mod's DLLs bare.
+                if !root_extracted.join("Mods").exists() && !root_extracted.join("UserLibs").exists() {
+                    let dlls: Vec<PathBuf> = fs::read_dir(&root_extracted)?
+                        .filter_map(Result::ok)
+                        .map(|entry| entry.path())
+                        .filter(|path| path.is_file())
+                        .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("dll")))
+                        .collect();
+                    if !dlls.is_empty() {
+                        fs::create_dir(root_extracted.join("Mods"))?;
+                        for dll in dlls {
+                            fs::rename(&dll, root_extracted.join("Mods").join(dll.file_name().unwrap_or_default()))?;
+                        }
+                    }
+                }
+
+                // Kept for `mod rollback`; a repair has nothing worth keeping.
+                if !reinstall {
+                    if let Err(ex) = mod_history::save(Path::new(target_path), &spec.component) {
+                        let message = format!("WARNING: Could not keep {} {}: {}", spec.name, installed_version, ex);
+                        write_log(log_file, &message)?;
+                    }
+                }
+
+                let mut copied_something = false;
+                let mut installed_files = Vec::new();
+                let mods_source = root_extracted.join("Mods");
+                if mods_source.exists() {
+                    let report = copy_directory(&mods_source, &Path::new(target_path).join("Mods"), conflict_policy)?;
+                    log_copy_report(log_file, "Mods", &report)?;
+                    installed_files.extend(report.files);
+                    copied_something = true;
+                }
+                let userlibs_source = root_extracted.join("UserLibs");
+                if userlibs_source.exists() {
+                    let report = copy_directory(&userlibs_source, &Path::new(target_path).join("UserLibs"), conflict_policy)?;
+                    log_copy_report(log_file, "UserLibs", &report)?;
+                    installed_files.extend(report.files);
+                    copied_something = true;
+                }
+                drop(extract_path);
+                if !copied_something {
+                    color_echo(ConsoleColor::Red, &t!("mod-no-dirs", mod = spec.name.as_str()));
+                    write_log(log_file, "ERROR: Extracted mod archive does not contain expected 'Mods' or 'UserLibs' directories.")?;
+                    return Err(t!("err-mod-no-dirs", mod = spec.name.as_str()).into());
+                }
+
+                // Drop files the previous release shipped but this one doesn't.
+                let names: Vec<String> = installed_files
+                    .iter()
+                    .filter_map(|path| components::relative_name(Path::new(target_path), path))
+                    .collect();
+                for stale in components::remove_dropped(Path::new(target_path), &spec.component, &names)? {
+                    write_log(log_file, &format!("Removed file dropped by the new release: {}", stale.display()))?;
+                }
+                components::save(Path::new(target_path), &spec.component, &latest_release.tag_name, &names)?;
+                components::record_source(
+                    Path::new(target_path),
+                    &spec.component,
+                    &latest_release.download_url,
+                    Some(archive_sha256),
+                )?;
+                color_echo(ConsoleColor::Green, &t!("mod-installed", mod = spec.name.as_str()));
+                write_log(log_file, &format!("{} installed/updated.", spec.name))?;
             }
-            fs::write(&version_file, &latest_release.tag_name)?;
-            color_echo(ConsoleColor::Green, "Custom Avatar Loader mod installed/updated successfully.");
-            write_log(log_file, "Custom Avatar Loader mod installed/updated.")?;
         }
-    } else {
-        color_echo(ConsoleColor::Yellow, "Could not retrieve latest Custom Avatar Loader mod release info. Skipping update check.");
-        write_log(log_file, "Failed to get latest Custom Avatar Loader mod release info.")?;
+        Err(ex) => {
+            color_echo(ConsoleColor::Yellow, &t!("mod-no-release-info", mod = spec.name.as_str(), error = ex.to_string()));
+            write_log(log_file, &format!("Failed to get latest {} release info: {}", spec.name, ex))?;
+        }
     }
     Ok(())
 }
 
-/// Recursively copies a directory from source to destination.
-fn copy_directory(source: &Path, destination: &Path) -> io::Result<()> {
-    fs::create_dir_all(destination)?;
-    for entry in fs::read_dir(source)? {
-        let entry = entry?;
-        let path = entry.path();
-        let dest_path = destination.join(entry.file_name());
-        if path.is_dir() {
-            copy_directory(&path, &dest_path)?;
-        } else {
-            fs::copy(&path, &dest_path)?;
-        }
+/// Logs what a copy into the game directory did and points out files the user should know about.
+fn log_copy_report(log_file: &Path, label: &str, report: &copy::CopyReport) -> io::Result<()> {
+    write_log(
+        log_file,
+        &format!(
+            "{}: {} file(s) copied, {} unchanged, {} kept, {} backed up, {} link(s) ignored.",
+            label,
+            report.copied,
+            report.unchanged,
+            report.skipped.len(),
+            report.backups.len(),
+            report.links_ignored.len()
+        ),
+    )?;
+    for path in &report.skipped {
+        color_echo(ConsoleColor::Yellow, &t!("copy-kept", path = path.display()));
+        write_log(log_file, &format!("Kept modified file: {}", path.display()))?;
+    }
+    for path in &report.backups {
+        color_echo(ConsoleColor::Yellow, &t!("copy-backed-up", path = path.display()));
+        write_log(log_file, &format!("Backed up modified file to: {}", path.display()))?;
     }
     Ok(())
 }
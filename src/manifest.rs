@@ -0,0 +1,460 @@
+// manifest.rs
+//! Component manifest: where every third-party piece comes from and which version to use.
+//!
+//! The manifest is fetched from the repository at startup so a dead download link can be fixed
+//! by editing `manifest/<channel>.json` instead of shipping a new binary. The copy compiled into
+//! the installer is used whenever the remote one is unreachable, unparsable, or too new.
+
+use crate::config::Channel;
+use crate::proxy;
+use crate::retry;
+use crate::write_log;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Highest manifest schema this build understands.
+pub const SUPPORTED_SCHEMA: u32 = 1;
+
+pub const REMOTE_BASE_URL: &str =
+    "https://raw.githubusercontent.com/Nighthawk42/desktopmate_installer/main/manifest";
+
+/// `game.manifest_id` meaning the depot's current build rather than a pinned one.
+pub const LATEST_BUILD: &str = "latest";
+
+const BUNDLED_STABLE: &str = include_str!("../manifest/stable.json");
+const BUNDLED_PREVIEW: &str = include_str!("../manifest/preview.json");
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentManifest {
+    pub schema: u32,
+    /// Free-form revision label, logged so support can tell which manifest a run used.
+    pub revision: String,
+    pub depot_downloader: DepotDownloaderSpec,
+    pub game: GameSpec,
+    pub goldberg: GoldbergSpec,
+    pub melonloader: MelonLoaderSpec,
+    pub avatar_loader: ModSpec,
+    /// What the install does, in order. Omitted, it is [`default_steps`].
+    #[serde(default = "default_steps")]
+    pub steps: Vec<Step>,
+}
+
+/// One step of the install pipeline. Written in the manifest as an object whose `type` names
+/// the step, e.g. `{ "type": "goldberg" }`; a `mod` step also carries a [`ModSpec`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Step {
+    /// Downloads the game depot when it is missing or an update was accepted.
+    Game,
+    /// Replaces the game's `steam_api64.dll` with Goldberg's.
+    Goldberg,
+    /// Installs the pinned MelonLoader.
+    #[serde(rename = "melonloader")]
+    MelonLoader,
+    /// Installs or updates the manifest's `avatar_loader` mod.
+    AvatarLoader,
+    /// Installs or updates another mod from its GitHub releases.
+    Mod(ModSpec),
+    /// Creates the desktop shortcuts the user asked for.
+    Shortcuts,
+}
+
+impl Step {
+    /// Whether the install stops when this step fails. The game, the patch, and MelonLoader
+    /// are what makes it run at all; a mod or the shortcuts failing is only a warning, and the
+    /// remaining steps still run.
+    pub fn is_critical(&self) -> bool {
+        matches!(self, Step::Game | Step::Goldberg | Step::MelonLoader)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepotDownloaderSpec {
+    pub url: String,
+    /// Release to download instead of the latest; see [`crate::depot_tool`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Publisher the Authenticode signature of its binaries must name; see [`crate::signature`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    /// Other places to download it from, tried in order when `url` fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
+    /// SHA-256 of the archive. When set, the download must match it, whatever the hash database
+    /// says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// The game being modded: its Steam identifiers, passed to DepotDownloader, and its layout on
+/// disk. The layout defaults to DesktopMate's, so only other games need to spell it out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSpec {
+    pub app_id: String,
+    pub depot_id: String,
+    /// Depot manifest to download, or [`LATEST_BUILD`] for whatever Steam currently serves.
+    pub manifest_id: String,
+    /// Steam branch to download (`beta`, say); `None` keeps the one installed, else `public`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Depot files to download; everything unless the manifest narrows it.
+    #[serde(default, skip_serializing_if = "FileFilter::is_empty")]
+    pub files: FileFilter,
+    /// Approximate size of the depot download in bytes, shown in the download estimate.
+    #[serde(default)]
+    pub download_size: Option<u64>,
+    /// Display name, also the default shortcut name.
+    #[serde(default = "default_game_name")]
+    pub name: String,
+    /// The game's executable, relative to the install folder.
+    #[serde(default = "default_executable")]
+    pub executable: String,
+    /// Unity's `<name>_Data` folder, whose presence means the game files are installed.
+    #[serde(default = "default_data_dir")]
+    pub data_dir: String,
+    /// Where Goldberg's `steam_api64.dll` goes, relative to the install folder, `/`-separated.
+    #[serde(default = "default_steam_api")]
+    pub steam_api: String,
+    /// Install folder offered when none was used before.
+    #[serde(default = "default_install_path")]
+    pub default_path: String,
+}
+
+impl GameSpec {
+    /// The depot manifest to ask DepotDownloader for; `None` lets it fetch the current build.
+    pub fn pinned_manifest(&self) -> Option<&str> {
+        Some(self.manifest_id.as_str()).filter(|id| !id.eq_ignore_ascii_case(LATEST_BUILD))
+    }
+}
+
+/// Which depot files DepotDownloader fetches, so optional ones such as extra language packs can
+/// be left out. Both lists hold regular expressions matched case-insensitively against whole
+/// `/`-separated paths: a file is downloaded when it matches an `include` pattern (or there are
+/// none) and no `exclude` pattern.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileFilter {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl FileFilter {
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether the depot file at `path` is downloaded.
+    pub fn includes(&self, path: &str) -> bool {
+        let path = path.replace('\\', "/");
+        let included = whole_match(&self.include).is_none_or(|include| include.is_match(&path));
+        included && !whole_match(&self.exclude).is_some_and(|exclude| exclude.is_match(&path))
+    }
+
+    /// The filter as a DepotDownloader `-filelist` file: one `regex:` line, which .NET, unlike
+    /// the `regex` crate, can express exclusions in with a lookahead.
+    pub fn filelist(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let include = if self.include.is_empty() { ".*".to_owned() } else { self.include.join("|") };
+        let exclude = if self.exclude.is_empty() {
+            String::new()
+        } else {
+            format!("(?!(?:{})$)", self.exclude.join("|"))
+        };
+        Some(format!("regex:^{}(?:{})$\n", exclude, include))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for pattern in self.include.iter().chain(&self.exclude) {
+            regex::Regex::new(pattern).map_err(|e| format!("game.files pattern {:?}: {}", pattern, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// One regex matching any of `patterns` whole, or `None` for no patterns.
+fn whole_match(patterns: &[String]) -> Option<regex::Regex> {
+    if patterns.is_empty() {
+        return None;
+    }
+    regex::Regex::new(&format!("(?i)^(?:{})$", patterns.join("|"))).ok()
+}
+
+/// Mr. Goldberg's emulator and the maintained gbe_fork. See [`crate::goldberg`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoldbergSpec {
+    /// Which emulator to install; the other one is the fallback.
+    #[serde(default)]
+    pub provider: GoldbergProvider,
+    /// gbe_fork's regular or experimental DLL.
+    #[serde(default)]
+    pub build: GoldbergBuild,
+    /// Where gbe_fork's releases are; without it, only Mr. Goldberg's emulator is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gbe_fork: Option<GbeForkSpec>,
+    /// Mr. Goldberg's build.
+    pub url: String,
+    /// Path of the replacement `steam_api64.dll` inside the archive, `/`-separated.
+    pub dll_path: String,
+    /// CI job whose newest artifacts replace `url`, which then only serves as the fallback.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitlab: Option<GitLabJob>,
+    /// Other places to download it from, tried in order when `url` fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
+    /// SHA-256 of the archive. When set, the download must match it, whatever the hash database
+    /// says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GoldbergProvider {
+    /// Mr. Goldberg's original emulator, built on GitLab; no longer developed.
+    #[default]
+    MrGoldberg,
+    /// Detanup01's maintained fork, released on GitHub.
+    GbeFork,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoldbergBuild {
+    /// The emulator alone.
+    Regular,
+    /// With the extra hooks some games need; what the installer has always used.
+    #[default]
+    Experimental,
+}
+
+/// gbe_fork's GitHub releases and the DLLs in their archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GbeForkSpec {
+    pub owner: String,
+    pub repo: String,
+    /// Release asset pattern, as for mods.
+    pub asset: String,
+    /// Path of the regular `steam_api64.dll` inside the archive, `/`-separated.
+    pub regular_dll: String,
+    /// Path of the experimental one.
+    pub experimental_dll: String,
+}
+
+/// A job in a GitLab project's CI. See [`crate::gitlab`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabJob {
+    /// `namespace/project`.
+    pub project: String,
+    /// Branch the job ran on.
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// The job's name in the pipeline.
+    pub job: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MelonLoaderSpec {
+    pub version: String,
+    pub url: String,
+    /// Publisher the Authenticode signature of its binaries must name; see [`crate::signature`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+    /// Other places to download it from, tried in order when `url` fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
+    /// SHA-256 of the archive. When set, the download must match it, whatever the hash database
+    /// says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// A mod distributed through GitHub releases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModSpec {
+    /// Display name used in messages.
+    #[serde(default = "default_mod_name")]
+    pub name: String,
+    /// Key of the mod's entry in the install state; keep it stable across releases.
+    #[serde(default = "default_mod_component")]
+    pub component: String,
+    pub owner: String,
+    pub repo: String,
+    /// Asset name pattern; see [`crate::assets`] for the syntax.
+    #[serde(default = "default_asset")]
+    pub asset: String,
+    /// The mod's DLL, relative to the install folder, `/`-separated; checked after installing.
+    #[serde(default = "default_mod_dll")]
+    pub dll: String,
+    /// Where releases come from. For Thunderstore, `owner` is the package's namespace and `repo`
+    /// its name; for GameBanana, `repo` is the mod's ID.
+    #[serde(default, skip_serializing_if = "ModSource::is_github")]
+    pub source: ModSource,
+}
+
+/// The site a mod is published on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ModSource {
+    /// GitHub releases.
+    #[default]
+    #[serde(rename = "github")]
+    GitHub,
+    /// Packages on Thunderstore; see [`crate::thunderstore`].
+    Thunderstore,
+    /// Mods on GameBanana; see [`crate::gamebanana`].
+    #[serde(rename = "gamebanana")]
+    GameBanana,
+}
+
+impl ModSource {
+    fn is_github(&self) -> bool {
+        *self == ModSource::GitHub
+    }
+}
+
+impl ComponentManifest {
+    /// The mod `step` installs, if it is a mod step.
+    pub fn mod_for<'a>(&'a self, step: &'a Step) -> Option<&'a ModSpec> {
+        match step {
+            Step::AvatarLoader => Some(&self.avatar_loader),
+            Step::Mod(spec) => Some(spec),
+            _ => None,
+        }
+    }
+
+    /// The mods the steps install, in order.
+    pub fn mods(&self) -> Vec<&ModSpec> {
+        self.steps.iter().filter_map(|step| self.mod_for(step)).collect()
+    }
+
+    /// Adds a `mod` step for each of `specs` before the shortcuts step, skipping mods whose
+    /// component a step installs already.
+    pub fn add_mod_steps(&mut self, specs: impl IntoIterator<Item = ModSpec>) {
+        let mut steps: Vec<Step> = Vec::new();
+        for spec in specs {
+            let installs = |step: &Step| {
+                self.mod_for(step).is_some_and(|known| known.component.eq_ignore_ascii_case(&spec.component))
+            };
+            if !self.steps.iter().chain(&steps).any(installs) {
+                steps.push(Step::Mod(spec));
+            }
+        }
+        let shortcuts = self.steps.iter().position(|step| matches!(step, Step::Shortcuts));
+        let at = shortcuts.unwrap_or(self.steps.len());
+        self.steps.splice(at..at, steps);
+    }
+}
+
+/// The steps of a manifest that doesn't list its own.
+pub fn default_steps() -> Vec<Step> {
+    vec![Step::Game, Step::Goldberg, Step::MelonLoader, Step::AvatarLoader, Step::Shortcuts]
+}
+
+fn default_asset() -> String {
+    crate::assets::DEFAULT_PATTERN.to_owned()
+}
+
+fn default_game_name() -> String {
+    "DesktopMate".to_owned()
+}
+
+fn default_executable() -> String {
+    "DesktopMate.exe".to_owned()
+}
+
+fn default_data_dir() -> String {
+    "DesktopMate_Data".to_owned()
+}
+
+fn default_steam_api() -> String {
+    "DesktopMate_Data/Plugins/x86_64/steam_api64.dll".to_owned()
+}
+
+fn default_install_path() -> String {
+    r"C:\Games\DesktopMate".to_owned()
+}
+
+fn default_mod_name() -> String {
+    "Custom Avatar Loader".to_owned()
+}
+
+fn default_mod_component() -> String {
+    "CustomAvatarLoader".to_owned()
+}
+
+fn default_mod_dll() -> String {
+    "Mods/CustomAvatarLoader.dll".to_owned()
+}
+
+/// Reads a manifest from a file, such as a game definition named in `installer.toml`.
+pub fn from_file(path: &Path) -> Result<ComponentManifest, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Returns the manifest for `channel`, preferring the remote copy over the bundled one.
+pub async fn load(channel: Channel, log_file: &Path) -> ComponentManifest {
+    match fetch_remote(channel).await {
+        Ok(manifest) => {
+            let _ = write_log(
+                log_file,
+                &format!(
+                    "Using remote {} component manifest (schema {}, revision {}).",
+                    channel, manifest.schema, manifest.revision
+                ),
+            );
+            manifest
+        }
+        Err(reason) => {
+            let manifest = bundled(channel);
+            let _ = write_log(
+                log_file,
+                &format!(
+                    "Remote component manifest unavailable ({}); using bundled revision {}.",
+                    reason, manifest.revision
+                ),
+            );
+            manifest
+        }
+    }
+}
+
+/// The manifest compiled into this build.
+pub fn bundled(channel: Channel) -> ComponentManifest {
+    let text = match channel {
+        Channel::Stable => BUNDLED_STABLE,
+        Channel::Preview => BUNDLED_PREVIEW,
+    };
+    serde_json::from_str(text).expect("bundled component manifest is valid")
+}
+
+async fn fetch_remote(channel: Channel) -> Result<ComponentManifest, String> {
+    let url = format!("{}/{}.json", REMOTE_BASE_URL, channel);
+    let client = proxy::client()
+        .build()
+        .map_err(|e| e.to_string())?;
+    let resp = retry::get(&client, &url).await.map_err(|e| e.to_string())?;
+    let text = resp.text().await.map_err(|e| e.to_string())?;
+    parse(&text)
+}
+
+pub fn parse(text: &str) -> Result<ComponentManifest, String> {
+    // Check the schema before the full parse so a newer layout gives a clear reason.
+    #[derive(Deserialize)]
+    struct SchemaOnly {
+        schema: u32,
+    }
+    let header: SchemaOnly = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    if header.schema > SUPPORTED_SCHEMA {
+        return Err(format!(
+            "schema {} is newer than supported schema {}",
+            header.schema, SUPPORTED_SCHEMA
+        ));
+    }
+    let manifest: ComponentManifest = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    manifest.game.files.validate()?;
+    Ok(manifest)
+}
@@ -0,0 +1,245 @@
+// melonloader.rs
+//! Which MelonLoader release gets installed.
+//!
+//! The manifest pins the release mods are known to work with. `--melonloader` lists LavaGang's
+//! releases and lets the user pick another one (or `--melonloader <tag>` names it); the choice
+//! is saved with the other answers, so later runs and `update` keep installing it until another
+//! is picked. `melonloader_version` in `installer.toml` sets it for every machine the file is
+//! copied to and wins over the saved one. `latest` follows the newest release.
+//!
+//! `melonloader_channel = "preview"` also offers MelonLoader's alpha and nightly prereleases.
+//!
+//! What is actually installed is read from `MelonLoader\net6\MelonLoader.dll` (or the `net35`
+//! build's), whose version resource carries the release number. A MelonLoader updated by hand
+//! leaves `install.json` naming the old release; [`reconcile`] corrects the record from the DLL.
+//!
+//! [`remove`] takes MelonLoader out of a game folder again: its folder, the proxy DLL the game
+//! loads it through, `dobby.dll`, and whatever else `install.json` lists as its files. `Mods`,
+//! `Plugins`, `UserLibs` and `UserData` stay, so reinstalling MelonLoader brings the mods back.
+
+use crate::assets::AssetPattern;
+use crate::config::Channel;
+use crate::game;
+use crate::github::{self, LookupError, ReleaseInfo};
+use crate::manifest::MelonLoaderSpec;
+use crate::state::InstallState;
+use crate::transaction::{self, sha256_file};
+use crate::version;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use winapi::shared::minwindef::{DWORD, UINT};
+use winapi::um::winver::{GetFileVersionInfoSizeW, GetFileVersionInfoW, VerQueryValueW};
+
+const OWNER: &str = "LavaGang";
+const REPO: &str = "MelonLoader";
+const ASSET: &str = "MelonLoader.x64.zip";
+/// MelonLoader's name in `install.json`.
+const COMPONENT: &str = "MelonLoader";
+/// Follows the newest release on the channel instead of one tag.
+pub const LATEST: &str = "latest";
+/// Files and folders only MelonLoader puts in the game folder. `MelonLoader.version` is what
+/// installer builds before `install.json` recorded the version in.
+const FILES: &[&str] = &["MelonLoader", "dobby.dll", "NOTICE.txt", "MelonLoader.version", "version.dll"];
+/// Other DLL names MelonLoader's proxy works under when renamed from `version.dll`.
+const PROXY_NAMES: &[&str] = &[
+    "winmm.dll",
+    "winhttp.dll",
+    "dinput.dll",
+    "dinput8.dll",
+    "dsound.dll",
+    "d3d8.dll",
+    "d3d9.dll",
+    "d3d10.dll",
+    "d3d11.dll",
+    "d3d12.dll",
+    "ddraw.dll",
+    "msacm32.dll",
+];
+/// The log MelonLoader starts each time the game runs with it.
+const LOG: &str = "MelonLoader/Latest.log";
+/// The MelonLoader assembly, by runtime, newest first.
+const ASSEMBLIES: [&str; 3] =
+    ["MelonLoader/net6/MelonLoader.dll", "MelonLoader/net35/MelonLoader.dll", "MelonLoader/MelonLoader.dll"];
+
+/// LavaGang's releases on `channel`, newest first.
+pub async fn releases(channel: Channel, log_file: &Path) -> Result<Vec<ReleaseInfo>, LookupError> {
+    let pattern = AssetPattern::new(ASSET).expect("the MelonLoader asset pattern is valid");
+    let releases = github::list_releases(OWNER, REPO, &pattern, channel == Channel::Preview, log_file).await?;
+    Ok(releases.into_iter().filter(|release| !release.download_url.is_empty()).collect())
+}
+
+/// Makes `spec` install `requested` (a tag or [`LATEST`]). Returns a line for the log, or
+/// `None` when `spec` already is that release.
+pub async fn select(
+    spec: &mut MelonLoaderSpec,
+    requested: &str,
+    channel: Channel,
+    log_file: &Path,
+) -> Result<Option<String>, String> {
+    if version::same(&spec.version, requested) {
+        return Ok(None);
+    }
+    let found = releases(channel, log_file).await.map(|releases| {
+        releases.into_iter().find(|release| {
+            requested.eq_ignore_ascii_case(LATEST) || version::same(&release.tag_name, requested)
+        })
+    });
+    let (tag, url) = match found {
+        Ok(Some(release)) => (release.tag_name, release.download_url),
+        // Without the list, a release's URL follows the pinned one's layout.
+        _ if !requested.eq_ignore_ascii_case(LATEST) && spec.url.contains(&spec.version) => {
+            // `0.6.6` and `v0.6.6` are the same release; the tag is spelled like the pinned one.
+            let bare = requested.trim_start_matches(['v', 'V']);
+            let tag = if spec.version.starts_with(['v', 'V']) { format!("v{}", bare) } else { bare.to_owned() };
+            let url = spec.url.replace(&spec.version, &tag);
+            (tag, url)
+        }
+        Ok(None) => return Err(t!("err-melon-version-unknown", version = requested)),
+        Err(ex) => return Err(ex.to_string()),
+    };
+    if version::same(&spec.version, &tag) {
+        return Ok(None);
+    }
+    let line = format!("MelonLoader {} selected instead of {} ({}).", tag, spec.version, url);
+    spec.version = tag;
+    spec.url = url;
+    // The manifest's hash is for its own release.
+    spec.sha256 = None;
+    Ok(Some(line))
+}
+
+/// The MelonLoader release installed in `root`, from its assembly's file version
+/// (`0.6.6.0` is `v0.6.6`).
+pub fn detect_version(root: &Path) -> Option<String> {
+    ASSEMBLIES.iter().find_map(|assembly| {
+        let (major, minor, patch) = file_version(&root.join(assembly))?;
+        Some(format!("v{}.{}.{}", major, minor, patch))
+    })
+}
+
+/// MelonLoader's log of the last game start in `root`.
+pub fn log_path(root: &Path) -> PathBuf {
+    game::relative(root, LOG)
+}
+
+/// The version MelonLoader printed at the top of the log at `path` (`MelonLoader v0.6.6
+/// Open-Beta`), if it got that far.
+pub fn logged_version(path: &Path) -> Option<String> {
+    let text = fs::read_to_string(path).ok()?;
+    text.lines().take(20).find_map(|line| {
+        let rest = &line[line.find("MelonLoader v")? + "MelonLoader ".len()..];
+        let version = rest.split_whitespace().next()?;
+        Some(version.to_owned())
+    })
+}
+
+/// Corrects the MelonLoader release `install.json` records in `root` when the installed assembly
+/// says otherwise. Returns the recorded and the detected release when they differed.
+pub fn reconcile(root: &Path) -> io::Result<Option<(String, String)>> {
+    let mut state = InstallState::load(root)?;
+    let Some(recorded) = state.version(COMPONENT).map(str::to_owned) else {
+        return Ok(None);
+    };
+    let Some(detected) = detect_version(root) else {
+        return Ok(None);
+    };
+    if version::same(&recorded, &detected) {
+        return Ok(None);
+    }
+    state.set_version(COMPONENT, &detected);
+    state.save(root)?;
+    Ok(Some((recorded, detected)))
+}
+
+/// Deletes MelonLoader from the game in `root` and forgets it in `install.json`, returning what
+/// was deleted. A DLL under another proxy name is only taken for MelonLoader's when it is a copy
+/// of its `version.dll`, so a graphics mod's `d3d11.dll`, say, stays.
+pub fn remove(root: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut state = InstallState::load(root)?;
+    let mut proxy_hashes: BTreeSet<String> = BTreeSet::new();
+    proxy_hashes.extend(sha256_file(&root.join("version.dll")).ok());
+    if let Some(component) = state.component(COMPONENT) {
+        proxy_hashes.extend(component.adopted_sha256.clone());
+        proxy_hashes.extend(component.sha256.get("version.dll").cloned());
+    }
+
+    let mut paths: Vec<PathBuf> = FILES.iter().map(|name| root.join(name)).collect();
+    for name in PROXY_NAMES {
+        let path = root.join(name);
+        let is_proxy = sha256_file(&path)
+            .is_ok_and(|sha256| proxy_hashes.iter().any(|known| known.eq_ignore_ascii_case(&sha256)));
+        if is_proxy {
+            paths.push(path);
+        }
+    }
+    if let Some(component) = state.component(COMPONENT) {
+        paths.extend(component.files.iter().map(|file| game::relative(root, file)));
+    }
+
+    let mut removed = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else if path.is_file() {
+            transaction::record_delete(&path)?;
+            fs::remove_file(&path)?;
+        } else {
+            continue;
+        }
+        removed.push(path);
+    }
+    state.remove_component(COMPONENT);
+    state.save(root)?;
+    Ok(removed)
+}
+
+/// The fixed part of a version resource (`VS_FIXEDFILEINFO`, which winapi doesn't declare).
+#[repr(C)]
+#[allow(non_snake_case)]
+struct FixedFileInfo {
+    dwSignature: DWORD,
+    dwStrucVersion: DWORD,
+    dwFileVersionMS: DWORD,
+    dwFileVersionLS: DWORD,
+    dwProductVersionMS: DWORD,
+    dwProductVersionLS: DWORD,
+    dwFileFlagsMask: DWORD,
+    dwFileFlags: DWORD,
+    dwFileOS: DWORD,
+    dwFileType: DWORD,
+    dwFileSubtype: DWORD,
+    dwFileDateMS: DWORD,
+    dwFileDateLS: DWORD,
+}
+
+/// Major, minor and build number from the fixed part of `path`'s version resource.
+fn file_version(path: &Path) -> Option<(u32, u32, u32)> {
+    if !path.is_file() {
+        return None;
+    }
+    let wide: Vec<u16> = OsStr::new(path).encode_wide().chain(Some(0)).collect();
+    let size = unsafe { GetFileVersionInfoSizeW(wide.as_ptr(), ptr::null_mut()) };
+    if size == 0 {
+        return None;
+    }
+    let mut data = vec![0u8; size as usize];
+    if unsafe { GetFileVersionInfoW(wide.as_ptr(), 0, size, data.as_mut_ptr().cast()) } == 0 {
+        return None;
+    }
+    let root_block: Vec<u16> = OsStr::new("\\").encode_wide().chain(Some(0)).collect();
+    let mut info = ptr::null_mut();
+    let mut length: UINT = 0;
+    let found = unsafe { VerQueryValueW(data.as_ptr().cast(), root_block.as_ptr(), &mut info, &mut length) };
+    if found == 0 || info.is_null() || (length as usize) < std::mem::size_of::<FixedFileInfo>() {
+        return None;
+    }
+    // `info` points into `data`, which is still alive.
+    let info = unsafe { &*(info as *const FixedFileInfo) };
+    Some((info.dwFileVersionMS >> 16, info.dwFileVersionMS & 0xffff, info.dwFileVersionLS >> 16))
+}
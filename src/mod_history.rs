@@ -0,0 +1,173 @@
+// mod_history.rs
+//! Earlier versions of each mod, kept so a broken update can be undone with `mod rollback`.
+//!
+//! Before a mod update copies the new release in, the files of the installed version are copied
+//! to `.dmi-mod-history\<component>\<timestamp>-<version>` in the game folder, together with
+//! the version's `install.json` entry. The newest [`DEFAULT_KEEP`] versions of each mod are kept
+//! (`keep_mod_versions` in `installer.toml`; 0 keeps none). `mod rollback <name>` puts a kept
+//! version back, the newest one different from what is installed unless `--to` names one; the
+//! version it replaces is kept in turn, so a rollback can itself be rolled back.
+//!
+//! A rolled-back mod isn't held at its version: the next update offers the newer release again,
+//! and declining it keeps the older one.
+
+use crate::game;
+use crate::state::{ComponentState, InstallState};
+use crate::transaction;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Versions of each mod kept unless `installer.toml` says otherwise.
+pub const DEFAULT_KEEP: usize = 3;
+const HISTORY_DIR: &str = ".dmi-mod-history";
+const ENTRY_FILE: &str = "component.json";
+const FILES_DIR: &str = "files";
+
+static KEEP: Mutex<usize> = Mutex::new(DEFAULT_KEEP);
+
+/// A kept version, as saved in its folder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Saved {
+    /// RFC 3339 time the version was put aside.
+    pub saved_at: String,
+    /// The version's `install.json` entry.
+    pub component: ComponentState,
+    #[serde(skip)]
+    dir: PathBuf,
+}
+
+/// Keeps `keep` versions of each mod from now on, or [`DEFAULT_KEEP`].
+pub fn set_keep(keep: Option<usize>) {
+    *KEEP.lock().unwrap_or_else(|e| e.into_inner()) = keep.unwrap_or(DEFAULT_KEEP);
+}
+
+fn keep() -> usize {
+    *KEEP.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Puts the installed version of `component` in `root` aside, deleting the oldest kept versions
+/// beyond the limit. Returns whether anything was kept; a component installed without a file
+/// list, or with keeping turned off, isn't.
+pub fn save(root: &Path, component: &str) -> io::Result<bool> {
+    if keep() == 0 || !archive(root, component)? {
+        return Ok(false);
+    }
+    prune(root, component)?;
+    Ok(true)
+}
+
+/// The kept versions of `component` in `root`, newest first.
+pub fn list(root: &Path, component: &str) -> io::Result<Vec<Saved>> {
+    let entries = match fs::read_dir(component_dir(root, component)) {
+        Ok(entries) => entries,
+        Err(ex) if ex.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(ex) => return Err(ex),
+    };
+    let mut saved = Vec::new();
+    for entry in entries {
+        let dir = entry?.path();
+        // A folder without its entry was left by an interrupted save.
+        let Some(mut version) = fs::read_to_string(dir.join(ENTRY_FILE))
+            .ok()
+            .and_then(|text| serde_json::from_str::<Saved>(&text).ok())
+        else {
+            continue;
+        };
+        version.dir = dir;
+        saved.push(version);
+    }
+    // The folder names start with the time, so they sort oldest first.
+    saved.sort_by(|a, b| b.dir.cmp(&a.dir));
+    Ok(saved)
+}
+
+/// Replaces the installed files of `component` in `root` with the kept version `saved`, and
+/// keeps the version it replaces instead. Every change goes through the run's transaction.
+/// Returns the files put back.
+pub fn restore(root: &Path, component: &str, saved: &Saved) -> io::Result<Vec<String>> {
+    let mut state = InstallState::load(root)?;
+    if keep() > 0 {
+        archive(root, component)?;
+    }
+    let wanted = &saved.component.files;
+    if let Some(installed) = state.component(component) {
+        for file in installed.files.iter().filter(|file| !wanted.iter().any(|kept| kept.eq_ignore_ascii_case(file))) {
+            let path = game::relative(root, file);
+            if path.is_file() {
+                transaction::record_delete(&path)?;
+                fs::remove_file(&path)?;
+            }
+        }
+    }
+    let mut restored = Vec::new();
+    for file in wanted {
+        let copy = game::relative(&saved.dir.join(FILES_DIR), file);
+        if !copy.is_file() {
+            continue;
+        }
+        let path = game::relative(root, file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        transaction::record_write(&path)?;
+        fs::copy(&copy, &path)?;
+        restored.push(file.clone());
+    }
+    let kept = &saved.component;
+    state.set_component(component, &kept.version, kept.files.clone(), kept.sha256.clone());
+    if let Some(url) = &kept.source_url {
+        state.set_source(component, url, kept.archive_sha256.clone());
+    }
+    state.save(root)?;
+    // It is installed again; the version it replaced is kept in its place.
+    fs::remove_dir_all(&saved.dir)?;
+    prune(root, component)?;
+    Ok(restored)
+}
+
+/// Copies the installed files of `component` in `root` to a new folder of its history.
+fn archive(root: &Path, component: &str) -> io::Result<bool> {
+    let state = InstallState::load(root)?;
+    let Some(installed) = state.component(component).filter(|installed| !installed.files.is_empty()) else {
+        return Ok(false);
+    };
+    let name = format!("{}-{}", Local::now().format("%Y%m%d-%H%M%S"), folder_name(&installed.version));
+    let dir = component_dir(root, component).join(name);
+    for file in &installed.files {
+        let path = game::relative(root, file);
+        if !path.is_file() {
+            continue;
+        }
+        let copy = game::relative(&dir.join(FILES_DIR), file);
+        if let Some(parent) = copy.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&path, &copy)?;
+    }
+    fs::create_dir_all(&dir)?;
+    let saved = Saved { saved_at: Local::now().to_rfc3339(), component: installed.clone(), dir: dir.clone() };
+    // Written last, so only a complete copy is listed.
+    fs::write(dir.join(ENTRY_FILE), serde_json::to_string_pretty(&saved).map_err(io::Error::other)?)?;
+    Ok(true)
+}
+
+/// Deletes the kept versions of `component` in `root` beyond the newest [`keep`].
+fn prune(root: &Path, component: &str) -> io::Result<()> {
+    for old in list(root, component)?.into_iter().skip(keep()) {
+        fs::remove_dir_all(&old.dir)?;
+    }
+    Ok(())
+}
+
+fn component_dir(root: &Path, component: &str) -> PathBuf {
+    root.join(HISTORY_DIR).join(folder_name(component))
+}
+
+/// `text` usable as a folder name.
+fn folder_name(text: &str) -> String {
+    text.chars().map(|c| if c.is_ascii_alphanumeric() || "._-".contains(c) { c } else { '_' }).collect()
+}
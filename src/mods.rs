@@ -0,0 +1,256 @@
+// mods.rs
+//! Mods installed straight from a GitHub repository (or from Thunderstore or GameBanana, see
+//! [`crate::thunderstore`] and [`crate::gamebanana`]), beyond the ones the manifest lists.
+//!
+//! `mod install https://github.com/<owner>/<repo>` installs the latest release of any MelonLoader
+//! mod published on GitHub: the release's archive is picked like a manifest mod's (the one built
+//! for x64 when there are several), and its `Mods` and `UserLibs` folders are copied into the game.
+//! The mod is then registered in `install.json` with its repository, so every later install,
+//! update, and repair of that game folder keeps it current like Custom Avatar Loader.
+//!
+//! `mod disable <name>` moves a mod's files from `Mods` to `Mods.disabled`, where MelonLoader
+//! doesn't load them, and `mod enable <name>` moves them back; handy for finding the mod that
+//! crashes the game. The name is a component's or mod's name, or a DLL's in `Mods` for mods the
+//! installer didn't install. Moved files are listed in `install.json`, so `verify` and `repair`
+//! look for them in `Mods.disabled`, and updates leave a disabled mod alone until it is enabled.
+
+use crate::assets::{self, AssetPattern};
+use crate::game;
+use crate::gamebanana;
+use crate::github::{get_latest_release, LookupError, ReleaseInfo};
+use crate::manifest::{ComponentManifest, ModSource, ModSpec};
+use crate::state::InstallState;
+use crate::thunderstore;
+use crate::version;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const MODS_DIR: &str = "Mods";
+/// Folders whose files can be disabled, each into `<folder>.disabled` next to it.
+pub const MANAGED_DIRS: &[&str] = &[MODS_DIR, "UserLibs"];
+
+/// The owner and repository a GitHub URL (or `owner/repo`) names. A URL of another host names
+/// none, with or without its scheme.
+pub fn parse_github_url(url: &str) -> Option<(String, String)> {
+    let url = url.trim().trim_end_matches('/');
+    let rest = ["https://", "http://"].iter().find_map(|scheme| url.strip_prefix(scheme));
+    let path = match rest {
+        Some(rest) => rest.strip_prefix("www.").unwrap_or(rest).strip_prefix("github.com/")?,
+        None => {
+            let rest = url.strip_prefix("www.").unwrap_or(url);
+            rest.strip_prefix("github.com/").unwrap_or(url)
+        }
+    };
+    let mut parts = path.split('/');
+    let owner = parts.next().filter(|owner| !owner.is_empty())?;
+    let repo = parts.next()?.trim_end_matches(".git");
+    // GitHub account names have no dots, so `thunderstore.io/c/...` isn't an owner and repository.
+    let valid_owner = owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    let valid_repo = !repo.is_empty() && repo.chars().all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c));
+    (valid_owner && valid_repo).then(|| (owner.to_owned(), repo.to_owned()))
+}
+
+/// The mod published in `owner/repo`: the manifest's entry when it lists that repository,
+/// else a new one named after the repository. A new one's DLL is unknown until it is installed.
+pub fn spec_for(manifest: &ComponentManifest, owner: &str, repo: &str) -> ModSpec {
+    let listed = manifest
+        .mods()
+        .into_iter()
+        .find(|spec| spec.owner.eq_ignore_ascii_case(owner) && spec.repo.eq_ignore_ascii_case(repo));
+    if let Some(spec) = listed {
+        return spec.clone();
+    }
+    ModSpec {
+        name: repo.to_owned(),
+        component: repo.to_owned(),
+        owner: owner.to_owned(),
+        repo: repo.to_owned(),
+        asset: assets::DEFAULT_PATTERN.to_owned(),
+        dll: String::new(),
+        source: ModSource::GitHub,
+    }
+}
+
+/// The newest release of the mod `spec`, from wherever it is published.
+pub async fn latest_release(
+    spec: &ModSpec,
+    pattern: &AssetPattern,
+    log_file: &Path,
+) -> Result<ReleaseInfo, LookupError> {
+    match spec.source {
+        ModSource::GitHub => get_latest_release(&spec.owner, &spec.repo, pattern, log_file).await,
+        ModSource::Thunderstore => thunderstore::latest_release(spec, log_file).await,
+        ModSource::GameBanana => gamebanana::latest_release(spec, pattern).await,
+    }
+}
+
+/// A mod's installed version and the newest release its source offers.
+pub struct UpdateCheck {
+    pub spec: ModSpec,
+    pub installed: String,
+    pub latest: Result<ReleaseInfo, LookupError>,
+}
+
+impl UpdateCheck {
+    /// Whether the newest release is a different one than installed, and not an older one.
+    pub fn is_update(&self) -> bool {
+        self.latest.as_ref().is_ok_and(|latest| {
+            !version::same(&self.installed, &latest.tag_name) && !version::is_newer(&self.installed, &latest.tag_name)
+        })
+    }
+}
+
+/// Looks up the newest release of every mod installed in `root`, those of `manifest` and those
+/// registered there. Disabled mods are left out, as updates skip them.
+pub async fn check_updates(
+    root: &Path,
+    manifest: &ComponentManifest,
+    log_file: &Path,
+) -> Result<Vec<UpdateCheck>, Box<dyn Error>> {
+    let manifest = with_registered(manifest, root)?;
+    let state = InstallState::load(root)?;
+    let mut checks = Vec::new();
+    for spec in manifest.mods() {
+        let Some(installed) = state.version(&spec.component) else {
+            continue;
+        };
+        if state.is_component_disabled(&spec.component) {
+            continue;
+        }
+        let pattern = AssetPattern::new(&spec.asset)?;
+        let latest = latest_release(spec, &pattern, log_file).await;
+        checks.push(UpdateCheck { spec: spec.clone(), installed: installed.to_owned(), latest });
+    }
+    Ok(checks)
+}
+
+/// The DLL `component` installed in `root`, preferring one in `Mods`.
+pub fn main_dll(root: &Path, component: &str) -> io::Result<Option<String>> {
+    let state = InstallState::load(root)?;
+    let Some(installed) = state.component(component) else {
+        return Ok(None);
+    };
+    let dlls: Vec<&String> = installed.files.iter().filter(|file| file.to_lowercase().ends_with(".dll")).collect();
+    let in_mods = dlls.iter().find(|file| file.to_lowercase().starts_with("mods/"));
+    Ok(in_mods.or(dlls.first()).map(|file| file.to_string()))
+}
+
+/// Records `spec` in `install.json` in `root`, so later runs keep the mod up to date.
+pub fn register(root: &Path, spec: ModSpec) -> io::Result<()> {
+    let mut state = InstallState::load(root)?;
+    state.register_mod(spec);
+    state.save(root)
+}
+
+/// Where `file`, a path in `Mods` or `UserLibs`, is kept while disabled.
+pub fn disabled_location(file: &str) -> String {
+    match file.split_once('/') {
+        Some((dir, rest)) => format!("{}.disabled/{}", dir, rest),
+        None => file.to_owned(),
+    }
+}
+
+/// Moves `file`, a path in `Mods` or `UserLibs`, in `root` to where it belongs when `enabled`
+/// or not, and records that in `state`, saving it. Returns whether it was moved; a file already
+/// in place or missing isn't.
+pub fn move_file(root: &Path, state: &mut InstallState, file: &str, enabled: bool) -> io::Result<bool> {
+    if state.is_disabled(file) != enabled {
+        return Ok(false);
+    }
+    let (active, disabled) = (game::relative(root, file), game::relative(root, &disabled_location(file)));
+    let (from, to) = if enabled { (disabled, active) } else { (active, disabled) };
+    if !from.is_file() {
+        return Ok(false);
+    }
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&from, &to)?;
+    state.set_disabled(file, !enabled);
+    // Saved after every move, so an interrupted run still knows where each file is.
+    state.save(root)?;
+    Ok(true)
+}
+
+/// Deletes the `.disabled` folders in `root` that are empty.
+pub fn remove_empty_disabled_dirs(root: &Path) {
+    for dir in MANAGED_DIRS {
+        let _ = fs::remove_dir(root.join(format!("{}.disabled", dir)));
+    }
+}
+
+/// Moves the files of the mod `name` from `Mods` to `Mods.disabled` in `root`. Returns the
+/// files moved; none when the mod was disabled already.
+pub fn disable(root: &Path, manifest: &ComponentManifest, name: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    set_enabled(root, manifest, name, false)
+}
+
+/// Moves the files of the mod `name` back to `Mods` in `root`. Returns the files moved; none
+/// when the mod wasn't disabled.
+pub fn enable(root: &Path, manifest: &ComponentManifest, name: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    set_enabled(root, manifest, name, true)
+}
+
+fn set_enabled(
+    root: &Path,
+    manifest: &ComponentManifest,
+    name: &str,
+    enabled: bool,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut state = InstallState::load(root)?;
+    let files = mod_files(root, &state, manifest, name);
+    if files.is_empty() {
+        return Err(t!("err-mod-unknown", mod = name).into());
+    }
+    let mut moved = Vec::new();
+    for file in files {
+        if move_file(root, &mut state, &file, enabled)? {
+            moved.push(file);
+        }
+    }
+    remove_empty_disabled_dirs(root);
+    Ok(moved)
+}
+
+/// The component recorded in `state` that the mod `name` is: named directly, or through the
+/// name of a mod `manifest` lists or `mod install` registered.
+pub fn installed_component(state: &InstallState, manifest: &ComponentManifest, name: &str) -> Option<String> {
+    let component = manifest
+        .mods()
+        .into_iter()
+        .chain(state.registered_mods())
+        .find(|spec| spec.name.eq_ignore_ascii_case(name))
+        .map_or(name, |spec| spec.component.as_str());
+    let (known, _) = state.components().find(|(known, _)| known.eq_ignore_ascii_case(component))?;
+    Some(known.clone())
+}
+
+/// The files in `Mods` that make up the mod `name`: those of the component it names (directly
+/// or through a mod's name), else the DLL of that name, wherever it is now.
+fn mod_files(root: &Path, state: &InstallState, manifest: &ComponentManifest, name: &str) -> Vec<String> {
+    let in_mods = |file: &&String| {
+        file.split_once('/').is_some_and(|(dir, _)| dir.eq_ignore_ascii_case(MODS_DIR))
+    };
+    if let Some(installed) = installed_component(state, manifest, name).and_then(|name| state.component(&name)) {
+        return installed.files.iter().filter(in_mods).cloned().collect();
+    }
+    let file_name = if name.to_lowercase().ends_with(".dll") { name.to_owned() } else { format!("{}.dll", name) };
+    let file = format!("{}/{}", MODS_DIR, file_name);
+    // Spelled as recorded when disabled, so it is found in `install.json` again.
+    let file = state.disabled_files().iter().find(|known| known.eq_ignore_ascii_case(&file)).cloned().unwrap_or(file);
+    if game::relative(root, &file).is_file() || game::relative(root, &disabled_location(&file)).is_file() {
+        vec![file]
+    } else {
+        Vec::new()
+    }
+}
+
+/// `manifest` with steps for the mods registered in `root` added.
+pub fn with_registered(manifest: &ComponentManifest, root: &Path) -> io::Result<ComponentManifest> {
+    let state = InstallState::load(root)?;
+    let mut manifest = manifest.clone();
+    manifest.add_mod_steps(state.registered_mods().iter().cloned());
+    Ok(manifest)
+}
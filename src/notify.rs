@@ -0,0 +1,34 @@
+// notify.rs
+//! Windows toast notifications, for telling a user about updates while no installer window is
+//! in front of them.
+//!
+//! Toasts are raised through PowerShell's WinRT bindings under PowerShell's own app id, since
+//! an unpackaged exe has no registered app id to show them under. The text is handed over in
+//! environment variables so it never needs quoting inside the script.
+
+use std::error::Error;
+use std::process::Command;
+
+const SCRIPT: &str = r#"
+[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null;
+[Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] | Out-Null;
+$Title = [Security.SecurityElement]::Escape($env:DMI_TOAST_TITLE);
+$Text = [Security.SecurityElement]::Escape($env:DMI_TOAST_TEXT);
+$Xml = New-Object Windows.Data.Xml.Dom.XmlDocument;
+$Xml.LoadXml("<toast><visual><binding template=""ToastGeneric""><text>$Title</text><text>$Text</text></binding></visual></toast>");
+$AppId = '{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe';
+[Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($AppId).Show([Windows.UI.Notifications.ToastNotification]::new($Xml));
+"#;
+
+/// Shows a toast with `title` and `text`.
+pub fn toast(title: &str, text: &str) -> Result<(), Box<dyn Error>> {
+    let status = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", SCRIPT])
+        .env("DMI_TOAST_TITLE", title)
+        .env("DMI_TOAST_TEXT", text)
+        .status()?;
+    if !status.success() {
+        return Err(t!("err-toast", code = status.code().unwrap_or(-1)).into());
+    }
+    Ok(())
+}
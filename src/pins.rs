@@ -0,0 +1,248 @@
+// pins.rs
+//! Pinned SHA-256 hashes for every third-party download.
+//!
+//! `manifest/hashes.json` maps each component (DepotDownloader, Goldberg, MelonLoader, and the
+//! mods, by their install-state name) to the versions known to be good and their archive's
+//! SHA-256. Like the component manifest, the current copy is fetched from the repository at
+//! startup and the bundled one is used when that fails, so a new mod release can be pinned
+//! without shipping a new binary.
+//!
+//! Every archive is checked right after it is downloaded. A hash that doesn't match, or a
+//! version of DepotDownloader, Goldberg or MelonLoader missing from the pins, stops the install
+//! unless the user passes `--allow-hash-mismatch`. Mods release more often than the database is
+//! updated, so a mod version that isn't pinned yet only gets a warning and is installed; it is
+//! checked once the remote `hashes.json` pins it. Components with no pins at all (a mod only a
+//! custom manifest knows about) are logged as unverified and installed.
+//!
+//! MelonLoader and gbe_fork archives are checked against the pin of their release tag.
+//! DepotDownloader is downloaded from its release's own URL, the pinned release or the newest
+//! one, but checked without a version, so any of its pinned hashes is accepted. So is any of
+//! Mr. Goldberg's, whose archive is the newest successful CI job's artifact (or the manifest's
+//! pinned job when GitLab can't be reached) and has no version. A `sha256` the manifest gives
+//! for an archive takes the place of its pins.
+//!
+//! `--pin-hashes <file>` downloads what the current manifest points at and adds it to `file`,
+//! for maintainers updating the database.
+
+use crate::assets::AssetPattern;
+use crate::cli;
+use crate::manifest::{ComponentManifest, REMOTE_BASE_URL};
+use crate::mods;
+use crate::proxy;
+use crate::retry;
+use crate::temp::TempPath;
+use crate::transaction::sha256_file;
+use crate::version;
+use crate::{color_echo, download_file, write_log, ConsoleColor};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Highest database schema this build understands.
+const SUPPORTED_SCHEMA: u32 = 1;
+
+const BUNDLED: &str = include_str!("../manifest/hashes.json");
+
+pub const DEPOT_DOWNLOADER: &str = "DepotDownloader";
+pub const GOLDBERG: &str = "Goldberg";
+pub const GBE_FORK: &str = "GbeFork";
+pub const MELONLOADER: &str = "MelonLoader";
+/// Components whose unpinned versions are refused; the rest are mods.
+const STRICT: &[&str] = &[DEPOT_DOWNLOADER, GOLDBERG, GBE_FORK, MELONLOADER];
+
+static PINS: Mutex<Option<PinDatabase>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PinDatabase {
+    schema: u32,
+    revision: String,
+    /// Component name to version to SHA-256.
+    components: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// Loads the database for this run, preferring the remote copy over the bundled one.
+pub async fn load(log_file: &Path) {
+    let database = match fetch_remote().await {
+        Ok(database) => {
+            let _ = write_log(log_file, &format!("Using remote hash database (revision {}).", database.revision));
+            database
+        }
+        Err(reason) => {
+            let database = bundled();
+            let _ = write_log(
+                log_file,
+                &format!(
+                    "Remote hash database unavailable ({}); using bundled revision {}.",
+                    reason, database.revision
+                ),
+            );
+            database
+        }
+    };
+    *PINS.lock().unwrap_or_else(|e| e.into_inner()) = Some(database);
+}
+
+/// Revision of the database in use.
+pub fn revision() -> String {
+    PINS.lock().unwrap_or_else(|e| e.into_inner()).get_or_insert_with(bundled).revision.clone()
+}
+
+/// True when the user passed `--allow-hash-mismatch`.
+pub fn override_requested() -> bool {
+    cli::args().allow_hash_mismatch
+}
+
+/// Checks the downloaded archive at `path` against `expected`, the hash the manifest gives, or
+/// else the pins for `component` at `version` (`None` when the download isn't tied to one). A
+/// failed check is an error unless the user asked to override it, in which case it is only
+/// reported.
+pub fn verify(
+    component: &str,
+    version: Option<&str>,
+    expected: Option<&str>,
+    path: &Path,
+    log_file: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let label = match version {
+        Some(version) => format!("{} {}", component, version),
+        None => component.to_owned(),
+    };
+    let actual = sha256_file(path)?;
+    if let Some(expected) = expected {
+        let failure = (!expected.trim().eq_ignore_ascii_case(&actual)).then(|| {
+            t!("err-pin-mismatch", artifact = label.as_str(), expected = expected.trim(), actual = actual.as_str())
+        });
+        return report(&label, &actual, "the manifest's", failure, log_file);
+    }
+    let pins = PINS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(bundled)
+        .components
+        .get(component)
+        .cloned()
+        .unwrap_or_default();
+    if pins.is_empty() {
+        write_log(log_file, &format!("WARNING: No pinned hash for {}; SHA-256 {} not verified.", label, actual))?;
+        return Ok(());
+    }
+
+    let failure = match version {
+        Some(version) => match pins.iter().find(|(pinned, _)| version::same(pinned, version)) {
+            Some((_, expected)) if expected.eq_ignore_ascii_case(&actual) => None,
+            Some((_, expected)) => Some(t!(
+                "err-pin-mismatch",
+                artifact = label.as_str(),
+                expected = expected.as_str(),
+                actual = actual.as_str()
+            )),
+            None if !STRICT.contains(&component) => {
+                color_echo(ConsoleColor::Yellow, &t!("pin-mod-unpinned", artifact = label.as_str()));
+                let message = format!("WARNING: {} isn't pinned yet; SHA-256 {} not verified.", label, actual);
+                write_log(log_file, &message)?;
+                return Ok(());
+            }
+            None => Some(t!("err-pin-unknown-version", artifact = label.as_str())),
+        },
+        None if pins.values().any(|expected| expected.eq_ignore_ascii_case(&actual)) => None,
+        None => Some(t!("err-pin-unknown-hash", artifact = label.as_str(), actual = actual.as_str())),
+    };
+    report(&label, &actual, "its pinned", failure, log_file)
+}
+
+/// Logs the outcome of a check of `label`, failing on `failure` unless overridden. `source`
+/// names where the expected hash came from.
+fn report(
+    label: &str,
+    actual: &str,
+    source: &str,
+    failure: Option<String>,
+    log_file: &Path,
+) -> Result<(), Box<dyn Error>> {
+    match failure {
+        None => {
+            write_log(log_file, &format!("{} matches {} SHA-256 {}.", label, source, actual))?;
+            Ok(())
+        }
+        Some(reason) if override_requested() => {
+            color_echo(ConsoleColor::Yellow, &t!("pin-overridden", artifact = label));
+            write_log(
+                log_file,
+                &format!("WARNING: Integrity check overridden with --allow-hash-mismatch: {}", reason),
+            )?;
+            Ok(())
+        }
+        Some(reason) => {
+            color_echo(ConsoleColor::Red, &t!("hash-check-failed", error = reason.as_str()));
+            write_log(log_file, &format!("ERROR: Integrity check failed: {}", reason))?;
+            Err(reason.into())
+        }
+    }
+}
+
+/// `--pin-hashes <file>`: downloads every archive `manifest` points at and adds its hash to the
+/// database in `dest` (created from the loaded one when missing).
+pub async fn record(dest: &Path, manifest: &ComponentManifest, log_file: &Path) -> Result<(), Box<dyn Error>> {
+    let mut database = match fs::read_to_string(dest) {
+        Ok(text) => parse(&text)?,
+        Err(ex) if ex.kind() == io::ErrorKind::NotFound => {
+            PINS.lock().unwrap_or_else(|e| e.into_inner()).clone().unwrap_or_else(bundled)
+        }
+        Err(ex) => return Err(ex.into()),
+    };
+
+    // Unversioned downloads are filed under the manifest revision they were fetched for.
+    let mut artifacts = vec![
+        (DEPOT_DOWNLOADER.to_owned(), manifest.revision.clone(), manifest.depot_downloader.url.clone()),
+        (GOLDBERG.to_owned(), manifest.revision.clone(), manifest.goldberg.url.clone()),
+        (MELONLOADER.to_owned(), manifest.melonloader.version.clone(), manifest.melonloader.url.clone()),
+    ];
+    for spec in manifest.mods() {
+        let pattern = AssetPattern::new(&spec.asset)?;
+        let release = mods::latest_release(spec, &pattern, log_file).await?;
+        if release.download_url.is_empty() {
+            return Err(t!("err-no-matching-asset", pattern = pattern.to_string()).into());
+        }
+        artifacts.push((spec.component.clone(), release.tag_name, release.download_url));
+    }
+
+    for (component, version, url) in artifacts {
+        color_echo(ConsoleColor::Blue, &t!("pin-hashing", artifact = format!("{} {}", component, version)));
+        let archive = TempPath::file("pin", "zip");
+        download_file(&url, &archive).await?;
+        let hash = sha256_file(&archive)?;
+        write_log(log_file, &format!("Pinned {} {} from {}: {}", component, version, url, hash))?;
+        database.components.entry(component).or_default().insert(version, hash);
+    }
+    database.revision = manifest.revision.clone();
+    let mut text = serde_json::to_string_pretty(&database).map_err(io::Error::other)?;
+    text.push('\n');
+    fs::write(dest, text)?;
+    Ok(())
+}
+
+fn bundled() -> PinDatabase {
+    parse(BUNDLED).expect("bundled hash database is valid")
+}
+
+async fn fetch_remote() -> Result<PinDatabase, String> {
+    let url = format!("{}/hashes.json", REMOTE_BASE_URL);
+    let client = proxy::client()
+        .build()
+        .map_err(|e| e.to_string())?;
+    let resp = retry::get(&client, &url).await.map_err(|e| e.to_string())?;
+    let text = resp.text().await.map_err(|e| e.to_string())?;
+    parse(&text)
+}
+
+fn parse(text: &str) -> Result<PinDatabase, String> {
+    let database: PinDatabase = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    if database.schema > SUPPORTED_SCHEMA {
+        return Err(format!("schema {} is newer than supported schema {}", database.schema, SUPPORTED_SCHEMA));
+    }
+    Ok(database)
+}
@@ -0,0 +1,108 @@
+// profiles.rs
+//! Named sets of enabled mods, such as "streaming" or "minimal", to switch between quickly.
+//!
+//! `profile save <name>` records which files in `Mods` and `UserLibs` are enabled right now, in
+//! `install.json`. `profile switch <name>` then enables exactly those: every other file in the
+//! two folders is moved to `Mods.disabled` or `UserLibs.disabled`, and the profile's files are
+//! moved back, the way `mod disable` and `mod enable` do it (see [`crate::mods`]). A mod
+//! installed after the profile was saved isn't in it, so switching to the profile disables it.
+//! Files of the profile that are gone since are reported rather than failing the switch.
+
+use crate::mods;
+use crate::state::InstallState;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// What a switch changed.
+#[derive(Debug, Default)]
+pub struct Switch {
+    pub enabled: Vec<String>,
+    pub disabled: Vec<String>,
+    /// Files of the profile found in neither folder.
+    pub missing: Vec<String>,
+}
+
+/// Saves the files enabled in `root` now as the profile `name`, replacing one of that name.
+/// Returns the files.
+pub fn save(root: &Path, name: &str) -> io::Result<Vec<String>> {
+    let mut state = InstallState::load(root)?;
+    let files = managed_files(root, &mut state)?;
+    let files: Vec<String> = files.into_iter().filter(|file| !state.is_disabled(file)).collect();
+    state.set_profile(name, files.clone());
+    state.save(root)?;
+    Ok(files)
+}
+
+/// Enables the files of the profile `name` in `root` and disables every other one.
+pub fn switch(root: &Path, name: &str) -> Result<Switch, Box<dyn Error>> {
+    let mut state = InstallState::load(root)?;
+    let Some((name, wanted)) = state.profile(name).map(|(name, files)| (name.to_owned(), files.to_vec())) else {
+        return Err(t!("err-profile-unknown", profile = name).into());
+    };
+    let present = managed_files(root, &mut state)?;
+    let mut switch = Switch::default();
+    for file in present.iter().filter(|file| !contains(&wanted, file)) {
+        if mods::move_file(root, &mut state, file, false)? {
+            switch.disabled.push(file.clone());
+        }
+    }
+    for file in &wanted {
+        let Some(file) = present.iter().find(|present| present.eq_ignore_ascii_case(file)) else {
+            switch.missing.push(file.clone());
+            continue;
+        };
+        if mods::move_file(root, &mut state, file, true)? {
+            switch.enabled.push(file.clone());
+        }
+    }
+    mods::remove_empty_disabled_dirs(root);
+    state.set_active_profile(Some(name));
+    state.save(root)?;
+    Ok(switch)
+}
+
+/// Deletes the profile `name` in `root`, leaving the files as they are. Returns whether there
+/// was one.
+pub fn delete(root: &Path, name: &str) -> io::Result<bool> {
+    let mut state = InstallState::load(root)?;
+    let removed = state.remove_profile(name);
+    if removed {
+        state.save(root)?;
+    }
+    Ok(removed)
+}
+
+/// The files directly in `Mods` and `UserLibs` in `root`, enabled or not, by their usual path.
+/// Files put in a `.disabled` folder by hand are recorded in `state` as disabled.
+fn managed_files(root: &Path, state: &mut InstallState) -> io::Result<Vec<String>> {
+    let mut files = Vec::new();
+    for dir in mods::MANAGED_DIRS {
+        for (folder, disabled) in [(dir.to_string(), false), (format!("{}.disabled", dir), true)] {
+            let entries = match fs::read_dir(root.join(&folder)) {
+                Ok(entries) => entries,
+                Err(ex) if ex.kind() == io::ErrorKind::NotFound => continue,
+                Err(ex) => return Err(ex),
+            };
+            for entry in entries {
+                let entry = entry?;
+                if !entry.file_type()?.is_file() {
+                    continue;
+                }
+                let file = format!("{}/{}", dir, entry.file_name().to_string_lossy());
+                if disabled && !state.is_disabled(&file) {
+                    state.set_disabled(&file, true);
+                }
+                if !contains(&files, &file) {
+                    files.push(file);
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn contains(files: &[String], file: &str) -> bool {
+    files.iter().any(|known| known.eq_ignore_ascii_case(file))
+}
@@ -0,0 +1,78 @@
+// progress.rs
+//! Files/bytes progress for long-running file operations (extraction, copying).
+//!
+//! In the wizard the counter is shown as a live line under the current step; in the classic
+//! console it overwrites a single line in place.
+
+use crate::tui;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How often the progress line is redrawn.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Shared files/bytes counter that redraws the progress line at most every `PROGRESS_INTERVAL`.
+pub struct Progress {
+    message_id: &'static str,
+    total_files: usize,
+    total_bytes: u64,
+    files: AtomicUsize,
+    bytes: AtomicU64,
+    last_draw: Mutex<Instant>,
+    in_tui: bool,
+}
+
+impl Progress {
+    /// Starts a counter whose line is the message `message_id`, formatted with `files`,
+    /// `total_files`, `mb`, and `total_mb`.
+    pub fn new(message_id: &'static str, total_files: usize, total_bytes: u64) -> Progress {
+        Progress {
+            message_id,
+            total_files,
+            total_bytes,
+            files: AtomicUsize::new(0),
+            bytes: AtomicU64::new(0),
+            last_draw: Mutex::new(Instant::now()),
+            in_tui: tui::activity(None),
+        }
+    }
+
+    /// Records one finished file of `bytes` bytes. Safe to call from several threads.
+    pub fn add(&self, bytes: u64) {
+        let files = self.files.fetch_add(1, Ordering::Relaxed) + 1;
+        let bytes = self.bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if let Ok(mut last_draw) = self.last_draw.try_lock() {
+            if last_draw.elapsed() >= PROGRESS_INTERVAL || files == self.total_files {
+                *last_draw = Instant::now();
+                self.draw(files, bytes);
+            }
+        }
+    }
+
+    fn draw(&self, files: usize, bytes: u64) {
+        let text = t!(
+            self.message_id,
+            files = files,
+            total_files = self.total_files,
+            mb = format!("{:.1}", bytes as f64 / 1_048_576.0),
+            total_mb = format!("{:.1}", self.total_bytes as f64 / 1_048_576.0),
+        );
+        if self.in_tui {
+            tui::activity(Some(text));
+        } else {
+            print!("\r{}", text);
+            let _ = io::stdout().flush();
+        }
+    }
+
+    /// Clears the progress line.
+    pub fn finish(&self) {
+        if self.in_tui {
+            tui::activity(None);
+        } else if self.files.load(Ordering::Relaxed) > 0 {
+            println!();
+        }
+    }
+}
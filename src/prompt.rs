@@ -0,0 +1,436 @@
+// prompt.rs
+//! Console questions with an optional timeout.
+//!
+//! In a console, Y/N questions are selection lists (up/down to choose, Enter to confirm, or press
+//! Y/N) and text questions are editable inputs prefilled with their default, so there is no
+//! invalid input to reject. With redirected input, every answer (the Steam password included) is
+//! read as a line from the pipe instead, so expect-style scripts and wrappers can drive a run.
+//!
+//! Semi-unattended runs (kiosks, lab machines) set `prompt_timeout_seconds` in `installer.toml`
+//! or pass `--prompt-timeout <seconds>`. A question that carries a default answer then takes that
+//! answer once the timeout passes with no input, instead of blocking forever. Questions without
+//! a sensible default (game ownership, the Steam username) always wait.
+//!
+//! `--unattended` runs don't wait at all: a question with a default takes it immediately (and is
+//! printed with the answer, for the record), and one without a default is an error.
+//!
+//! A timeout only applies until the first key press. Lines are read on a helper thread, one per
+//! request, so a timed-out question can be abandoned; its read stays outstanding and answers the
+//! next question.
+
+use crate::cli;
+use crate::redact;
+use crate::{color_echo, ConsoleColor};
+use colored::*;
+use crossterm::{
+    cursor::{self, MoveToColumn, MoveToNextLine, MoveUp},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    queue,
+    style::Print,
+    terminal::{self, disable_raw_mode, enable_raw_mode, ClearType},
+};
+use std::io::{self, IsTerminal, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthStr;
+
+static TIMEOUT: Mutex<Option<Duration>> = Mutex::new(None);
+static READER: Mutex<Option<Reader>> = Mutex::new(None);
+
+struct Reader {
+    requests: Sender<()>,
+    lines: Receiver<io::Result<String>>,
+    /// A line was requested that no question has received yet.
+    pending: bool,
+}
+
+/// Sets how long questions with a default answer wait; `None` waits forever.
+pub fn set_timeout(timeout: Option<Duration>) {
+    *TIMEOUT.lock().unwrap_or_else(|e| e.into_inner()) = timeout;
+}
+
+pub fn timeout() -> Option<Duration> {
+    *TIMEOUT.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Asks a Y/N question. With `default` set, it is preselected and the question times out to it.
+pub fn yes_no(question: &str, default: Option<bool>) -> io::Result<bool> {
+    if cli::args().unattended {
+        return unattended(question, default, |answer| if *answer { "Y" } else { "N" }.to_owned());
+    }
+    let answer = if is_interactive() {
+        let options = [t!("prompt-yes"), t!("prompt-no")];
+        let initial = if default == Some(true) { 0 } else { 1 };
+        select(question.trim_end(), &options, initial, &['y', 'n'], default.and(timeout()))?.map(|index| index == 0)
+    } else {
+        print!("{}", question);
+        io::stdout().flush()?;
+        // An empty line takes the default, as it does for `line` and `choose`.
+        read_line(default.and(timeout()))?.map(|answer| match answer.trim().to_ascii_lowercase().as_str() {
+            "" => default.unwrap_or(false),
+            "y" | "yes" => true,
+            "n" | "no" => false,
+            _ => false,
+        })
+    };
+    match answer {
+        Some(answer) => Ok(answer),
+        None => {
+            let default = default.unwrap_or(false);
+            println!();
+            color_echo(
+                ConsoleColor::Yellow,
+                &t!("prompt-timed-out", answer = if default { "Y" } else { "N" }),
+            );
+            Ok(default)
+        }
+    }
+}
+
+/// Asks for a line of text, trimmed. With `default` set, the input starts out holding it and the
+/// question times out to it; an empty answer is returned as is.
+pub fn line(question: &str, default: Option<&str>) -> io::Result<String> {
+    if cli::args().unattended {
+        return unattended(question, default.map(str::to_owned), String::clone);
+    }
+    let answer = if is_interactive() {
+        edit(question, default.unwrap_or_default(), default.and(timeout()))?
+    } else {
+        print!("{}", question);
+        io::stdout().flush()?;
+        read_line(default.and(timeout()))?
+    };
+    match answer {
+        Some(answer) => Ok(answer.trim().to_owned()),
+        None => {
+            let default = default.unwrap_or_default();
+            println!();
+            color_echo(ConsoleColor::Yellow, &t!("prompt-timed-out", answer = default));
+            Ok(default.to_owned())
+        }
+    }
+}
+
+/// Asks to pick one of `options` under `heading` and returns its index. Without a console the
+/// options are numbered and `number_question` asks for one; an answer that isn't one of the
+/// numbers is an error. With `default` set, it is preselected and the question times out to it.
+pub fn choose(heading: &str, options: &[String], number_question: &str, default: Option<usize>) -> io::Result<usize> {
+    if cli::args().unattended {
+        return unattended(&format!("{} ", heading.trim_end()), default, |index| options[*index].clone());
+    }
+    if is_interactive() {
+        if let Some(index) = select(heading, options, default.unwrap_or(0), &[], default.and(timeout()))? {
+            return Ok(index);
+        }
+        let default = default.unwrap_or(0);
+        color_echo(ConsoleColor::Yellow, &t!("prompt-timed-out", answer = options[default].as_str()));
+        return Ok(default);
+    }
+    println!("{}", heading.cyan());
+    for (number, option) in options.iter().enumerate() {
+        println!("  {}) {}", number + 1, option);
+    }
+    let default_number = default.map(|index| (index + 1).to_string());
+    let answer = line(number_question, default_number.as_deref())?;
+    if answer.is_empty() {
+        if let Some(default) = default {
+            return Ok(default);
+        }
+    }
+    match answer.parse::<usize>().ok().filter(|n| (1..=options.len()).contains(n)) {
+        Some(number) => Ok(number - 1),
+        None => Err(io::Error::new(io::ErrorKind::InvalidInput, t!("err-invalid-choice", choice = answer))),
+    }
+}
+
+/// Asks which of `options` under `heading` to pick, any number of them, and returns their
+/// indexes. The options are numbered, the ones in `selected` marked, and `question` asks for the
+/// numbers, separated by commas or spaces, with the marked ones as the default answer. An empty
+/// answer picks none; a number that isn't one of the options is an error.
+pub fn checklist(heading: &str, options: &[String], selected: &[usize], question: &str) -> io::Result<Vec<usize>> {
+    println!("{}", heading.cyan());
+    for (index, option) in options.iter().enumerate() {
+        let mark = if selected.contains(&index) { "x" } else { " " };
+        println!("  [{}] {}) {}", mark, index + 1, option);
+    }
+    let default: Vec<String> = selected.iter().map(|index| (index + 1).to_string()).collect();
+    let answer = line(question, Some(&default.join(", ")))?;
+    let mut picked = Vec::new();
+    for part in answer.split([',', ' ']).map(str::trim).filter(|part| !part.is_empty()) {
+        match part.parse::<usize>().ok().filter(|n| (1..=options.len()).contains(n)) {
+            Some(number) if !picked.contains(&(number - 1)) => picked.push(number - 1),
+            Some(_) => {}
+            None => return Err(io::Error::new(io::ErrorKind::InvalidInput, t!("err-invalid-choice", choice = part))),
+        }
+    }
+    Ok(picked)
+}
+
+/// Reads a password, masked with asterisks in a console. Piped input is read as a line, keeping
+/// everything but the line ending. The answer is kept out of the log.
+pub fn password(question: &str) -> io::Result<String> {
+    let answer = read_password(question)?;
+    redact::add(&answer);
+    Ok(answer)
+}
+
+fn read_password(question: &str) -> io::Result<String> {
+    if cli::args().unattended {
+        return unattended(question, None, String::clone);
+    }
+    print!("{}", question);
+    io::stdout().flush()?;
+    if !io::stdin().is_terminal() {
+        let line = read_line(None)?.unwrap_or_default();
+        println!();
+        return Ok(line.trim_end_matches(['\r', '\n']).to_owned());
+    }
+
+    let mut stdout = io::stdout();
+    let _raw = RawMode::enable()?;
+    let mut password = String::new();
+    while let Some(key) = next_key(None)? {
+        match key.code {
+            KeyCode::Enter => break,
+            KeyCode::Backspace if !password.is_empty() => {
+                password.pop();
+                queue!(stdout, Print("\u{8} \u{8}"))?;
+            }
+            KeyCode::Char(c) => {
+                password.push(c);
+                queue!(stdout, Print('*'))?;
+            }
+            _ => {}
+        }
+        stdout.flush()?;
+    }
+    queue!(stdout, Print("\r\n"))?;
+    stdout.flush()?;
+    Ok(password)
+}
+
+/// Waits for a key press, or for the prompt timeout if one is set. Returns at once when input is
+/// piped or the run is unattended, as there is nobody to press a key.
+pub fn any_key() -> io::Result<()> {
+    if !io::stdin().is_terminal() || cli::args().unattended {
+        return Ok(());
+    }
+    let _raw = RawMode::enable()?;
+    next_key(timeout().map(|timeout| Instant::now() + timeout))?;
+    Ok(())
+}
+
+/// Answers `question` with `default` without waiting, showing the answer as `shown` spells it.
+fn unattended<T>(question: &str, default: Option<T>, shown: impl FnOnce(&T) -> String) -> io::Result<T> {
+    match default {
+        Some(answer) => {
+            println!("{}{}", question, shown(&answer));
+            Ok(answer)
+        }
+        None => Err(io::Error::other(t!("err-unattended-question", question = question.trim()))),
+    }
+}
+
+/// Whether questions can use key events rather than lines.
+pub fn is_interactive() -> bool {
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+/// Restores cooked mode however a question ends.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<RawMode> {
+        enable_raw_mode()?;
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+    }
+}
+
+/// The next key press, or `None` once `deadline` passes. Ctrl+C exits, since raw mode keeps the
+/// console from doing it.
+fn next_key(deadline: Option<Instant>) -> io::Result<Option<KeyEvent>> {
+    loop {
+        let wait = match deadline {
+            Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                Some(wait) => wait,
+                None => return Ok(None),
+            },
+            None => Duration::from_millis(500),
+        };
+        if !event::poll(wait)? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                let _ = disable_raw_mode();
+                println!();
+                std::process::exit(130);
+            }
+            return Ok(Some(key));
+        }
+    }
+}
+
+/// A selection list below `question`. `hotkeys[i]` picks option `i` directly. Returns the chosen
+/// index, or `None` if `timeout` passes before any key.
+fn select(
+    question: &str,
+    options: &[String],
+    initial: usize,
+    hotkeys: &[char],
+    timeout: Option<Duration>,
+) -> io::Result<Option<usize>> {
+    println!("{}", question);
+    for _ in options {
+        println!();
+    }
+    let mut stdout = io::stdout();
+    let draw = |stdout: &mut io::Stdout, selected: usize| -> io::Result<()> {
+        queue!(stdout, MoveUp(options.len() as u16))?;
+        for (index, option) in options.iter().enumerate() {
+            let text = if index == selected {
+                format!("  > {}", option).cyan().bold().to_string()
+            } else {
+                format!("    {}", option)
+            };
+            queue!(stdout, MoveToColumn(0), terminal::Clear(ClearType::CurrentLine), Print(text), MoveToNextLine(1))?;
+        }
+        stdout.flush()
+    };
+
+    let _raw = RawMode::enable()?;
+    let mut selected = initial.min(options.len().saturating_sub(1));
+    let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+    draw(&mut stdout, selected)?;
+    loop {
+        let Some(key) = next_key(deadline)? else {
+            return Ok(None);
+        };
+        deadline = None;
+        match key.code {
+            KeyCode::Up | KeyCode::Left | KeyCode::BackTab => selected = selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Right | KeyCode::Tab => selected = (selected + 1).min(options.len() - 1),
+            KeyCode::Home => selected = 0,
+            KeyCode::End => selected = options.len() - 1,
+            KeyCode::Enter => return Ok(Some(selected)),
+            KeyCode::Char(c) => {
+                if let Some(index) = hotkeys.iter().position(|hotkey| hotkey.eq_ignore_ascii_case(&c)) {
+                    draw(&mut stdout, index)?;
+                    return Ok(Some(index));
+                }
+            }
+            _ => {}
+        }
+        draw(&mut stdout, selected)?;
+    }
+}
+
+/// An input after `question` holding `initial`, edited in place. Esc puts `initial` back.
+/// Returns the text, or `None` if `timeout` passes before any key.
+fn edit(question: &str, initial: &str, timeout: Option<Duration>) -> io::Result<Option<String>> {
+    print!("{}", question);
+    io::stdout().flush()?;
+    let (start, _) = cursor::position()?;
+    let mut stdout = io::stdout();
+    let draw = |stdout: &mut io::Stdout, text: &[char], at: usize| -> io::Result<()> {
+        let before: String = text[..at].iter().collect();
+        let column = start as usize + UnicodeWidthStr::width(before.as_str());
+        queue!(
+            stdout,
+            MoveToColumn(start),
+            terminal::Clear(ClearType::UntilNewLine),
+            Print(text.iter().collect::<String>()),
+            MoveToColumn(column.min(u16::MAX as usize) as u16)
+        )?;
+        stdout.flush()
+    };
+
+    let _raw = RawMode::enable()?;
+    let mut text: Vec<char> = initial.chars().collect();
+    let mut at = text.len();
+    let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+    draw(&mut stdout, &text, at)?;
+    let answer = loop {
+        let Some(key) = next_key(deadline)? else {
+            return Ok(None);
+        };
+        deadline = None;
+        match key.code {
+            KeyCode::Enter => break text.iter().collect::<String>(),
+            KeyCode::Esc => {
+                text = initial.chars().collect();
+                at = text.len();
+            }
+            KeyCode::Left => at = at.saturating_sub(1),
+            KeyCode::Right => at = (at + 1).min(text.len()),
+            KeyCode::Home => at = 0,
+            KeyCode::End => at = text.len(),
+            KeyCode::Backspace if at > 0 => {
+                at -= 1;
+                text.remove(at);
+            }
+            KeyCode::Delete if at < text.len() => {
+                text.remove(at);
+            }
+            KeyCode::Char(c) => {
+                text.insert(at, c);
+                at += 1;
+            }
+            _ => {}
+        }
+        draw(&mut stdout, &text, at)?;
+    };
+    queue!(stdout, Print("\r\n"))?;
+    stdout.flush()?;
+    Ok(Some(answer))
+}
+
+/// The next line of input, or `None` if `timeout` passes first. End of input reads as an empty
+/// line, as `read_line` would.
+fn read_line(timeout: Option<Duration>) -> io::Result<Option<String>> {
+    let mut reader = READER.lock().unwrap_or_else(|e| e.into_inner());
+    let reader = reader.get_or_insert_with(spawn_reader);
+    if !reader.pending {
+        if reader.requests.send(()).is_err() {
+            return Ok(Some(String::new()));
+        }
+        reader.pending = true;
+    }
+    let line = match timeout {
+        Some(timeout) => match reader.lines.recv_timeout(timeout) {
+            Ok(line) => line,
+            Err(RecvTimeoutError::Timeout) => return Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Ok(String::new()),
+        },
+        None => reader.lines.recv().unwrap_or_else(|_| Ok(String::new())),
+    };
+    reader.pending = false;
+    line.map(Some)
+}
+
+fn spawn_reader() -> Reader {
+    let (requests, request_rx) = mpsc::channel::<()>();
+    let (line_tx, lines) = mpsc::channel();
+    thread::spawn(move || {
+        for () in request_rx {
+            let mut line = String::new();
+            let result = io::stdin().read_line(&mut line).map(|_| line);
+            let eof = matches!(result, Ok(ref line) if line.is_empty());
+            if line_tx.send(result).is_err() || eof {
+                break;
+            }
+        }
+    });
+    Reader { requests, lines, pending: false }
+}
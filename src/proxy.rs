@@ -0,0 +1,66 @@
+// proxy.rs
+//! The HTTP proxy and extra trusted certificate, for corporate networks that only reach GitHub
+//! and Steam through a proxy, often one that inspects TLS with its own certificate authority.
+//!
+//! Every HTTP client the installer builds starts from [`client`], which routes it through the
+//! proxy (`http://`, `https://`, or `socks5://`, from `--proxy` or `proxy` in `installer.toml`)
+//! and trusts the certificates from `--ca-cert` or `ca_cert` besides the usual ones.
+//! DepotDownloader is a separate program, so it is handed the proxy in the `HTTP_PROXY` and
+//! `HTTPS_PROXY` variables .NET reads; it only trusts the Windows certificate store. Without a
+//! proxy setting, the `HTTP_PROXY` and `HTTPS_PROXY` variables apply as they are.
+
+use reqwest::{Certificate, ClientBuilder, Proxy};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+use tokio::process::Command;
+
+static PROXY: Mutex<Option<String>> = Mutex::new(None);
+static CA_CERTS: Mutex<Vec<Certificate>> = Mutex::new(Vec::new());
+
+/// Makes `url` (`http://host:port` or `socks5://host:port`, optionally with `user:password@`)
+/// this run's proxy.
+pub fn set(url: &str) -> Result<(), String> {
+    Proxy::all(url).map_err(|e| format!("{}: {}", url, e))?;
+    *PROXY.lock().unwrap_or_else(|e| e.into_inner()) = Some(url.to_owned());
+    Ok(())
+}
+
+/// Trusts the certificates in `path` in addition to the system's: every one of a PEM bundle, or
+/// a single DER certificate.
+pub fn set_ca_cert(path: &Path) -> Result<(), String> {
+    let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    // A DER file has no PEM blocks, so it reads as an empty bundle.
+    let certificates = Certificate::from_pem_bundle(&bytes)
+        .ok()
+        .filter(|certificates| !certificates.is_empty())
+        .map_or_else(|| Certificate::from_der(&bytes).map(|certificate| vec![certificate]), Ok)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    *CA_CERTS.lock().unwrap_or_else(|e| e.into_inner()) = certificates;
+    Ok(())
+}
+
+/// A client builder with the installer's user agent, going through the proxy if one is set and
+/// trusting the extra certificates.
+pub fn client() -> ClientBuilder {
+    let mut builder = reqwest::Client::builder().user_agent("DesktopMateInstaller");
+    // `set` checked the URL, so this only fails if no proxy is set.
+    if let Some(proxy) = current().and_then(|url| Proxy::all(url).ok()) {
+        builder = builder.proxy(proxy);
+    }
+    for certificate in CA_CERTS.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+        builder = builder.add_root_certificate(certificate.clone());
+    }
+    builder
+}
+
+/// Passes the proxy on to a program the installer runs.
+pub fn apply_to(command: &mut Command) {
+    if let Some(url) = current() {
+        command.env("HTTP_PROXY", &url).env("HTTPS_PROXY", &url);
+    }
+}
+
+fn current() -> Option<String> {
+    PROXY.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
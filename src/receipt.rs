@@ -0,0 +1,63 @@
+// receipt.rs
+//! `install-info.txt`, a plain-text receipt left in the game folder after each run.
+//!
+//! It lists the installer version, the game build, and every component with its version,
+//! install date, and download source, so support can see what a setup contains from one file a
+//! user can attach without digging through `install.json` or the log.
+
+use crate::game_build;
+use crate::manifest::ComponentManifest;
+use crate::state::InstallState;
+use crate::transaction;
+use chrono::Local;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const RECEIPT_FILE: &str = "install-info.txt";
+
+/// Writes the receipt for the install in `root` and returns its path.
+pub fn write(root: &Path, manifest: &ComponentManifest) -> io::Result<PathBuf> {
+    let state = InstallState::load(root)?;
+    let mut lines = Vec::new();
+    lines.push(format!("DesktopMate Installer {}", env!("CARGO_PKG_VERSION")));
+    lines.push(format!("Written:           {}", Local::now().to_rfc3339()));
+    lines.push(format!("Install folder:    {}", root.display()));
+    lines.push(format!("Manifest revision: {}", manifest.revision));
+    lines.push(String::new());
+    lines.push("Game".to_owned());
+    lines.push(format!(
+        "  Steam app {}, depot {}, manifest {}",
+        manifest.game.app_id, manifest.game.depot_id, manifest.game.manifest_id
+    ));
+    if let Some(game) = state.component(game_build::COMPONENT) {
+        lines.push(format!("  Installed build {} on {}", game.version, game.installed_at));
+    }
+    lines.push(String::new());
+    lines.push("Components".to_owned());
+    let avatar_loader_source =
+        format!("https://github.com/{}/{}/releases", manifest.avatar_loader.owner, manifest.avatar_loader.repo);
+    let components = [
+        ("DepotDownloader", None, manifest.depot_downloader.url.as_str()),
+        ("Goldberg", None, manifest.goldberg.url.as_str()),
+        ("MelonLoader", Some("MelonLoader"), manifest.melonloader.url.as_str()),
+        ("Custom Avatar Loader", Some("CustomAvatarLoader"), avatar_loader_source.as_str()),
+    ];
+    for (name, key, source) in components {
+        lines.push(format!("  {}", name));
+        if let Some(component) = key.and_then(|key| state.component(key)) {
+            let version = if component.version.is_empty() { "unknown" } else { component.version.as_str() };
+            lines.push(format!("    Version:   {}", version));
+            lines.push(format!("    Installed: {}", component.installed_at));
+            if component.adopted_sha256.is_some() {
+                lines.push("    Found already installed; not downloaded by the installer.".to_owned());
+            }
+        }
+        lines.push(format!("    Source:    {}", source));
+    }
+
+    let path = root.join(RECEIPT_FILE);
+    transaction::record_write(&path)?;
+    fs::write(&path, lines.join("\r\n") + "\r\n")?;
+    Ok(path)
+}
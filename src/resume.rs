@@ -0,0 +1,76 @@
+// resume.rs
+//! Progress of an install that hasn't finished, so one cut short by a crash or a reboot picks
+//! up after its last completed step instead of starting over.
+//!
+//! The steps finished so far are kept in `.dmi-progress.json` in the game folder, written after
+//! each one and deleted once the run completes. A later run with the same manifest revision
+//! skips those steps; a different revision means the steps may install something else, so the
+//! file is ignored. The file goes through the transaction like any other write, so rolling a
+//! run back also takes back the steps it recorded.
+
+use crate::manifest::{ComponentManifest, Step};
+use crate::transaction;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const PROGRESS_FILE: &str = ".dmi-progress.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Progress {
+    /// Revision of the manifest the steps came from.
+    revision: String,
+    /// The completed steps, as they are written in the manifest.
+    completed: Vec<Step>,
+}
+
+impl Progress {
+    /// Reads what an unfinished earlier run into `root` completed. Nothing counts as completed
+    /// without a file, or when it was written for another manifest revision.
+    pub fn load(root: &Path, manifest: &ComponentManifest) -> Progress {
+        fs::read_to_string(path(root))
+            .ok()
+            .and_then(|text| serde_json::from_str::<Progress>(&text).ok())
+            .filter(|progress| progress.revision == manifest.revision)
+            .unwrap_or_else(|| Progress::fresh(manifest))
+    }
+
+    /// Progress for a run that starts from the first step.
+    pub fn fresh(manifest: &ComponentManifest) -> Progress {
+        Progress { revision: manifest.revision.clone(), completed: Vec::new() }
+    }
+
+    pub fn is_completed(&self, step: &Step) -> bool {
+        self.completed.iter().any(|done| same_step(done, step))
+    }
+
+    pub fn has_completed_steps(&self) -> bool {
+        !self.completed.is_empty()
+    }
+
+    /// Records `step` as completed and saves the progress in `root`.
+    pub fn complete(&mut self, root: &Path, step: &Step) -> io::Result<()> {
+        self.completed.push(step.clone());
+        let path = path(root);
+        transaction::record_write(&path)?;
+        fs::write(path, serde_json::to_string_pretty(self).map_err(io::Error::other)?)
+    }
+}
+
+/// Deletes the progress file once the run has finished.
+pub fn clear(root: &Path) -> io::Result<()> {
+    match fs::remove_file(path(root)) {
+        Err(ex) if ex.kind() != io::ErrorKind::NotFound => Err(ex),
+        _ => Ok(()),
+    }
+}
+
+/// Steps compare by how they are written in the manifest, which `Step` has no `PartialEq` for.
+fn same_step(a: &Step, b: &Step) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+fn path(root: &Path) -> PathBuf {
+    root.join(PROGRESS_FILE)
+}
@@ -0,0 +1,128 @@
+// retry.rs
+//! Retries for network operations, so a DNS hiccup or a dropped connection doesn't abort the
+//! whole install.
+//!
+//! An attempt that fails with a transient error (no connection, a timeout, a server error or
+//! `429`, a body cut short) is repeated after an exponentially growing delay: about 1 s, 2 s,
+//! 4 s, ..., at most 30 s, each jittered by up to half so installers behind one connection don't
+//! retry in lockstep. Other errors, such as a `404`, fail at once. `network_attempts` in
+//! `installer.toml` sets how many attempts there are.
+
+use crate::{color_echo, ConsoleColor};
+use reqwest::{Client, Response, StatusCode};
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_ATTEMPTS: u32 = 4;
+const FIRST_DELAY: Duration = Duration::from_secs(1);
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+static ATTEMPTS: Mutex<u32> = Mutex::new(DEFAULT_ATTEMPTS);
+
+/// An error worth another attempt that isn't a [`reqwest::Error`] or [`io::Error`], such as an
+/// HTTP error status or a download shorter than announced.
+#[derive(Debug)]
+pub struct Transient(pub String);
+
+impl fmt::Display for Transient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for Transient {}
+
+/// Sets how many times an operation is tried in all; at least once.
+pub fn set_attempts(attempts: u32) {
+    *ATTEMPTS.lock().unwrap_or_else(|e| e.into_inner()) = attempts.max(1);
+}
+
+pub fn attempts() -> u32 {
+    *ATTEMPTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The wait before attempt `attempt + 1`.
+pub fn delay(attempt: u32) -> Duration {
+    let base = FIRST_DELAY.saturating_mul(1 << attempt.saturating_sub(1).min(16)).min(MAX_DELAY);
+    // Not worth a random number generator: the clock's nanoseconds differ between machines.
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.subsec_nanos());
+    base.mul_f64(0.5 + f64::from(nanos % 1000) / 1000.0)
+}
+
+/// Runs `operation` until it succeeds, fails with an error `is_transient` rejects, or has been
+/// tried [`attempts`] times. `what` names the operation in the retry message.
+pub async fn with_backoff<T, E, F, Fut>(
+    what: &str,
+    mut operation: F,
+    is_transient: impl Fn(&E) -> bool,
+) -> Result<T, E>
+where
+    E: fmt::Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let attempts = attempts();
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Err(ex) if attempt < attempts && is_transient(&ex) => {
+                let wait = delay(attempt);
+                attempt += 1;
+                color_echo(
+                    ConsoleColor::Yellow,
+                    &t!(
+                        "network-retrying",
+                        what = what,
+                        error = ex.to_string(),
+                        seconds = wait.as_secs().max(1),
+                        attempt = attempt,
+                        attempts = attempts,
+                    ),
+                );
+                tokio::time::sleep(wait).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// GETs `url`, retrying transient failures. An error status is returned as an error.
+pub async fn get(client: &Client, url: &str) -> Result<Response, reqwest::Error> {
+    with_backoff(url, || async { client.get(url).send().await?.error_for_status() }, is_transient_http).await
+}
+
+/// Whether `error` is worth another attempt.
+pub fn is_transient(error: &(dyn Error + 'static)) -> bool {
+    if error.is::<Transient>() {
+        return true;
+    }
+    if let Some(ex) = error.downcast_ref::<reqwest::Error>() {
+        return is_transient_http(ex);
+    }
+    if let Some(ex) = error.downcast_ref::<io::Error>() {
+        return matches!(
+            ex.kind(),
+            io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::TimedOut
+                | io::ErrorKind::UnexpectedEof
+        );
+    }
+    false
+}
+
+/// Whether an HTTP `status` is worth another attempt.
+pub fn is_transient_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::REQUEST_TIMEOUT
+}
+
+fn is_transient_http(error: &reqwest::Error) -> bool {
+    match error.status() {
+        Some(status) => is_transient_status(status),
+        None => error.is_connect() || error.is_timeout() || error.is_request() || error.is_body(),
+    }
+}
@@ -0,0 +1,199 @@
+// self_update.rs
+//! Self-update for the installer executable.
+//!
+//! A downloaded update is only ever executed after its signature checks out: either a
+//! minisign signature (`<asset>.minisig` next to the release asset) made with the key compiled
+//! into this build, or a valid Authenticode signature embedded in the binary by the publisher
+//! compiled into this build. Anything else is refused and the current installer carries on.
+
+use crate::cli;
+use crate::config::Channel;
+use crate::temp::TempPath;
+use crate::assets::AssetPattern;
+use crate::github::get_channel_release;
+use crate::prompt;
+use crate::signature;
+use crate::version;
+use crate::{color_echo, download_file, write_log, ConsoleColor};
+use minisign_verify::{PublicKey, Signature};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::mem;
+use std::os::windows::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use winapi::shared::guiddef::GUID;
+use winapi::um::softpub::WINTRUST_ACTION_GENERIC_VERIFY_V2;
+use winapi::um::wintrust::{
+    WinVerifyTrust, WINTRUST_DATA, WINTRUST_FILE_INFO, WTD_CHOICE_FILE, WTD_REVOKE_NONE,
+    WTD_STATEACTION_CLOSE, WTD_STATEACTION_VERIFY, WTD_UI_NONE,
+};
+
+pub const UPDATE_OWNER: &str = "Nighthawk42";
+pub const UPDATE_REPO: &str = "desktopmate_installer";
+const UPDATE_ASSET: &str = "desktopmate_installer.exe";
+
+/// Base64 minisign public key for release signatures, baked in at build time from
+/// `DMI_UPDATE_PUBKEY`. Builds without it can only accept Authenticode-signed updates.
+const UPDATE_PUBLIC_KEY: Option<&str> = option_env!("DMI_UPDATE_PUBKEY");
+
+/// Publisher the installer's Authenticode certificate is issued to, baked in at build time from
+/// `DMI_UPDATE_PUBLISHER`. Builds without it can only accept minisign-signed updates.
+const UPDATE_PUBLISHER: Option<&str> = option_env!("DMI_UPDATE_PUBLISHER");
+
+/// How a downloaded update proved its origin.
+enum Verification {
+    Minisign,
+    Authenticode,
+}
+
+/// False when `--no-self-update` was passed.
+pub fn is_enabled() -> bool {
+    !cli::args().no_self_update
+}
+
+/// Checks GitHub for a newer installer on `channel` and, if the user agrees and the download
+/// verifies, swaps it in and relaunches. Returns normally when no update is applied.
+pub async fn check_and_apply(log_file: &Path, channel: Channel) -> Result<(), Box<dyn Error>> {
+    let current_exe = env::current_exe()?;
+    remove_stale_backup(&current_exe);
+    if !is_enabled() {
+        return Ok(());
+    }
+
+    let asset_pattern = AssetPattern::new(UPDATE_ASSET)?;
+    let release = match get_channel_release(UPDATE_OWNER, UPDATE_REPO, &asset_pattern, channel, log_file).await {
+        Ok(release) if !release.download_url.is_empty() => release,
+        Ok(release) => {
+            write_log(log_file, &format!("Installer update check skipped: {} has no {} asset.", release.tag_name, UPDATE_ASSET))?;
+            return Ok(());
+        }
+        Err(ex) => {
+            write_log(log_file, &format!("Installer update check skipped: {}", ex))?;
+            return Ok(());
+        }
+    };
+    let current_version = env!("CARGO_PKG_VERSION");
+    if !version::is_newer(&release.tag_name, current_version) {
+        write_log(log_file, &format!("Installer is up-to-date (version {}).", current_version))?;
+        return Ok(());
+    }
+
+    color_echo(
+        ConsoleColor::Yellow,
+        &t!("update-available", installed = current_version, latest = release.tag_name),
+    );
+    write_log(
+        log_file,
+        &format!("Installer update available on the {} channel: {}", channel, release.tag_name),
+    )?;
+    if !prompt::yes_no(&t!("update-prompt"), Some(false))? {
+        write_log(log_file, "User opted to skip the installer update.")?;
+        return Ok(());
+    }
+
+    let update_exe = TempPath::file("desktopmate_installer", "exe");
+    let update_sig = update_exe.sibling(".minisig");
+    color_echo(ConsoleColor::Blue, &t!("update-downloading"));
+    download_file(&release.download_url, &update_exe).await?;
+    let has_signature = download_file(&format!("{}.minisig", release.download_url), &update_sig)
+        .await
+        .is_ok();
+
+    let verification = verify_update(&update_exe, has_signature.then_some(update_sig.path()));
+    drop(update_sig);
+    match verification {
+        Ok(Verification::Minisign) => {
+            write_log(log_file, "Installer update verified (minisign).")?;
+        }
+        Ok(Verification::Authenticode) => {
+            write_log(log_file, "Installer update verified (Authenticode).")?;
+        }
+        Err(reason) => {
+            color_echo(ConsoleColor::Red, &t!("update-rejected", reason = reason));
+            write_log(log_file, &format!("ERROR: Installer update rejected: {}", reason))?;
+            return Ok(());
+        }
+    }
+
+    // A running exe can't be overwritten on Windows, but it can be renamed out of the way.
+    let backup = backup_path(&current_exe);
+    fs::rename(&current_exe, &backup)?;
+    if let Err(ex) = fs::copy(&update_exe, &current_exe) {
+        fs::rename(&backup, &current_exe)?;
+        return Err(ex.into());
+    }
+    // `process::exit` below skips destructors, so release the download explicitly.
+    drop(update_exe);
+
+    color_echo(ConsoleColor::Green, &t!("update-applied", version = release.tag_name));
+    write_log(log_file, &format!("Installer updated to {}; relaunching.", release.tag_name))?;
+    std::process::Command::new(&current_exe)
+        .args(env::args().skip(1))
+        .spawn()?;
+    std::process::exit(0);
+}
+
+/// Accepts the update only if a trusted signature vouches for it.
+fn verify_update(exe: &Path, signature: Option<&Path>) -> Result<Verification, String> {
+    if let Some(signature) = signature {
+        let key = UPDATE_PUBLIC_KEY.ok_or_else(|| t!("update-reason-no-key"))?;
+        let key = PublicKey::from_base64(key).map_err(|e| t!("update-reason-bad-key", error = e))?;
+        let signature = Signature::from_file(signature)
+            .map_err(|e| t!("update-reason-bad-signature-file", error = e))?;
+        let data = fs::read(exe).map_err(|e| e.to_string())?;
+        // A signature that is present but wrong is treated as tampering, not as "unsigned".
+        key.verify(&data, &signature, false)
+            .map_err(|e| t!("update-reason-mismatch", error = e))?;
+        return Ok(Verification::Minisign);
+    }
+    // Any valid signature would do otherwise, and anyone can buy a certificate.
+    match (signature::check(exe), UPDATE_PUBLISHER) {
+        (signature::Signature::Valid { publisher }, Some(expected)) if publisher.eq_ignore_ascii_case(expected) => {
+            Ok(Verification::Authenticode)
+        }
+        (signature::Signature::Valid { publisher }, Some(expected)) => {
+            Err(t!("update-reason-wrong-publisher", publisher = publisher, expected = expected))
+        }
+        _ => Err(t!("update-reason-unsigned")),
+    }
+}
+
+/// Asks WinVerifyTrust whether the file has a valid, trusted Authenticode signature.
+pub fn authenticode_valid(path: &Path) -> bool {
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+    unsafe {
+        let mut file_info: WINTRUST_FILE_INFO = mem::zeroed();
+        file_info.cbStruct = mem::size_of::<WINTRUST_FILE_INFO>() as u32;
+        file_info.pcwszFilePath = wide.as_ptr();
+
+        let mut data: WINTRUST_DATA = mem::zeroed();
+        data.cbStruct = mem::size_of::<WINTRUST_DATA>() as u32;
+        data.dwUIChoice = WTD_UI_NONE;
+        data.fdwRevocationChecks = WTD_REVOKE_NONE;
+        data.dwUnionChoice = WTD_CHOICE_FILE;
+        *data.u.pFile_mut() = &mut file_info;
+        data.dwStateAction = WTD_STATEACTION_VERIFY;
+
+        let mut action: GUID = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+        let status = WinVerifyTrust(ptr::null_mut(), &mut action, &mut data as *mut _ as *mut _);
+
+        data.dwStateAction = WTD_STATEACTION_CLOSE;
+        WinVerifyTrust(ptr::null_mut(), &mut action, &mut data as *mut _ as *mut _);
+        status == 0
+    }
+}
+
+
+fn backup_path(exe: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.old", exe.display()))
+}
+
+/// Removes the previous executable left behind by the last successful self-update.
+fn remove_stale_backup(exe: &Path) {
+    let backup = backup_path(exe);
+    if backup.exists() {
+        let _ = fs::remove_file(backup);
+    }
+}
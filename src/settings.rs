@@ -0,0 +1,51 @@
+// settings.rs
+//! Answers remembered between runs on this machine.
+//!
+//! Kept in `%LOCALAPPDATA%\DesktopMateInstaller\settings.json`, separate from the hand-edited
+//! `installer.toml`. The file doesn't exist until the first run finishes its setup questions,
+//! which is how a first run is recognised.
+
+use crate::shortcuts::{Names, Variant};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The user confirmed owning DesktopMate on Steam.
+    pub ownership_confirmed: bool,
+    /// Defaults for the setup questions, from the last run that answered them.
+    pub target_path: Option<String>,
+    pub update_avatar_loader: Option<bool>,
+    pub backup_user_data: Option<bool>,
+    pub shortcuts: Option<Variant>,
+    pub shortcut_names: Option<Names>,
+    pub pin_to_start: Option<bool>,
+    pub safe_mode_shortcut: Option<bool>,
+    /// MelonLoader release picked with `--melonloader`.
+    pub melonloader_version: Option<String>,
+    /// Components of the catalog mods picked with `--mods`.
+    pub extra_mods: Vec<String>,
+}
+
+impl Settings {
+    /// The saved settings, or `None` on the first run.
+    pub fn load() -> Option<Settings> {
+        let text = fs::read_to_string(path()?).ok()?;
+        serde_json::from_str::<Settings>(&text).ok().filter(|settings| settings.ownership_confirmed)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = path().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no local app data folder"))?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self).map_err(io::Error::other)?)
+    }
+}
+
+fn path() -> Option<PathBuf> {
+    Some(dirs::data_local_dir()?.join("DesktopMateInstaller").join("settings.json"))
+}
@@ -0,0 +1,264 @@
+// shortcuts.rs
+//! Desktop shortcuts for starting the game.
+//!
+//! Two variants exist: one that shows MelonLoader's console and one that hides it. Which of them
+//! are created is the user's choice, remembered in the settings file, since many people only
+//! ever use one and delete the other.
+//!
+//! Their display names default to "DesktopMate" and "DesktopMate (Console)" and can be changed
+//! at the console prompt or in `installer.toml`. Shortcuts under names the installer used before
+//! (`DesktopMate_NoConsole.lnk` from older builds, or a previous custom name) are removed when the
+//! new ones are created, so renaming doesn't leave duplicates behind.
+//!
+//! An optional "DesktopMate (Safe Mode)" shortcut starts the game with MelonLoader's mods
+//! disabled, for telling whether a crash comes from a mod.
+//!
+//! The no-console shortcut can also go in the Start menu, since a desktop mascot is usually
+//! started from Start or the taskbar rather than a desktop icon. Windows only lets programs pin
+//! through the shell's `pintostartscreen` verb on builds that still honour it; elsewhere the
+//! shortcut is listed under all apps and the user pins it from there.
+
+use crate::game;
+use crate::transaction;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Game argument that keeps MelonLoader's console window hidden.
+pub const HIDE_CONSOLE_ARG: &str = "melonloader.hideconsole";
+/// MelonLoader argument that starts the game without loading any mods.
+pub const NO_MODS_ARG: &str = "--no-mods";
+/// File names used by builds before shortcut names could be chosen.
+const LEGACY_NAMES: [&str; 2] = ["DesktopMate_Console.lnk", "DesktopMate_NoConsole.lnk"];
+
+/// Which shortcuts to create.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Variant {
+    Console,
+    NoConsole,
+    #[default]
+    Both,
+    None,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 4] = [Variant::Both, Variant::NoConsole, Variant::Console, Variant::None];
+
+    pub fn label(self) -> String {
+        match self {
+            Variant::Console => t!("shortcuts-variant-console"),
+            Variant::NoConsole => t!("shortcuts-variant-no-console"),
+            Variant::Both => t!("shortcuts-variant-both"),
+            Variant::None => t!("shortcuts-variant-none"),
+        }
+    }
+
+    /// Whether the no-console shortcut, the one that can be pinned to Start, is created.
+    pub fn has_no_console(self) -> bool {
+        matches!(self, Variant::NoConsole | Variant::Both)
+    }
+
+    /// The next (or previous) variant in [`Variant::ALL`], wrapping around.
+    pub fn cycle(self, forward: bool) -> Variant {
+        let index = Variant::ALL.iter().position(|variant| *variant == self).unwrap_or(0);
+        let len = Variant::ALL.len();
+        Variant::ALL[if forward { (index + 1) % len } else { (index + len - 1) % len }]
+    }
+}
+
+/// Display names of the two shortcuts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Names {
+    pub no_console: String,
+    pub console: String,
+}
+
+impl Default for Names {
+    fn default() -> Names {
+        let game = game::name();
+        Names { no_console: game.clone(), console: format!("{} (Console)", game) }
+    }
+}
+
+impl Names {
+    /// Both shortcut file names, characters Windows doesn't allow in file names replaced.
+    fn files(&self) -> [String; 2] {
+        [file_name(&self.no_console), file_name(&self.console)]
+    }
+}
+
+/// File name of the safe-mode shortcut.
+fn safe_mode_file() -> String {
+    file_name(&format!("{} (Safe Mode)", game::name()))
+}
+
+fn file_name(display_name: &str) -> String {
+    let name: String = display_name
+        .trim()
+        .chars()
+        .map(|c| if c.is_control() || r#"<>:"/\|?*"#.contains(c) { '_' } else { c })
+        .collect();
+    format!("{}.lnk", name.trim_end_matches(['.', ' ']))
+}
+
+/// Creates the shortcuts `variant` asks for in `desktop`, plus the safe-mode one if `safe_mode`,
+/// pointing at the game in `target_path`, and returns their paths. Shortcuts in `desktop` under
+/// legacy names, the `previous` names, or the safe-mode name are deleted unless they were just
+/// recreated.
+pub fn create(
+    desktop: &Path,
+    target_path: &str,
+    variant: Variant,
+    safe_mode: bool,
+    names: &Names,
+    previous: Option<&Names>,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let exe_path = game::executable(Path::new(target_path));
+    let [no_console, console] = names.files();
+    let mut wanted = Vec::new();
+    if matches!(variant, Variant::Console | Variant::Both) {
+        wanted.push((desktop.join(console), ""));
+    }
+    if variant.has_no_console() {
+        wanted.push((desktop.join(no_console), HIDE_CONSOLE_ARG));
+    }
+    if safe_mode {
+        wanted.push((desktop.join(safe_mode_file()), NO_MODS_ARG));
+    }
+    let mut created = Vec::new();
+    for (shortcut, arguments) in wanted {
+        transaction::record_write(&shortcut)?;
+        create_shortcut(&shortcut, &exe_path, target_path, arguments)?;
+        created.push(shortcut);
+    }
+
+    let mut stale: Vec<String> = LEGACY_NAMES.iter().map(|name| name.to_string()).collect();
+    stale.push(safe_mode_file());
+    stale.extend(previous.into_iter().flat_map(Names::files));
+    for shortcut in stale.iter().map(|name| desktop.join(name)) {
+        if !created.contains(&shortcut) && shortcut.is_file() {
+            transaction::record_delete(&shortcut)?;
+            fs::remove_file(&shortcut)?;
+        }
+    }
+    Ok(created)
+}
+
+/// Deletes the installer's shortcuts from desktop folders other than `desktop`, such as the local
+/// one left behind when OneDrive started redirecting the desktop. Returns the deleted paths.
+pub fn remove_elsewhere(desktop: &Path, names: &Names) -> io::Result<Vec<PathBuf>> {
+    let mut candidates: Vec<PathBuf> = dirs::home_dir().map(|home| home.join("Desktop")).into_iter().collect();
+    if let Some(onedrive) = env::var_os("OneDrive") {
+        candidates.push(Path::new(&onedrive).join("Desktop"));
+    }
+    let mut removed = Vec::new();
+    for dir in candidates.iter().filter(|dir| !same_dir(dir, desktop)) {
+        let mut ours: Vec<String> = LEGACY_NAMES.iter().map(|name| name.to_string()).collect();
+        ours.push(safe_mode_file());
+        ours.extend(names.files());
+        for name in ours {
+            let shortcut = dir.join(name);
+            if shortcut.is_file() {
+                transaction::record_delete(&shortcut)?;
+                fs::remove_file(&shortcut)?;
+                removed.push(shortcut);
+            }
+        }
+    }
+    Ok(removed)
+}
+
+/// Deletes every shortcut the installer may have made, under `names` or a legacy name, from the
+/// desktop folders and the Start menu. Used when uninstalling; returns the deleted paths.
+pub fn remove_all(names: &Names) -> io::Result<Vec<PathBuf>> {
+    let mut folders: Vec<PathBuf> = dirs::desktop_dir().into_iter().collect();
+    folders.extend(dirs::home_dir().map(|home| home.join("Desktop")));
+    if let Some(onedrive) = env::var_os("OneDrive") {
+        folders.push(Path::new(&onedrive).join("Desktop"));
+    }
+    folders.extend(dirs::data_dir().map(|roaming| roaming.join(r"Microsoft\Windows\Start Menu\Programs")));
+    let mut ours: Vec<String> = LEGACY_NAMES.iter().map(|name| name.to_string()).collect();
+    ours.push(safe_mode_file());
+    ours.extend(names.files());
+    let mut removed = Vec::new();
+    for shortcut in folders.iter().flat_map(|dir| ours.iter().map(move |name| dir.join(name))) {
+        if shortcut.is_file() && !removed.contains(&shortcut) {
+            fs::remove_file(&shortcut)?;
+            removed.push(shortcut);
+        }
+    }
+    Ok(removed)
+}
+
+/// Puts the no-console shortcut in the current user's Start menu programs folder and asks the
+/// shell to pin it to Start. Returns the shortcut's path; whether the pin took can't be told.
+pub fn pin_to_start(target_path: &str, names: &Names, previous: Option<&Names>) -> Result<PathBuf, Box<dyn Error>> {
+    let programs = dirs::data_dir()
+        .map(|roaming| roaming.join(r"Microsoft\Windows\Start Menu\Programs"))
+        .filter(|dir| dir.is_dir())
+        .ok_or_else(|| t!("err-no-start-menu"))?;
+    let shortcut = create(&programs, target_path, Variant::NoConsole, false, names, previous)?
+        .pop()
+        .ok_or_else(|| t!("err-shortcut"))?;
+    let script = format!(
+        r#"
+$Shell = New-Object -ComObject Shell.Application;
+$Item = $Shell.Namespace("{0}").ParseName("{1}");
+$Item.InvokeVerb("pintostartscreen");
+"#,
+        programs.display(),
+        shortcut.file_name().unwrap_or_default().to_string_lossy()
+    );
+    // Older builds pin; newer ones ignore the verb, which isn't an error.
+    std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .status()?;
+    Ok(shortcut)
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Uses PowerShell to create a Windows shortcut.
+fn create_shortcut(
+    shortcut_path: &Path,
+    target_path: &Path,
+    working_directory: &str,
+    arguments: &str,
+) -> Result<(), Box<dyn Error>> {
+    // Build a PowerShell command to create the shortcut via WScript.Shell.
+    let script = format!(
+        r#"
+$WshShell = New-Object -ComObject WScript.Shell;
+$Shortcut = $WshShell.CreateShortcut("{0}");
+$Shortcut.TargetPath = "{1}";
+$Shortcut.WorkingDirectory = "{2}";
+{3}
+$Shortcut.Save();
+"#,
+        shortcut_path.display(),
+        target_path.display(),
+        working_directory,
+        if arguments.trim().is_empty() {
+            "".to_string()
+        } else {
+            format!(r#"$Shortcut.Arguments = "{}";"#, arguments)
+        }
+    );
+    // Spawn PowerShell to run the script.
+    let status = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .status()?;
+    if !status.success() {
+        return Err(t!("err-shortcut").into());
+    }
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// signature.rs
+//! Authenticode checks on the programs and DLLs the installer runs or puts into the game.
+//!
+//! DepotDownloader.exe is run with the user's Steam login, and MelonLoader's `version.dll` and
+//! `MelonLoader.dll` are loaded into the game, so before either is used its signature is looked
+//! at. A signature that doesn't verify, or that names another publisher than the `publisher` the
+//! manifest expects for the component, is reported in red: the file isn't what its project
+//! ships. Neither project signs its releases today, so an unsigned file is only logged, its
+//! archive having already been checked against the pinned hash; once the manifest names a
+//! publisher, an unsigned file is reported as well.
+
+use crate::self_update::authenticode_valid;
+use crate::{color_echo, write_log, ConsoleColor};
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+use std::path::Path;
+use std::ptr;
+use winapi::shared::minwindef::DWORD;
+use winapi::um::wincrypt::{
+    CertCloseStore, CertFreeCertificateContext, CertGetNameStringW, CertGetSubjectCertificateFromStore,
+    CryptMsgClose, CryptMsgGetParam, CryptQueryObject, CERT_INFO, CERT_NAME_SIMPLE_DISPLAY_TYPE,
+    CERT_QUERY_CONTENT_FLAG_PKCS7_SIGNED_EMBED, CERT_QUERY_FORMAT_FLAG_BINARY, CERT_QUERY_OBJECT_FILE,
+    CMSG_SIGNER_CERT_INFO_PARAM, HCERTSTORE, HCRYPTMSG, PKCS_7_ASN_ENCODING, X509_ASN_ENCODING,
+};
+
+/// What a file's Authenticode signature says.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Signature {
+    /// Valid and trusted, by this publisher.
+    Valid { publisher: String },
+    /// Present but not valid or not trusted; `publisher` is the name it claims, if readable.
+    Invalid { publisher: Option<String> },
+    Unsigned,
+}
+
+/// Reads and verifies the signature embedded in `path`.
+pub fn check(path: &Path) -> Signature {
+    let publisher = publisher(path);
+    match publisher {
+        None => Signature::Unsigned,
+        Some(publisher) if authenticode_valid(path) => Signature::Valid { publisher },
+        Some(publisher) => Signature::Invalid { publisher: Some(publisher) },
+    }
+}
+
+/// Checks each of `files` (relative to `root`) of `component`, expecting `expected` as their
+/// publisher when the manifest names one. Problems are shown and logged; the caller carries on.
+pub fn audit(component: &str, root: &Path, files: &[&str], expected: Option<&str>, log_file: &Path) {
+    for file in files {
+        let path = root.join(file);
+        if !path.is_file() {
+            continue;
+        }
+        let warning = match (check(&path), expected) {
+            (Signature::Valid { publisher }, Some(expected))
+                if !publisher.to_lowercase().contains(&expected.to_lowercase()) =>
+            {
+                Some(t!("signature-wrong-publisher", file = *file, publisher = publisher, expected = expected))
+            }
+            (Signature::Valid { publisher }, _) => {
+                let _ = write_log(log_file, &format!("{} {} is signed by {}.", component, file, publisher));
+                None
+            }
+            (Signature::Invalid { publisher }, _) => Some(t!(
+                "signature-invalid",
+                file = *file,
+                publisher = publisher.unwrap_or_else(|| "?".to_owned())
+            )),
+            (Signature::Unsigned, Some(expected)) => {
+                Some(t!("signature-unsigned", file = *file, expected = expected))
+            }
+            (Signature::Unsigned, None) => {
+                let message = format!("{} {} is unsigned; its archive hash was checked instead.", component, file);
+                let _ = write_log(log_file, &message);
+                None
+            }
+        };
+        if let Some(warning) = warning {
+            color_echo(ConsoleColor::Red, &warning);
+            let _ = write_log(log_file, &format!("WARNING: {} signature check: {}", component, warning));
+        }
+    }
+}
+
+/// The display name of the certificate that signed `path`, or `None` without a signature.
+fn publisher(path: &Path) -> Option<String> {
+    let wide: Vec<u16> = OsStr::new(path).encode_wide().chain(Some(0)).collect();
+    let mut store: HCERTSTORE = ptr::null_mut();
+    let mut message: HCRYPTMSG = ptr::null_mut();
+    unsafe {
+        let found = CryptQueryObject(
+            CERT_QUERY_OBJECT_FILE,
+            wide.as_ptr().cast(),
+            CERT_QUERY_CONTENT_FLAG_PKCS7_SIGNED_EMBED,
+            CERT_QUERY_FORMAT_FLAG_BINARY,
+            0,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut store,
+            &mut message,
+            ptr::null_mut(),
+        );
+        if found == 0 {
+            return None;
+        }
+        let name = signer_name(store, message);
+        CryptMsgClose(message);
+        CertCloseStore(store, 0);
+        Some(name.unwrap_or_default())
+    }
+}
+
+/// The signer's name from the signed message `message`, its certificate taken from `store`.
+unsafe fn signer_name(store: HCERTSTORE, message: HCRYPTMSG) -> Option<String> {
+    let mut size: DWORD = 0;
+    if CryptMsgGetParam(message, CMSG_SIGNER_CERT_INFO_PARAM, 0, ptr::null_mut(), &mut size) == 0 {
+        return None;
+    }
+    // `CERT_INFO` holds pointers, so the buffer has to be aligned for them.
+    let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+    if CryptMsgGetParam(message, CMSG_SIGNER_CERT_INFO_PARAM, 0, buffer.as_mut_ptr().cast(), &mut size) == 0 {
+        return None;
+    }
+    let info = buffer.as_mut_ptr().cast::<CERT_INFO>();
+    let certificate = CertGetSubjectCertificateFromStore(store, X509_ASN_ENCODING | PKCS_7_ASN_ENCODING, info);
+    if certificate.is_null() {
+        return None;
+    }
+    let mut name = [0u16; 256];
+    let length = CertGetNameStringW(
+        certificate,
+        CERT_NAME_SIMPLE_DISPLAY_TYPE,
+        0,
+        ptr::null_mut(),
+        name.as_mut_ptr(),
+        name.len() as DWORD,
+    );
+    CertFreeCertificateContext(certificate);
+    // The length includes the terminating null.
+    Some(String::from_utf16_lossy(&name[..(length as usize).saturating_sub(1)]))
+}
@@ -0,0 +1,162 @@
+// staging.rs
+//! Staged installation of components into the game directory.
+//!
+//! A component is first assembled in a staging directory inside the game folder (so it shares
+//! a volume with the destination), checked, and only then moved into place with renames. If a
+//! rename fails part-way, every file already moved is put back, so the game folder holds either
+//! the old component or the new one, never a mix.
+//!
+//! [`Stage::promote_delta`] additionally skips files whose content is already installed and
+//! removes files the previous version of the component shipped but the new one doesn't.
+
+use crate::components;
+use crate::temp::TempPath;
+use crate::transaction::{self, sha256_file};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const STAGING_PREFIX: &str = ".dmi-staging";
+const DISPLACED_SUFFIX: &str = ".dmi-old";
+
+/// A component being assembled for `root`; removed on drop unless promoted.
+pub struct Stage {
+    root: PathBuf,
+    dir: TempPath,
+}
+
+impl Stage {
+    /// Creates an empty staging directory under `root`, clearing any left by a crashed run.
+    pub fn new(root: &Path) -> io::Result<Stage> {
+        fs::create_dir_all(root)?;
+        remove_stale(root);
+        Ok(Stage { root: root.to_path_buf(), dir: TempPath::dir_in(root, STAGING_PREFIX)? })
+    }
+
+    /// Where the component's files go, laid out relative to `root`.
+    pub fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Moves every staged file to the same relative path under `root`, replacing existing files.
+    /// On failure, `root` is restored to how it was before the call.
+    pub fn promote(self) -> io::Result<()> {
+        let mut staged = Vec::new();
+        collect_files(&self.dir, Path::new(""), &mut staged)?;
+
+        // (destination, where the file it replaced was moved to)
+        let mut moved: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+        for relative in &staged {
+            let destination = self.root.join(relative);
+            if let Err(ex) = move_into_place(&self.dir.join(relative), &destination, &mut moved) {
+                roll_back(&moved);
+                return Err(ex);
+            }
+        }
+        for (_, displaced) in moved {
+            if let Some(displaced) = displaced {
+                let _ = fs::remove_file(displaced);
+            }
+        }
+        Ok(())
+    }
+
+    /// Promotes only what changed for `component` at `version`: staged files identical to the
+    /// installed ones are left alone, and files only the previous version had are deleted.
+    pub fn promote_delta(self, component: &str, version: &str) -> io::Result<DeltaReport> {
+        let mut staged = Vec::new();
+        collect_files(&self.dir, Path::new(""), &mut staged)?;
+        let names: Vec<String> = staged
+            .iter()
+            .filter_map(|relative| components::relative_name(Path::new(""), relative))
+            .collect();
+
+        let mut report = DeltaReport::default();
+        for relative in &staged {
+            let installed = self.root.join(relative);
+            let incoming = self.dir.join(relative);
+            if installed.is_file() && sha256_file(&installed)? == sha256_file(&incoming)? {
+                fs::remove_file(&incoming)?;
+                report.unchanged += 1;
+            } else {
+                report.written += 1;
+            }
+        }
+        let root = self.root.clone();
+        self.promote()?;
+        // Only now: a failed promote puts the old version back, and it needs all of its files.
+        report.removed = components::remove_dropped(&root, component, &names)?.len();
+        components::save(&root, component, version, &names)?;
+        Ok(report)
+    }
+}
+
+/// What [`Stage::promote_delta`] did.
+#[derive(Debug, Default)]
+pub struct DeltaReport {
+    pub written: usize,
+    pub unchanged: usize,
+    pub removed: usize,
+}
+
+fn move_into_place(
+    source: &Path,
+    destination: &Path,
+    moved: &mut Vec<(PathBuf, Option<PathBuf>)>,
+) -> io::Result<()> {
+    transaction::record_write(destination)?;
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let displaced = if destination.exists() {
+        let displaced = PathBuf::from(format!("{}{}", destination.display(), DISPLACED_SUFFIX));
+        fs::rename(destination, &displaced)?;
+        Some(displaced)
+    } else {
+        None
+    };
+    if let Err(ex) = fs::rename(source, destination) {
+        if let Some(displaced) = displaced {
+            let _ = fs::rename(displaced, destination);
+        }
+        return Err(ex);
+    }
+    moved.push((destination.to_path_buf(), displaced));
+    Ok(())
+}
+
+/// Undoes `moved` in reverse order: removes each new file and puts back the one it replaced.
+fn roll_back(moved: &[(PathBuf, Option<PathBuf>)]) {
+    for (destination, displaced) in moved.iter().rev() {
+        let _ = fs::remove_file(destination);
+        if let Some(displaced) = displaced {
+            let _ = fs::rename(displaced, destination);
+        }
+    }
+}
+
+/// Relative paths of every file under `dir`.
+fn collect_files(dir: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir.join(relative))? {
+        let entry = entry?;
+        let path = relative.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(dir, &path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Removes staging directories that a crashed or killed run left in `root`.
+fn remove_stale(root: &Path) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(STAGING_PREFIX) {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
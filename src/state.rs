@@ -0,0 +1,360 @@
+// state.rs
+//! What the installer has put into a game folder, kept in `install.json` there.
+//!
+//! One entry per component (MelonLoader, Custom Avatar Loader, ...) records the installed
+//! version, when it was installed, the files it consists of with their SHA-256 hashes, and the
+//! URL and SHA-256 of the archive it came from. Everything that needs to know what is installed
+//! reads it through [`InstallState`] rather than probing the folder.
+//!
+//! The file carries a schema number. A file from an older schema is upgraded when read, and one
+//! from a newer installer is refused rather than misread. Folders set up by builds that predate
+//! `install.json` (bare `<component>.version` files and `.dmi-components` lists) are converted by
+//! [`migrate_legacy`], so their recorded versions survive and nothing is reinstalled needlessly.
+
+use crate::manifest::ModSpec;
+use crate::transaction;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const STATE_FILE: &str = "install.json";
+/// Schema written by this build.
+pub const SCHEMA: u32 = 1;
+
+/// Components older builds tracked with a bare `<component>.version` text file.
+const LEGACY_COMPONENTS: &[&str] = &["MelonLoader", "CustomAvatarLoader"];
+const LEGACY_LISTS_DIR: &str = ".dmi-components";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstallState {
+    schema: u32,
+    #[serde(default)]
+    components: BTreeMap<String, ComponentState>,
+    /// The game's own `steam_api64.dll`, saved before the Goldberg patch replaced it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    steam_api_backup: Option<DllBackup>,
+    /// Mods added with `mod install`, which every later run of this install keeps up to date.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    registered_mods: Vec<ModSpec>,
+    /// Files turned off with `mod disable` or a profile switch, by their usual path in `Mods` or
+    /// `UserLibs`, `/`-separated. They are in `Mods.disabled` or `UserLibs.disabled` instead; see
+    /// [`crate::mods::move_file`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    disabled_files: Vec<String>,
+    /// Mod profiles by name, each the files it enables; see [`crate::profiles`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    profiles: BTreeMap<String, Vec<String>>,
+    /// The profile last switched to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    active_profile: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ComponentState {
+    pub version: String,
+    /// RFC 3339 time the component was last installed or updated.
+    pub installed_at: String,
+    /// Paths relative to the game folder, `/`-separated.
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// SHA-256 of each of `files` as installed, by path. Empty for components recorded by builds
+    /// that didn't hash them.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sha256: BTreeMap<String, String>,
+    /// SHA-256 of the main file, for a component found already installed rather than installed
+    /// by the installer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_sha256: Option<String>,
+    /// Steam branch the game was downloaded from; `None` for the public one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Where the installed release was downloaded from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+    /// SHA-256 of the downloaded archive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_sha256: Option<String>,
+}
+
+/// A copy of a game file the installer replaced. See [`crate::unpatch`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DllBackup {
+    /// Path of the copy relative to the game folder, `/`-separated.
+    pub path: String,
+    pub sha256: String,
+    /// RFC 3339 time the copy was made.
+    pub backed_up_at: String,
+}
+
+impl Default for InstallState {
+    fn default() -> InstallState {
+        InstallState {
+            schema: SCHEMA,
+            components: BTreeMap::new(),
+            steam_api_backup: None,
+            registered_mods: Vec::new(),
+            disabled_files: Vec::new(),
+            profiles: BTreeMap::new(),
+            active_profile: None,
+        }
+    }
+}
+
+impl InstallState {
+    /// Reads the state of the install in `root`; a folder without one has nothing installed.
+    pub fn load(root: &Path) -> io::Result<InstallState> {
+        let path = path(root);
+        match fs::read_to_string(&path) {
+            Ok(text) => upgrade(&text).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))
+            }),
+            Err(ex) if ex.kind() == io::ErrorKind::NotFound => Ok(InstallState::default()),
+            Err(ex) => Err(ex),
+        }
+    }
+
+    /// Writes the state, always in the current schema.
+    pub fn save(&self, root: &Path) -> io::Result<()> {
+        let path = path(root);
+        let current = InstallState {
+            schema: SCHEMA,
+            components: self.components.clone(),
+            steam_api_backup: self.steam_api_backup.clone(),
+            registered_mods: self.registered_mods.clone(),
+            disabled_files: self.disabled_files.clone(),
+            profiles: self.profiles.clone(),
+            active_profile: self.active_profile.clone(),
+        };
+        transaction::record_write(&path)?;
+        fs::write(path, serde_json::to_string_pretty(&current).map_err(io::Error::other)?)
+    }
+
+    pub fn component(&self, name: &str) -> Option<&ComponentState> {
+        self.components.get(name)
+    }
+
+    /// The installed version of `name`, if the installer installed it.
+    pub fn version(&self, name: &str) -> Option<&str> {
+        self.component(name).map(|component| component.version.as_str())
+    }
+
+    /// Records that `name` at `version`, consisting of `files` with hashes `sha256`, was just
+    /// installed.
+    pub fn set_component(
+        &mut self,
+        name: &str,
+        version: &str,
+        files: Vec<String>,
+        sha256: BTreeMap<String, String>,
+    ) {
+        let installed_at = Local::now().to_rfc3339();
+        self.components.insert(
+            name.to_owned(),
+            ComponentState { version: version.to_owned(), installed_at, files, sha256, ..Default::default() },
+        );
+    }
+
+    /// Corrects the version recorded for `name`, keeping its files and install time.
+    pub fn set_version(&mut self, name: &str, version: &str) {
+        if let Some(component) = self.components.get_mut(name) {
+            component.version = version.to_owned();
+        }
+    }
+
+    /// Forgets `name`, whose files are no longer the installer's.
+    pub fn remove_component(&mut self, name: &str) {
+        self.components.remove(name);
+    }
+
+    /// Records the Steam branch `name` came from.
+    pub fn set_branch(&mut self, name: &str, branch: Option<&str>) {
+        if let Some(component) = self.components.get_mut(name) {
+            component.branch = branch.map(str::to_owned);
+        }
+    }
+
+    /// Records where `name` was downloaded from and the hash of what was downloaded.
+    pub fn set_source(&mut self, name: &str, url: &str, archive_sha256: Option<String>) {
+        if let Some(component) = self.components.get_mut(name) {
+            component.source_url = Some(url.to_owned());
+            component.archive_sha256 = archive_sha256;
+        }
+    }
+
+    /// The recorded copy of the game's own `steam_api64.dll`, if one was made.
+    pub fn steam_api_backup(&self) -> Option<&DllBackup> {
+        self.steam_api_backup.as_ref()
+    }
+
+    pub fn set_steam_api_backup(&mut self, backup: DllBackup) {
+        self.steam_api_backup = Some(backup);
+    }
+
+    /// The mods added with `mod install`.
+    pub fn registered_mods(&self) -> &[ModSpec] {
+        &self.registered_mods
+    }
+
+    /// Remembers `spec`, replacing the entry for the same component.
+    pub fn register_mod(&mut self, spec: ModSpec) {
+        self.registered_mods.retain(|known| !known.component.eq_ignore_ascii_case(&spec.component));
+        self.registered_mods.push(spec);
+    }
+
+    /// Whether `file`, a path in `Mods`, was moved to `Mods.disabled`.
+    pub fn is_disabled(&self, file: &str) -> bool {
+        self.disabled_files.iter().any(|known| known.eq_ignore_ascii_case(file))
+    }
+
+    /// Files moved to `Mods.disabled`, by their usual path.
+    pub fn disabled_files(&self) -> &[String] {
+        &self.disabled_files
+    }
+
+    /// Whether any file of the component `name` is disabled.
+    pub fn is_component_disabled(&self, name: &str) -> bool {
+        self.component(name).is_some_and(|component| component.files.iter().any(|file| self.is_disabled(file)))
+    }
+
+    /// Records that `file` was moved to `Mods.disabled`, or back.
+    pub fn set_disabled(&mut self, file: &str, disabled: bool) {
+        self.disabled_files.retain(|known| !known.eq_ignore_ascii_case(file));
+        if disabled {
+            self.disabled_files.push(file.to_owned());
+        }
+    }
+
+    /// Every mod profile, by name.
+    pub fn profiles(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.profiles.iter()
+    }
+
+    /// The profile `name`, matched without case, with its name as saved.
+    pub fn profile(&self, name: &str) -> Option<(&str, &[String])> {
+        let (name, files) = self.profiles.iter().find(|(known, _)| known.eq_ignore_ascii_case(name))?;
+        Some((name.as_str(), files.as_slice()))
+    }
+
+    /// Saves the profile `name` as enabling `files`, replacing one of that name.
+    pub fn set_profile(&mut self, name: &str, files: Vec<String>) {
+        self.remove_profile(name);
+        self.profiles.insert(name.to_owned(), files);
+    }
+
+    /// Forgets the profile `name`. Returns whether there was one.
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        let Some((known, _)) = self.profile(name) else {
+            return false;
+        };
+        let known = known.to_owned();
+        if self.active_profile.as_deref().is_some_and(|active| active.eq_ignore_ascii_case(&known)) {
+            self.active_profile = None;
+        }
+        self.profiles.remove(&known).is_some()
+    }
+
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+
+    pub fn set_active_profile(&mut self, name: Option<String>) {
+        self.active_profile = name;
+    }
+
+    /// Every recorded component, by name.
+    pub fn components(&self) -> impl Iterator<Item = (&String, &ComponentState)> {
+        self.components.iter()
+    }
+
+    /// Records `name` as found already installed, with an unknown file list.
+    pub fn adopt(&mut self, name: &str, version: &str, sha256: String) {
+        self.components.insert(
+            name.to_owned(),
+            ComponentState {
+                version: version.to_owned(),
+                installed_at: Local::now().to_rfc3339(),
+                adopted_sha256: Some(sha256),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+/// Parses `install.json` text of any schema up to [`SCHEMA`].
+fn upgrade(text: &str) -> Result<InstallState, String> {
+    let mut value: Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    // The first `install.json` layout had no schema field; it is schema 1 without the number.
+    let schema = value.get("schema").and_then(Value::as_u64).unwrap_or(1);
+    if schema > u64::from(SCHEMA) {
+        return Err(format!("schema {} is newer than supported schema {}", schema, SCHEMA));
+    }
+    if let Some(object) = value.as_object_mut() {
+        object.insert("schema".to_owned(), Value::from(SCHEMA));
+    }
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Moves versions and file lists recorded by pre-`install.json` builds into the state file and
+/// deletes the old files. Components `install.json` already knows are left as they are. Returns
+/// the migrated components.
+pub fn migrate_legacy(root: &Path) -> io::Result<Vec<String>> {
+    let mut state = InstallState::load(root)?;
+    let mut migrated = Vec::new();
+    let mut obsolete = Vec::new();
+    for &name in LEGACY_COMPONENTS {
+        let version_file = root.join(format!("{}.version", name));
+        let list_file = root.join(LEGACY_LISTS_DIR).join(format!("{}.json", name));
+        if !version_file.is_file() && !list_file.is_file() {
+            continue;
+        }
+        if state.component(name).is_none() {
+            // The list also held a version, which the `.version` file overrides.
+            #[derive(Default, Deserialize)]
+            #[serde(default)]
+            struct LegacyList {
+                version: String,
+                files: Vec<String>,
+            }
+            let list: LegacyList = fs::read_to_string(&list_file)
+                .ok()
+                .and_then(|text| serde_json::from_str(&text).ok())
+                .unwrap_or_default();
+            let version = fs::read_to_string(&version_file)
+                .map(|text| text.trim().to_owned())
+                .unwrap_or(list.version);
+            let installed_at = fs::metadata(&version_file)
+                .or_else(|_| fs::metadata(&list_file))
+                .and_then(|meta| meta.modified())
+                .map(|time| DateTime::<Local>::from(time).to_rfc3339())
+                .unwrap_or_default();
+            state.components.insert(
+                name.to_owned(),
+                ComponentState {
+                    version,
+                    installed_at,
+                    files: list.files,
+                    ..Default::default()
+                },
+            );
+            migrated.push(name.to_owned());
+        }
+        obsolete.extend([version_file, list_file].into_iter().filter(|path| path.is_file()));
+    }
+    if !migrated.is_empty() {
+        state.save(root)?;
+    }
+    for path in obsolete {
+        transaction::record_delete(&path)?;
+        fs::remove_file(path)?;
+    }
+    let _ = fs::remove_dir(root.join(LEGACY_LISTS_DIR));
+    Ok(migrated)
+}
+
+fn path(root: &Path) -> PathBuf {
+    root.join(STATE_FILE)
+}
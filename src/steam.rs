@@ -0,0 +1,218 @@
+// steam.rs
+//! Finding a copy of the game the Steam client already installed, so a new install can copy it
+//! instead of downloading the whole depot again.
+//!
+//! Steam's folder comes from the registry (`SteamPath` for the current user, else the machine's
+//! `InstallPath`). `steamapps\libraryfolders.vdf` there lists every library, and a library holds
+//! the game when it has `steamapps\appmanifest_<app id>.acf` marked fully installed. That file
+//! also names the folder under `steamapps\common` and the depot manifest installed, which tells
+//! whether the copy is the build the installer wants or DepotDownloader still has to update it.
+//!
+//! Files are copied rather than linked: the Goldberg patch and MelonLoader change the game
+//! folder, and through a link they would change Steam's copy as well.
+
+use crate::game;
+use crate::progress::Progress;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::path::{Path, PathBuf};
+use std::ptr;
+use winapi::shared::minwindef::{DWORD, HKEY};
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::winreg::{RegGetValueW, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ};
+
+/// `StateFlags` bit Steam sets once an app is fully installed.
+const FULLY_INSTALLED: u32 = 4;
+
+/// The game as installed by the Steam client.
+#[derive(Debug)]
+pub struct SteamCopy {
+    pub path: PathBuf,
+    /// Depot manifest installed there, if Steam recorded one for the depot.
+    pub manifest_id: Option<String>,
+}
+
+/// Finds the game `app_id` in the Steam libraries, with the manifest of `depot_id` installed.
+pub fn find(app_id: &str, depot_id: &str) -> Option<SteamCopy> {
+    let steam = steam_dir()?;
+    let mut libraries = vec![steam.clone()];
+    if let Ok(text) = fs::read_to_string(steam.join("steamapps").join("libraryfolders.vdf")) {
+        let folders = parse(&text);
+        let folders = folders.iter().find_map(|(key, value)| (key == "libraryfolders").then_some(value));
+        for (_, library) in folders.map(Vdf::entries).unwrap_or_default() {
+            if let Some(path) = library.get("path").and_then(Vdf::text) {
+                libraries.push(PathBuf::from(path));
+            }
+        }
+    }
+    libraries.iter().find_map(|library| find_in(library, app_id, depot_id))
+}
+
+/// Copies the game from `source` into `destination`, returning how many files were copied.
+/// Like DepotDownloader's downloads, the copies aren't part of the run's transaction.
+pub fn copy_game(source: &Path, destination: &Path) -> io::Result<usize> {
+    let (files, bytes) = crate::copy::measure(source)?;
+    let progress = Progress::new("copy-progress", files, bytes);
+    let result = copy_tree(source, destination, &progress);
+    progress.finish();
+    result.map(|()| files)
+}
+
+fn copy_tree(source: &Path, destination: &Path, progress: &Progress) -> io::Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let dest_path = destination.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_tree(&entry.path(), &dest_path, progress)?;
+        } else if file_type.is_file() {
+            progress.add(fs::copy(entry.path(), dest_path)?);
+        }
+    }
+    Ok(())
+}
+
+fn find_in(library: &Path, app_id: &str, depot_id: &str) -> Option<SteamCopy> {
+    let steamapps = library.join("steamapps");
+    let text = fs::read_to_string(steamapps.join(format!("appmanifest_{}.acf", app_id))).ok()?;
+    let manifest = parse(&text);
+    let app = manifest.iter().find_map(|(key, value)| key.eq_ignore_ascii_case("AppState").then_some(value))?;
+    let flags: u32 = app.get("StateFlags").and_then(Vdf::text)?.parse().ok()?;
+    if flags & FULLY_INSTALLED == 0 {
+        return None;
+    }
+    let path = steamapps.join("common").join(app.get("installdir").and_then(Vdf::text)?);
+    if !game::is_installed(&path) {
+        return None;
+    }
+    let manifest_id = app
+        .get("InstalledDepots")
+        .and_then(|depots| depots.get(depot_id))
+        .and_then(|depot| depot.get("manifest"))
+        .and_then(Vdf::text)
+        .map(str::to_owned);
+    Some(SteamCopy { path, manifest_id })
+}
+
+/// Steam's folder from the registry.
+fn steam_dir() -> Option<PathBuf> {
+    let path = registry_string(HKEY_CURRENT_USER, r"Software\Valve\Steam", "SteamPath")
+        .or_else(|| registry_string(HKEY_LOCAL_MACHINE, r"SOFTWARE\WOW6432Node\Valve\Steam", "InstallPath"))?;
+    // `SteamPath` is written with forward slashes.
+    Some(PathBuf::from(path.replace('/', "\\"))).filter(|path| path.is_dir())
+}
+
+fn registry_string(root: HKEY, key: &str, value: &str) -> Option<String> {
+    let wide = |text: &str| OsStr::new(text).encode_wide().chain(Some(0)).collect::<Vec<u16>>();
+    let (key, value) = (wide(key), wide(value));
+    let mut size: DWORD = 0;
+    let status = unsafe {
+        RegGetValueW(root, key.as_ptr(), value.as_ptr(), RRF_RT_REG_SZ, ptr::null_mut(), ptr::null_mut(), &mut size)
+    };
+    if status != ERROR_SUCCESS as i32 || size == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u16; size as usize / 2];
+    let status = unsafe {
+        RegGetValueW(
+            root,
+            key.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_SZ,
+            ptr::null_mut(),
+            buffer.as_mut_ptr().cast(),
+            &mut size,
+        )
+    };
+    if status != ERROR_SUCCESS as i32 {
+        return None;
+    }
+    let end = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+    std::ffi::OsString::from_wide(&buffer[..end]).into_string().ok()
+}
+
+/// A value in Valve's KeyValues text format (`.vdf`, `.acf`): a string or a nested table.
+#[derive(Debug)]
+enum Vdf {
+    Text(String),
+    Table(Vec<(String, Vdf)>),
+}
+
+impl Vdf {
+    fn text(&self) -> Option<&str> {
+        match self {
+            Vdf::Text(text) => Some(text),
+            Vdf::Table(_) => None,
+        }
+    }
+
+    fn entries(&self) -> Vec<(&str, &Vdf)> {
+        match self {
+            Vdf::Table(entries) => entries.iter().map(|(key, value)| (key.as_str(), value)).collect(),
+            Vdf::Text(_) => Vec::new(),
+        }
+    }
+
+    /// The value of `key` in a table; keys are case-insensitive.
+    fn get(&self, key: &str) -> Option<&Vdf> {
+        self.entries().into_iter().find(|(name, _)| name.eq_ignore_ascii_case(key)).map(|(_, value)| value)
+    }
+}
+
+/// Parses KeyValues text into its top-level entries. Malformed input ends the parse early.
+fn parse(text: &str) -> Vec<(String, Vdf)> {
+    let mut tokens = tokenize(text).into_iter();
+    parse_table(&mut tokens)
+}
+
+fn parse_table(tokens: &mut impl Iterator<Item = Token>) -> Vec<(String, Vdf)> {
+    let mut entries = Vec::new();
+    while let Some(Token::Text(key)) = tokens.next() {
+        match tokens.next() {
+            Some(Token::Text(value)) => entries.push((key, Vdf::Text(value))),
+            Some(Token::Open) => entries.push((key, Vdf::Table(parse_table(tokens)))),
+            _ => break,
+        }
+    }
+    entries
+}
+
+enum Token {
+    Text(String),
+    Open,
+    Close,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => tokens.push(Token::Open),
+            '}' => tokens.push(Token::Close),
+            '"' => {
+                let mut value = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => value.extend(chars.next()),
+                        _ => value.push(c),
+                    }
+                }
+                tokens.push(Token::Text(value));
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
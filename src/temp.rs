@@ -0,0 +1,77 @@
+// temp.rs
+//! Scratch files and directories under `%TEMP%` that clean up after themselves.
+//!
+//! Downloads and extraction trees can be hundreds of MB, so every one of them is held in a
+//! [`TempPath`] that deletes it on drop — including when a step fails and returns early.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A path in the temp directory that is removed when the guard goes out of scope.
+#[derive(Debug)]
+pub struct TempPath {
+    path: PathBuf,
+    is_dir: bool,
+}
+
+impl TempPath {
+    /// Reserves a unique `%TEMP%\<prefix>_<uuid>.<extension>` for a single file. Nothing is
+    /// created until the caller writes it.
+    pub fn file(prefix: &str, extension: &str) -> TempPath {
+        let name = format!("{}_{}.{}", prefix, uuid::Uuid::new_v4(), extension);
+        TempPath { path: env::temp_dir().join(name), is_dir: false }
+    }
+
+    /// Creates a fresh, empty `%TEMP%\<prefix>_<uuid>` directory.
+    ///
+    /// Names are unique per call, so two installers running at once, or leftovers from a run
+    /// that crashed before its guards could fire, never share scratch space.
+    pub fn dir(prefix: &str) -> io::Result<TempPath> {
+        TempPath::dir_in(&env::temp_dir(), prefix)
+    }
+
+    /// Like [`TempPath::dir`], but under `parent` instead of `%TEMP%` (e.g. to stay on the
+    /// same volume as a later rename target).
+    pub fn dir_in(parent: &Path, prefix: &str) -> io::Result<TempPath> {
+        let path = parent.join(format!("{}_{}", prefix, uuid::Uuid::new_v4()));
+        fs::create_dir(&path)?;
+        Ok(TempPath { path, is_dir: true })
+    }
+
+    /// A file next to this one, named by appending `suffix` (e.g. a detached `.minisig`).
+    pub fn sibling(&self, suffix: &str) -> TempPath {
+        TempPath { path: PathBuf::from(format!("{}{}", self.path.display(), suffix)), is_dir: false }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        // Best effort: a locked file (e.g. held by antivirus) must not turn into a second error.
+        let _ = if self.is_dir {
+            fs::remove_dir_all(&self.path)
+        } else {
+            fs::remove_file(&self.path)
+        };
+    }
+}
@@ -0,0 +1,98 @@
+// theme.rs
+//! Console colors by role, so they can be changed for terminal color schemes the defaults are
+//! unreadable on (dark blue on black being the usual complaint).
+//!
+//! Messages are still tagged with a [`ConsoleColor`], which now names a role rather than a
+//! color: `Cyan` is a heading, `Blue` progress information, `Green` success, `Yellow` a warning,
+//! and `Red` an error. The `[theme]` table in `installer.toml` picks a preset (`default` or
+//! `high-contrast`) and can override each role with a color name (`"bright cyan"`) or `#rrggbb`.
+
+use crate::config::{ThemeConfig, ThemePreset};
+use crate::ConsoleColor;
+use colored::Color;
+use std::sync::Mutex;
+
+static THEME: Mutex<Option<Theme>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy)]
+struct Theme {
+    heading: Color,
+    info: Color,
+    success: Color,
+    warning: Color,
+    error: Color,
+}
+
+impl Theme {
+    fn preset(preset: ThemePreset) -> Theme {
+        match preset {
+            ThemePreset::Default => Theme {
+                heading: Color::Cyan,
+                info: Color::Blue,
+                success: Color::Green,
+                warning: Color::Yellow,
+                error: Color::Red,
+            },
+            // Bright variants only, which stay legible on dark, light, and blue-tinted schemes.
+            ThemePreset::HighContrast => Theme {
+                heading: Color::BrightWhite,
+                info: Color::BrightCyan,
+                success: Color::BrightGreen,
+                warning: Color::BrightYellow,
+                error: Color::BrightRed,
+            },
+        }
+    }
+}
+
+/// Makes `config` this run's theme. Returns the role overrides whose color wasn't recognised;
+/// those roles keep the preset's color.
+pub fn apply(config: &ThemeConfig) -> Vec<String> {
+    let mut theme = Theme::preset(config.preset);
+    let mut unknown = Vec::new();
+    let roles = [
+        (&config.heading, &mut theme.heading),
+        (&config.info, &mut theme.info),
+        (&config.success, &mut theme.success),
+        (&config.warning, &mut theme.warning),
+        (&config.error, &mut theme.error),
+    ];
+    for (name, color) in roles {
+        if let Some(name) = name {
+            match parse_color(name) {
+                Some(parsed) => *color = parsed,
+                None => unknown.push(name.clone()),
+            }
+        }
+    }
+    *THEME.lock().unwrap_or_else(|e| e.into_inner()) = Some(theme);
+    unknown
+}
+
+/// The color for `role` in the current theme.
+pub fn color(role: ConsoleColor) -> Color {
+    let theme = THEME
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .unwrap_or_else(|| Theme::preset(ThemePreset::Default));
+    match role {
+        ConsoleColor::Cyan => theme.heading,
+        ConsoleColor::Blue => theme.info,
+        ConsoleColor::Green => theme.success,
+        ConsoleColor::Yellow => theme.warning,
+        ConsoleColor::Red => theme.error,
+    }
+}
+
+/// A color name as `colored` spells them (`red`, `bright blue`, ...) or `#rrggbb`.
+fn parse_color(name: &str) -> Option<Color> {
+    let name = name.trim();
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Color::TrueColor { r: channel(0)?, g: channel(2)?, b: channel(4)? });
+    }
+    name.replace(['_', '-'], " ").parse().ok()
+}
@@ -0,0 +1,235 @@
+// thunderstore.rs
+//! Mods published on Thunderstore instead of GitHub.
+//!
+//! Many DesktopMate mods are uploaded to Thunderstore's DesktopMate community. Its package list
+//! (`/c/<community>/api/v1/package/`) names every package with its versions, each version with
+//! its download and the dependencies the package's `manifest.json` declares. `mod search` looks
+//! through it, `mod versions` lists one package's versions, and `mod install` takes a package
+//! name (`Namespace-Name`) or its Thunderstore page as well as a GitHub repository, installing
+//! the package's dependencies first. MelonLoader itself, which packages list as a dependency,
+//! is left to the installer's own step.
+//!
+//! A package is a mod like any other from then on: its [`ModSpec`] has `"source":
+//! "thunderstore"` with the namespace as `owner` and the package name as `repo`, and updates
+//! come from the newest version on Thunderstore. The list is saved in
+//! `%LOCALAPPDATA%\DesktopMateInstaller\thunderstore` and reused for as long as GitHub lookups
+//! are (see [`crate::github`]); when Thunderstore can't be reached, the saved list is used
+//! however old it is.
+
+use crate::assets;
+use crate::github::{self, LookupError, ReleaseInfo};
+use crate::manifest::{ModSource, ModSpec};
+use crate::proxy;
+use crate::retry;
+use crate::write_log;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+const HOST: &str = "https://thunderstore.io";
+/// Community DesktopMate packages are listed in, unless `installer.toml` names another.
+const DEFAULT_COMMUNITY: &str = "desktopmate";
+/// Packages that only provide MelonLoader, which the installer manages itself.
+const MELONLOADER_PACKAGES: &[&str] = &["LavaGang-MelonLoader"];
+
+static COMMUNITY: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Package {
+    /// `Namespace-Name`.
+    pub full_name: String,
+    pub name: String,
+    pub owner: String,
+    #[serde(default)]
+    pub is_deprecated: bool,
+    /// Newest first.
+    pub versions: Vec<PackageVersion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PackageVersion {
+    pub version_number: String,
+    #[serde(default)]
+    pub description: String,
+    /// `Namespace-Name-Version` of each package this one needs.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    pub download_url: String,
+    #[serde(default)]
+    pub file_size: Option<u64>,
+    #[serde(default = "active")]
+    pub is_active: bool,
+}
+
+fn active() -> bool {
+    true
+}
+
+impl Package {
+    /// The newest version that hasn't been withdrawn.
+    pub fn latest(&self) -> Option<&PackageVersion> {
+        self.versions.iter().find(|version| version.is_active)
+    }
+}
+
+/// Uses `community` instead of DesktopMate's from now on.
+pub fn set_community(community: Option<String>) {
+    *COMMUNITY.lock().unwrap_or_else(|e| e.into_inner()) = community;
+}
+
+fn community() -> String {
+    let community = COMMUNITY.lock().unwrap_or_else(|e| e.into_inner());
+    community.clone().unwrap_or_else(|| DEFAULT_COMMUNITY.to_owned())
+}
+
+/// The namespace and name a package reference names: `Namespace-Name`, `Namespace-Name-1.2.3`,
+/// or a Thunderstore package page such as `https://thunderstore.io/c/desktopmate/p/Namespace/Name/`.
+pub fn parse_package(text: &str) -> Option<(String, String)> {
+    let text = text.trim().trim_end_matches('/');
+    if let Some(page) = text.strip_prefix(HOST).or_else(|| text.strip_prefix("thunderstore.io")) {
+        let parts: Vec<&str> = page.split('/').filter(|part| !part.is_empty()).collect();
+        let at = parts.iter().position(|part| *part == "p")?;
+        return Some((parts.get(at + 1)?.to_string(), parts.get(at + 2)?.to_string()));
+    }
+    if text.contains(['/', ':']) {
+        return None;
+    }
+    let mut parts = text.splitn(3, '-');
+    let namespace = parts.next().filter(|part| !part.is_empty())?;
+    let name = parts.next().filter(|part| !part.is_empty())?;
+    Some((namespace.to_owned(), name.to_owned()))
+}
+
+/// The mod Thunderstore package `namespace-name` is. Its DLL is unknown until it is installed.
+pub fn spec_for(package: &Package) -> ModSpec {
+    ModSpec {
+        name: package.name.replace('_', " "),
+        component: package.full_name.clone(),
+        owner: package.owner.clone(),
+        repo: package.name.clone(),
+        asset: assets::DEFAULT_PATTERN.to_owned(),
+        dll: String::new(),
+        source: ModSource::Thunderstore,
+    }
+}
+
+/// The community's packages that aren't deprecated.
+pub async fn packages(log_file: &Path) -> Result<Vec<Package>, LookupError> {
+    let community = community();
+    let cache_file = cache_dir().map(|dir| dir.join(format!("{}.json", community)));
+    let cached = cache_file.as_deref().and_then(|path| fs::read_to_string(path).ok());
+    let age = cache_file
+        .as_deref()
+        .and_then(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    let fresh = matches!((age, github::cache_ttl()), (Some(age), Some(ttl)) if age <= ttl);
+    let text = match cached {
+        Some(text) if fresh => {
+            let _ = write_log(log_file, &format!("Using saved Thunderstore package list for {}.", community));
+            text
+        }
+        cached => match fetch(&community).await {
+            Ok(text) => {
+                if let Some(cache_file) = &cache_file {
+                    let _ = fs::create_dir_all(cache_file.parent().unwrap_or(Path::new(".")));
+                    let _ = fs::write(cache_file, &text);
+                }
+                text
+            }
+            Err(ex) => {
+                let _ = write_log(log_file, &format!("WARNING: Thunderstore package list unavailable: {}", ex));
+                cached.ok_or(ex)?
+            }
+        },
+    };
+    let packages: Vec<Package> = serde_json::from_str(&text).map_err(|e| LookupError::Network(e.to_string()))?;
+    Ok(packages.into_iter().filter(|package| !package.is_deprecated).collect())
+}
+
+async fn fetch(community: &str) -> Result<String, LookupError> {
+    let url = format!("{}/c/{}/api/v1/package/", HOST, community);
+    let client = proxy::client().build().map_err(|e| LookupError::Network(e.to_string()))?;
+    let resp = retry::get(&client, &url).await.map_err(|e| match e.status() {
+        Some(status) => LookupError::Http(status),
+        None => LookupError::Network(e.to_string()),
+    })?;
+    resp.text().await.map_err(|e| LookupError::Network(e.to_string()))
+}
+
+/// The package `namespace-name`.
+pub async fn find(namespace: &str, name: &str, log_file: &Path) -> Result<Package, LookupError> {
+    let full_name = format!("{}-{}", namespace, name);
+    packages(log_file)
+        .await?
+        .into_iter()
+        .find(|package| package.full_name.eq_ignore_ascii_case(&full_name))
+        .ok_or(LookupError::NoRelease)
+}
+
+/// Packages whose name or description contains `query`, most recently updated first.
+pub async fn search(query: &str, log_file: &Path) -> Result<Vec<Package>, LookupError> {
+    let query = query.to_lowercase();
+    let matches = |package: &Package| {
+        package.full_name.to_lowercase().contains(&query)
+            || package.latest().is_some_and(|latest| latest.description.to_lowercase().contains(&query))
+    };
+    Ok(packages(log_file).await?.into_iter().filter(matches).collect())
+}
+
+/// The newest version of the package `spec` is, as a release.
+pub async fn latest_release(spec: &ModSpec, log_file: &Path) -> Result<ReleaseInfo, LookupError> {
+    let package = find(&spec.owner, &spec.repo, log_file).await?;
+    let latest = package.latest().ok_or(LookupError::NoRelease)?;
+    Ok(ReleaseInfo {
+        tag_name: latest.version_number.clone(),
+        download_url: latest.download_url.clone(),
+        asset_names: Vec::new(),
+        download_size: latest.file_size,
+        notes: latest.description.clone(),
+    })
+}
+
+/// `package` and the packages it depends on, dependencies first, leaving out MelonLoader.
+/// Dependencies that aren't in the community are returned separately.
+pub async fn with_dependencies(package: Package, log_file: &Path) -> Result<(Vec<Package>, Vec<String>), LookupError> {
+    let all = packages(log_file).await?;
+    let mut ordered: Vec<Package> = Vec::new();
+    let mut missing = Vec::new();
+    let mut pending = vec![(package, false)];
+    // Depth first: a package goes in once everything it needs is in.
+    while let Some((package, expanded)) = pending.pop() {
+        if ordered.iter().any(|known| known.full_name.eq_ignore_ascii_case(&package.full_name)) {
+            continue;
+        }
+        if expanded {
+            ordered.push(package);
+            continue;
+        }
+        let dependencies = package.latest().map(|latest| latest.dependencies.clone()).unwrap_or_default();
+        pending.push((package, true));
+        for dependency in dependencies {
+            // `Namespace-Name-Version`; any version will do, the newest is installed.
+            let full_name = dependency.rsplit_once('-').map_or(dependency.as_str(), |(name, _)| name);
+            if MELONLOADER_PACKAGES.iter().any(|known| known.eq_ignore_ascii_case(full_name)) {
+                continue;
+            }
+            // One being expanded further up needs this one: a cycle, which is broken here.
+            let cycle = pending
+                .iter()
+                .any(|(queued, expanded)| *expanded && queued.full_name.eq_ignore_ascii_case(full_name));
+            match all.iter().find(|known| known.full_name.eq_ignore_ascii_case(full_name)) {
+                Some(_) if cycle => {}
+                Some(found) => pending.push((found.clone(), false)),
+                None => missing.push(full_name.to_owned()),
+            }
+        }
+    }
+    Ok((ordered, missing))
+}
+
+/// `%LOCALAPPDATA%\DesktopMateInstaller\thunderstore`.
+fn cache_dir() -> Option<PathBuf> {
+    Some(dirs::cache_dir()?.join("DesktopMateInstaller").join("thunderstore"))
+}
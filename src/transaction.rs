@@ -0,0 +1,298 @@
+// transaction.rs
+//! Run-wide record of the files the installer changes, so a failed run can be undone.
+//!
+//! Once [`begin`] is called, every write into the game directory (and the desktop shortcuts)
+//! goes through [`record_write`] first: a file about to be replaced is copied into a rollback
+//! store inside the game folder, and a file about to be created is noted. If a later step fails,
+//! [`roll_back`] deletes what was created and restores what was replaced, newest first. A panic
+//! skips the failure handling that would do that, so [`roll_back_on_panic`] has the panic hook
+//! do it instead, for a panic on the thread that began the run. A panic on another thread, such
+//! as an extraction worker, is rolled back only once it reaches that thread, as a panic or an
+//! error of its own, and not while the run may still be writing.
+//!
+//! Every change is also appended to a per-run journal, `.dmi-journal/<timestamp>.jsonl` in the
+//! game folder, one JSON object per line with the operation, the path, and the SHA-256 of the
+//! file before and after. The journal outlives the run, so it can answer "what did the installer
+//! just change?" and drive uninstall later.
+//!
+//! Game files fetched by DepotDownloader are not tracked; they are the expensive part of a
+//! retry and are correct regardless of which later step failed.
+
+use crate::temp::TempPath;
+use chrono::Local;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, TryLockError};
+use std::thread::{self, ThreadId};
+
+const STORE_PREFIX: &str = ".dmi-rollback";
+pub const JOURNAL_DIR: &str = ".dmi-journal";
+
+/// The transaction for the current run, if one was started.
+static ACTIVE: Mutex<Option<Transaction>> = Mutex::new(None);
+
+struct Transaction {
+    root: PathBuf,
+    store: TempPath,
+    journal: File,
+    changes: Vec<Change>,
+    seen: HashSet<PathBuf>,
+    /// The thread that began the run.
+    thread: ThreadId,
+}
+
+/// One line of the journal.
+#[derive(Serialize)]
+struct JournalEntry<'a> {
+    time: String,
+    op: &'a str,
+    path: &'a Path,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before_sha256: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after_sha256: Option<String>,
+}
+
+impl Transaction {
+    /// Appends an entry; a journal that can't be written must not fail the install.
+    fn journal(&mut self, op: &str, path: &Path, before: Option<String>, after: Option<String>) {
+        let entry = JournalEntry {
+            time: Local::now().to_rfc3339(),
+            op,
+            path,
+            before_sha256: before,
+            after_sha256: after,
+        };
+        if let Ok(line) = serde_json::to_string(&entry) {
+            let _ = writeln!(self.journal, "{}", line);
+        }
+    }
+}
+
+enum Change {
+    /// A file that didn't exist before the run.
+    Created(PathBuf),
+    /// The outermost directory the run had to create to hold a new file.
+    CreatedDir(PathBuf),
+    /// A file that was replaced; its original content is at `saved`.
+    Replaced { path: PathBuf, saved: PathBuf },
+}
+
+/// Starts tracking changes for a run installing into `root`. Returns the journal's path.
+pub fn begin(root: &Path) -> io::Result<PathBuf> {
+    remove_stale(root);
+    let store = TempPath::dir_in(root, STORE_PREFIX)?;
+    let journal_dir = root.join(JOURNAL_DIR);
+    fs::create_dir_all(&journal_dir)?;
+    let journal_path = journal_dir.join(format!("{}.jsonl", Local::now().format("%Y%m%d-%H%M%S")));
+    let journal = OpenOptions::new().create(true).append(true).open(&journal_path)?;
+    let mut transaction = Transaction {
+        root: root.to_path_buf(),
+        store,
+        journal,
+        changes: Vec::new(),
+        seen: HashSet::new(),
+        thread: thread::current().id(),
+    };
+    transaction.journal("begin", root, None, None);
+    *lock() = Some(transaction);
+    Ok(journal_path)
+}
+
+/// Must be called before `path` is created or overwritten. Saves the current content the first
+/// time a path is seen; later calls for the same path are no-ops. Does nothing outside a run.
+pub fn record_write(path: &Path) -> io::Result<()> {
+    record(path, "overwrite")
+}
+
+/// Must be called before `path` is deleted. Like [`record_write`], the original is saved so a
+/// rollback can bring it back.
+pub fn record_delete(path: &Path) -> io::Result<()> {
+    record(path, "delete")
+}
+
+fn record(path: &Path, op: &str) -> io::Result<()> {
+    let mut active = lock();
+    let Some(transaction) = active.as_mut() else {
+        return Ok(());
+    };
+    if !transaction.seen.insert(path.to_path_buf()) {
+        return Ok(());
+    }
+    if path.is_file() {
+        let saved = transaction.store.join(transaction.changes.len().to_string());
+        fs::copy(path, &saved)?;
+        transaction.journal(op, path, sha256_file(&saved).ok(), None);
+        transaction.changes.push(Change::Replaced { path: path.to_path_buf(), saved });
+    } else {
+        transaction.journal("create", path, None, None);
+        if let Some(dir) = outermost_missing_dir(path) {
+            if transaction.seen.insert(dir.clone()) {
+                transaction.changes.push(Change::CreatedDir(dir));
+            }
+        }
+        transaction.changes.push(Change::Created(path.to_path_buf()));
+    }
+    Ok(())
+}
+
+/// Journals the final content of every file the run wrote. Call once the run's writes are done.
+fn journal_results(transaction: &mut Transaction) {
+    let written: Vec<PathBuf> = transaction
+        .changes
+        .iter()
+        .filter_map(|change| match change {
+            Change::Created(path) | Change::Replaced { path, .. } => Some(path.clone()),
+            Change::CreatedDir(_) => None,
+        })
+        .collect();
+    for path in written {
+        // Files deleted by the run have no final content to report.
+        if let Ok(after) = sha256_file(&path) {
+            transaction.journal("written", &path, None, Some(after));
+        }
+    }
+}
+
+/// True when the current run has changed anything that [`roll_back`] could undo.
+pub fn has_changes() -> bool {
+    lock().as_ref().is_some_and(|transaction| !transaction.changes.is_empty())
+}
+
+/// Ends the run, keeping every change and discarding the saved originals.
+pub fn commit() {
+    if let Some(mut transaction) = lock().take() {
+        journal_results(&mut transaction);
+        let root = transaction.root.clone();
+        transaction.journal("commit", &root, None, None);
+    }
+}
+
+/// Undoes every recorded change, newest first, and ends the run. Returns how many changes were
+/// undone; if some could not be, the error lists them and the rest are still undone.
+pub fn roll_back() -> Result<usize, String> {
+    match lock().take() {
+        Some(transaction) => undo(transaction),
+        None => Ok(0),
+    }
+}
+
+/// Makes a panic on the thread that began the current run roll it back, after the usual panic
+/// message.
+pub fn roll_back_on_panic() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        // The panic may have happened while the transaction was locked on this very thread.
+        let mut active = match ACTIVE.try_lock() {
+            Ok(active) => active,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return,
+        };
+        // The run's own thread may still handle another thread's panic, and still be writing.
+        if active.as_ref().is_some_and(|transaction| transaction.thread != thread::current().id()) {
+            return;
+        }
+        let taken = active.take();
+        drop(active);
+        let Some(transaction) = taken.filter(|transaction| !transaction.changes.is_empty()) else {
+            return;
+        };
+        match undo(transaction) {
+            Ok(count) => eprintln!("{}", t!("rollback-done", count = count)),
+            Err(ex) => eprintln!("{}", t!("rollback-incomplete", error = ex)),
+        }
+    }));
+}
+
+fn undo(mut transaction: Transaction) -> Result<usize, String> {
+    let changes = std::mem::take(&mut transaction.changes);
+    let root = transaction.root.clone();
+    transaction.journal("rollback", &root, None, None);
+    let mut undone = 0;
+    let mut failures = Vec::new();
+    for change in changes.iter().rev() {
+        let result = match change {
+            Change::Created(path) => {
+                let before = sha256_file(path).ok();
+                transaction.journal("delete", path, before, None);
+                match fs::remove_file(path) {
+                    Err(ex) if ex.kind() != io::ErrorKind::NotFound => Err((path, ex)),
+                    _ => Ok(()),
+                }
+            }
+            Change::CreatedDir(dir) => {
+                remove_empty_dirs(dir);
+                Ok(())
+            }
+            Change::Replaced { path, saved } => {
+                let before = sha256_file(path).ok();
+                transaction.journal("restore", path, before, sha256_file(saved).ok());
+                fs::copy(saved, path).map(|_| ()).map_err(|ex| (path, ex))
+            }
+        };
+        match result {
+            Ok(()) => undone += 1,
+            Err((path, ex)) => failures.push(format!("{}: {}", path.display(), ex)),
+        }
+    }
+    if failures.is_empty() {
+        Ok(undone)
+    } else {
+        Err(failures.join("; "))
+    }
+}
+
+/// Lowercase hex SHA-256 of a file's content.
+pub fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<Transaction>> {
+    ACTIVE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The highest ancestor of `path` that doesn't exist yet, if any.
+fn outermost_missing_dir(path: &Path) -> Option<PathBuf> {
+    let mut missing = None;
+    let mut current = path.parent();
+    while let Some(dir) = current {
+        if dir.as_os_str().is_empty() || dir.exists() {
+            break;
+        }
+        missing = Some(dir.to_path_buf());
+        current = dir.parent();
+    }
+    missing
+}
+
+/// Removes `dir` and its subdirectories if, after the files were removed, nothing else is left.
+fn remove_empty_dirs(dir: &Path) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                remove_empty_dirs(&entry.path());
+            }
+        }
+    }
+    let _ = fs::remove_dir(dir);
+}
+
+/// Removes rollback stores that a crashed or killed run left in `root`.
+fn remove_stale(root: &Path) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(STORE_PREFIX) {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
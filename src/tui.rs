@@ -0,0 +1,892 @@
+// tui.rs
+//! Full-screen wizard front-end used when the installer runs in an interactive console.
+//!
+//! The wizard collects every answer up front (with back/forward navigation between pages),
+//! then hands the terminal to a render thread that shows step progress and the install log
+//! while `run_install` does the work. Console output from the rest of the installer is
+//! routed here through [`forward`] for as long as a progress session is active.
+
+use crate::branding;
+use crate::cli;
+use crate::estimate::Estimate;
+use crate::game;
+use crate::game_build;
+use crate::manifest::GameSpec;
+use crate::shortcuts;
+use crate::theme;
+use crate::ConsoleColor;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Gauge, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use std::io::{self, IsTerminal, Stdout};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+type Backend = CrosstermBackend<Stdout>;
+
+/// Channel into the render thread while a progress session is running.
+static SINK: Mutex<Option<Sender<UiEvent>>> = Mutex::new(None);
+
+/// Returns true when the console is interactive and the user didn't ask for the classic prompts
+/// or an unattended run.
+pub fn is_supported() -> bool {
+    io::stdin().is_terminal()
+        && io::stdout().is_terminal()
+        && !cli::args().no_tui
+        && !cli::args().unattended
+}
+
+/// An installation step shown in the sidebar. Which steps run, and in what order, comes from
+/// the manifest and is announced with [`set_plan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstallStep {
+    DepotDownloader,
+    GameFiles,
+    GoldbergPatch,
+    MelonLoader,
+    /// A mod, by display name.
+    Mod(String),
+    Shortcuts,
+    HealthCheck,
+}
+
+impl InstallStep {
+    /// Stable, untranslated name for logs.
+    pub fn id(&self) -> String {
+        match self {
+            InstallStep::DepotDownloader => "depot-downloader".to_owned(),
+            InstallStep::GameFiles => "game-files".to_owned(),
+            InstallStep::GoldbergPatch => "goldberg".to_owned(),
+            InstallStep::MelonLoader => "melonloader".to_owned(),
+            InstallStep::Mod(name) => format!("mod:{}", name),
+            InstallStep::Shortcuts => "shortcuts".to_owned(),
+            InstallStep::HealthCheck => "health-check".to_owned(),
+        }
+    }
+
+    /// Translated name shown in the sidebar.
+    pub fn label(&self) -> String {
+        match self {
+            InstallStep::DepotDownloader => t!("step-depot-downloader"),
+            InstallStep::GameFiles => t!("step-game-files"),
+            InstallStep::GoldbergPatch => t!("step-goldberg"),
+            InstallStep::MelonLoader => t!("step-melonloader"),
+            InstallStep::Mod(name) => name.clone(),
+            InstallStep::Shortcuts => t!("step-shortcuts"),
+            InstallStep::HealthCheck => t!("step-health-check"),
+        }
+    }
+}
+
+/// Messages from the installer to the render thread.
+enum UiEvent {
+    Message(Option<ConsoleColor>, String),
+    Plan(Vec<InstallStep>),
+    Step(InstallStep),
+    Activity(Option<String>),
+    Suspend(Sender<()>),
+    Resume,
+    Finished(Result<(), String>),
+}
+
+fn send(event: UiEvent) -> bool {
+    match SINK.lock() {
+        Ok(sink) => sink.as_ref().is_some_and(|tx| tx.send(event).is_ok()),
+        Err(_) => false,
+    }
+}
+
+/// Routes a console line into the progress log. Returns false when no session is active,
+/// in which case the caller should print it itself.
+pub fn forward(color: Option<ConsoleColor>, message: &str) -> bool {
+    send(UiEvent::Message(color, message.to_owned()))
+}
+
+/// Sets the steps listed in the sidebar, in execution order.
+pub fn set_plan(steps: Vec<InstallStep>) {
+    send(UiEvent::Plan(steps));
+}
+
+/// Marks `step` as the one currently running; earlier steps are shown as done.
+pub fn begin_step(step: InstallStep) {
+    send(UiEvent::Step(step));
+}
+
+/// Shows (or with `None`, clears) a live detail line under the current step, such as
+/// extraction progress. Returns false when no session is active.
+pub fn activity(text: Option<String>) -> bool {
+    send(UiEvent::Activity(text))
+}
+
+/// Temporarily hands the real console back (e.g. for DepotDownloader's Steam Guard prompt).
+/// The progress screen is restored when the returned guard is dropped.
+pub fn suspend() -> SuspendGuard {
+    let (ack_tx, ack_rx) = mpsc::channel();
+    let active = send(UiEvent::Suspend(ack_tx));
+    if active {
+        let _ = ack_rx.recv_timeout(Duration::from_secs(5));
+    }
+    SuspendGuard { active }
+}
+
+pub struct SuspendGuard {
+    active: bool,
+}
+
+impl Drop for SuspendGuard {
+    fn drop(&mut self) {
+        if self.active {
+            send(UiEvent::Resume);
+        }
+    }
+}
+
+/// Rows on the Options page: the two checkboxes, the shortcut choice, then the pinning and
+/// safe-mode checkboxes.
+const OPTION_ROWS: usize = 5;
+
+/// What the welcome page lists as being installed, in install order.
+pub const WELCOME_ITEMS: [&str; 5] = [
+    "wizard-welcome-game",
+    "wizard-welcome-goldberg",
+    "wizard-welcome-melonloader",
+    "wizard-welcome-avatar-loader",
+    "wizard-welcome-shortcuts",
+];
+
+/// Everything the wizard collects before installation starts.
+pub struct WizardAnswers {
+    pub target_path: String,
+    pub steam_credentials: Option<(String, String)>,
+    pub update_avatar_loader: bool,
+    pub backup_user_data: bool,
+    pub shortcuts: shortcuts::Variant,
+    pub pin_to_start: bool,
+    pub safe_mode_shortcut: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Page {
+    /// Only on the first run: what gets installed, and confirming the game is owned.
+    Welcome,
+    Location,
+    SteamAccount,
+    Options,
+    Review,
+}
+
+impl Page {
+    const ALL: [Page; 5] = [Page::Welcome, Page::Location, Page::SteamAccount, Page::Options, Page::Review];
+
+    fn label(self) -> String {
+        match self {
+            Page::Welcome => t!("page-welcome"),
+            Page::Location => t!("page-location"),
+            Page::SteamAccount => t!("page-steam"),
+            Page::Options => t!("page-options"),
+            Page::Review => t!("page-review"),
+        }
+    }
+}
+
+struct Wizard {
+    page: Page,
+    estimate: Estimate,
+    first_run: bool,
+    ownership_confirmed: bool,
+    target_path: String,
+    steam_user: String,
+    steam_pass: String,
+    /// 0 = username field, 1 = password field on the Steam page; the highlighted row on the
+    /// Options page.
+    focus: usize,
+    update_avatar_loader: bool,
+    backup_user_data: bool,
+    shortcuts: shortcuts::Variant,
+    pin_to_start: bool,
+    safe_mode_shortcut: bool,
+    /// The game build and branch the component manifest asks for.
+    game: GameSpec,
+    error: Option<String>,
+}
+
+impl Wizard {
+    /// The depot only needs downloading (and thus Steam credentials) when game data is missing
+    /// or an older build than the pinned one.
+    fn needs_depot(&self) -> bool {
+        !game::is_installed(Path::new(self.target_path.trim())) || self.outdated_build().is_some()
+    }
+
+    /// The installed game build, if it is to be updated.
+    fn outdated_build(&self) -> Option<String> {
+        game_build::update_available(Path::new(self.target_path.trim()), &self.game)
+    }
+
+    fn next(&mut self) {
+        self.error = None;
+        self.focus = 0;
+        self.page = match self.page {
+            Page::Welcome => {
+                if !self.ownership_confirmed {
+                    self.error = Some(t!("wizard-ownership-required"));
+                    return;
+                }
+                Page::Location
+            }
+            Page::Location => {
+                if self.target_path.trim().is_empty() {
+                    self.error = Some(t!("wizard-path-required"));
+                    return;
+                }
+                if self.needs_depot() {
+                    Page::SteamAccount
+                } else {
+                    Page::Options
+                }
+            }
+            Page::SteamAccount => {
+                if self.steam_user.trim().is_empty() {
+                    self.error = Some(t!("steam-user-required"));
+                    self.focus = 0;
+                    return;
+                }
+                Page::Options
+            }
+            Page::Options => Page::Review,
+            Page::Review => Page::Review,
+        };
+    }
+
+    fn back(&mut self) {
+        self.error = None;
+        self.focus = 0;
+        self.page = match self.page {
+            Page::Welcome => Page::Welcome,
+            Page::Location if self.first_run => Page::Welcome,
+            Page::Location => Page::Location,
+            Page::SteamAccount => Page::Location,
+            Page::Options => {
+                if self.needs_depot() {
+                    Page::SteamAccount
+                } else {
+                    Page::Location
+                }
+            }
+            Page::Review => Page::Options,
+        };
+    }
+
+    fn active_field(&mut self) -> Option<&mut String> {
+        match (self.page, self.focus) {
+            (Page::Location, _) => Some(&mut self.target_path),
+            (Page::SteamAccount, 0) => Some(&mut self.steam_user),
+            (Page::SteamAccount, _) => Some(&mut self.steam_pass),
+            _ => None,
+        }
+    }
+
+    fn answers(&self) -> WizardAnswers {
+        WizardAnswers {
+            target_path: self.target_path.trim().to_owned(),
+            steam_credentials: if self.needs_depot() {
+                Some((self.steam_user.trim().to_owned(), self.steam_pass.clone()))
+            } else {
+                None
+            },
+            update_avatar_loader: self.update_avatar_loader,
+            backup_user_data: self.backup_user_data,
+            shortcuts: self.shortcuts,
+            pin_to_start: self.pin_to_start && self.shortcuts.has_no_console(),
+            safe_mode_shortcut: self.safe_mode_shortcut,
+        }
+    }
+}
+
+/// Owns the terminal while the wizard is on screen; restores it when dropped.
+pub struct Tui {
+    terminal: Option<Terminal<Backend>>,
+}
+
+impl Tui {
+    pub fn enter() -> io::Result<Tui> {
+        let terminal = open_terminal()?;
+        Ok(Tui { terminal: Some(terminal) })
+    }
+
+    /// Runs the configuration pages, starting from `defaults`. On the first run a welcome page
+    /// comes first. A game other than the build or branch `game` asks for is updated, which needs
+    /// the Steam page.
+    /// Returns `None` if the user cancels.
+    pub fn run_wizard(
+        &mut self,
+        defaults: WizardAnswers,
+        first_run: bool,
+        estimate: Estimate,
+        game: &GameSpec,
+    ) -> io::Result<Option<WizardAnswers>> {
+        let terminal = self.terminal.as_mut().expect("terminal is open");
+        let mut wizard = Wizard {
+            page: if first_run { Page::Welcome } else { Page::Location },
+            estimate,
+            first_run,
+            ownership_confirmed: false,
+            target_path: defaults.target_path,
+            steam_user: defaults.steam_credentials.as_ref().map(|(user, _)| user.clone()).unwrap_or_default(),
+            steam_pass: defaults.steam_credentials.map(|(_, pass)| pass).unwrap_or_default(),
+            focus: 0,
+            update_avatar_loader: defaults.update_avatar_loader,
+            backup_user_data: defaults.backup_user_data,
+            shortcuts: defaults.shortcuts,
+            pin_to_start: defaults.pin_to_start,
+            safe_mode_shortcut: defaults.safe_mode_shortcut,
+            game: game.clone(),
+            error: None,
+        };
+        loop {
+            terminal.draw(|frame| draw_wizard(frame, &wizard))?;
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                _ => continue,
+            };
+            if is_ctrl_c(&key) {
+                return Ok(None);
+            }
+            match key.code {
+                KeyCode::Enter if wizard.page == Page::Review => return Ok(Some(wizard.answers())),
+                KeyCode::Enter => wizard.next(),
+                KeyCode::Esc if wizard.page == Page::Welcome => return Ok(None),
+                KeyCode::Esc if wizard.page == Page::Location && !wizard.first_run => return Ok(None),
+                KeyCode::Esc => wizard.back(),
+                KeyCode::Tab | KeyCode::Down | KeyCode::Up if wizard.page == Page::SteamAccount => {
+                    wizard.focus = 1 - wizard.focus;
+                }
+                KeyCode::Char(' ') if wizard.page == Page::Welcome => {
+                    wizard.ownership_confirmed = !wizard.ownership_confirmed;
+                }
+                KeyCode::Tab | KeyCode::Down if wizard.page == Page::Options => {
+                    wizard.focus = (wizard.focus + 1) % OPTION_ROWS;
+                }
+                KeyCode::BackTab | KeyCode::Up if wizard.page == Page::Options => {
+                    wizard.focus = (wizard.focus + OPTION_ROWS - 1) % OPTION_ROWS;
+                }
+                KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right if wizard.page == Page::Options => {
+                    match wizard.focus {
+                        0 => wizard.update_avatar_loader = !wizard.update_avatar_loader,
+                        1 => wizard.backup_user_data = !wizard.backup_user_data,
+                        2 => wizard.shortcuts = wizard.shortcuts.cycle(key.code != KeyCode::Left),
+                        3 => wizard.pin_to_start = !wizard.pin_to_start,
+                        _ => wizard.safe_mode_shortcut = !wizard.safe_mode_shortcut,
+                    }
+                }
+                KeyCode::Backspace => {
+                    if let Some(field) = wizard.active_field() {
+                        field.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(field) = wizard.active_field() {
+                        field.push(c);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Moves the terminal to a render thread and starts routing console output to it.
+    pub fn start_progress(mut self) -> ProgressSession {
+        let terminal = self.terminal.take().expect("terminal is open");
+        let (tx, rx) = mpsc::channel();
+        if let Ok(mut sink) = SINK.lock() {
+            *sink = Some(tx);
+        }
+        let handle = thread::spawn(move || render_progress(terminal, rx));
+        ProgressSession { handle: Some(handle) }
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        if let Some(mut terminal) = self.terminal.take() {
+            close_terminal(&mut terminal);
+        }
+    }
+}
+
+/// Handle to the running progress screen.
+pub struct ProgressSession {
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ProgressSession {
+    /// Shows the final outcome, waits for a key press, and restores the console.
+    pub fn finish(mut self, outcome: Result<(), String>) {
+        send(UiEvent::Finished(outcome));
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        if let Ok(mut sink) = SINK.lock() {
+            *sink = None;
+        }
+    }
+}
+
+impl Drop for ProgressSession {
+    fn drop(&mut self) {
+        // Unwinding without `finish`: tell the thread to stop rather than hang on a key press.
+        if self.handle.is_some() {
+            send(UiEvent::Finished(Err(t!("tui-stopped-unexpectedly"))));
+            self.shutdown();
+        }
+    }
+}
+
+fn open_terminal() -> io::Result<Terminal<Backend>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(stdout))
+}
+
+fn close_terminal(terminal: &mut Terminal<Backend>) {
+    let _ = disable_raw_mode();
+    let _ = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let _ = terminal.show_cursor();
+}
+
+fn is_ctrl_c(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c')
+}
+
+struct ProgressView {
+    plan: Vec<InstallStep>,
+    current: Option<InstallStep>,
+    /// Live detail for the running step; replaced in place rather than appended to the log.
+    activity: Option<String>,
+    log: Vec<(Option<ConsoleColor>, String)>,
+    /// Lines scrolled up from the bottom of the log; 0 follows new output.
+    scroll_back: usize,
+    outcome: Option<Result<(), String>>,
+}
+
+impl ProgressView {
+    fn completed_steps(&self) -> usize {
+        match (&self.outcome, &self.current) {
+            (Some(Ok(())), _) => self.plan.len(),
+            (_, Some(step)) => self.plan.iter().position(|s| s == step).unwrap_or(0),
+            (_, None) => 0,
+        }
+    }
+}
+
+fn render_progress(mut terminal: Terminal<Backend>, rx: Receiver<UiEvent>) {
+    let mut view = ProgressView {
+        plan: Vec::new(),
+        current: None,
+        activity: None,
+        log: Vec::new(),
+        scroll_back: 0,
+        outcome: None,
+    };
+    let mut suspended = false;
+    loop {
+        // Drain everything queued since the last frame.
+        loop {
+            let event = if suspended {
+                match rx.recv_timeout(Duration::from_millis(50)) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return close_terminal(&mut terminal),
+                }
+            } else {
+                match rx.try_recv() {
+                    Ok(event) => event,
+                    Err(_) => break,
+                }
+            };
+            match event {
+                UiEvent::Message(color, text) => {
+                    if suspended {
+                        println!("{}", text);
+                    }
+                    view.log.push((color, text));
+                }
+                UiEvent::Plan(steps) => view.plan = steps,
+                UiEvent::Step(step) => {
+                    view.current = Some(step);
+                    view.activity = None;
+                }
+                UiEvent::Activity(text) => view.activity = text,
+                UiEvent::Suspend(ack) => {
+                    close_terminal(&mut terminal);
+                    suspended = true;
+                    let _ = ack.send(());
+                }
+                UiEvent::Resume => {
+                    if enable_raw_mode().is_ok()
+                        && execute!(terminal.backend_mut(), EnterAlternateScreen).is_ok()
+                    {
+                        let _ = terminal.clear();
+                    }
+                    suspended = false;
+                }
+                UiEvent::Finished(outcome) => view.outcome = Some(outcome),
+            }
+        }
+        if suspended {
+            continue;
+        }
+
+        if terminal.draw(|frame| draw_progress(frame, &view)).is_err() {
+            break;
+        }
+
+        if !event::poll(Duration::from_millis(50)).unwrap_or(false) {
+            continue;
+        }
+        let key = match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        if is_ctrl_c(&key) {
+            close_terminal(&mut terminal);
+            std::process::exit(1);
+        }
+        let max_scroll = view.log.len();
+        match key.code {
+            KeyCode::Up => view.scroll_back = (view.scroll_back + 1).min(max_scroll),
+            KeyCode::Down => view.scroll_back = view.scroll_back.saturating_sub(1),
+            KeyCode::PageUp => view.scroll_back = (view.scroll_back + 10).min(max_scroll),
+            KeyCode::PageDown => view.scroll_back = view.scroll_back.saturating_sub(10),
+            KeyCode::Home => view.scroll_back = max_scroll,
+            KeyCode::End => view.scroll_back = 0,
+            _ if view.outcome.is_some() => break,
+            _ => {}
+        }
+    }
+    close_terminal(&mut terminal);
+}
+
+/// Splits the screen into title bar, sidebar, content pane, and key-hint footer.
+fn frame_layout(area: Rect) -> (Rect, Rect, Rect, Rect) {
+    let header_height = 3 + branding::extra_lines().len() as u16;
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(header_height),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+    let [sidebar, content] =
+        Layout::horizontal([Constraint::Length(28), Constraint::Min(0)]).areas(body);
+    (header, sidebar, content, footer)
+}
+
+fn draw_header(frame: &mut Frame, area: Rect) {
+    let mut lines = vec![Line::styled(branding::title(), Style::default().add_modifier(Modifier::BOLD))];
+    lines.extend(branding::extra_lines().into_iter().map(Line::raw));
+    let title = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(to_color(ConsoleColor::Cyan)))
+        .block(Block::bordered().border_style(Style::default().fg(to_color(ConsoleColor::Cyan))));
+    frame.render_widget(title, area);
+}
+
+fn draw_footer(frame: &mut Frame, area: Rect, hint: &str) {
+    let footer = Paragraph::new(format!(" {}", hint)).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(footer, area);
+}
+
+/// Sidebar listing the wizard pages followed by the installation steps.
+fn draw_sidebar(frame: &mut Frame, area: Rect, pages: &[Page], page: Option<Page>, view: Option<&ProgressView>) {
+    let done = Style::default().fg(to_color(ConsoleColor::Green));
+    let active = Style::default().fg(to_color(ConsoleColor::Yellow)).add_modifier(Modifier::BOLD);
+    let pending = Style::default().fg(Color::DarkGray);
+
+    let mut lines = vec![Line::styled(t!("tui-sidebar-setup"), Style::default().add_modifier(Modifier::BOLD))];
+    let page_index = page.and_then(|p| pages.iter().position(|x| *x == p));
+    for (i, p) in pages.iter().enumerate() {
+        let (marker, style) = match page_index {
+            None => ("✓", done),
+            Some(current) if i < current => ("✓", done),
+            Some(current) if i == current => ("▶", active),
+            Some(_) => ("·", pending),
+        };
+        lines.push(Line::styled(format!(" {} {}", marker, p.label()), style));
+    }
+
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(t!("tui-sidebar-install"), Style::default().add_modifier(Modifier::BOLD)));
+    let completed = view.map_or(0, ProgressView::completed_steps);
+    let failed = view.is_some_and(|v| matches!(v.outcome, Some(Err(_))));
+    let plan = view.map_or(&[][..], |v| &v.plan[..]);
+    for (i, step) in plan.iter().enumerate() {
+        let running = view.is_some_and(|v| v.current.as_ref() == Some(step));
+        let (marker, style) = if i < completed {
+            ("✓", done)
+        } else if running && failed {
+            ("✗", Style::default().fg(to_color(ConsoleColor::Red)).add_modifier(Modifier::BOLD))
+        } else if running {
+            ("▶", active)
+        } else {
+            ("·", pending)
+        };
+        lines.push(Line::styled(format!(" {} {}", marker, step.label()), style));
+    }
+
+    let block = Block::bordered().title(format!(" {} ", t!("tui-sidebar-title")));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_wizard(frame: &mut Frame, wizard: &Wizard) {
+    let (header, sidebar, content, footer) = frame_layout(frame.area());
+    draw_header(frame, header);
+    let pages = if wizard.first_run { &Page::ALL[..] } else { &Page::ALL[1..] };
+    draw_sidebar(frame, sidebar, pages, Some(wizard.page), None);
+
+    let label = Style::default().add_modifier(Modifier::BOLD);
+    let field = |value: &str, focused: bool| {
+        let style = if focused {
+            Style::default().fg(Color::Black).bg(to_color(ConsoleColor::Cyan))
+        } else {
+            Style::default().fg(to_color(ConsoleColor::Cyan))
+        };
+        Line::from(vec![Span::raw("  "), Span::styled(format!("{}_", value), style)])
+    };
+
+    let mut lines: Vec<Line> = Vec::new();
+    match wizard.page {
+        Page::Welcome => {
+            lines.push(Line::raw(t!("wizard-welcome-intro")));
+            lines.push(Line::raw(""));
+            for item in WELCOME_ITEMS {
+                lines.push(Line::raw(format!("  • {}", t!(item))));
+            }
+            lines.push(Line::raw(""));
+            let check = if wizard.ownership_confirmed { "[x]" } else { "[ ]" };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", check), Style::default().fg(to_color(ConsoleColor::Cyan))),
+                Span::raw(t!("wizard-ownership")),
+            ]));
+        }
+        Page::Location => {
+            lines.push(Line::raw(t!("wizard-location-question")));
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(t!("wizard-location-label"), label));
+            lines.push(field(&wizard.target_path, true));
+            lines.push(Line::raw(""));
+            if !wizard.target_path.trim().is_empty() && !wizard.needs_depot() {
+                lines.push(Line::styled(
+                    t!("wizard-location-existing"),
+                    Style::default().fg(to_color(ConsoleColor::Yellow)),
+                ));
+            }
+        }
+        Page::SteamAccount => {
+            if let Some(installed) = wizard.outdated_build() {
+                let update = t!("game-update-available", installed = installed, latest = wizard.game.manifest_id.as_str());
+                lines.push(Line::styled(update, Style::default().fg(to_color(ConsoleColor::Yellow))));
+            }
+            lines.push(Line::raw(t!("wizard-steam-intro")));
+            lines.push(Line::raw(t!("wizard-steam-guard")));
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(t!("wizard-steam-user"), label));
+            lines.push(field(&wizard.steam_user, wizard.focus == 0));
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(t!("wizard-steam-password"), label));
+            lines.push(field(&"*".repeat(wizard.steam_pass.chars().count()), wizard.focus == 1));
+        }
+        Page::Options => {
+            lines.push(Line::raw(t!("wizard-options-intro")));
+            lines.push(Line::raw(""));
+            let options = [
+                (wizard.update_avatar_loader, t!("wizard-option-update-mod")),
+                (wizard.backup_user_data, t!("wizard-option-backup")),
+            ];
+            for (index, (checked, text)) in options.into_iter().enumerate() {
+                let check = if checked { "[x]" } else { "[ ]" };
+                let marker = if index == wizard.focus { ">" } else { " " };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{} {} ", marker, check), Style::default().fg(to_color(ConsoleColor::Cyan))),
+                    Span::raw(text),
+                ]));
+            }
+            let marker = if wizard.focus == 2 { ">" } else { " " };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}     ", marker), Style::default().fg(to_color(ConsoleColor::Cyan))),
+                Span::raw(format!("{}: ", t!("wizard-option-shortcuts"))),
+                Span::styled(format!("< {} >", wizard.shortcuts.label()), Style::default().fg(to_color(ConsoleColor::Cyan))),
+            ]));
+            let extras = [
+                (wizard.pin_to_start && wizard.shortcuts.has_no_console(), t!("wizard-option-pin")),
+                (wizard.safe_mode_shortcut, t!("wizard-option-safe-mode")),
+            ];
+            for (index, (checked, text)) in extras.into_iter().enumerate() {
+                let check = if checked { "[x]" } else { "[ ]" };
+                let marker = if index + 3 == wizard.focus { ">" } else { " " };
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{} {} ", marker, check), Style::default().fg(to_color(ConsoleColor::Cyan))),
+                    Span::raw(text),
+                ]));
+            }
+        }
+        Page::Review => {
+            let answers = wizard.answers();
+            let depot = match &answers.steam_credentials {
+                Some((user, _)) if wizard.outdated_build().is_some() => {
+                    t!("wizard-review-depot-update", build = wizard.game.manifest_id.as_str(), user = user)
+                }
+                Some((user, _)) => t!("wizard-review-depot-download", user = user),
+                None => t!("wizard-review-depot-skip"),
+            };
+            let yes_no = |value: bool| if value { t!("wizard-yes") } else { t!("wizard-no") };
+            let summary = [
+                (t!("wizard-review-path"), answers.target_path.clone()),
+                (t!("wizard-review-depot"), depot),
+                (t!("wizard-review-update"), yes_no(answers.update_avatar_loader)),
+                (t!("wizard-review-backup"), yes_no(answers.backup_user_data)),
+                (t!("wizard-review-shortcuts"), answers.shortcuts.label()),
+                (t!("wizard-review-pin"), yes_no(answers.pin_to_start)),
+                (t!("wizard-review-safe-mode"), yes_no(answers.safe_mode_shortcut)),
+                (t!("wizard-review-download"), wizard.estimate.size_text(wizard.needs_depot())),
+            ];
+            lines.push(Line::raw(t!("wizard-review-intro")));
+            lines.push(Line::raw(""));
+            for (name, value) in summary {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}: ", name), label),
+                    Span::raw(value),
+                ]));
+            }
+            lines.push(Line::raw(""));
+            lines.push(Line::raw(t!("wizard-review-start")));
+        }
+    }
+    if let Some(error) = &wizard.error {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(error.clone(), Style::default().fg(to_color(ConsoleColor::Red))));
+    }
+
+    let body = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::bordered().title(format!(" {} ", wizard.page.label())));
+    frame.render_widget(body, content);
+
+    let hint = match wizard.page {
+        Page::Welcome => t!("hint-welcome"),
+        Page::Location if wizard.first_run => t!("hint-location-back"),
+        Page::Location => t!("hint-location"),
+        Page::SteamAccount => t!("hint-steam"),
+        Page::Options => t!("hint-options"),
+        Page::Review => t!("hint-review"),
+    };
+    draw_footer(frame, footer, &hint);
+}
+
+fn draw_progress(frame: &mut Frame, view: &ProgressView) {
+    let (header, sidebar, content, footer) = frame_layout(frame.area());
+    draw_header(frame, header);
+    draw_sidebar(frame, sidebar, &Page::ALL[1..], None, Some(view));
+
+    let [gauge_area, status_area, log_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Length(4),
+        Constraint::Min(0),
+    ])
+    .areas(content);
+
+    let total = view.plan.len();
+    let completed = view.completed_steps();
+    let gauge = Gauge::default()
+        .block(Block::bordered().title(format!(" {} ", t!("progress-title"))))
+        .gauge_style(Style::default().fg(to_color(ConsoleColor::Cyan)))
+        .ratio(completed as f64 / total.max(1) as f64)
+        .label(t!("progress-steps", completed = completed, total = total));
+    frame.render_widget(gauge, gauge_area);
+
+    let status = match (&view.outcome, &view.current) {
+        (Some(Ok(())), _) => Line::styled(t!("status-complete"), Style::default().fg(to_color(ConsoleColor::Green))),
+        (Some(Err(error)), _) => {
+            Line::styled(t!("status-failed", error = error), Style::default().fg(to_color(ConsoleColor::Red)))
+        }
+        (None, Some(step)) => Line::styled(
+            t!("status-running", step = step.label()),
+            Style::default().fg(to_color(ConsoleColor::Yellow)),
+        ),
+        (None, None) => Line::raw(t!("status-starting")),
+    };
+    let mut status_lines = vec![status];
+    if let (None, Some(activity)) = (&view.outcome, &view.activity) {
+        status_lines.push(Line::raw(format!("  {}", activity)));
+    }
+    let block = Block::bordered().title(format!(" {} ", t!("status-title")));
+    frame.render_widget(Paragraph::new(status_lines).block(block), status_area);
+
+    let visible = log_area.height.saturating_sub(2) as usize;
+    let top = view.log.len().saturating_sub(visible + view.scroll_back);
+    let lines: Vec<Line> = view
+        .log
+        .iter()
+        .map(|(color, text)| match color {
+            Some(color) => Line::styled(text.clone(), Style::default().fg(to_color(*color))),
+            None => Line::raw(text.clone()),
+        })
+        .collect();
+    let title = if view.scroll_back > 0 {
+        format!(" {} ", t!("log-title-scrolled", lines = view.scroll_back))
+    } else {
+        format!(" {} ", t!("log-title"))
+    };
+    let log = Paragraph::new(Text::from(lines))
+        .scroll((top.min(u16::MAX as usize) as u16, 0))
+        .block(Block::bordered().title(title));
+    frame.render_widget(log, log_area);
+
+    let hint = if view.outcome.is_some() {
+        t!("hint-finished")
+    } else {
+        t!("hint-running")
+    };
+    draw_footer(frame, footer, &hint);
+}
+
+/// The theme's color for `role`, in ratatui's terms.
+fn to_color(role: ConsoleColor) -> Color {
+    match theme::color(role) {
+        colored::Color::Black => Color::Black,
+        colored::Color::Red => Color::Red,
+        colored::Color::Green => Color::Green,
+        colored::Color::Yellow => Color::Yellow,
+        // Dark blue is hard to read on the progress screen's black background; it has always
+        // shown the blue role lighter.
+        colored::Color::Blue => Color::LightBlue,
+        colored::Color::Magenta => Color::Magenta,
+        colored::Color::Cyan => Color::Cyan,
+        colored::Color::White => Color::Gray,
+        colored::Color::BrightBlack => Color::DarkGray,
+        colored::Color::BrightRed => Color::LightRed,
+        colored::Color::BrightGreen => Color::LightGreen,
+        colored::Color::BrightYellow => Color::LightYellow,
+        colored::Color::BrightBlue => Color::LightBlue,
+        colored::Color::BrightMagenta => Color::LightMagenta,
+        colored::Color::BrightCyan => Color::LightCyan,
+        colored::Color::BrightWhite => Color::White,
+        colored::Color::TrueColor { r, g, b } => Color::Rgb(r, g, b),
+    }
+}
@@ -0,0 +1,96 @@
+// unpatch.rs
+//! The game's own `steam_api64.dll`, kept so the Goldberg patch can be undone.
+//!
+//! Before Goldberg's DLL replaces it, the original is copied to
+//! `backup\steam_api64.<timestamp>.dll` in the game folder, and `install.json` records where the
+//! copy is and its hash. A DLL that is already Goldberg's (an earlier patch being updated or
+//! repaired) isn't backed up, and neither is one identical to the recorded backup, so repairs
+//! don't pile up copies of the same file.
+//!
+//! [`restore_original`] undoes the patch for users who bought the game on Steam since: the
+//! backup goes back in place, and Goldberg's settings next to the DLL are deleted, so the game
+//! talks to the Steam client again without being reinstalled.
+
+use crate::components;
+use crate::defender;
+use crate::game;
+use crate::state::{DllBackup, InstallState};
+use crate::transaction::{self, sha256_file};
+use chrono::Local;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Folder in the game folder the original DLL is copied to.
+pub const BACKUP_DIR: &str = "backup";
+
+/// Goldberg's settings and state, next to its `steam_api64.dll`.
+const GOLDBERG_FILES: &[&str] = &["steam_settings", "steam_appid.txt", "steam_interfaces.txt", "local_save.txt"];
+
+/// Copies the game's `steam_api64.dll` in `root` to the backup folder and records it, unless it
+/// is missing, is Goldberg's (`goldberg_sha256` or the patch recorded earlier), or was already
+/// backed up. Returns the copy's path.
+pub fn back_up_original(root: &Path, goldberg_sha256: &str) -> io::Result<Option<PathBuf>> {
+    let dll = game::steam_api(root);
+    if !dll.is_file() {
+        return Ok(None);
+    }
+    let sha256 = sha256_file(&dll)?;
+    let mut state = InstallState::load(root)?;
+    let known = [
+        Some(goldberg_sha256),
+        state.version(defender::COMPONENT),
+        state.steam_api_backup().map(|backup| backup.sha256.as_str()),
+    ];
+    if known.into_iter().flatten().any(|known| known.eq_ignore_ascii_case(&sha256)) {
+        return Ok(None);
+    }
+
+    let now = Local::now();
+    let stem = dll.file_stem().unwrap_or_default().to_string_lossy().into_owned();
+    let extension = dll.extension().unwrap_or_default().to_string_lossy().into_owned();
+    let backup = root.join(BACKUP_DIR).join(format!("{}.{}.{}", stem, now.format("%Y%m%d-%H%M%S"), extension));
+    fs::create_dir_all(backup.parent().unwrap_or(root))?;
+    transaction::record_write(&backup)?;
+    fs::copy(&dll, &backup)?;
+    let path = components::relative_name(root, &backup).unwrap_or_default();
+    state.set_steam_api_backup(DllBackup { path, sha256, backed_up_at: now.to_rfc3339() });
+    state.save(root)?;
+    Ok(Some(backup))
+}
+
+/// Puts the backed-up `steam_api64.dll` back in `root` and deletes Goldberg's files, returning
+/// the ones deleted. Fails without changing anything when there is no backup or it was changed.
+pub fn restore_original(root: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut state = InstallState::load(root)?;
+    let Some(backup) = state.steam_api_backup().cloned() else {
+        return Err(t!("err-steam-mode-no-backup").into());
+    };
+    let backup_path = game::relative(root, &backup.path);
+    if !sha256_file(&backup_path).is_ok_and(|actual| actual.eq_ignore_ascii_case(&backup.sha256)) {
+        return Err(t!("err-steam-mode-backup-changed", path = backup_path.display().to_string()).into());
+    }
+
+    let dll = game::steam_api(root);
+    transaction::record_write(&dll)?;
+    fs::copy(&backup_path, &dll)?;
+    let mut removed = Vec::new();
+    let dll_dir = dll.parent().unwrap_or(root);
+    for name in GOLDBERG_FILES {
+        let path = dll_dir.join(name);
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else if path.is_file() {
+            transaction::record_delete(&path)?;
+            fs::remove_file(&path)?;
+        } else {
+            continue;
+        }
+        removed.push(path);
+    }
+    // Without the patch on record, later runs don't take the original DLL for a quarantined one.
+    state.remove_component(defender::COMPONENT);
+    state.save(root)?;
+    Ok(removed)
+}
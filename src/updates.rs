@@ -0,0 +1,84 @@
+// updates.rs
+//! What in an install is out of date: the game build and the two mod components.
+//!
+//! The game and MelonLoader are compared with the versions the component manifest pins, Custom
+//! Avatar Loader with its latest GitHub release. `--check-updates` prints the result once;
+//! `--watch` repeats the check daily and raises a notification when something new turns up.
+//! MelonLoader's installed version is read from its DLL, not just `install.json`.
+
+use crate::assets::AssetPattern;
+use crate::game_build;
+use crate::github::get_latest_release;
+use crate::manifest::ComponentManifest;
+use crate::melonloader;
+use crate::state::InstallState;
+use crate::version;
+use std::error::Error;
+use std::path::Path;
+
+/// One component's installed and available versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Status {
+    pub component: &'static str,
+    /// `None` when the installer didn't record a version.
+    pub installed: Option<String>,
+    /// `None` when the latest version couldn't be looked up.
+    pub latest: Option<String>,
+    pub outdated: bool,
+}
+
+/// Compares the install in `root` with what `manifest` and GitHub offer.
+pub async fn check(root: &Path, manifest: &ComponentManifest, log_file: &Path) -> Result<Vec<Status>, Box<dyn Error>> {
+    let state = InstallState::load(root)?;
+    let installed = |name: &str| state.version(name).filter(|v| !v.is_empty()).map(str::to_owned);
+
+    let latest_build = manifest.game.manifest_id.clone();
+    let game = Status {
+        component: "DesktopMate",
+        installed: game_build::installed(root),
+        outdated: game_build::update_available(root, &manifest.game).is_some(),
+        latest: Some(latest_build),
+    };
+
+    // The DLL tells the truth when MelonLoader was updated by hand.
+    let melonloader = installed("MelonLoader").map(|recorded| melonloader::detect_version(root).unwrap_or(recorded));
+    let pinned = manifest.melonloader.version.clone();
+    let melonloader = Status {
+        component: "MelonLoader",
+        outdated: melonloader.as_deref().is_some_and(|v| !version::same(v, &pinned)),
+        installed: melonloader,
+        latest: Some(pinned),
+    };
+
+    let pattern = AssetPattern::new(&manifest.avatar_loader.asset)?;
+    let latest = get_latest_release(&manifest.avatar_loader.owner, &manifest.avatar_loader.repo, &pattern, log_file)
+        .await
+        .map(|release| release.tag_name)
+        .ok();
+    let avatar_loader = installed("CustomAvatarLoader");
+    let avatar_loader = Status {
+        component: "Custom Avatar Loader",
+        outdated: matches!((&avatar_loader, &latest), (Some(installed), Some(latest)) if version::is_newer(latest, installed)),
+        installed: avatar_loader,
+        latest,
+    };
+    Ok(vec![game, melonloader, avatar_loader])
+}
+
+impl Status {
+    /// One localized line describing the status.
+    pub fn describe(&self) -> String {
+        match (&self.installed, &self.latest) {
+            (None, _) => t!("check-updates-unknown", component = self.component),
+            (Some(installed), Some(latest)) if self.outdated => t!(
+                "check-updates-outdated",
+                component = self.component,
+                installed = installed.as_str(),
+                latest = latest.as_str()
+            ),
+            (Some(installed), _) => {
+                t!("check-updates-current", component = self.component, installed = installed.as_str())
+            }
+        }
+    }
+}
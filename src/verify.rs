@@ -0,0 +1,106 @@
+// verify.rs
+//! Verification of installed game files against the Steam depot manifest.
+//!
+//! DepotDownloader's `-manifest-only` mode writes the manifest as a text table (size, chunk
+//! count, SHA-1, flags, name per file). Each listed file is checked on disk, and anything the
+//! installer patches on purpose (the Goldberg DLL) is reported separately from real damage.
+
+use crate::game;
+use crate::progress::Progress;
+use sha1::{Digest, Sha1};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+/// `EDepotFileFlag.Directory` in DepotDownloader's flags column.
+const FLAG_DIRECTORY: u32 = 64;
+
+
+/// One file listed in the depot manifest.
+pub struct DepotFile {
+    /// Path relative to the game folder, `/`-separated.
+    pub name: String,
+    pub size: u64,
+    /// Lowercase hex SHA-1 of the file content.
+    pub sha1: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStatus {
+    Missing,
+    /// Wrong size or content.
+    Modified,
+    /// Differs from the depot because the installer patched it.
+    Patched,
+}
+
+/// A file that doesn't match the manifest.
+pub struct Discrepancy {
+    pub name: String,
+    pub status: FileStatus,
+}
+
+/// Parses the manifest table written by `DepotDownloader -manifest-only`, skipping directories.
+pub fn parse_manifest(text: &str) -> Vec<DepotFile> {
+    let mut files = Vec::new();
+    let mut in_table = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if !in_table {
+            in_table = trimmed.starts_with("Size") && trimmed.ends_with("Name");
+            continue;
+        }
+        // Size, chunk count, SHA, flags, then the name, which may contain spaces.
+        let mut rest = trimmed;
+        let mut columns = [""; 4];
+        for column in &mut columns {
+            rest = rest.trim_start();
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            *column = &rest[..end];
+            rest = &rest[end..];
+        }
+        let [size, _chunks, sha1, flags] = columns;
+        let name = rest.trim();
+        let (Ok(size), Ok(flags)) = (size.parse::<u64>(), flags.parse::<u32>()) else {
+            continue;
+        };
+        if name.is_empty() || flags & FLAG_DIRECTORY != 0 {
+            continue;
+        }
+        files.push(DepotFile { name: name.replace('\\', "/"), size, sha1: sha1.to_ascii_lowercase() });
+    }
+    files
+}
+
+/// Checks every manifest file under `game_dir` and returns the ones that don't match.
+pub fn compare(game_dir: &Path, files: &[DepotFile]) -> Vec<Discrepancy> {
+    let total_bytes = files.iter().map(|file| file.size).sum();
+    let progress = Progress::new("verify-progress", files.len(), total_bytes);
+    let mut discrepancies = Vec::new();
+    for file in files {
+        let path = file.name.split('/').fold(game_dir.to_path_buf(), |path, part| path.join(part));
+        let status = match fs::metadata(&path) {
+            Err(_) => Some(FileStatus::Missing),
+            Ok(meta) if meta.len() != file.size => Some(FileStatus::Modified),
+            Ok(_) => match sha1_file(&path) {
+                Ok(hash) if hash == file.sha1 => None,
+                _ => Some(FileStatus::Modified),
+            },
+        };
+        progress.add(file.size);
+        if let Some(status) = status {
+            // The Goldberg DLL is the one file the installer replaces on purpose.
+            let patched = game::current().steam_api.eq_ignore_ascii_case(&file.name);
+            let status = if patched && status == FileStatus::Modified { FileStatus::Patched } else { status };
+            discrepancies.push(Discrepancy { name: file.name.clone(), status });
+        }
+    }
+    progress.finish();
+    discrepancies
+}
+
+fn sha1_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha1::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
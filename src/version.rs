@@ -0,0 +1,39 @@
+// version.rs
+//! Comparing release tags.
+//!
+//! Tags are compared as semantic versions: a leading `v` is ignored, missing minor or patch
+//! numbers count as zero (`v0.6` is `0.6.0`), a prerelease sorts before its release, and build
+//! metadata is ignored. Tags that aren't versions at all (`nightly`, a date) only compare equal
+//! to the identical tag and are never considered newer or older.
+
+use semver::{BuildMetadata, Version};
+use std::cmp::Ordering;
+
+/// Parses a release tag as a semantic version.
+pub fn parse(tag: &str) -> Option<Version> {
+    let tag = tag.trim().trim_start_matches(['v', 'V']);
+    let (core, suffix) = tag.split_at(tag.find(['-', '+']).unwrap_or(tag.len()));
+    let padding = 2usize.checked_sub(core.matches('.').count())?;
+    let mut version = Version::parse(&format!("{}{}{}", core, ".0".repeat(padding), suffix)).ok()?;
+    version.build = BuildMetadata::EMPTY;
+    Some(version)
+}
+
+/// How `a` orders against `b`, or `None` when either isn't a version and they differ.
+pub fn compare(a: &str, b: &str) -> Option<Ordering> {
+    match (parse(a), parse(b)) {
+        (Some(a), Some(b)) => Some(a.cmp(&b)),
+        _ if a.trim() == b.trim() => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+/// True when both tags name the same release (`v0.6.6` and `0.6.6`).
+pub fn same(a: &str, b: &str) -> bool {
+    compare(a, b) == Some(Ordering::Equal)
+}
+
+/// True when `latest` is a later release than `current`.
+pub fn is_newer(latest: &str, current: &str) -> bool {
+    compare(latest, current) == Some(Ordering::Greater)
+}